@@ -2,4 +2,14 @@ use anchor_lang::prelude::*;
 
 #[constant]
 pub const SEED: &str = "escrow";
-pub const ANCHOR_DISCREMINATOR: usize = 8;
\ No newline at end of file
+#[constant]
+pub const VESTING_SEED: &str = "vesting";
+pub const ANCHOR_DISCREMINATOR: usize = 8;
+#[constant]
+pub const CONFIG_SEED: &str = "config";
+pub const MAX_FEE_BPS: u16 = 1000; // 10% cap on the protocol fee
+#[constant]
+pub const BASKET_SEED: &str = "basket";
+pub const MAX_BASKET_ITEMS: usize = 5; // Most tokens a single basket escrow can offer
+#[constant]
+pub const MAKER_STATS_SEED: &str = "maker_stats";
@@ -0,0 +1,71 @@
+use anchor_lang::prelude::*;
+
+// Custom error types for our escrow program
+#[error_code]
+pub enum EscrowError {
+    #[msg("Vesting duration must be positive and at least as long as the cliff")]
+    InvalidVestingSchedule,
+    
+    #[msg("This escrow vests its tokens, use take_vesting")]
+    VestingEscrowRequiresVestingTake,
+    
+    #[msg("This escrow does not vest its tokens, use take")]
+    NotAVestingEscrow,
+    
+    #[msg("Vesting cliff has not been reached yet")]
+    CliffNotReached,
+    
+    #[msg("No vested tokens available to claim")]
+    NothingToClaim,
+    
+    #[msg("Vault holds less mint_a than the taker's minimum")]
+    ReceiveBelowMinimum,
+    
+    #[msg("Escrow or take deadline has passed")]
+    EscrowExpired,
+    
+    #[msg("Only the allowed taker can take this escrow")]
+    UnauthorizedTaker,
+    
+    #[msg("This escrow is paid in native SOL, use take_sol")]
+    NativeSolEscrowRequiresSolTake,
+    
+    #[msg("This escrow is not paid in native SOL, use take")]
+    NotANativeSolEscrow,
+    
+    #[msg("Protocol fee cannot exceed 10% (1000 bps)")]
+    FeeTooHigh,
+    
+    #[msg("Amount must be greater than zero")]
+    InvalidAmount,
+    
+    #[msg("Vault holds less than the maker's deposit")]
+    VaultBalanceMismatch,
+    
+    #[msg("Basket must offer 1 to 5 distinct tokens, each with a positive amount")]
+    InvalidBasket,
+    
+    #[msg("Remaining accounts do not match the basket's offered tokens")]
+    BasketAccountsMismatch,
+    
+    #[msg("Expiry must be zero or in the future")]
+    InvalidExpiry,
+    
+    #[msg("Escrow asks for more mint_b than the taker's maximum")]
+    SlippageExceeded,
+    
+    #[msg("A take is already waiting for the maker's confirmation")]
+    TakePending,
+    
+    #[msg("No take is waiting for confirmation")]
+    NoPendingTake,
+    
+    #[msg("Only the maker or the pending taker can cancel the take")]
+    UnauthorizedCancel,
+    
+    #[msg("Two-step takes are only supported on regular token escrows")]
+    TwoStepUnsupported,
+    
+    #[msg("Payment vault must be passed for two-step takes and only for them")]
+    PaymentVaultMismatch,
+}
@@ -0,0 +1,106 @@
+use anchor_lang::prelude::*;
+
+// Now we need token-related types
+use anchor_spl::token::{close_account, transfer, CloseAccount, Mint, Token, TokenAccount, Transfer};
+
+// Import our program's state and constants
+use crate::{constants::SEED, error::EscrowError, state::Escrow};
+
+// This struct defines what accounts the 'cancel_take' instruction needs
+#[derive(Accounts)]
+pub struct CancelTake<'info> {
+    // The maker rejecting the taker, or the taker withdrawing (must sign)
+    pub signer: Signer<'info>,
+    
+    // The taker who proposed the take (receives their payment and the payment vault's rent)
+    #[account(mut)]
+    pub taker: SystemAccount<'info>,
+    
+    // The escrow's maker (used to derive the escrow address)
+    pub maker: SystemAccount<'info>,
+    
+    // The token the taker paid with
+    pub mint_b: Account<'info, Mint>,
+    
+    // Taker's token account for mint_b (where the payment is returned)
+    #[account(
+        mut,                               // We'll transfer to here
+        associated_token::mint = mint_b,   // For mint_b tokens
+        associated_token::authority = taker, // Owned by taker
+    )]
+    pub taker_ata_b: Account<'info, TokenAccount>,
+    
+    // The escrow with a pending take (stays open for another taker)
+    #[account(
+        mut,                               // We'll clear the pending take
+        has_one = maker,                   // Verify this escrow belongs to this maker
+        has_one = mint_b,                  // Verify this escrow is for mint_b
+        constraint = escrow.pending_taker == Some(taker.key()) @ EscrowError::NoPendingTake,
+        constraint = escrow.can_cancel_take(&signer.key()) @ EscrowError::UnauthorizedCancel,
+        seeds = [SEED.as_bytes(), maker.key().as_ref(), escrow.seed.to_le_bytes().as_ref()],
+        bump = escrow.bump                 // Use the bump stored in escrow
+    )]
+    pub escrow: Account<'info, Escrow>,
+    
+    // The payment vault holding the taker's mint_b (will be closed and rent returned to taker)
+    #[account(
+        mut,                               // We'll transfer from and close this account
+        associated_token::mint = mint_b,   // Must be for mint_b
+        associated_token::authority = escrow, // Must be owned by escrow
+    )]
+    pub payment_vault: Account<'info, TokenAccount>,
+    
+    // Required programs for token operations
+    pub token_program: Program<'info, Token>,
+}
+
+// Implementation block for the CancelTake instruction
+impl<'info> CancelTake<'info> {
+    pub fn cancel_take(&mut self) -> Result<()> {
+        // Create signer seeds for the escrow PDA to authorize the transfers
+        let maker_key = self.maker.key();
+        let signer_seeds: &[&[&[u8]]] = &[&[
+            SEED.as_bytes(),
+            maker_key.as_ref(),
+            &self.escrow.seed.to_le_bytes(),
+            &[self.escrow.bump],
+        ]];
+
+        // Step 1: Return everything in the payment vault to the taker
+        // The whole balance, so stray tokens sent to the public vault can't block the close
+        let transfer_to_taker = Transfer {
+            from: self.payment_vault.to_account_info(),  // From the payment vault
+            to: self.taker_ata_b.to_account_info(),      // To taker's mint_b account
+            authority: self.escrow.to_account_info(),    // Escrow PDA authorizes
+        };
+
+        let ctx = CpiContext::new_with_signer(
+            self.token_program.to_account_info(),
+            transfer_to_taker,
+            signer_seeds,
+        );
+
+        transfer(ctx, self.payment_vault.amount)?;
+
+        // Step 2: Close the payment vault (return rent to taker)
+        let close_accounts = CloseAccount {
+            account: self.payment_vault.to_account_info(), // Account to close
+            destination: self.taker.to_account_info(),     // Where to send rent
+            authority: self.escrow.to_account_info(),      // Escrow PDA authorizes
+        };
+
+        let ctx = CpiContext::new_with_signer(
+            self.token_program.to_account_info(),
+            close_accounts,
+            signer_seeds,
+        );
+
+        close_account(ctx)?;
+
+        // Step 3: Reopen the escrow to new takers
+        self.escrow.pending_taker = None;
+        self.escrow.pending_payment = 0;
+
+        Ok(())
+    }
+}
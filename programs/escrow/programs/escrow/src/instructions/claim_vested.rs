@@ -0,0 +1,111 @@
+use anchor_lang::prelude::*;
+
+// Now we need token-related types
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token::{close_account, transfer, CloseAccount, Mint, Token, TokenAccount, Transfer},
+};
+
+// Import our program's state and constants
+use crate::{constants::VESTING_SEED, error::EscrowError, state::VestingEscrow};
+
+// This struct defines what accounts the 'claim_vested' instruction needs
+#[derive(Accounts)]
+pub struct ClaimVested<'info> {
+    // The taker claiming their unlocked tokens (must sign)
+    #[account(mut)] // mut because they may pay for their ATA and receive rent back
+    pub taker: Signer<'info>,
+    
+    // The token being vested
+    pub mint_a: Account<'info, Mint>,
+    
+    // Taker's token account for mint_a (where vested tokens are sent)
+    #[account(
+        init_if_needed,                    // Create if it doesn't exist
+        payer = taker,                     // Taker pays for creation
+        associated_token::mint = mint_a,   // For mint_a tokens
+        associated_token::authority = taker, // Owned by taker
+    )]
+    pub taker_ata_a: Account<'info, TokenAccount>,
+    
+    // The vesting schedule (closed once everything has been claimed)
+    #[account(
+        mut,
+        has_one = taker,                   // Only the taker can claim
+        has_one = mint_a,                  // Verify this schedule is for mint_a
+        seeds = [VESTING_SEED.as_bytes(), vesting_escrow.escrow.as_ref()],
+        bump = vesting_escrow.bump         // Use the bump stored in the schedule
+    )]
+    pub vesting_escrow: Account<'info, VestingEscrow>,
+    
+    // The vault holding the still-locked tokens
+    #[account(
+        mut,                               // We'll transfer from (and eventually close) this account
+        associated_token::mint = mint_a,   // Must be for mint_a
+        associated_token::authority = vesting_escrow, // Must be owned by the vesting PDA
+    )]
+    pub vesting_vault: Account<'info, TokenAccount>,
+    
+    // Required programs for token operations
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
+
+// Implementation block for the ClaimVested instruction
+impl<'info> ClaimVested<'info> {
+    pub fn claim_vested(&mut self) -> Result<()> {
+        let now = Clock::get()?.unix_timestamp;
+
+        // Step 1: Work out how much has unlocked since the last claim
+        require!(now >= self.vesting_escrow.cliff_time, EscrowError::CliffNotReached);
+
+        let claimable = self.vesting_escrow.claimable_amount(now);
+        require!(claimable > 0, EscrowError::NothingToClaim);
+
+        // Step 2: Transfer the unlocked tokens to the taker
+        let transfer_accounts = Transfer {
+            from: self.vesting_vault.to_account_info(),  // From vesting vault
+            to: self.taker_ata_a.to_account_info(),      // To taker's mint_a account
+            authority: self.vesting_escrow.to_account_info(), // Vesting PDA authorizes
+        };
+
+        let escrow_key = self.vesting_escrow.escrow;
+        let signer_seeds: &[&[&[u8]]] = &[&[
+            VESTING_SEED.as_bytes(),
+            escrow_key.as_ref(),
+            &[self.vesting_escrow.bump],
+        ]];
+
+        let ctx = CpiContext::new_with_signer(
+            self.token_program.to_account_info(),
+            transfer_accounts,
+            signer_seeds,
+        );
+
+        transfer(ctx, claimable)?;
+
+        self.vesting_escrow.claimed_amount += claimable;
+
+        // Step 3: Once everything is claimed, close the vault and schedule (rent to taker)
+        if self.vesting_escrow.is_fully_claimed() {
+            let close_accounts = CloseAccount {
+                account: self.vesting_vault.to_account_info(), // Account to close
+                destination: self.taker.to_account_info(),     // Where to send rent
+                authority: self.vesting_escrow.to_account_info(), // Vesting PDA authorizes
+            };
+
+            let ctx = CpiContext::new_with_signer(
+                self.token_program.to_account_info(),
+                close_accounts,
+                signer_seeds,
+            );
+
+            close_account(ctx)?;
+
+            self.vesting_escrow.close(self.taker.to_account_info())?;
+        }
+
+        Ok(())
+    }
+}
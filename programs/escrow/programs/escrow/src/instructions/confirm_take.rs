@@ -0,0 +1,220 @@
+use anchor_lang::prelude::*;
+
+// Now we need token-related types
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token::{close_account, transfer, CloseAccount, Mint, Token, TokenAccount, Transfer},
+};
+
+// Import our program's state and constants
+use crate::{
+    constants::{CONFIG_SEED, MAKER_STATS_SEED, SEED},
+    error::EscrowError,
+    instructions::quote_take,
+    state::{Config, Escrow, MakerStats},
+};
+
+// This struct defines what accounts the 'confirm_take' instruction needs
+#[derive(Accounts)]
+pub struct ConfirmTake<'info> {
+    // The original maker approving the pending taker (must sign)
+    #[account(mut)] // mut because they pay for any missing accounts and receive rent
+    pub maker: Signer<'info>,
+    
+    // The taker who proposed the take (receives mint_a and the payment vault's rent)
+    #[account(mut)]
+    pub taker: SystemAccount<'info>,
+    
+    // The token the maker offered (what taker will receive)
+    pub mint_a: Account<'info, Mint>,
+    
+    // The token the maker wants (what taker already paid into the payment vault)
+    pub mint_b: Account<'info, Mint>,
+    
+    // Taker's token account for mint_a (created when the take was proposed)
+    #[account(
+        mut,                               // We'll transfer to here
+        associated_token::mint = mint_a,   // For mint_a tokens
+        associated_token::authority = taker, // Owned by taker
+    )]
+    pub taker_ata_a: Account<'info, TokenAccount>,
+    
+    // Maker's token account for mint_b (where they'll receive payment)
+    #[account(
+        init_if_needed,                    // Create if it doesn't exist
+        payer = maker,                     // Maker pays for creation
+        associated_token::mint = mint_b,   // For mint_b tokens
+        associated_token::authority = maker, // Owned by maker
+    )]
+    pub maker_ata_b: Account<'info, TokenAccount>,
+    
+    // Global protocol settings (fee rate and who collects it)
+    #[account(
+        seeds = [CONFIG_SEED.as_bytes()],
+        bump = config.bump                 // Use the bump stored in config
+    )]
+    pub config: Account<'info, Config>,
+    
+    // The marketplace operator collecting the protocol fee
+    #[account(address = config.authority)] // Must be the config authority
+    pub fee_collector: SystemAccount<'info>,
+    
+    // Fee collector's token account for mint_b (where the protocol fee goes)
+    #[account(
+        init_if_needed,                    // Create if it doesn't exist
+        payer = maker,                     // Maker pays for creation
+        associated_token::mint = mint_b,   // For mint_b tokens
+        associated_token::authority = fee_collector, // Owned by fee collector
+    )]
+    pub fee_collector_ata_b: Account<'info, TokenAccount>,
+    
+    // The escrow with a pending take (will be closed and rent returned to maker)
+    #[account(
+        mut,                               // We'll close this account
+        close = maker,                     // Return rent to maker
+        has_one = maker,                   // Verify this escrow belongs to this maker
+        has_one = mint_a,                  // Verify this escrow is for mint_a
+        has_one = mint_b,                  // Verify this escrow is for mint_b
+        constraint = escrow.pending_taker == Some(taker.key()) @ EscrowError::NoPendingTake,
+        seeds = [SEED.as_bytes(), maker.key().as_ref(), escrow.seed.to_le_bytes().as_ref()],
+        bump = escrow.bump                 // Use the bump stored in escrow
+    )]
+    pub escrow: Account<'info, Escrow>,
+    
+    // The existing vault (will be closed and rent returned to maker)
+    #[account(
+        mut,                               // We'll transfer from and close this account
+        associated_token::mint = mint_a,   // Must be for mint_a
+        associated_token::authority = escrow, // Must be owned by escrow
+    )]
+    pub vault: Account<'info, TokenAccount>,
+    
+    // The payment vault holding the taker's mint_b (will be closed and rent returned to taker)
+    #[account(
+        mut,                               // We'll transfer from and close this account
+        associated_token::mint = mint_b,   // Must be for mint_b
+        associated_token::authority = escrow, // Must be owned by escrow
+    )]
+    pub payment_vault: Account<'info, TokenAccount>,
+    
+    // The maker's escrow counters (open_count drops once this escrow closes)
+    #[account(
+        mut,
+        seeds = [MAKER_STATS_SEED.as_bytes(), maker.key().as_ref()],
+        bump = maker_stats.bump
+    )]
+    pub maker_stats: Account<'info, MakerStats>,
+    
+    // Required programs for token operations
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
+
+// Implementation block for the ConfirmTake instruction
+impl<'info> ConfirmTake<'info> {
+    pub fn confirm_take(&mut self) -> Result<()> {
+        // The vault must still hold the maker's deposit (stray extra tokens go to the taker)
+        require!(
+            self.escrow.vault_covers_deposit(self.vault.amount),
+            EscrowError::VaultBalanceMismatch
+        );
+
+        // The taker pays what they escrowed, not the current receive amount
+        let (payout, _) = quote_take(&self.escrow, self.vault.amount);
+        let payment = self.escrow.pending_payment;
+        let fee = self.config.fee_for(payment);
+
+        // Every transfer out of the escrow's vaults is signed by the escrow PDA
+        let maker_key = self.maker.key();
+        let signer_seeds: &[&[&[u8]]] = &[&[
+            SEED.as_bytes(),
+            maker_key.as_ref(),
+            &self.escrow.seed.to_le_bytes(),
+            &[self.escrow.bump],
+        ]];
+
+        // Step 1: Transfer the protocol fee from the payment vault to fee collector
+        if fee > 0 {
+            let transfer_fee = Transfer {
+                from: self.payment_vault.to_account_info(),       // From the payment vault
+                to: self.fee_collector_ata_b.to_account_info(),   // To fee collector's mint_b account
+                authority: self.escrow.to_account_info(),         // Escrow PDA authorizes
+            };
+
+            let ctx = CpiContext::new_with_signer(
+                self.token_program.to_account_info(),
+                transfer_fee,
+                signer_seeds,
+            );
+
+            transfer(ctx, fee)?;
+        }
+
+        // Step 2: Release the payment, minus the protocol fee, to the maker
+        // Sends the vault's whole balance, so stray tokens sent to it can't block the close
+        let transfer_to_maker = Transfer {
+            from: self.payment_vault.to_account_info(),  // From the payment vault
+            to: self.maker_ata_b.to_account_info(),      // To maker's mint_b account
+            authority: self.escrow.to_account_info(),    // Escrow PDA authorizes
+        };
+
+        let ctx = CpiContext::new_with_signer(
+            self.token_program.to_account_info(),
+            transfer_to_maker,
+            signer_seeds,
+        );
+
+        transfer(ctx, self.payment_vault.amount - fee)?;
+
+        // Step 3: Transfer mint_a tokens from vault to taker (delivery)
+        let transfer_to_taker = Transfer {
+            from: self.vault.to_account_info(),          // From vault
+            to: self.taker_ata_a.to_account_info(),      // To taker's mint_a account
+            authority: self.escrow.to_account_info(),    // Escrow PDA authorizes
+        };
+
+        let ctx = CpiContext::new_with_signer(
+            self.token_program.to_account_info(),
+            transfer_to_taker,
+            signer_seeds,
+        );
+
+        transfer(ctx, payout)?;
+
+        // Step 4: Close both vaults (rent goes back to whoever paid for each)
+        let close_vault = CloseAccount {
+            account: self.vault.to_account_info(),       // Account to close
+            destination: self.maker.to_account_info(),   // Where to send rent
+            authority: self.escrow.to_account_info(),    // Escrow PDA authorizes
+        };
+
+        let ctx = CpiContext::new_with_signer(
+            self.token_program.to_account_info(),
+            close_vault,
+            signer_seeds,
+        );
+
+        close_account(ctx)?;
+
+        let close_payment_vault = CloseAccount {
+            account: self.payment_vault.to_account_info(), // Account to close
+            destination: self.taker.to_account_info(),     // Where to send rent
+            authority: self.escrow.to_account_info(),      // Escrow PDA authorizes
+        };
+
+        let ctx = CpiContext::new_with_signer(
+            self.token_program.to_account_info(),
+            close_payment_vault,
+            signer_seeds,
+        );
+
+        close_account(ctx)?;
+
+        // The escrow no longer counts as open for the maker
+        self.maker_stats.record_close();
+
+        // Note: The escrow account is closed automatically due to the 'close' constraint
+        Ok(())
+    }
+}
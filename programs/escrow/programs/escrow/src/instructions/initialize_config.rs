@@ -0,0 +1,44 @@
+use anchor_lang::prelude::*;
+
+// Import our program's state and constants
+use crate::{
+    constants::{CONFIG_SEED, MAX_FEE_BPS},
+    error::EscrowError,
+    state::Config,
+};
+
+// This struct defines what accounts the 'initialize_config' instruction needs
+#[derive(Accounts)]
+pub struct InitializeConfig<'info> {
+    // The marketplace operator (collects fees, must sign the transaction)
+    #[account(mut)] // mut because they pay for the config account
+    pub authority: Signer<'info>,
+
+    // Global protocol settings (PDA, only one per program)
+    #[account(
+        init,                    // Create a new account
+        payer = authority,       // Authority pays for account creation
+        space = 8 + Config::INIT_SPACE, // Size: 8 bytes (discriminator) + our struct size
+        seeds = [CONFIG_SEED.as_bytes()],
+        bump                     // Anchor finds the bump for us
+    )]
+    pub config: Account<'info, Config>,
+
+    pub system_program: Program<'info, System>,
+}
+
+// Implementation block for the InitializeConfig instruction
+impl<'info> InitializeConfig<'info> {
+    pub fn initialize_config(&mut self, fee_bps: u16, bumps: &InitializeConfigBumps) -> Result<()> {
+        // Cap the fee so takers can't be charged more than 10%
+        require!(fee_bps <= MAX_FEE_BPS, EscrowError::FeeTooHigh);
+
+        self.config.set_inner(Config {
+            authority: self.authority.key(), // Who receives the protocol fee
+            fee_bps,                         // Fee taken from every take (basis points)
+            bump: bumps.config,              // PDA bump for security
+        });
+
+        Ok(())
+    }
+}
@@ -7,7 +7,11 @@ use anchor_spl::{
 };
 
 // Import our program's state and constants
-use crate::{constants::SEED, state::Escrow};
+use crate::{
+    constants::{MAKER_STATS_SEED, SEED},
+    error::EscrowError,
+    state::{Escrow, MakerStats},
+};
 
 // This struct defines what accounts the 'make' instruction needs
 #[derive(Accounts)]
@@ -41,6 +45,16 @@ pub struct Make<'info> {
     )]
     pub escrow: Account<'info, Escrow>,
     
+    // Per-maker counters of open and created escrows (PDA, created on the maker's first escrow)
+    #[account(
+        init_if_needed,          // Create on the maker's first escrow
+        payer = maker,          // Maker pays for account creation
+        space = 8 + MakerStats::INIT_SPACE,
+        seeds = [MAKER_STATS_SEED.as_bytes(), maker.key().as_ref()],
+        bump
+    )]
+    pub maker_stats: Account<'info, MakerStats>,
+    
     // The vault that will hold the deposited tokens (owned by escrow PDA)
     #[account(
         init,                           // Create new token account
@@ -58,7 +72,14 @@ pub struct Make<'info> {
 
 // Implementation block for the Make instruction
 impl<'info> Make<'info> {
-    pub fn make(&mut self, seed: u64, receive: u64, deposit: u64, bumps: &MakeBumps) -> Result<()> {
+    pub fn make(
+        &mut self,
+        seed: u64,
+        receive: u64,
+        deposit: u64,
+        allowed_taker: Option<Pubkey>,
+        bumps: &MakeBumps,
+    ) -> Result<()> {
         // Step 1: Initialize the escrow account with trade details
         self.escrow.set_inner(Escrow {
             seed,                           // User-provided seed
@@ -66,10 +87,24 @@ impl<'info> Make<'info> {
             mint_a: self.mint_a.key(),     // Token they're offering
             mint_b: self.mint_b.key(),     // Token they want
             receive,                       // Amount of mint_b they want
+            deposit,                       // Amount of mint_a going into the vault
             bump: bumps.escrow,           // PDA bump for security
+            cliff_duration: 0,            // No vesting for a regular escrow
+            vesting_duration: 0,
+            allowed_taker,                 // Optional private taker for OTC deals
+            receive_is_native: false,      // Taker pays in mint_b
+            expires_at: 0,                 // Open until refunded (see set_expiry)
+            two_step: false,               // Takes complete immediately (see set_two_step)
+            pending_taker: None,
+            pending_payment: 0,
         });
 
-        // Step 2: Transfer tokens from maker to vault
+        // Step 2: Count the new escrow in the maker's stats
+        self.maker_stats.maker = self.maker.key();
+        self.maker_stats.bump = bumps.maker_stats;
+        self.maker_stats.record_make();
+
+        // Step 3: Transfer tokens from maker to vault
         let transfer_accounts = Transfer {
             from: self.maker_ata_a.to_account_info(),  // From maker's token account
             to: self.vault.to_account_info(),          // To vault
@@ -84,4 +119,28 @@ impl<'info> Make<'info> {
         // Execute the transfer
         transfer(ctx, deposit)
     }
+
+    pub fn make_vesting(
+        &mut self,
+        seed: u64,
+        receive: u64,
+        deposit: u64,
+        cliff_duration: i64,
+        vesting_duration: i64,
+        bumps: &MakeBumps,
+    ) -> Result<()> {
+        // The cliff must fall inside the vesting window
+        require!(
+            vesting_duration > 0 && cliff_duration >= 0 && cliff_duration <= vesting_duration,
+            EscrowError::InvalidVestingSchedule
+        );
+
+        // Same setup as a regular escrow, then attach the vesting schedule
+        self.make(seed, receive, deposit, None, bumps)?;
+
+        self.escrow.cliff_duration = cliff_duration;
+        self.escrow.vesting_duration = vesting_duration;
+
+        Ok(())
+    }
 }
\ No newline at end of file
@@ -0,0 +1,165 @@
+use anchor_lang::prelude::*;
+
+// Now we need token-related types
+use anchor_spl::{
+    associated_token::{create, get_associated_token_address, AssociatedToken, Create},
+    token::{transfer, Mint, Token, Transfer},
+};
+
+// Import our program's state and constants
+use crate::{
+    constants::{BASKET_SEED, MAX_BASKET_ITEMS},
+    error::EscrowError,
+    state::{BasketEscrow, BasketItem},
+};
+
+// This struct defines what accounts the 'make_basket' instruction needs
+// Each offered token's mint, maker account and vault are passed as remaining accounts
+#[derive(Accounts)]
+#[instruction(seed: u64)] // This instruction takes a seed parameter
+pub struct MakeBasket<'info> {
+    // The person creating the basket escrow (must sign the transaction)
+    #[account(mut)] // mut = mutable, because we'll deduct SOL for account creation
+    pub maker: Signer<'info>,
+
+    // The token the maker wants in return for the whole basket
+    pub mint_b: Account<'info, Mint>,
+
+    // The basket escrow account that stores the offer (PDA)
+    #[account(
+        init,                    // Create a new account
+        payer = maker,          // Maker pays for account creation
+        space = 8 + BasketEscrow::INIT_SPACE, // Size: 8 bytes (discriminator) + our struct size
+        seeds = [BASKET_SEED.as_bytes(), maker.key().as_ref(), seed.to_le_bytes().as_ref()],
+        bump                    // Anchor finds the bump for us
+    )]
+    pub basket_escrow: Account<'info, BasketEscrow>,
+
+    // Required programs for token operations
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
+
+// Implementation block for the MakeBasket instruction
+impl<'info> MakeBasket<'info> {
+    pub fn make_basket(
+        &mut self,
+        seed: u64,
+        receive: u64,
+        offered: Vec<BasketItem>,
+        bumps: &MakeBasketBumps,
+        item_accounts: &'info [AccountInfo<'info>],
+    ) -> Result<()> {
+        require!(receive > 0, EscrowError::InvalidAmount);
+        validate_basket(&offered)?;
+
+        // Three accounts per offered token: [mint, maker_ata, vault]
+        require!(item_accounts.len() == offered.len() * 3, EscrowError::BasketAccountsMismatch);
+
+        // Step 1: Initialize the basket escrow with the offer details
+        self.basket_escrow.set_inner(BasketEscrow {
+            seed,                           // User-provided seed
+            maker: self.maker.key(),       // Who created this escrow
+            mint_b: self.mint_b.key(),     // Token they want
+            receive,                       // Amount of mint_b they want
+            offered: offered.clone(),      // Every token going into a vault
+            bump: bumps.basket_escrow,    // PDA bump for security
+        });
+
+        // Step 2: Create a vault for each token and move the maker's deposit into it
+        let basket_key = self.basket_escrow.key();
+        for (item, accounts) in offered.iter().zip(item_accounts.chunks(3)) {
+            let (mint, maker_ata, vault) = (&accounts[0], &accounts[1], &accounts[2]);
+
+            // The vault is the basket escrow's associated token account for this mint
+            require_keys_eq!(mint.key(), item.mint, EscrowError::BasketAccountsMismatch);
+            require_keys_eq!(
+                vault.key(),
+                get_associated_token_address(&basket_key, &item.mint),
+                EscrowError::BasketAccountsMismatch
+            );
+
+            let create_vault = Create {
+                payer: self.maker.to_account_info(),                 // Maker pays for the vault
+                associated_token: vault.clone(),                     // The vault being created
+                authority: self.basket_escrow.to_account_info(),     // Owned by the basket PDA
+                mint: mint.clone(),
+                system_program: self.system_program.to_account_info(),
+                token_program: self.token_program.to_account_info(),
+            };
+
+            create(CpiContext::new(
+                self.associated_token_program.to_account_info(),
+                create_vault,
+            ))?;
+
+            // The token program rejects a source that isn't the maker's account for this mint
+            let transfer_accounts = Transfer {
+                from: maker_ata.clone(),                     // From maker's token account
+                to: vault.clone(),                           // To this token's vault
+                authority: self.maker.to_account_info(),     // Maker authorizes
+            };
+
+            let ctx = CpiContext::new(
+                self.token_program.to_account_info(),
+                transfer_accounts,
+            );
+
+            transfer(ctx, item.amount)?;
+        }
+
+        Ok(())
+    }
+}
+
+// A basket offers 1 to MAX_BASKET_ITEMS distinct tokens, each with a positive amount
+// Mints must be distinct because each one gets the basket's single vault for that mint
+pub fn validate_basket(offered: &[BasketItem]) -> Result<()> {
+    require!(
+        !offered.is_empty() && offered.len() <= MAX_BASKET_ITEMS,
+        EscrowError::InvalidBasket
+    );
+
+    for (index, item) in offered.iter().enumerate() {
+        require!(item.amount > 0, EscrowError::InvalidBasket);
+        require!(
+            offered[..index].iter().all(|other| other.mint != item.mint),
+            EscrowError::InvalidBasket
+        );
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn item(mint: Pubkey, amount: u64) -> BasketItem {
+        BasketItem { mint, amount }
+    }
+
+    #[test]
+    fn test_valid_basket() {
+        let basket = [item(Pubkey::new_unique(), 100), item(Pubkey::new_unique(), 200)];
+
+        assert!(validate_basket(&basket).is_ok());
+    }
+
+    #[test]
+    fn test_invalid_baskets() {
+        let mint = Pubkey::new_unique();
+
+        // Empty, zero amount and duplicate mint
+        assert!(validate_basket(&[]).is_err());
+        assert!(validate_basket(&[item(mint, 0)]).is_err());
+        assert!(validate_basket(&[item(mint, 100), item(mint, 200)]).is_err());
+
+        // One more token than a basket can hold
+        let too_many: Vec<BasketItem> = (0..=MAX_BASKET_ITEMS)
+            .map(|_| item(Pubkey::new_unique(), 100))
+            .collect();
+        assert!(validate_basket(&too_many).is_err());
+    }
+}
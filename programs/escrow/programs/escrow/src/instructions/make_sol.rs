@@ -0,0 +1,112 @@
+use anchor_lang::prelude::*;
+
+// Now we need token-related types
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token::{spl_token::native_mint, transfer, Mint, Token, TokenAccount, Transfer},
+};
+
+// Import our program's state and constants
+use crate::{
+    constants::{MAKER_STATS_SEED, SEED},
+    state::{Escrow, MakerStats},
+};
+
+// This struct defines what accounts the 'make_sol' instruction needs
+// Same as 'make', but the maker asks for native SOL so there is no mint_b
+#[derive(Accounts)]
+#[instruction(seed: u64)] // This instruction takes a seed parameter
+pub struct MakeSol<'info> {
+    // The person creating the escrow (must sign the transaction)
+    #[account(mut)] // mut = mutable, because we'll deduct SOL for account creation
+    pub maker: Signer<'info>,
+
+    // The token the maker is offering (e.g., USDC)
+    pub mint_a: Account<'info, Mint>,
+
+    // The maker's token account for mint_a (where they currently hold their tokens)
+    #[account(
+        mut,                           // We'll transfer tokens from here
+        associated_token::mint = mint_a,  // Must be for mint_a
+        associated_token::authority = maker, // Must be owned by maker
+    )]
+    pub maker_ata_a: Account<'info, TokenAccount>,
+
+    // The escrow account that stores our trade details (PDA)
+    #[account(
+        init,                    // Create a new account
+        payer = maker,          // Maker pays for account creation
+        space = 8 + Escrow::INIT_SPACE, // Size: 8 bytes (discriminator) + our struct size
+        seeds = [SEED.as_bytes(), maker.key().as_ref(), seed.to_le_bytes().as_ref()],
+        bump                    // Anchor finds the bump for us
+    )]
+    pub escrow: Account<'info, Escrow>,
+
+    // Per-maker counters of open and created escrows (PDA, created on the maker's first escrow)
+    #[account(
+        init_if_needed,          // Create on the maker's first escrow
+        payer = maker,          // Maker pays for account creation
+        space = 8 + MakerStats::INIT_SPACE,
+        seeds = [MAKER_STATS_SEED.as_bytes(), maker.key().as_ref()],
+        bump
+    )]
+    pub maker_stats: Account<'info, MakerStats>,
+
+    // The vault that will hold the deposited tokens (owned by escrow PDA)
+    #[account(
+        init,                           // Create new token account
+        payer = maker,                 // Maker pays for creation
+        associated_token::mint = mint_a,   // For mint_a tokens
+        associated_token::authority = escrow, // Owned by escrow PDA
+    )]
+    pub vault: Account<'info, TokenAccount>,
+
+    // Required programs for token operations
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
+
+// Implementation block for the MakeSol instruction
+impl<'info> MakeSol<'info> {
+    pub fn make_sol(&mut self, seed: u64, receive_lamports: u64, deposit: u64, bumps: &MakeSolBumps) -> Result<()> {
+        // Step 1: Initialize the escrow account with trade details
+        self.escrow.set_inner(Escrow {
+            seed,                           // User-provided seed
+            maker: self.maker.key(),       // Who created this escrow
+            mint_a: self.mint_a.key(),     // Token they're offering
+            mint_b: native_mint::ID,       // Native SOL (no SPL mint is transferred)
+            receive: receive_lamports,     // Lamports they want
+            deposit,                       // Amount of mint_a going into the vault
+            bump: bumps.escrow,           // PDA bump for security
+            cliff_duration: 0,            // No vesting for a SOL escrow
+            vesting_duration: 0,
+            allowed_taker: None,           // Anyone may take
+            receive_is_native: true,       // Taker pays with take_sol
+            expires_at: 0,                 // Open until refunded (see set_expiry)
+            two_step: false,               // Takes complete immediately (see set_two_step)
+            pending_taker: None,
+            pending_payment: 0,
+        });
+
+        // Step 2: Count the new escrow in the maker's stats
+        self.maker_stats.maker = self.maker.key();
+        self.maker_stats.bump = bumps.maker_stats;
+        self.maker_stats.record_make();
+
+        // Step 3: Transfer tokens from maker to vault
+        let transfer_accounts = Transfer {
+            from: self.maker_ata_a.to_account_info(),    // From maker's account
+            to: self.vault.to_account_info(),            // To vault
+            authority: self.maker.to_account_info(),     // Maker authorizes
+        };
+
+        let ctx = CpiContext::new(
+            self.token_program.to_account_info(),
+            transfer_accounts,
+        );
+
+        // Execute the transfer
+        transfer(ctx, deposit)
+    }
+}
@@ -5,8 +5,32 @@
 pub mod make;   // ✅ Implemented!
 pub mod take;   // ✅ Implemented!
 pub mod refund; // ✅ Implemented!
+pub mod take_vesting;
+pub mod claim_vested;
+pub mod make_sol;
+pub mod take_sol;
+pub mod initialize_config;
+pub mod update_escrow;
+pub mod quote;
+pub mod make_basket;
+pub mod take_basket;
+pub mod refund_basket;
+pub mod confirm_take;
+pub mod cancel_take;
 
 // And re-export them for easy access:
 pub use make::*;   // ✅ Exported!
 pub use take::*;   // ✅ Exported!
-pub use refund::*; // ✅ Exported!
\ No newline at end of file
+pub use refund::*; // ✅ Exported!
+pub use take_vesting::*;
+pub use claim_vested::*;
+pub use make_sol::*;
+pub use take_sol::*;
+pub use initialize_config::*;
+pub use update_escrow::*;
+pub use quote::*;
+pub use make_basket::*;
+pub use take_basket::*;
+pub use refund_basket::*;
+pub use confirm_take::*;
+pub use cancel_take::*;
\ No newline at end of file
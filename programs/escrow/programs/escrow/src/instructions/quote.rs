@@ -0,0 +1,173 @@
+// Import our program's state
+use crate::state::Escrow;
+
+// Preview of a take: what the taker gets and what they pay
+// Returns (mint_a payout, mint_b cost) so on-chain take and off-chain clients agree
+// The whole vault is always delivered and the taker always pays the full receive amount
+// (before any protocol fee); take itself only proceeds when the vault covers the deposit
+pub fn quote_take(escrow: &Escrow, vault_amount: u64) -> (u64, u64) {
+    (vault_amount, escrow.receive)
+}
+
+// What a wallet should show for an escrow right now
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum EscrowStatus {
+    Fillable,       // A take would go through
+    Expired,        // Past the maker's expiry, only a refund is possible
+    Empty,          // The vault holds nothing to take
+    Pending,        // A two-step take is waiting on the maker, no other take is accepted
+    VaultShort,     // The vault holds less than the deposit, takes are rejected
+}
+
+// Single source of truth for whether an escrow can be taken
+// Mirrors the checks take makes before moving any tokens
+pub fn escrow_status(escrow: &Escrow, vault_amount: u64, current_time: i64) -> EscrowStatus {
+    if vault_amount == 0 {
+        EscrowStatus::Empty
+    } else if escrow.has_pending_take() {
+        EscrowStatus::Pending
+    } else if escrow.is_expired(current_time) {
+        EscrowStatus::Expired
+    } else if !escrow.vault_covers_deposit(vault_amount) {
+        EscrowStatus::VaultShort
+    } else {
+        EscrowStatus::Fillable
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use anchor_lang::prelude::Pubkey;
+
+    use super::*;
+
+    fn mock_escrow(receive: u64) -> Escrow {
+        Escrow {
+            seed: 42,
+            maker: Pubkey::new_unique(),
+            mint_a: Pubkey::new_unique(),
+            mint_b: Pubkey::new_unique(),
+            receive,
+            deposit: 500_000_000,
+            bump: 255,
+            cliff_duration: 0,
+            vesting_duration: 0,
+            allowed_taker: None,
+            receive_is_native: false,
+            expires_at: 0,
+            two_step: false,
+            pending_taker: None,
+            pending_payment: 0,
+        }
+    }
+
+    #[test]
+    fn test_quote_exact_fill() {
+        let escrow = mock_escrow(1_000_000_000);
+
+        // Vault holds exactly what the maker deposited
+        let (payout, cost) = quote_take(&escrow, 500_000_000);
+
+        assert_eq!(payout, 500_000_000);
+        assert_eq!(cost, 1_000_000_000);
+    }
+
+    #[test]
+    fn test_quote_partial_vault() {
+        let escrow = mock_escrow(1_000_000_000);
+
+        // Vault holds less than the original deposit: taker still gets all of it
+        // but pays the full receive amount, so clients should check min_receive
+        let (payout, cost) = quote_take(&escrow, 200_000_000);
+
+        assert_eq!(payout, 200_000_000);
+        assert_eq!(cost, 1_000_000_000);
+    }
+
+    #[test]
+    fn test_quote_empty_vault() {
+        let escrow = mock_escrow(1_000_000_000);
+
+        let (payout, cost) = quote_take(&escrow, 0);
+
+        assert_eq!(payout, 0);
+        assert_eq!(cost, 1_000_000_000);
+    }
+
+    #[test]
+    fn test_vault_must_cover_deposit() {
+        let escrow = mock_escrow(1_000_000_000);
+
+        assert!(escrow.vault_covers_deposit(500_000_000));
+
+        // Externally credited vaults can still be taken, the extra goes to the taker
+        assert!(escrow.vault_covers_deposit(500_000_001));
+        assert_eq!(quote_take(&escrow, 500_000_001).0, 500_000_001);
+
+        // Short vaults are rejected by take
+        assert!(!escrow.vault_covers_deposit(499_999_999));
+    }
+
+    #[test]
+    fn test_quote_tracks_updated_receive() {
+        let mut escrow = mock_escrow(1_000_000_000);
+        escrow.receive = 800_000_000;
+
+        let (_, cost) = quote_take(&escrow, 500_000_000);
+
+        assert_eq!(cost, 800_000_000);
+    }
+
+    #[test]
+    fn test_status_fillable() {
+        let escrow = mock_escrow(1_000_000_000);
+
+        assert_eq!(escrow_status(&escrow, 500_000_000, 1_000), EscrowStatus::Fillable);
+    }
+
+    #[test]
+    fn test_status_expired() {
+        let mut escrow = mock_escrow(1_000_000_000);
+        escrow.expires_at = 1_000;
+
+        // Fillable right up to the expiry, expired from it on
+        assert_eq!(escrow_status(&escrow, 500_000_000, 999), EscrowStatus::Fillable);
+        assert_eq!(escrow_status(&escrow, 500_000_000, 1_000), EscrowStatus::Expired);
+
+        // No expiry means never expired
+        escrow.expires_at = 0;
+        assert_eq!(escrow_status(&escrow, 500_000_000, i64::MAX), EscrowStatus::Fillable);
+    }
+
+    #[test]
+    fn test_status_empty_and_short_vault() {
+        let mut escrow = mock_escrow(1_000_000_000);
+        escrow.expires_at = 1_000;
+
+        // An empty vault has nothing to take, expired or not
+        assert_eq!(escrow_status(&escrow, 0, 500), EscrowStatus::Empty);
+        assert_eq!(escrow_status(&escrow, 0, 2_000), EscrowStatus::Empty);
+
+        assert_eq!(escrow_status(&escrow, 499_999_999, 500), EscrowStatus::VaultShort);
+        assert_eq!(escrow_status(&escrow, 500_000_001, 500), EscrowStatus::Fillable);
+    }
+
+    #[test]
+    fn test_status_pending_two_step_take() {
+        let mut escrow = mock_escrow(1_000_000_000);
+        escrow.two_step = true;
+
+        // Two-step escrows are fillable until someone proposes a take
+        assert_eq!(escrow_status(&escrow, 500_000_000, 1_000), EscrowStatus::Fillable);
+
+        let taker = Pubkey::new_unique();
+        escrow.pending_taker = Some(taker);
+        escrow.pending_payment = 1_000_000_000;
+        assert_eq!(escrow_status(&escrow, 500_000_000, 1_000), EscrowStatus::Pending);
+
+        // Only the maker or the pending taker can back out
+        assert!(escrow.can_cancel_take(&escrow.maker));
+        assert!(escrow.can_cancel_take(&taker));
+        assert!(!escrow.can_cancel_take(&Pubkey::new_unique()));
+    }
+}
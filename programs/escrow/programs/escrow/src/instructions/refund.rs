@@ -7,7 +7,11 @@ use anchor_spl::{
 };
 
 // Import our program's state and constants
-use crate::{constants::SEED, state::Escrow};
+use crate::{
+    constants::{MAKER_STATS_SEED, SEED},
+    error::EscrowError,
+    state::{Escrow, MakerStats},
+};
 
 // This struct defines what accounts the 'refund' instruction needs
 #[derive(Accounts)]
@@ -46,6 +50,14 @@ pub struct Refund<'info> {
     )]
     pub vault: Account<'info, TokenAccount>,
     
+    // The maker's escrow counters (open_count drops once this escrow closes)
+    #[account(
+        mut,
+        seeds = [MAKER_STATS_SEED.as_bytes(), maker.key().as_ref()],
+        bump = maker_stats.bump
+    )]
+    pub maker_stats: Account<'info, MakerStats>,
+    
     // Required programs for token operations
     pub associated_token_program: Program<'info, AssociatedToken>,
     pub token_program: Program<'info, Token>,
@@ -55,6 +67,9 @@ pub struct Refund<'info> {
 // Implementation block for the Refund instruction
 impl<'info> Refund<'info> {
     pub fn refund(&mut self) -> Result<()> {
+        // A pending taker's payment must be settled with confirm_take or cancel_take first
+        require!(!self.escrow.has_pending_take(), EscrowError::TakePending);
+
         // Step 1: Transfer tokens from vault back to maker
         let transfer_accounts = Transfer {
             from: self.vault.to_account_info(),          // From vault
@@ -93,7 +108,12 @@ impl<'info> Refund<'info> {
             signer_seeds,
         );
 
-        close_account(ctx)
+        close_account(ctx)?;
+
+        // The escrow no longer counts as open for the maker
+        self.maker_stats.record_close();
+
         // Note: The escrow account is closed automatically due to the 'close' constraint
+        Ok(())
     }
 }
\ No newline at end of file
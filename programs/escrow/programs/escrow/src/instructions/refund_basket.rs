@@ -0,0 +1,48 @@
+use anchor_lang::prelude::*;
+
+// Now we need token-related types
+use anchor_spl::{associated_token::AssociatedToken, token::Token};
+
+// Import our program's state and constants
+use crate::{constants::BASKET_SEED, instructions::release_basket, state::BasketEscrow};
+
+// This struct defines what accounts the 'refund_basket' instruction needs
+// Each offered token's mint, vault and maker account are passed as remaining accounts
+#[derive(Accounts)]
+pub struct RefundBasket<'info> {
+    // The original maker (must sign to prove ownership)
+    #[account(mut)] // mut because they'll receive SOL when accounts are closed
+    pub maker: Signer<'info>,
+
+    // The existing basket escrow (will be closed and rent returned to maker)
+    #[account(
+        mut,                               // We'll close this account
+        close = maker,                     // Return rent to maker
+        has_one = maker,                   // Verify this escrow belongs to this maker
+        seeds = [BASKET_SEED.as_bytes(), maker.key().as_ref(), basket_escrow.seed.to_le_bytes().as_ref()],
+        bump = basket_escrow.bump          // Use the bump stored in the basket escrow
+    )]
+    pub basket_escrow: Account<'info, BasketEscrow>,
+
+    // Required programs for token operations
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
+
+// Implementation block for the RefundBasket instruction
+impl<'info> RefundBasket<'info> {
+    pub fn refund_basket(&mut self, item_accounts: &'info [AccountInfo<'info>]) -> Result<()> {
+        // Return every vault's full balance to the maker and close the vaults
+        release_basket(
+            &self.basket_escrow,
+            &self.maker.to_account_info(),
+            &self.maker.to_account_info(),
+            item_accounts,
+            &self.associated_token_program,
+            &self.token_program,
+            &self.system_program,
+        )
+        // Note: The basket escrow account is closed automatically due to the 'close' constraint
+    }
+}
@@ -7,7 +7,20 @@ use anchor_spl::{
 };
 
 // Import our program's state and constants
-use crate::{constants::SEED, state::Escrow};
+use crate::{
+    constants::{CONFIG_SEED, MAKER_STATS_SEED, SEED},
+    error::EscrowError,
+    instructions::quote_take,
+    state::{Config, Escrow, MakerStats},
+};
+
+// Protections the taker can enforce on the swap (like a DEX swap's slippage and deadline)
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug)]
+pub struct TakeParams {
+    pub min_receive: u64, // Minimum amount of mint_a the taker will accept
+    pub deadline: i64,    // Unix timestamp after which the take is rejected
+    pub max_pay: u64,     // Most mint_b the taker will pay (guards against a last-second update_escrow)
+}
 
 // This struct defines what accounts the 'take' instruction needs
 #[derive(Accounts)]
@@ -52,10 +65,29 @@ pub struct Take<'info> {
     )]
     pub maker_ata_b: Account<'info, TokenAccount>,
     
-    // The existing escrow account (will be closed and rent returned to maker)
+    // Global protocol settings (fee rate and who collects it)
+    #[account(
+        seeds = [CONFIG_SEED.as_bytes()],
+        bump = config.bump                 // Use the bump stored in config
+    )]
+    pub config: Account<'info, Config>,
+    
+    // The marketplace operator collecting the protocol fee
+    #[account(address = config.authority)] // Must be the config authority
+    pub fee_collector: SystemAccount<'info>,
+    
+    // Fee collector's token account for mint_b (where the protocol fee goes)
     #[account(
-        mut,                               // We'll close this account
-        close = maker,                     // Return rent to maker
+        init_if_needed,                    // Create if it doesn't exist
+        payer = taker,                     // Taker pays for creation
+        associated_token::mint = mint_b,   // For mint_b tokens
+        associated_token::authority = fee_collector, // Owned by fee collector
+    )]
+    pub fee_collector_ata_b: Account<'info, TokenAccount>,
+    
+    // The existing escrow account (closed and rent returned to maker, unless the take is two-step)
+    #[account(
+        mut,                               // We'll close or update this account
         has_one = maker,                   // Verify this escrow belongs to this maker
         has_one = mint_a,                  // Verify this escrow is for mint_a
         has_one = mint_b,                  // Verify this escrow is for mint_b
@@ -72,6 +104,24 @@ pub struct Take<'info> {
     )]
     pub vault: Account<'info, TokenAccount>,
     
+    // Holds the taker's mint_b while a two-step take waits for the maker (owned by escrow PDA)
+    // Only passed for two-step escrows; one-step takes never create it
+    #[account(
+        init_if_needed,                    // Create if it doesn't exist
+        payer = taker,                     // Taker pays for creation
+        associated_token::mint = mint_b,   // For mint_b tokens
+        associated_token::authority = escrow, // Owned by escrow PDA
+    )]
+    pub payment_vault: Option<Account<'info, TokenAccount>>,
+    
+    // The maker's escrow counters (open_count drops once this escrow closes)
+    #[account(
+        mut,
+        seeds = [MAKER_STATS_SEED.as_bytes(), maker.key().as_ref()],
+        bump = maker_stats.bump
+    )]
+    pub maker_stats: Account<'info, MakerStats>,
+    
     // Required programs for token operations
     pub associated_token_program: Program<'info, AssociatedToken>,
     pub token_program: Program<'info, Token>,
@@ -80,8 +130,66 @@ pub struct Take<'info> {
 
 // Implementation block for the Take instruction
 impl<'info> Take<'info> {
-    pub fn take(&mut self) -> Result<()> {
-        // Step 1: Transfer mint_b tokens from taker to maker (payment)
+    pub fn take(&mut self, params: TakeParams) -> Result<()> {
+        // Vesting escrows must lock mint_a through take_vesting instead
+        require!(!self.escrow.is_vesting(), EscrowError::VestingEscrowRequiresVestingTake);
+
+        // Native SOL escrows are paid through take_sol instead
+        require!(!self.escrow.receive_is_native, EscrowError::NativeSolEscrowRequiresSolTake);
+
+        // OTC escrows can only be taken by the taker the maker named
+        require!(self.escrow.can_be_taken_by(&self.taker.key()), EscrowError::UnauthorizedTaker);
+
+        // The vault must still hold the maker's deposit (stray extra tokens go to the taker)
+        require!(
+            self.escrow.vault_covers_deposit(self.vault.amount),
+            EscrowError::VaultBalanceMismatch
+        );
+
+        // Reject if the transaction lands after the maker's expiry or the taker's deadline
+        let now = Clock::get()?.unix_timestamp;
+        require!(!self.escrow.is_expired(now), EscrowError::EscrowExpired);
+        require!(now <= params.deadline, EscrowError::EscrowExpired);
+
+        // Work out what the taker gets and pays (same math clients use to preview)
+        let (payout, cost) = quote_take(&self.escrow, self.vault.amount);
+
+        // Reject if the vault holds less mint_a than the taker expects
+        require!(payout >= params.min_receive, EscrowError::ReceiveBelowMinimum);
+
+        // Reject if the maker raised the price above what the taker agreed to pay
+        require!(cost <= params.max_pay, EscrowError::SlippageExceeded);
+
+        // The payment vault belongs to two-step takes, so one-step takes never leave one behind
+        require!(
+            self.payment_vault.is_some() == self.escrow.two_step,
+            EscrowError::PaymentVaultMismatch
+        );
+
+        // Two-step escrows only hold the payment until the maker confirms
+        if self.escrow.two_step {
+            return self.propose(cost);
+        }
+
+        // Step 1: Transfer the protocol fee from taker to fee collector
+        let fee = self.config.fee_for(cost);
+
+        if fee > 0 {
+            let transfer_fee = Transfer {
+                from: self.taker_ata_b.to_account_info(),         // From taker's mint_b account
+                to: self.fee_collector_ata_b.to_account_info(),   // To fee collector's mint_b account
+                authority: self.taker.to_account_info(),          // Taker authorizes
+            };
+
+            let ctx = CpiContext::new(
+                self.token_program.to_account_info(),
+                transfer_fee,
+            );
+
+            transfer(ctx, fee)?;
+        }
+
+        // Step 2: Transfer mint_b tokens from taker to maker (payment)
         let transfer_to_maker = Transfer {
             from: self.taker_ata_b.to_account_info(),    // From taker's mint_b account
             to: self.maker_ata_b.to_account_info(),      // To maker's mint_b account
@@ -93,10 +201,10 @@ impl<'info> Take<'info> {
             transfer_to_maker,
         );
 
-        // Transfer the amount the maker requested
-        transfer(ctx, self.escrow.receive)?;
+        // Transfer the amount the maker requested, minus the protocol fee
+        transfer(ctx, cost - fee)?;
 
-        // Step 2: Transfer mint_a tokens from vault to taker (delivery)
+        // Step 3: Transfer mint_a tokens from vault to taker (delivery)
         let transfer_to_taker = Transfer {
             from: self.vault.to_account_info(),          // From vault
             to: self.taker_ata_a.to_account_info(),      // To taker's mint_a account
@@ -119,9 +227,9 @@ impl<'info> Take<'info> {
         );
 
         // Transfer all tokens from vault to taker
-        transfer(ctx, self.vault.amount)?;
+        transfer(ctx, payout)?;
 
-        // Step 3: Close the vault account (return rent to maker)
+        // Step 4: Close the vault account (return rent to maker)
         let close_accounts = CloseAccount {
             account: self.vault.to_account_info(),       // Account to close
             destination: self.maker.to_account_info(),   // Where to send rent
@@ -134,7 +242,41 @@ impl<'info> Take<'info> {
             signer_seeds,
         );
 
-        close_account(ctx)
-        // Note: The escrow account is closed automatically due to the 'close' constraint
+        close_account(ctx)?;
+
+        // The escrow no longer counts as open for the maker
+        self.maker_stats.record_close();
+
+        // Step 5: Close the escrow account (return rent to maker)
+        self.escrow.close(self.maker.to_account_info())
+    }
+
+    // First half of a two-step take: escrow the payment and wait for confirm_take
+    fn propose(&mut self, cost: u64) -> Result<()> {
+        // Only one taker can be waiting on the maker at a time
+        require!(!self.escrow.has_pending_take(), EscrowError::TakePending);
+
+        // Checked against the escrow's two-step mode in take
+        let payment_vault = self.payment_vault.as_ref().ok_or(EscrowError::PaymentVaultMismatch)?;
+
+        // Move the full payment (fee included) into the payment vault
+        let transfer_payment = Transfer {
+            from: self.taker_ata_b.to_account_info(),    // From taker's mint_b account
+            to: payment_vault.to_account_info(),         // To the escrow's payment vault
+            authority: self.taker.to_account_info(),     // Taker authorizes
+        };
+
+        let ctx = CpiContext::new(
+            self.token_program.to_account_info(),
+            transfer_payment,
+        );
+
+        transfer(ctx, cost)?;
+
+        // The escrow stays open until the maker confirms or either side cancels
+        self.escrow.pending_taker = Some(self.taker.key());
+        self.escrow.pending_payment = cost;
+
+        Ok(())
     }
 }
\ No newline at end of file
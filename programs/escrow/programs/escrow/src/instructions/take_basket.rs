@@ -0,0 +1,206 @@
+use anchor_lang::prelude::*;
+
+// Now we need token-related types
+use anchor_spl::{
+    associated_token::{create_idempotent, get_associated_token_address, AssociatedToken, Create},
+    token::{close_account, transfer, CloseAccount, Mint, Token, TokenAccount, Transfer},
+};
+
+// Import our program's state and constants
+use crate::{constants::BASKET_SEED, error::EscrowError, state::BasketEscrow};
+
+// This struct defines what accounts the 'take_basket' instruction needs
+// Each offered token's mint, vault and taker account are passed as remaining accounts
+#[derive(Accounts)]
+pub struct TakeBasket<'info> {
+    // The person fulfilling the basket escrow (must sign the transaction)
+    #[account(mut)] // mut because they'll pay for account creation if needed
+    pub taker: Signer<'info>,
+
+    // The original maker (will receive payment)
+    #[account(mut)] // mut because they'll receive SOL when accounts are closed
+    pub maker: SystemAccount<'info>,
+
+    // The token the maker wants (what taker will provide)
+    pub mint_b: Account<'info, Mint>,
+
+    // Taker's token account for mint_b (where they'll send payment from)
+    #[account(
+        mut,                               // We'll transfer from here
+        associated_token::mint = mint_b,   // For mint_b tokens
+        associated_token::authority = taker, // Owned by taker
+    )]
+    pub taker_ata_b: Account<'info, TokenAccount>,
+
+    // Maker's token account for mint_b (where they'll receive payment)
+    #[account(
+        init_if_needed,                    // Create if it doesn't exist
+        payer = taker,                     // Taker pays for creation
+        associated_token::mint = mint_b,   // For mint_b tokens
+        associated_token::authority = maker, // Owned by maker
+    )]
+    pub maker_ata_b: Account<'info, TokenAccount>,
+
+    // The existing basket escrow (will be closed and rent returned to maker)
+    #[account(
+        mut,                               // We'll close this account
+        close = maker,                     // Return rent to maker
+        has_one = maker,                   // Verify this escrow belongs to this maker
+        has_one = mint_b,                  // Verify this escrow is for mint_b
+        seeds = [BASKET_SEED.as_bytes(), maker.key().as_ref(), basket_escrow.seed.to_le_bytes().as_ref()],
+        bump = basket_escrow.bump          // Use the bump stored in the basket escrow
+    )]
+    pub basket_escrow: Account<'info, BasketEscrow>,
+
+    // Required programs for token operations
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
+
+// Implementation block for the TakeBasket instruction
+impl<'info> TakeBasket<'info> {
+    pub fn take_basket(&mut self, item_accounts: &'info [AccountInfo<'info>]) -> Result<()> {
+        // Every vault must hold exactly what the maker deposited, as with a regular take
+        check_basket_vaults(&self.basket_escrow, item_accounts)?;
+
+        // Step 1: Transfer mint_b tokens from taker to maker (payment for the whole basket)
+        let transfer_to_maker = Transfer {
+            from: self.taker_ata_b.to_account_info(),    // From taker's mint_b account
+            to: self.maker_ata_b.to_account_info(),      // To maker's mint_b account
+            authority: self.taker.to_account_info(),     // Taker authorizes
+        };
+
+        let ctx = CpiContext::new(
+            self.token_program.to_account_info(),
+            transfer_to_maker,
+        );
+
+        transfer(ctx, self.basket_escrow.receive)?;
+
+        // Step 2: Deliver every offered token to the taker and close its vault
+        release_basket(
+            &self.basket_escrow,
+            &self.taker.to_account_info(),
+            &self.maker.to_account_info(),
+            item_accounts,
+            &self.associated_token_program,
+            &self.token_program,
+            &self.system_program,
+        )
+        // Note: The basket escrow account is closed automatically due to the 'close' constraint
+    }
+}
+
+// Check there are accounts for every offered token and each vault still holds its deposit
+// Extra tokens sent to a vault don't block the take; release_basket sweeps them to the taker
+// Expects [mint, vault, recipient_ata] for each offered token, in order
+pub fn check_basket_vaults<'info>(
+    basket: &Account<'info, BasketEscrow>,
+    item_accounts: &'info [AccountInfo<'info>],
+) -> Result<()> {
+    require!(
+        item_accounts.len() == basket.offered.len() * 3,
+        EscrowError::BasketAccountsMismatch
+    );
+
+    for (item, accounts) in basket.offered.iter().zip(item_accounts.chunks(3)) {
+        let vault: Account<TokenAccount> = Account::try_from(&accounts[1])?;
+        require!(vault.amount >= item.amount, EscrowError::VaultBalanceMismatch);
+    }
+
+    Ok(())
+}
+
+// Move each vault's whole balance to `recipient`'s account for that mint, then close the vault
+// The recipient signs and pays to create any token account they don't have yet;
+// vault rent goes back to the maker
+// Expects [mint, vault, recipient_ata] for each offered token, in order
+pub fn release_basket<'info>(
+    basket: &Account<'info, BasketEscrow>,
+    recipient: &AccountInfo<'info>,
+    maker: &AccountInfo<'info>,
+    item_accounts: &'info [AccountInfo<'info>],
+    associated_token_program: &Program<'info, AssociatedToken>,
+    token_program: &Program<'info, Token>,
+    system_program: &Program<'info, System>,
+) -> Result<()> {
+    require!(
+        item_accounts.len() == basket.offered.len() * 3,
+        EscrowError::BasketAccountsMismatch
+    );
+
+    // Create signer seeds for the basket PDA to authorize the transfers
+    let maker_key = maker.key();
+    let signer_seeds: &[&[&[u8]]] = &[&[
+        BASKET_SEED.as_bytes(),
+        maker_key.as_ref(),
+        &basket.seed.to_le_bytes(),
+        &[basket.bump],
+    ]];
+
+    for (item, accounts) in basket.offered.iter().zip(item_accounts.chunks(3)) {
+        let (mint, vault_info, recipient_ata) = (&accounts[0], &accounts[1], &accounts[2]);
+
+        // Only the basket's own vault and the recipient's own account for this mint
+        require_keys_eq!(mint.key(), item.mint, EscrowError::BasketAccountsMismatch);
+        require_keys_eq!(
+            vault_info.key(),
+            get_associated_token_address(&basket.key(), &item.mint),
+            EscrowError::BasketAccountsMismatch
+        );
+        require_keys_eq!(
+            recipient_ata.key(),
+            get_associated_token_address(&recipient.key(), &item.mint),
+            EscrowError::BasketAccountsMismatch
+        );
+
+        // Create the recipient's account for this mint if it doesn't exist yet
+        let create_recipient_ata = Create {
+            payer: recipient.clone(),
+            associated_token: recipient_ata.clone(),
+            authority: recipient.clone(),
+            mint: mint.clone(),
+            system_program: system_program.to_account_info(),
+            token_program: token_program.to_account_info(),
+        };
+
+        create_idempotent(CpiContext::new(
+            associated_token_program.to_account_info(),
+            create_recipient_ata,
+        ))?;
+
+        let vault: Account<TokenAccount> = Account::try_from(vault_info)?;
+
+        let transfer_out = Transfer {
+            from: vault_info.clone(),                    // From this token's vault
+            to: recipient_ata.clone(),                   // To recipient's token account
+            authority: basket.to_account_info(),         // Basket PDA authorizes
+        };
+
+        let ctx = CpiContext::new_with_signer(
+            token_program.to_account_info(),
+            transfer_out,
+            signer_seeds,
+        );
+
+        transfer(ctx, vault.amount)?;
+
+        // Close the vault account (return rent to maker)
+        let close_accounts = CloseAccount {
+            account: vault_info.clone(),                 // Account to close
+            destination: maker.clone(),                  // Where to send rent
+            authority: basket.to_account_info(),         // Basket PDA authorizes
+        };
+
+        let ctx = CpiContext::new_with_signer(
+            token_program.to_account_info(),
+            close_accounts,
+            signer_seeds,
+        );
+
+        close_account(ctx)?;
+    }
+
+    Ok(())
+}
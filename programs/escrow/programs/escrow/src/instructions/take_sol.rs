@@ -0,0 +1,194 @@
+use anchor_lang::{
+    prelude::*,
+    system_program::{self, Transfer as SystemTransfer},
+};
+
+// Now we need token-related types
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token::{close_account, transfer, CloseAccount, Mint, Token, TokenAccount, Transfer},
+};
+
+// Import our program's state and constants
+use crate::{
+    constants::{CONFIG_SEED, MAKER_STATS_SEED, SEED},
+    error::EscrowError,
+    instructions::{quote_take, TakeParams},
+    state::{Config, Escrow, MakerStats},
+};
+
+// This struct defines what accounts the 'take_sol' instruction needs
+// Same as 'take', but the taker pays the maker in native SOL
+#[derive(Accounts)]
+pub struct TakeSol<'info> {
+    // The person fulfilling the escrow (must sign the transaction)
+    #[account(mut)] // mut because they pay lamports and account creation
+    pub taker: Signer<'info>,
+
+    // The original maker (will receive payment)
+    #[account(mut)] // mut because they'll receive the lamports and rent
+    pub maker: SystemAccount<'info>,
+
+    // The token the maker offered (what taker will receive)
+    pub mint_a: Account<'info, Mint>,
+
+    // Taker's token account for mint_a (where they'll receive the deposited tokens)
+    #[account(
+        init_if_needed,                    // Create if it doesn't exist
+        payer = taker,                     // Taker pays for creation
+        associated_token::mint = mint_a,   // For mint_a tokens
+        associated_token::authority = taker, // Owned by taker
+    )]
+    pub taker_ata_a: Account<'info, TokenAccount>,
+
+    // Global protocol settings (fee rate and who collects it)
+    #[account(
+        seeds = [CONFIG_SEED.as_bytes()],
+        bump = config.bump                 // Use the bump stored in config
+    )]
+    pub config: Account<'info, Config>,
+
+    // The marketplace operator collecting the protocol fee (paid in lamports here)
+    #[account(
+        mut,                               // mut because they'll receive the fee lamports
+        address = config.authority         // Must be the config authority
+    )]
+    pub fee_collector: SystemAccount<'info>,
+
+    // The existing escrow account (will be closed and rent returned to maker)
+    #[account(
+        mut,                               // We'll close this account
+        close = maker,                     // Return rent to maker
+        has_one = maker,                   // Verify this escrow belongs to this maker
+        has_one = mint_a,                  // Verify this escrow is for mint_a
+        seeds = [SEED.as_bytes(), maker.key().as_ref(), escrow.seed.to_le_bytes().as_ref()],
+        bump = escrow.bump                 // Use the bump stored in escrow
+    )]
+    pub escrow: Account<'info, Escrow>,
+
+    // The existing vault (will be closed and rent returned to maker)
+    #[account(
+        mut,                               // We'll transfer from and close this account
+        associated_token::mint = mint_a,   // Must be for mint_a
+        associated_token::authority = escrow, // Must be owned by escrow
+    )]
+    pub vault: Account<'info, TokenAccount>,
+
+    // The maker's escrow counters (open_count drops once this escrow closes)
+    #[account(
+        mut,
+        seeds = [MAKER_STATS_SEED.as_bytes(), maker.key().as_ref()],
+        bump = maker_stats.bump
+    )]
+    pub maker_stats: Account<'info, MakerStats>,
+
+    // Required programs for token operations
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
+
+// Implementation block for the TakeSol instruction
+impl<'info> TakeSol<'info> {
+    pub fn take_sol(&mut self, params: TakeParams) -> Result<()> {
+        // Only escrows created with make_sol are paid in native SOL
+        require!(self.escrow.receive_is_native, EscrowError::NotANativeSolEscrow);
+
+        // The vault must still hold the maker's deposit (stray extra tokens go to the taker)
+        require!(
+            self.escrow.vault_covers_deposit(self.vault.amount),
+            EscrowError::VaultBalanceMismatch
+        );
+
+        // Reject if the transaction lands after the maker's expiry or the taker's deadline
+        let now = Clock::get()?.unix_timestamp;
+        require!(!self.escrow.is_expired(now), EscrowError::EscrowExpired);
+        require!(now <= params.deadline, EscrowError::EscrowExpired);
+
+        // Work out what the taker gets and pays (cost is in lamports here)
+        let (payout, cost) = quote_take(&self.escrow, self.vault.amount);
+
+        // Reject if the vault holds less mint_a than the taker expects
+        require!(payout >= params.min_receive, EscrowError::ReceiveBelowMinimum);
+
+        // Reject if the maker raised the price above what the taker agreed to pay
+        require!(cost <= params.max_pay, EscrowError::SlippageExceeded);
+
+        // Step 1: Transfer the protocol fee from taker to fee collector
+        let fee = self.config.fee_for(cost);
+
+        if fee > 0 {
+            let transfer_fee = SystemTransfer {
+                from: self.taker.to_account_info(),          // From taker's wallet
+                to: self.fee_collector.to_account_info(),    // To fee collector's wallet
+            };
+
+            let ctx = CpiContext::new(
+                self.system_program.to_account_info(),
+                transfer_fee,
+            );
+
+            system_program::transfer(ctx, fee)?;
+        }
+
+        // Step 2: Transfer lamports from taker to maker (payment)
+        let transfer_to_maker = SystemTransfer {
+            from: self.taker.to_account_info(),          // From taker's wallet
+            to: self.maker.to_account_info(),            // To maker's wallet
+        };
+
+        let ctx = CpiContext::new(
+            self.system_program.to_account_info(),
+            transfer_to_maker,
+        );
+
+        // Transfer the lamports the maker requested, minus the protocol fee
+        system_program::transfer(ctx, cost - fee)?;
+
+        // Step 3: Transfer mint_a tokens from vault to taker (delivery)
+        let transfer_to_taker = Transfer {
+            from: self.vault.to_account_info(),          // From vault
+            to: self.taker_ata_a.to_account_info(),      // To taker's mint_a account
+            authority: self.escrow.to_account_info(),    // Escrow PDA authorizes
+        };
+
+        // Create signer seeds for the escrow PDA to authorize the transfer
+        let maker_key = self.maker.key();
+        let signer_seeds: &[&[&[u8]]] = &[&[
+            SEED.as_bytes(),
+            maker_key.as_ref(),
+            &self.escrow.seed.to_le_bytes(),
+            &[self.escrow.bump],
+        ]];
+
+        let ctx = CpiContext::new_with_signer(
+            self.token_program.to_account_info(),
+            transfer_to_taker,
+            signer_seeds,
+        );
+
+        // Transfer all tokens from vault to taker
+        transfer(ctx, payout)?;
+
+        // Step 4: Close the vault account (return rent to maker)
+        let close_accounts = CloseAccount {
+            account: self.vault.to_account_info(),       // Account to close
+            destination: self.maker.to_account_info(),   // Where to send rent
+            authority: self.escrow.to_account_info(),    // Escrow PDA authorizes
+        };
+
+        let ctx = CpiContext::new_with_signer(
+            self.token_program.to_account_info(),
+            close_accounts,
+            signer_seeds,
+        );
+
+        close_account(ctx)?;
+
+        // The escrow no longer counts as open for the maker
+        self.maker_stats.record_close();
+
+        // Note: The escrow account is closed automatically due to the 'close' constraint
+        Ok(())
+    }
+}
@@ -0,0 +1,242 @@
+use anchor_lang::prelude::*;
+
+// Now we need token-related types
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token::{close_account, transfer, CloseAccount, Mint, Token, TokenAccount, Transfer},
+};
+
+// Import our program's state and constants
+use crate::{
+    constants::{ANCHOR_DISCREMINATOR, CONFIG_SEED, MAKER_STATS_SEED, SEED, VESTING_SEED},
+    error::EscrowError,
+    instructions::{quote_take, TakeParams},
+    state::{Config, Escrow, MakerStats, VestingEscrow},
+};
+
+// This struct defines what accounts the 'take_vesting' instruction needs
+#[derive(Accounts)]
+pub struct TakeVesting<'info> {
+    // The person fulfilling the escrow (must sign the transaction)
+    #[account(mut)] // mut because they'll pay for the vesting accounts
+    pub taker: Signer<'info>,
+    
+    // The original maker (will receive payment)
+    #[account(mut)] // mut because they'll receive SOL when accounts are closed
+    pub maker: SystemAccount<'info>,
+    
+    // The token the maker offered (what taker will receive over time)
+    pub mint_a: Account<'info, Mint>,
+    
+    // The token the maker wants (what taker will provide)
+    pub mint_b: Account<'info, Mint>,
+    
+    // Taker's token account for mint_b (where they'll send payment from)
+    #[account(
+        mut,                               // We'll transfer from here
+        associated_token::mint = mint_b,   // For mint_b tokens
+        associated_token::authority = taker, // Owned by taker
+    )]
+    pub taker_ata_b: Account<'info, TokenAccount>,
+    
+    // Maker's token account for mint_b (where they'll receive payment)
+    #[account(
+        init_if_needed,                    // Create if it doesn't exist
+        payer = taker,                     // Taker pays for creation
+        associated_token::mint = mint_b,   // For mint_b tokens
+        associated_token::authority = maker, // Owned by maker
+    )]
+    pub maker_ata_b: Account<'info, TokenAccount>,
+    
+    // Global protocol settings (fee rate and who collects it)
+    #[account(
+        seeds = [CONFIG_SEED.as_bytes()],
+        bump = config.bump                 // Use the bump stored in config
+    )]
+    pub config: Account<'info, Config>,
+    
+    // The marketplace operator collecting the protocol fee
+    #[account(address = config.authority)] // Must be the config authority
+    pub fee_collector: SystemAccount<'info>,
+    
+    // Fee collector's token account for mint_b (where the protocol fee goes)
+    #[account(
+        init_if_needed,                    // Create if it doesn't exist
+        payer = taker,                     // Taker pays for creation
+        associated_token::mint = mint_b,   // For mint_b tokens
+        associated_token::authority = fee_collector, // Owned by fee collector
+    )]
+    pub fee_collector_ata_b: Account<'info, TokenAccount>,
+    
+    // The existing escrow account (will be closed and rent returned to maker)
+    #[account(
+        mut,                               // We'll close this account
+        close = maker,                     // Return rent to maker
+        has_one = maker,                   // Verify this escrow belongs to this maker
+        has_one = mint_a,                  // Verify this escrow is for mint_a
+        has_one = mint_b,                  // Verify this escrow is for mint_b
+        seeds = [SEED.as_bytes(), maker.key().as_ref(), escrow.seed.to_le_bytes().as_ref()],
+        bump = escrow.bump                 // Use the bump stored in escrow
+    )]
+    pub escrow: Account<'info, Escrow>,
+    
+    // The existing vault (will be closed and rent returned to maker)
+    #[account(
+        mut,                               // We'll transfer from and close this account
+        associated_token::mint = mint_a,   // Must be for mint_a
+        associated_token::authority = escrow, // Must be owned by escrow
+    )]
+    pub vault: Account<'info, TokenAccount>,
+    
+    // The maker's escrow counters (open_count drops once this escrow closes)
+    #[account(
+        mut,
+        seeds = [MAKER_STATS_SEED.as_bytes(), maker.key().as_ref()],
+        bump = maker_stats.bump
+    )]
+    pub maker_stats: Account<'info, MakerStats>,
+    
+    // The vesting schedule for the taker (PDA)
+    #[account(
+        init,                    // Create a new account
+        payer = taker,          // Taker pays for account creation
+        space = ANCHOR_DISCREMINATOR + VestingEscrow::INIT_SPACE,
+        seeds = [VESTING_SEED.as_bytes(), escrow.key().as_ref()],
+        bump                    // Anchor finds the bump for us
+    )]
+    pub vesting_escrow: Account<'info, VestingEscrow>,
+    
+    // The vault that holds mint_a until it vests (owned by vesting PDA)
+    #[account(
+        init,                           // Create new token account
+        payer = taker,                 // Taker pays for creation
+        associated_token::mint = mint_a,   // For mint_a tokens
+        associated_token::authority = vesting_escrow, // Owned by vesting PDA
+    )]
+    pub vesting_vault: Account<'info, TokenAccount>,
+    
+    // Required programs for token operations
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
+
+// Implementation block for the TakeVesting instruction
+impl<'info> TakeVesting<'info> {
+    pub fn take_vesting(&mut self, params: TakeParams, bumps: &TakeVestingBumps) -> Result<()> {
+        // Only escrows created with make_vesting can be taken this way
+        require!(self.escrow.is_vesting(), EscrowError::NotAVestingEscrow);
+
+        // The vault must still hold the maker's deposit (stray extra tokens go to the taker)
+        require!(
+            self.escrow.vault_covers_deposit(self.vault.amount),
+            EscrowError::VaultBalanceMismatch
+        );
+
+        // Reject if the transaction lands after the maker's expiry or the taker's deadline
+        let now = Clock::get()?.unix_timestamp;
+        require!(!self.escrow.is_expired(now), EscrowError::EscrowExpired);
+        require!(now <= params.deadline, EscrowError::EscrowExpired);
+
+        // Work out what the taker gets and pays (same math clients use to preview)
+        let (payout, cost) = quote_take(&self.escrow, self.vault.amount);
+
+        // Reject if the vault holds less mint_a than the taker expects
+        require!(payout >= params.min_receive, EscrowError::ReceiveBelowMinimum);
+
+        // Reject if the maker raised the price above what the taker agreed to pay
+        require!(cost <= params.max_pay, EscrowError::SlippageExceeded);
+
+        // Step 1: Transfer the protocol fee from taker to fee collector
+        let fee = self.config.fee_for(cost);
+
+        if fee > 0 {
+            let transfer_fee = Transfer {
+                from: self.taker_ata_b.to_account_info(),         // From taker's mint_b account
+                to: self.fee_collector_ata_b.to_account_info(),   // To fee collector's mint_b account
+                authority: self.taker.to_account_info(),          // Taker authorizes
+            };
+
+            let ctx = CpiContext::new(
+                self.token_program.to_account_info(),
+                transfer_fee,
+            );
+
+            transfer(ctx, fee)?;
+        }
+
+        // Step 2: Transfer mint_b tokens from taker to maker (payment)
+        let transfer_to_maker = Transfer {
+            from: self.taker_ata_b.to_account_info(),    // From taker's mint_b account
+            to: self.maker_ata_b.to_account_info(),      // To maker's mint_b account
+            authority: self.taker.to_account_info(),     // Taker authorizes
+        };
+
+        let ctx = CpiContext::new(
+            self.token_program.to_account_info(),
+            transfer_to_maker,
+        );
+
+        // Transfer the amount the maker requested, minus the protocol fee
+        transfer(ctx, cost - fee)?;
+
+        // Step 3: Record the vesting schedule starting now
+        let total_amount = payout;
+
+        self.vesting_escrow.set_inner(VestingEscrow {
+            escrow: self.escrow.key(),
+            taker: self.taker.key(),
+            mint_a: self.mint_a.key(),
+            total_amount,
+            claimed_amount: 0,
+            start_time: now,
+            cliff_time: now + self.escrow.cliff_duration,
+            end_time: now + self.escrow.vesting_duration,
+            bump: bumps.vesting_escrow,
+        });
+
+        // Step 4: Move mint_a from the escrow vault into the vesting vault
+        let transfer_to_vesting = Transfer {
+            from: self.vault.to_account_info(),          // From vault
+            to: self.vesting_vault.to_account_info(),    // To vesting vault
+            authority: self.escrow.to_account_info(),    // Escrow PDA authorizes
+        };
+
+        let maker_key = self.maker.key();
+        let signer_seeds: &[&[&[u8]]] = &[&[
+            SEED.as_bytes(),
+            maker_key.as_ref(),
+            &self.escrow.seed.to_le_bytes(),
+            &[self.escrow.bump],
+        ]];
+
+        let ctx = CpiContext::new_with_signer(
+            self.token_program.to_account_info(),
+            transfer_to_vesting,
+            signer_seeds,
+        );
+
+        transfer(ctx, total_amount)?;
+
+        // Step 5: Close the escrow vault (return rent to maker)
+        let close_accounts = CloseAccount {
+            account: self.vault.to_account_info(),       // Account to close
+            destination: self.maker.to_account_info(),   // Where to send rent
+            authority: self.escrow.to_account_info(),    // Escrow PDA authorizes
+        };
+
+        let ctx = CpiContext::new_with_signer(
+            self.token_program.to_account_info(),
+            close_accounts,
+            signer_seeds,
+        );
+
+        close_account(ctx)?;
+
+        // The escrow no longer counts as open for the maker
+        self.maker_stats.record_close();
+
+        // Note: The escrow account is closed automatically due to the 'close' constraint
+        Ok(())
+    }
+}
@@ -0,0 +1,58 @@
+use anchor_lang::prelude::*;
+
+// Import our program's state and constants
+use crate::{constants::SEED, error::EscrowError, state::Escrow};
+
+// This struct defines what accounts the 'update_escrow' instruction needs
+#[derive(Accounts)]
+pub struct UpdateEscrow<'info> {
+    // The original maker (must sign to prove ownership)
+    pub maker: Signer<'info>,
+
+    // The existing escrow account whose terms are being changed
+    #[account(
+        mut,                               // We'll update the receive amount
+        has_one = maker,                   // Verify this escrow belongs to this maker
+        seeds = [SEED.as_bytes(), maker.key().as_ref(), escrow.seed.to_le_bytes().as_ref()],
+        bump = escrow.bump                 // Use the bump stored in escrow
+    )]
+    pub escrow: Account<'info, Escrow>,
+}
+
+// Implementation block for the UpdateEscrow instruction
+impl<'info> UpdateEscrow<'info> {
+    pub fn update_escrow(&mut self, new_receive: u64) -> Result<()> {
+        // An escrow asking for nothing would give the deposit away
+        require!(new_receive > 0, EscrowError::InvalidAmount);
+
+        // Only the counter-amount changes, the vault is left untouched
+        self.escrow.receive = new_receive;
+
+        Ok(())
+    }
+
+    pub fn set_expiry(&mut self, expires_at: i64) -> Result<()> {
+        // Zero removes the expiry; anything else must still be in the future
+        let now = Clock::get()?.unix_timestamp;
+        require!(expires_at == 0 || expires_at > now, EscrowError::InvalidExpiry);
+
+        self.escrow.expires_at = expires_at;
+
+        Ok(())
+    }
+
+    pub fn set_two_step(&mut self, two_step: bool) -> Result<()> {
+        // Vesting and native SOL escrows are taken through their own instructions
+        require!(
+            !two_step || (!self.escrow.is_vesting() && !self.escrow.receive_is_native),
+            EscrowError::TwoStepUnsupported
+        );
+
+        // Switching modes mid-proposal would strand the taker's payment
+        require!(!self.escrow.has_pending_take(), EscrowError::TakePending);
+
+        self.escrow.two_step = two_step;
+
+        Ok(())
+    }
+}
@@ -2,10 +2,12 @@
 use anchor_lang::prelude::*;
 
 pub mod constants;
+pub mod error;
 pub mod state;
 pub mod instructions;
 
 use instructions::*;
+use state::BasketItem;
 
 declare_id!("FEUtZsWm99vwPCMuwPiKrBWg4TTSgTaqeBUsmEovhPJD");
 
@@ -14,15 +16,101 @@ declare_id!("FEUtZsWm99vwPCMuwPiKrBWg4TTSgTaqeBUsmEovhPJD");
 pub mod escrow_program {
     use super::*;
 
-    pub fn make(ctx: Context<Make>, seed: u64, receive: u64, deposit: u64) -> Result<()> {
-        ctx.accounts.make(seed, receive, deposit, &ctx.bumps)
+    pub fn make(
+        ctx: Context<Make>,
+        seed: u64,
+        receive: u64,
+        deposit: u64,
+        allowed_taker: Option<Pubkey>,
+    ) -> Result<()> {
+        ctx.accounts.make(seed, receive, deposit, allowed_taker, &ctx.bumps)
     }
 
-    pub fn take(ctx: Context<Take>) -> Result<()> {
-        ctx.accounts.take()
+    pub fn take(ctx: Context<Take>, params: TakeParams) -> Result<()> {
+        ctx.accounts.take(params)
     }
 
     pub fn refund(ctx: Context<Refund>) -> Result<()> {
         ctx.accounts.refund()
     }
+
+    // Makes takes only propose the swap until the maker approves the taker with confirm_take
+    pub fn set_two_step(ctx: Context<UpdateEscrow>, two_step: bool) -> Result<()> {
+        ctx.accounts.set_two_step(two_step)
+    }
+
+    // Maker completes a proposed two-step take: payment to maker, deposit to taker
+    pub fn confirm_take(ctx: Context<ConfirmTake>) -> Result<()> {
+        ctx.accounts.confirm_take()
+    }
+
+    // Maker or pending taker backs out; the taker's escrowed payment is returned
+    pub fn cancel_take(ctx: Context<CancelTake>) -> Result<()> {
+        ctx.accounts.cancel_take()
+    }
+
+    // Lets the maker reprice an open escrow without refunding and re-making
+    pub fn update_escrow(ctx: Context<UpdateEscrow>, new_receive: u64) -> Result<()> {
+        ctx.accounts.update_escrow(new_receive)
+    }
+
+    // Stops the escrow from being taken from `expires_at` on (0 = never expires)
+    pub fn set_expiry(ctx: Context<UpdateEscrow>, expires_at: i64) -> Result<()> {
+        ctx.accounts.set_expiry(expires_at)
+    }
+
+    // Same as make, but the taker receives mint_a gradually after a cliff
+    pub fn make_vesting(
+        ctx: Context<Make>,
+        seed: u64,
+        receive: u64,
+        deposit: u64,
+        cliff_duration: i64,
+        vesting_duration: i64,
+    ) -> Result<()> {
+        ctx.accounts.make_vesting(seed, receive, deposit, cliff_duration, vesting_duration, &ctx.bumps)
+    }
+
+    pub fn take_vesting(ctx: Context<TakeVesting>, params: TakeParams) -> Result<()> {
+        ctx.accounts.take_vesting(params, &ctx.bumps)
+    }
+
+    pub fn claim_vested(ctx: Context<ClaimVested>) -> Result<()> {
+        ctx.accounts.claim_vested()
+    }
+
+    // Same as make, but the maker is paid in native SOL instead of mint_b
+    pub fn make_sol(ctx: Context<MakeSol>, seed: u64, receive_lamports: u64, deposit: u64) -> Result<()> {
+        ctx.accounts.make_sol(seed, receive_lamports, deposit, &ctx.bumps)
+    }
+
+    pub fn take_sol(ctx: Context<TakeSol>, params: TakeParams) -> Result<()> {
+        ctx.accounts.take_sol(params)
+    }
+
+    // Offer several tokens at once for a single mint_b payment
+    // remaining_accounts: [mint, maker_ata, vault] for each offered token, in order
+    pub fn make_basket<'info>(
+        ctx: Context<'_, '_, 'info, 'info, MakeBasket<'info>>,
+        seed: u64,
+        receive: u64,
+        offered: Vec<BasketItem>,
+    ) -> Result<()> {
+        ctx.accounts.make_basket(seed, receive, offered, &ctx.bumps, ctx.remaining_accounts)
+    }
+
+    // remaining_accounts: [mint, vault, taker_ata] for each offered token, in order
+    pub fn take_basket<'info>(ctx: Context<'_, '_, 'info, 'info, TakeBasket<'info>>) -> Result<()> {
+        ctx.accounts.take_basket(ctx.remaining_accounts)
+    }
+
+    // remaining_accounts: [mint, vault, maker_ata] for each offered token, in order
+    pub fn refund_basket<'info>(ctx: Context<'_, '_, 'info, 'info, RefundBasket<'info>>) -> Result<()> {
+        ctx.accounts.refund_basket(ctx.remaining_accounts)
+    }
+
+    // One-time setup of the protocol fee charged on every take
+    pub fn initialize_config(ctx: Context<InitializeConfig>, fee_bps: u16) -> Result<()> {
+        ctx.accounts.initialize_config(fee_bps, &ctx.bumps)
+    }
 }
\ No newline at end of file
@@ -1,5 +1,7 @@
 use anchor_lang::prelude::*;
 
+use crate::constants::MAX_BASKET_ITEMS;
+
 #[account]
 #[derive(InitSpace)]
 pub struct Escrow {
@@ -8,5 +10,145 @@ pub struct Escrow {
     pub mint_a: Pubkey, // Token they're offering
     pub mint_b: Pubkey, // Token they're receiving in return
     pub receive: u64, // The amount of the second token to receive
+    pub deposit: u64, // The amount of the first token the maker put in the vault
     pub bump: u8, // The bump of the escrow for security
+    pub cliff_duration: i64, // Seconds after take before any mint_a unlocks (vesting only)
+    pub vesting_duration: i64, // Seconds over which mint_a unlocks linearly (0 = no vesting)
+    pub allowed_taker: Option<Pubkey>, // Only this taker may fulfill the escrow (None = anyone)
+    pub receive_is_native: bool, // Maker is paid `receive` lamports via take_sol instead of mint_b
+    pub expires_at: i64, // Unix timestamp from which the escrow can no longer be taken (0 = never)
+    pub two_step: bool, // Takes only propose the swap; the maker completes it with confirm_take
+    pub pending_taker: Option<Pubkey>, // Taker whose two-step take awaits the maker (None = no proposal)
+    pub pending_payment: u64, // mint_b the pending taker escrowed in the payment vault
+}
+
+impl Escrow {
+    // Vesting escrows release mint_a gradually through claim_vested instead of on take
+    pub fn is_vesting(&self) -> bool {
+        self.vesting_duration > 0
+    }
+
+    // OTC escrows restrict who can take; open escrows accept anyone
+    // map_or instead of is_none_or, which needs Rust 1.82 and older Solana toolchains lack it
+    #[allow(clippy::unnecessary_map_or)]
+    pub fn can_be_taken_by(&self, taker: &Pubkey) -> bool {
+        self.allowed_taker.map_or(true, |allowed| allowed == *taker)
+    }
+
+    // Takes only go through while the vault still holds the maker's deposit
+    // Extra tokens anyone sent to the public vault are swept to the taker, so they can't block the take
+    pub fn vault_covers_deposit(&self, vault_amount: u64) -> bool {
+        vault_amount >= self.deposit
+    }
+
+    // Expired escrows can only be refunded by the maker
+    pub fn is_expired(&self, now: i64) -> bool {
+        self.expires_at != 0 && now >= self.expires_at
+    }
+
+    // A two-step take has been proposed and the maker hasn't confirmed or cancelled it
+    pub fn has_pending_take(&self) -> bool {
+        self.pending_taker.is_some()
+    }
+
+    // Either side of a pending take may back out of it
+    pub fn can_cancel_take(&self, signer: &Pubkey) -> bool {
+        *signer == self.maker || self.pending_taker == Some(*signer)
+    }
+}
+
+// One token offered in a basket escrow
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq, InitSpace)]
+pub struct BasketItem {
+    pub mint: Pubkey, // Token being offered
+    pub amount: u64, // How much of it the maker deposited
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct BasketEscrow {
+    pub seed: u64, // Unique identifier for the basket escrow
+    pub maker: Pubkey, // Person who created the basket escrow
+    pub mint_b: Pubkey, // Token they're receiving in return
+    pub receive: u64, // The amount of mint_b to receive for the whole basket
+    #[max_len(MAX_BASKET_ITEMS)]
+    pub offered: Vec<BasketItem>, // Every token offered, each held in its own vault
+    pub bump: u8, // The bump of the basket escrow for security
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct MakerStats {
+    pub maker: Pubkey, // Person these counters belong to
+    pub open_count: u64, // Escrows currently open (made but not yet taken or refunded)
+    pub total_created: u64, // Every escrow this maker has ever made
+    pub bump: u8, // The bump of the maker stats for security
+}
+
+impl MakerStats {
+    // A new escrow is open until it is taken or refunded
+    pub fn record_make(&mut self) {
+        self.open_count = self.open_count.saturating_add(1);
+        self.total_created = self.total_created.saturating_add(1);
+    }
+
+    // Called when an escrow is taken or refunded
+    pub fn record_close(&mut self) {
+        self.open_count = self.open_count.saturating_sub(1);
+    }
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct Config {
+    pub authority: Pubkey, // Marketplace operator, receives the protocol fee
+    pub fee_bps: u16, // Fee on every take in basis points (100 = 1%)
+    pub bump: u8, // The bump of the config for security
+}
+
+impl Config {
+    // Portion of `amount` kept by the protocol
+    pub fn fee_for(&self, amount: u64) -> u64 {
+        ((amount as u128) * (self.fee_bps as u128) / 10_000) as u64
+    }
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct VestingEscrow {
+    pub escrow: Pubkey, // The escrow this schedule came from (used in the PDA seeds)
+    pub taker: Pubkey, // Person receiving the vested tokens
+    pub mint_a: Pubkey, // Token being vested
+    pub total_amount: u64, // Total mint_a locked at take time
+    pub claimed_amount: u64, // How much the taker has already claimed
+    pub start_time: i64, // When the take happened (vesting starts)
+    pub cliff_time: i64, // Nothing is claimable before this timestamp
+    pub end_time: i64, // Everything is claimable from this timestamp
+    pub bump: u8, // The bump of the vesting escrow for security
+}
+
+impl VestingEscrow {
+    // Total amount unlocked at `now`: zero before the cliff, then linear from start to end
+    pub fn vested_amount(&self, now: i64) -> u64 {
+        if now < self.cliff_time {
+            return 0;
+        }
+        if now >= self.end_time {
+            return self.total_amount;
+        }
+
+        let elapsed = (now - self.start_time) as u128;
+        let duration = (self.end_time - self.start_time) as u128;
+
+        ((self.total_amount as u128) * elapsed / duration) as u64
+    }
+
+    // Amount the taker can claim right now
+    pub fn claimable_amount(&self, now: i64) -> u64 {
+        self.vested_amount(now).saturating_sub(self.claimed_amount)
+    }
+
+    pub fn is_fully_claimed(&self) -> bool {
+        self.claimed_amount >= self.total_amount
+    }
 }
\ No newline at end of file
@@ -40,13 +40,61 @@ describe("Escrow Program Tests", () => {
   let escrow: PublicKey;
   let vault: PublicKey;
   
+  // Protocol fee accounts (config is global, the provider wallet collects fees)
+  let config: PublicKey;
+  let feeCollector: PublicKey;
+  let feeCollectorAtaB: PublicKey; // Fee collector's account for mint B
+  
   // Test constants
   const seed = new anchor.BN(42); // Unique seed for this escrow
   const depositAmount = new anchor.BN(500_000_000); // 500 tokens (with 6 decimals)
   const receiveAmount = new anchor.BN(1_000_000_000); // 1000 tokens (with 6 decimals)
   
+  // Take protections that any honest take of this escrow satisfies
+  const takeParams = (
+    minReceive: anchor.BN = depositAmount,
+    deadline: anchor.BN = new anchor.BN(Math.floor(Date.now() / 1000) + 60),
+    maxPay: anchor.BN = receiveAmount
+  ) => ({ minReceive, deadline, maxPay });
+  
   console.log("🧪 Setting up comprehensive escrow tests...");
   
+  before(async () => {
+    feeCollector = provider.wallet.publicKey;
+    [config] = PublicKey.findProgramAddressSync(
+      [Buffer.from("config")],
+      program.programId
+    );
+    
+    // Fees above the 10% cap are rejected
+    try {
+      await program.methods
+        .initializeConfig(1001)
+        .accounts({
+          authority: feeCollector,
+          config: config,
+          systemProgram: SystemProgram.programId,
+        })
+        .rpc();
+      
+      assert.fail("Should have failed with FeeTooHigh");
+    } catch (error) {
+      assert(error.message.includes("FeeTooHigh"));
+    }
+    
+    // The config is global, so set it up once with a zero fee
+    await program.methods
+      .initializeConfig(0)
+      .accounts({
+        authority: feeCollector,
+        config: config,
+        systemProgram: SystemProgram.programId,
+      })
+      .rpc();
+    
+    console.log(`Config PDA: ${config.toString()}`);
+  });
+  
   beforeEach(async () => {
     console.log("\n🔄 Setting up fresh test environment...");
     
@@ -89,6 +137,7 @@ describe("Escrow Program Tests", () => {
     makerAtaB = await getAssociatedTokenAddress(mintB, maker.publicKey);
     takerAtaA = await getAssociatedTokenAddress(mintA, taker.publicKey);
     takerAtaB = await getAssociatedTokenAddress(mintB, taker.publicKey);
+    feeCollectorAtaB = await getAssociatedTokenAddress(mintB, feeCollector);
     
     // Step 5: Create and fund token accounts
     // Maker gets mintA tokens (what they'll deposit)
@@ -143,7 +192,8 @@ describe("Escrow Program Tests", () => {
         .make(
           seed,         // seed: u64
           receiveAmount, // receive: u64 (amount of mintB maker wants)
-          depositAmount  // deposit: u64 (amount of mintA maker deposits)
+          depositAmount, // deposit: u64 (amount of mintA maker deposits)
+          null           // allowed_taker: Option<Pubkey> (anyone may take)
         )
         .accounts({
           maker: maker.publicKey,
@@ -169,6 +219,7 @@ describe("Escrow Program Tests", () => {
       console.log(`  Mint A: ${escrowAccount.mintA.toString()}`);
       console.log(`  Mint B: ${escrowAccount.mintB.toString()}`);
       console.log(`  Receive: ${escrowAccount.receive}`);
+      console.log(`  Deposit: ${escrowAccount.deposit}`);
       
       // Assertions
       assert.equal(escrowAccount.seed.toString(), seed.toString());
@@ -176,6 +227,7 @@ describe("Escrow Program Tests", () => {
       assert.equal(escrowAccount.mintA.toString(), mintA.toString());
       assert.equal(escrowAccount.mintB.toString(), mintB.toString());
       assert.equal(escrowAccount.receive.toString(), receiveAmount.toString());
+      assert.isNull(escrowAccount.allowedTaker);
       
       // Verify tokens were transferred to vault
       const vaultAccount = await getAccount(provider.connection, vault);
@@ -193,13 +245,48 @@ describe("Escrow Program Tests", () => {
       
       console.log("✅ Make escrow test passed!");
     });
+    
+    it("Should record the deposit that was transferred to the vault", async () => {
+      console.log("\n🧾 Testing stored deposit amount...");
+      
+      // An amount different from the shared default, so a hard-coded value can't pass
+      const oddDeposit = new anchor.BN(123_456_789);
+      const initialMakerBalance = await getAccount(provider.connection, makerAtaA);
+      
+      await program.methods
+        .make(seed, receiveAmount, oddDeposit, null)
+        .accounts({
+          maker: maker.publicKey,
+          mintA: mintA,
+          mintB: mintB,
+          makerAtaA: makerAtaA,
+          escrow: escrow,
+          vault: vault,
+          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([maker])
+        .rpc();
+      
+      const escrowAccount = await program.account.escrow.fetch(escrow);
+      const vaultAccount = await getAccount(provider.connection, vault);
+      const finalMakerBalance = await getAccount(provider.connection, makerAtaA);
+      const transferred = BigInt(initialMakerBalance.amount.toString()) - BigInt(finalMakerBalance.amount.toString());
+      
+      assert.equal(escrowAccount.deposit.toString(), oddDeposit.toString());
+      assert.equal(escrowAccount.deposit.toString(), vaultAccount.amount.toString());
+      assert.equal(escrowAccount.deposit.toString(), transferred.toString());
+      
+      console.log("✅ Stored deposit matches the transfer!");
+    });
   });
   
   describe("Take Escrow Tests", () => {
     beforeEach(async () => {
       // Create escrow first (needed for take tests)
       await program.methods
-        .make(seed, receiveAmount, depositAmount)
+        .make(seed, receiveAmount, depositAmount, null)
         .accounts({
           maker: maker.publicKey,
           mintA: mintA,
@@ -229,7 +316,7 @@ describe("Escrow Program Tests", () => {
       
       // Call the take instruction
       const tx = await program.methods
-        .take()
+        .take(takeParams())
         .accounts({
           taker: taker.publicKey,
           maker: maker.publicKey,
@@ -238,8 +325,12 @@ describe("Escrow Program Tests", () => {
           takerAtaA: takerAtaA,
           takerAtaB: takerAtaB,
           makerAtaB: makerAtaB,
+          config: config,
+          feeCollector: feeCollector,
+          feeCollectorAtaB: feeCollectorAtaB,
           escrow: escrow,
           vault: vault,
+          paymentVault: null, // One-step takes have no payment vault
           associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
           tokenProgram: TOKEN_PROGRAM_ID,
           systemProgram: SystemProgram.programId,
@@ -291,7 +382,7 @@ describe("Escrow Program Tests", () => {
     beforeEach(async () => {
       // Create escrow first (needed for refund tests)
       await program.methods
-        .make(seed, receiveAmount, depositAmount)
+        .make(seed, receiveAmount, depositAmount, null)
         .accounts({
           maker: maker.publicKey,
           mintA: mintA,
@@ -365,11 +456,1429 @@ describe("Escrow Program Tests", () => {
     });
   });
   
+  describe("Update Escrow Tests", () => {
+    const newReceiveAmount = new anchor.BN(800_000_000); // 800 tokens (with 6 decimals)
+    
+    beforeEach(async () => {
+      // Create escrow first (needed for update tests)
+      await program.methods
+        .make(seed, receiveAmount, depositAmount, null)
+        .accounts({
+          maker: maker.publicKey,
+          mintA: mintA,
+          mintB: mintB,
+          makerAtaA: makerAtaA,
+          escrow: escrow,
+          vault: vault,
+          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([maker])
+        .rpc();
+      
+      console.log("🔄 Escrow created for update tests");
+    });
+    
+    it("Should update the receive amount without touching the vault", async () => {
+      console.log("\n✏️  Testing update escrow...");
+      
+      await program.methods
+        .updateEscrow(newReceiveAmount)
+        .accounts({
+          maker: maker.publicKey,
+          escrow: escrow,
+        })
+        .signers([maker])
+        .rpc();
+      
+      const escrowAccount = await program.account.escrow.fetch(escrow);
+      assert.equal(escrowAccount.receive.toString(), newReceiveAmount.toString());
+      
+      const vaultAccount = await getAccount(provider.connection, vault);
+      assert.equal(vaultAccount.amount.toString(), depositAmount.toString());
+      
+      // Taker now pays the new price
+      await program.methods
+        .take(takeParams())
+        .accounts({
+          taker: taker.publicKey,
+          maker: maker.publicKey,
+          mintA: mintA,
+          mintB: mintB,
+          takerAtaA: takerAtaA,
+          takerAtaB: takerAtaB,
+          makerAtaB: makerAtaB,
+          config: config,
+          feeCollector: feeCollector,
+          feeCollectorAtaB: feeCollectorAtaB,
+          escrow: escrow,
+          vault: vault,
+          paymentVault: null, // One-step takes have no payment vault
+          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([taker])
+        .rpc();
+      
+      const makerBalanceB = await getAccount(provider.connection, makerAtaB);
+      assert.equal(makerBalanceB.amount.toString(), newReceiveAmount.toString());
+      
+      console.log("✅ Update escrow test passed!");
+    });
+    
+    it("Should reject a zero receive amount", async () => {
+      try {
+        await program.methods
+          .updateEscrow(new anchor.BN(0))
+          .accounts({
+            maker: maker.publicKey,
+            escrow: escrow,
+          })
+          .signers([maker])
+          .rpc();
+        
+        assert.fail("Should have failed with InvalidAmount");
+      } catch (error) {
+        console.log(`✅ Correctly rejected zero amount: ${error.message}`);
+        assert(error.message.includes("InvalidAmount"));
+      }
+    });
+    
+    it("Should reject updates from anyone but the maker", async () => {
+      try {
+        await program.methods
+          .updateEscrow(new anchor.BN(1))
+          .accounts({
+            maker: taker.publicKey,
+            escrow: escrow,
+          })
+          .signers([taker])
+          .rpc();
+        
+        assert.fail("Should have failed");
+      } catch (error) {
+        console.log(`✅ Correctly rejected non-maker update: ${error.message}`);
+        assert(!error.message.includes("Should have failed"));
+      }
+      
+      const escrowAccount = await program.account.escrow.fetch(escrow);
+      assert.equal(escrowAccount.receive.toString(), receiveAmount.toString());
+    });
+  });
+  
+  describe("Take Protection Tests", () => {
+    beforeEach(async () => {
+      // Create escrow first (needed for take protection tests)
+      await program.methods
+        .make(seed, receiveAmount, depositAmount, null)
+        .accounts({
+          maker: maker.publicKey,
+          mintA: mintA,
+          mintB: mintB,
+          makerAtaA: makerAtaA,
+          escrow: escrow,
+          vault: vault,
+          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([maker])
+        .rpc();
+    });
+    
+    const takeWith = (params: { minReceive: anchor.BN; deadline: anchor.BN; maxPay: anchor.BN }) =>
+      program.methods
+        .take(params)
+        .accounts({
+          taker: taker.publicKey,
+          maker: maker.publicKey,
+          mintA: mintA,
+          mintB: mintB,
+          takerAtaA: takerAtaA,
+          takerAtaB: takerAtaB,
+          makerAtaB: makerAtaB,
+          config: config,
+          feeCollector: feeCollector,
+          feeCollectorAtaB: feeCollectorAtaB,
+          escrow: escrow,
+          vault: vault,
+          paymentVault: null, // One-step takes have no payment vault
+          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([taker])
+        .rpc();
+    
+    it("Should take when within both minimum receive and deadline", async () => {
+      console.log("\n🛡️  Testing take within bounds...");
+      
+      await takeWith(takeParams(depositAmount));
+      
+      const takerBalanceA = await getAccount(provider.connection, takerAtaA);
+      assert.equal(takerBalanceA.amount.toString(), depositAmount.toString());
+      
+      console.log("✅ Take within bounds passed!");
+    });
+    
+    it("Should reject when the vault holds less than the minimum receive", async () => {
+      console.log("\n⚠️  Testing minimum receive protection...");
+      
+      try {
+        // Ask for one more token than the vault holds, with a valid deadline
+        await takeWith(takeParams(depositAmount.addn(1)));
+        assert.fail("Should have failed with ReceiveBelowMinimum");
+      } catch (error) {
+        console.log(`✅ Correctly rejected low payout: ${error.message}`);
+        assert(error.message.includes("ReceiveBelowMinimum"));
+      }
+      
+      // Escrow is untouched
+      const vaultAccount = await getAccount(provider.connection, vault);
+      assert.equal(vaultAccount.amount.toString(), depositAmount.toString());
+    });
+    
+    it("Should take when the price is within the taker's maximum", async () => {
+      console.log("\n🛡️  Testing slippage protection within bounds...");
+      
+      // Exactly the escrow's price is accepted
+      await takeWith(takeParams(depositAmount, undefined, receiveAmount));
+      
+      const makerBalanceB = await getAccount(provider.connection, makerAtaB);
+      assert.equal(makerBalanceB.amount.toString(), receiveAmount.toString());
+      
+      console.log("✅ Take within slippage bound passed!");
+    });
+    
+    it("Should reject when the maker raised the price above the taker's maximum", async () => {
+      console.log("\n⚠️  Testing slippage protection...");
+      
+      // The maker reprices after the taker read the escrow
+      await program.methods
+        .updateEscrow(receiveAmount.addn(1))
+        .accounts({
+          maker: maker.publicKey,
+          escrow: escrow,
+        })
+        .signers([maker])
+        .rpc();
+      
+      try {
+        await takeWith(takeParams(depositAmount, undefined, receiveAmount));
+        assert.fail("Should have failed with SlippageExceeded");
+      } catch (error) {
+        console.log(`✅ Correctly rejected repriced take: ${error.message}`);
+        assert(error.message.includes("SlippageExceeded"));
+      }
+      
+      // Escrow is untouched
+      const vaultAccount = await getAccount(provider.connection, vault);
+      assert.equal(vaultAccount.amount.toString(), depositAmount.toString());
+    });
+    
+    it("Should reject when the deadline has passed", async () => {
+      console.log("\n⚠️  Testing deadline protection...");
+      
+      try {
+        // Deadline well in the past, with a satisfiable minimum
+        const expired = new anchor.BN(Math.floor(Date.now() / 1000) - 60);
+        await takeWith(takeParams(depositAmount, expired));
+        assert.fail("Should have failed with EscrowExpired");
+      } catch (error) {
+        console.log(`✅ Correctly rejected expired take: ${error.message}`);
+        assert(error.message.includes("EscrowExpired"));
+      }
+      
+      // Escrow is untouched
+      const vaultAccount = await getAccount(provider.connection, vault);
+      assert.equal(vaultAccount.amount.toString(), depositAmount.toString());
+    });
+    
+    it("Should take when the vault matches the recorded deposit", async () => {
+      console.log("\n🧾 Testing vault balance check with a matching vault...");
+      
+      const escrowAccount = await program.account.escrow.fetch(escrow);
+      const vaultAccount = await getAccount(provider.connection, vault);
+      assert.equal(vaultAccount.amount.toString(), escrowAccount.deposit.toString());
+      
+      await takeWith(takeParams());
+      
+      const takerBalanceA = await getAccount(provider.connection, takerAtaA);
+      assert.equal(takerBalanceA.amount.toString(), depositAmount.toString());
+    });
+    
+    it("Should still take when the vault was credited from outside", async () => {
+      console.log("\n⚠️  Testing vault balance check with an extra credit...");
+      
+      // Anyone can send mint_a straight to the vault ATA
+      await mintTo(provider.connection, maker, mintA, vault, maker, 1);
+      
+      await takeWith(takeParams());
+      
+      // The taker gets the deposit plus the stray token, and the vault is closed
+      const takerBalanceA = await getAccount(provider.connection, takerAtaA);
+      assert.equal(takerBalanceA.amount.toString(), depositAmount.addn(1).toString());
+      assert.isNull(await provider.connection.getAccountInfo(vault));
+      
+      console.log("✅ Dust couldn't block the take!");
+    });
+    
+    const setExpiry = (expiresAt: anchor.BN) =>
+      program.methods
+        .setExpiry(expiresAt)
+        .accounts({
+          maker: maker.publicKey,
+          escrow: escrow,
+        })
+        .signers([maker])
+        .rpc();
+    
+    const chainTime = async () =>
+      provider.connection.getBlockTime(await provider.connection.getSlot());
+    
+    it("Should reject a take once the maker's expiry has passed", async () => {
+      console.log("\n⌛ Testing maker expiry...");
+      
+      const expiresAt = (await chainTime()) + 2;
+      await setExpiry(new anchor.BN(expiresAt));
+      
+      const escrowAccount = await program.account.escrow.fetch(escrow);
+      assert.equal(escrowAccount.expiresAt.toNumber(), expiresAt);
+      
+      while ((await chainTime()) < expiresAt) {
+        await new Promise((resolve) => setTimeout(resolve, 500));
+      }
+      
+      try {
+        await takeWith(takeParams());
+        assert.fail("Should have failed with EscrowExpired");
+      } catch (error) {
+        console.log(`✅ Correctly rejected take after expiry: ${error.message}`);
+        assert(error.message.includes("EscrowExpired"));
+      }
+    });
+    
+    it("Should reject an expiry in the past", async () => {
+      try {
+        await setExpiry(new anchor.BN((await chainTime()) - 60));
+        assert.fail("Should have failed with InvalidExpiry");
+      } catch (error) {
+        console.log(`✅ Correctly rejected past expiry: ${error.message}`);
+        assert(error.message.includes("InvalidExpiry"));
+      }
+    });
+  });
+  
+  describe("Taker Allowlist Tests", () => {
+    beforeEach(async () => {
+      // Create an OTC escrow that only the taker may fulfill
+      await program.methods
+        .make(seed, receiveAmount, depositAmount, taker.publicKey)
+        .accounts({
+          maker: maker.publicKey,
+          mintA: mintA,
+          mintB: mintB,
+          makerAtaA: makerAtaA,
+          escrow: escrow,
+          vault: vault,
+          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([maker])
+        .rpc();
+      
+      console.log("🔄 OTC escrow created for allowlist tests");
+    });
+    
+    it("Should store the allowed taker on the escrow", async () => {
+      const escrowAccount = await program.account.escrow.fetch(escrow);
+      assert.equal(escrowAccount.allowedTaker.toString(), taker.publicKey.toString());
+    });
+    
+    it("Should let the allowed taker take the escrow", async () => {
+      console.log("\n🤝 Testing take by the allowed taker...");
+      
+      await program.methods
+        .take(takeParams())
+        .accounts({
+          taker: taker.publicKey,
+          maker: maker.publicKey,
+          mintA: mintA,
+          mintB: mintB,
+          takerAtaA: takerAtaA,
+          takerAtaB: takerAtaB,
+          makerAtaB: makerAtaB,
+          config: config,
+          feeCollector: feeCollector,
+          feeCollectorAtaB: feeCollectorAtaB,
+          escrow: escrow,
+          vault: vault,
+          paymentVault: null, // One-step takes have no payment vault
+          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([taker])
+        .rpc();
+      
+      const takerBalanceA = await getAccount(provider.connection, takerAtaA);
+      assert.equal(takerBalanceA.amount.toString(), depositAmount.toString());
+      
+      console.log("✅ Allowed taker test passed!");
+    });
+    
+    it("Should reject a taker who is not on the allowlist", async () => {
+      console.log("\n⚠️  Testing take by a front-runner...");
+      
+      // A second taker with enough mintB to pay
+      const sniper = Keypair.generate();
+      await provider.connection.requestAirdrop(sniper.publicKey, LAMPORTS_PER_SOL);
+      await new Promise(resolve => setTimeout(resolve, 1000));
+      
+      const sniperAtaA = await getAssociatedTokenAddress(mintA, sniper.publicKey);
+      const sniperAtaB = await getAssociatedTokenAddress(mintB, sniper.publicKey);
+      await createAccount(provider.connection, sniper, mintB, sniper.publicKey);
+      await mintTo(provider.connection, taker, mintB, sniperAtaB, taker, 2000_000_000);
+      
+      try {
+        await program.methods
+          .take(takeParams())
+          .accounts({
+            taker: sniper.publicKey,
+            maker: maker.publicKey,
+            mintA: mintA,
+            mintB: mintB,
+            takerAtaA: sniperAtaA,
+            takerAtaB: sniperAtaB,
+            makerAtaB: makerAtaB,
+            config: config,
+            feeCollector: feeCollector,
+            feeCollectorAtaB: feeCollectorAtaB,
+            escrow: escrow,
+            vault: vault,
+            paymentVault: null, // One-step takes have no payment vault
+            associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+            tokenProgram: TOKEN_PROGRAM_ID,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([sniper])
+          .rpc();
+        
+        assert.fail("Should have failed with UnauthorizedTaker");
+      } catch (error) {
+        console.log(`✅ Correctly rejected front-runner: ${error.message}`);
+        assert(error.message.includes("UnauthorizedTaker"));
+      }
+      
+      // Escrow is untouched
+      const vaultAccount = await getAccount(provider.connection, vault);
+      assert.equal(vaultAccount.amount.toString(), depositAmount.toString());
+    });
+  });
+  
+  describe("Vesting Escrow Tests", () => {
+    // Short schedule so the test can wait it out on a local validator
+    const cliffDuration = new anchor.BN(3);   // seconds before anything unlocks
+    const vestingDuration = new anchor.BN(8); // seconds until everything unlocks
+    
+    let vestingEscrow: PublicKey;
+    let vestingVault: PublicKey;
+    
+    const sleep = (seconds: number) => new Promise(resolve => setTimeout(resolve, seconds * 1000));
+    
+    const claimVested = () =>
+      program.methods
+        .claimVested()
+        .accounts({
+          taker: taker.publicKey,
+          mintA: mintA,
+          takerAtaA: takerAtaA,
+          vestingEscrow: vestingEscrow,
+          vestingVault: vestingVault,
+          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([taker])
+        .rpc();
+    
+    beforeEach(async () => {
+      // Create a vesting escrow and take it (locks mint_a for the taker)
+      await program.methods
+        .makeVesting(seed, receiveAmount, depositAmount, cliffDuration, vestingDuration)
+        .accounts({
+          maker: maker.publicKey,
+          mintA: mintA,
+          mintB: mintB,
+          makerAtaA: makerAtaA,
+          escrow: escrow,
+          vault: vault,
+          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([maker])
+        .rpc();
+      
+      [vestingEscrow] = PublicKey.findProgramAddressSync(
+        [Buffer.from("vesting"), escrow.toBuffer()],
+        program.programId
+      );
+      vestingVault = await getAssociatedTokenAddress(mintA, vestingEscrow, true); // true = allow PDA
+      
+      await program.methods
+        .takeVesting(takeParams())
+        .accounts({
+          taker: taker.publicKey,
+          maker: maker.publicKey,
+          mintA: mintA,
+          mintB: mintB,
+          takerAtaB: takerAtaB,
+          makerAtaB: makerAtaB,
+          config: config,
+          feeCollector: feeCollector,
+          feeCollectorAtaB: feeCollectorAtaB,
+          escrow: escrow,
+          vault: vault,
+          vestingEscrow: vestingEscrow,
+          vestingVault: vestingVault,
+          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([taker])
+        .rpc();
+      
+      console.log("🔄 Vesting escrow created and taken");
+    });
+    
+    it("Should pay the maker and lock mint_a in the vesting vault", async () => {
+      console.log("\n🔒 Testing take_vesting...");
+      
+      // Maker is paid up front
+      const makerBalanceB = await getAccount(provider.connection, makerAtaB);
+      assert.equal(makerBalanceB.amount.toString(), receiveAmount.toString());
+      
+      // All of mint_a moved into the vesting vault
+      const vestingVaultAccount = await getAccount(provider.connection, vestingVault);
+      assert.equal(vestingVaultAccount.amount.toString(), depositAmount.toString());
+      
+      // Schedule is recorded relative to the take
+      const schedule = await program.account.vestingEscrow.fetch(vestingEscrow);
+      assert.equal(schedule.taker.toString(), taker.publicKey.toString());
+      assert.equal(schedule.totalAmount.toString(), depositAmount.toString());
+      assert.equal(schedule.claimedAmount.toString(), "0");
+      assert.equal(schedule.cliffTime.sub(schedule.startTime).toString(), cliffDuration.toString());
+      assert.equal(schedule.endTime.sub(schedule.startTime).toString(), vestingDuration.toString());
+      
+      // The original escrow is closed
+      try {
+        await program.account.escrow.fetch(escrow);
+        assert.fail("Escrow account should be closed");
+      } catch (error) {
+        console.log("✅ Escrow account properly closed");
+      }
+      
+      console.log("✅ take_vesting test passed!");
+    });
+    
+    it("Should release nothing before the cliff", async () => {
+      console.log("\n⏳ Testing claim before cliff...");
+      
+      try {
+        await claimVested();
+        assert.fail("Should have failed before the cliff");
+      } catch (error) {
+        console.log(`✅ Correctly rejected early claim: ${error.message}`);
+        assert(error.message.includes("CliffNotReached"));
+      }
+      
+      // Taker has received nothing and the vault is untouched
+      const vestingVaultAccount = await getAccount(provider.connection, vestingVault);
+      assert.equal(vestingVaultAccount.amount.toString(), depositAmount.toString());
+      
+      const schedule = await program.account.vestingEscrow.fetch(vestingEscrow);
+      assert.equal(schedule.claimedAmount.toString(), "0");
+      
+      console.log("✅ Pre-cliff test passed!");
+    });
+    
+    it("Should release tokens linearly after the cliff", async () => {
+      console.log("\n📈 Testing linear release...");
+      
+      const schedule = await program.account.vestingEscrow.fetch(vestingEscrow);
+      const total = BigInt(depositAmount.toString());
+      const start = BigInt(schedule.startTime.toString());
+      const duration = BigInt(vestingDuration.toString());
+      
+      // Claim part way through the schedule (after the cliff, before the end)
+      await sleep(cliffDuration.toNumber() + 2);
+      await claimVested();
+      
+      const partial = await program.account.vestingEscrow.fetch(vestingEscrow);
+      const claimed = BigInt(partial.claimedAmount.toString());
+      const takerBalance = await getAccount(provider.connection, takerAtaA);
+      
+      console.log(`Claimed after cliff: ${claimed} of ${total}`);
+      assert.equal(takerBalance.amount.toString(), claimed.toString());
+      assert(claimed > 0n && claimed < total, "Only part of the deposit should be released");
+      
+      // The claimed amount matches the linear schedule at some point in the window
+      // between the cliff and the current block time
+      const now = BigInt(await provider.connection.getBlockTime(await provider.connection.getSlot()));
+      const minExpected = (total * BigInt(cliffDuration.toString())) / duration;
+      const maxExpected = (total * (now - start)) / duration;
+      assert(claimed >= minExpected, "Claimed less than vested at the cliff");
+      assert(claimed <= maxExpected, "Claimed more than has vested");
+      
+      // Claim the rest once the schedule has ended
+      await sleep(vestingDuration.toNumber());
+      await claimVested();
+      
+      const finalTakerBalance = await getAccount(provider.connection, takerAtaA);
+      assert.equal(finalTakerBalance.amount.toString(), depositAmount.toString());
+      
+      // Vesting accounts are closed once everything is claimed
+      try {
+        await program.account.vestingEscrow.fetch(vestingEscrow);
+        assert.fail("Vesting escrow should be closed");
+      } catch (error) {
+        console.log("✅ Vesting escrow properly closed");
+      }
+      
+      try {
+        await getAccount(provider.connection, vestingVault);
+        assert.fail("Vesting vault should be closed");
+      } catch (error) {
+        console.log("✅ Vesting vault properly closed");
+      }
+      
+      console.log("✅ Linear release test passed!");
+    });
+  });
+  
+  describe("Native SOL Escrow Tests", () => {
+    // Separate seed so these escrows never collide with the SPL ones above
+    const solSeed = new anchor.BN(7);
+    const receiveLamports = new anchor.BN(LAMPORTS_PER_SOL / 2); // 0.5 SOL
+    
+    let solEscrow: PublicKey;
+    let solVault: PublicKey;
+    
+    before(async () => {
+      [solEscrow] = PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("escrow"),
+          maker.publicKey.toBuffer(),
+          solSeed.toArrayLike(Buffer, "le", 8)
+        ],
+        program.programId
+      );
+      solVault = await getAssociatedTokenAddress(mintA, solEscrow, true); // true = allow PDA
+    });
+    
+    beforeEach(async () => {
+      // Create an escrow that asks for lamports instead of mintB
+      await program.methods
+        .makeSol(solSeed, receiveLamports, depositAmount)
+        .accounts({
+          maker: maker.publicKey,
+          mintA: mintA,
+          makerAtaA: makerAtaA,
+          escrow: solEscrow,
+          vault: solVault,
+          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([maker])
+        .rpc();
+      
+      console.log("🔄 Native SOL escrow created");
+    });
+    
+    it("Should store the lamport price and native flag", async () => {
+      const escrowAccount = await program.account.escrow.fetch(solEscrow);
+      assert.equal(escrowAccount.receive.toString(), receiveLamports.toString());
+      assert.isTrue(escrowAccount.receiveIsNative);
+      assert.equal(escrowAccount.deposit.toString(), depositAmount.toString());
+      
+      const vaultAccount = await getAccount(provider.connection, solVault);
+      assert.equal(vaultAccount.amount.toString(), depositAmount.toString());
+      
+      // Clean up so the next test can recreate the escrow
+      await program.methods
+        .refund()
+        .accounts({
+          maker: maker.publicKey,
+          mintA: mintA,
+          makerAtaA: makerAtaA,
+          escrow: solEscrow,
+          vault: solVault,
+          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([maker])
+        .rpc();
+    });
+    
+    it("Should reject the SPL take path for a native SOL escrow", async () => {
+      console.log("\n⚠️  Testing take on a native SOL escrow...");
+      
+      try {
+        await program.methods
+          .take(takeParams())
+          .accounts({
+            taker: taker.publicKey,
+            maker: maker.publicKey,
+            mintA: mintA,
+            mintB: mintB,
+            takerAtaA: takerAtaA,
+            takerAtaB: takerAtaB,
+            makerAtaB: makerAtaB,
+            config: config,
+            feeCollector: feeCollector,
+            feeCollectorAtaB: feeCollectorAtaB,
+            escrow: solEscrow,
+            vault: solVault,
+            paymentVault: null, // One-step takes have no payment vault
+            associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+            tokenProgram: TOKEN_PROGRAM_ID,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([taker])
+          .rpc();
+        
+        assert.fail("Should have failed");
+      } catch (error) {
+        // mint_b is the native mint, so has_one rejects it before the handler runs
+        console.log(`✅ Correctly rejected SPL take: ${error.message}`);
+        assert(!error.message.includes("Should have failed"));
+      }
+      
+      // Refund restores the maker's mintA
+      const initialMakerBalance = await getAccount(provider.connection, makerAtaA);
+      
+      await program.methods
+        .refund()
+        .accounts({
+          maker: maker.publicKey,
+          mintA: mintA,
+          makerAtaA: makerAtaA,
+          escrow: solEscrow,
+          vault: solVault,
+          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([maker])
+        .rpc();
+      
+      const finalMakerBalance = await getAccount(provider.connection, makerAtaA);
+      const expectedMakerBalance = BigInt(initialMakerBalance.amount.toString()) + BigInt(depositAmount.toString());
+      assert.equal(finalMakerBalance.amount.toString(), expectedMakerBalance.toString());
+    });
+    
+    it("Should pay the maker in lamports and deliver mintA to the taker", async () => {
+      console.log("\n💰 Testing take_sol...");
+      
+      const initialMakerLamports = await provider.connection.getBalance(maker.publicKey);
+      const initialTakerBalanceA = await getAccount(provider.connection, takerAtaA)
+        .then(account => BigInt(account.amount.toString()))
+        .catch(() => BigInt(0));
+      
+      const tx = await program.methods
+        .takeSol(takeParams(depositAmount, undefined, receiveLamports))
+        .accounts({
+          taker: taker.publicKey,
+          maker: maker.publicKey,
+          mintA: mintA,
+          takerAtaA: takerAtaA,
+          config: config,
+          feeCollector: feeCollector,
+          escrow: solEscrow,
+          vault: solVault,
+          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([taker])
+        .rpc();
+      
+      console.log(`✅ Take SOL transaction: ${tx}`);
+      
+      // Maker gets the price plus the rent from the closed escrow and vault
+      const finalMakerLamports = await provider.connection.getBalance(maker.publicKey);
+      assert.isAtLeast(finalMakerLamports - initialMakerLamports, receiveLamports.toNumber());
+      
+      const finalTakerBalanceA = await getAccount(provider.connection, takerAtaA);
+      assert.equal(
+        finalTakerBalanceA.amount.toString(),
+        (initialTakerBalanceA + BigInt(depositAmount.toString())).toString()
+      );
+      
+      try {
+        await program.account.escrow.fetch(solEscrow);
+        assert.fail("Escrow account should be closed");
+      } catch (error) {
+        console.log("✅ Escrow account properly closed");
+      }
+      
+      console.log("✅ Native SOL escrow test passed!");
+    });
+    
+    it("Should reject take_sol when the maker raised the lamport price", async () => {
+      console.log("\n⚠️  Testing take_sol slippage protection...");
+      
+      // The maker reprices after the taker read the escrow
+      await program.methods
+        .updateEscrow(receiveLamports.addn(1))
+        .accounts({
+          maker: maker.publicKey,
+          escrow: solEscrow,
+        })
+        .signers([maker])
+        .rpc();
+      
+      try {
+        await program.methods
+          .takeSol(takeParams(depositAmount, undefined, receiveLamports))
+          .accounts({
+            taker: taker.publicKey,
+            maker: maker.publicKey,
+            mintA: mintA,
+            takerAtaA: takerAtaA,
+            config: config,
+            feeCollector: feeCollector,
+            escrow: solEscrow,
+            vault: solVault,
+            associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+            tokenProgram: TOKEN_PROGRAM_ID,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([taker])
+          .rpc();
+        assert.fail("Should have failed with SlippageExceeded");
+      } catch (error) {
+        console.log(`✅ Correctly rejected repriced take_sol: ${error.message}`);
+        assert(error.message.includes("SlippageExceeded"));
+      }
+      
+      // Clean up so the next test can recreate the escrow
+      await program.methods
+        .refund()
+        .accounts({
+          maker: maker.publicKey,
+          mintA: mintA,
+          makerAtaA: makerAtaA,
+          escrow: solEscrow,
+          vault: solVault,
+          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([maker])
+        .rpc();
+    });
+  });
+  
+  describe("Protocol Fee Tests", () => {
+    beforeEach(async () => {
+      // Create escrow first (needed for fee tests)
+      await program.methods
+        .make(seed, receiveAmount, depositAmount, null)
+        .accounts({
+          maker: maker.publicKey,
+          mintA: mintA,
+          mintB: mintB,
+          makerAtaA: makerAtaA,
+          escrow: escrow,
+          vault: vault,
+          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([maker])
+        .rpc();
+      
+      console.log("🔄 Escrow created for fee tests");
+    });
+    
+    it("Should store the fee authority and rate on the config", async () => {
+      const configAccount = await program.account.config.fetch(config);
+      assert.equal(configAccount.authority.toString(), feeCollector.toString());
+      assert.equal(configAccount.feeBps, 0);
+      
+      // Clean up so the next test can recreate the escrow
+      await program.methods
+        .refund()
+        .accounts({
+          maker: maker.publicKey,
+          mintA: mintA,
+          makerAtaA: makerAtaA,
+          escrow: escrow,
+          vault: vault,
+          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([maker])
+        .rpc();
+    });
+    
+    it("Should pay the maker in full when the fee is zero", async () => {
+      console.log("\n💸 Testing take with a zero protocol fee...");
+      
+      const initialTakerBalanceB = await getAccount(provider.connection, takerAtaB);
+      
+      await program.methods
+        .take(takeParams())
+        .accounts({
+          taker: taker.publicKey,
+          maker: maker.publicKey,
+          mintA: mintA,
+          mintB: mintB,
+          takerAtaA: takerAtaA,
+          takerAtaB: takerAtaB,
+          makerAtaB: makerAtaB,
+          config: config,
+          feeCollector: feeCollector,
+          feeCollectorAtaB: feeCollectorAtaB,
+          escrow: escrow,
+          vault: vault,
+          paymentVault: null, // One-step takes have no payment vault
+          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([taker])
+        .rpc();
+      
+      // Maker receives exactly what they asked for, same as before fees existed
+      const makerBalanceB = await getAccount(provider.connection, makerAtaB);
+      assert.equal(makerBalanceB.amount.toString(), receiveAmount.toString());
+      
+      // Taker pays exactly the receive amount
+      const finalTakerBalanceB = await getAccount(provider.connection, takerAtaB);
+      const expectedTakerBalanceB = BigInt(initialTakerBalanceB.amount.toString()) - BigInt(receiveAmount.toString());
+      assert.equal(finalTakerBalanceB.amount.toString(), expectedTakerBalanceB.toString());
+      
+      // Nothing is routed to the fee collector
+      const feeBalanceB = await getAccount(provider.connection, feeCollectorAtaB);
+      assert.equal(feeBalanceB.amount.toString(), "0");
+      
+      console.log("✅ Zero fee test passed!");
+    });
+  });
+  
+  describe("Maker Stats Tests", () => {
+    let makerStats: PublicKey;
+    
+    beforeEach(async () => {
+      [makerStats] = PublicKey.findProgramAddressSync(
+        [Buffer.from("maker_stats"), maker.publicKey.toBuffer()],
+        program.programId
+      );
+      
+      await program.methods
+        .make(seed, receiveAmount, depositAmount, null)
+        .accounts({
+          maker: maker.publicKey,
+          mintA: mintA,
+          mintB: mintB,
+          makerAtaA: makerAtaA,
+          escrow: escrow,
+          makerStats: makerStats,
+          vault: vault,
+          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([maker])
+        .rpc();
+    });
+    
+    it("Should count an escrow as open once it is made", async () => {
+      console.log("\n📊 Testing maker stats after make...");
+      
+      const stats = await program.account.makerStats.fetch(makerStats);
+      assert.equal(stats.maker.toString(), maker.publicKey.toString());
+      assert.equal(stats.openCount.toNumber(), 1);
+      assert.equal(stats.totalCreated.toNumber(), 1);
+      
+      // A second escrow from the same maker reuses the stats account
+      const secondSeed = new anchor.BN(43);
+      const [secondEscrow] = PublicKey.findProgramAddressSync(
+        [Buffer.from("escrow"), maker.publicKey.toBuffer(), secondSeed.toArrayLike(Buffer, "le", 8)],
+        program.programId
+      );
+      
+      await program.methods
+        .make(secondSeed, receiveAmount, depositAmount, null)
+        .accounts({
+          maker: maker.publicKey,
+          mintA: mintA,
+          mintB: mintB,
+          makerAtaA: makerAtaA,
+          escrow: secondEscrow,
+          makerStats: makerStats,
+          vault: await getAssociatedTokenAddress(mintA, secondEscrow, true),
+          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([maker])
+        .rpc();
+      
+      const updated = await program.account.makerStats.fetch(makerStats);
+      assert.equal(updated.openCount.toNumber(), 2);
+      assert.equal(updated.totalCreated.toNumber(), 2);
+      
+      console.log("✅ Make increments open and total counts!");
+    });
+    
+    it("Should stop counting an escrow as open once it is taken", async () => {
+      console.log("\n📊 Testing maker stats after take...");
+      
+      await program.methods
+        .take(takeParams())
+        .accounts({
+          taker: taker.publicKey,
+          maker: maker.publicKey,
+          mintA: mintA,
+          mintB: mintB,
+          takerAtaA: takerAtaA,
+          takerAtaB: takerAtaB,
+          makerAtaB: makerAtaB,
+          config: config,
+          feeCollector: feeCollector,
+          feeCollectorAtaB: feeCollectorAtaB,
+          escrow: escrow,
+          vault: vault,
+          paymentVault: null, // One-step takes have no payment vault
+          makerStats: makerStats,
+          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([taker])
+        .rpc();
+      
+      const stats = await program.account.makerStats.fetch(makerStats);
+      assert.equal(stats.openCount.toNumber(), 0);
+      assert.equal(stats.totalCreated.toNumber(), 1);
+      
+      console.log("✅ Take decrements the open count!");
+    });
+    
+    it("Should stop counting an escrow as open once it is refunded", async () => {
+      console.log("\n📊 Testing maker stats after refund...");
+      
+      await program.methods
+        .refund()
+        .accounts({
+          maker: maker.publicKey,
+          mintA: mintA,
+          makerAtaA: makerAtaA,
+          escrow: escrow,
+          vault: vault,
+          makerStats: makerStats,
+          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([maker])
+        .rpc();
+      
+      const stats = await program.account.makerStats.fetch(makerStats);
+      assert.equal(stats.openCount.toNumber(), 0);
+      assert.equal(stats.totalCreated.toNumber(), 1);
+      
+      console.log("✅ Refund decrements the open count!");
+    });
+  });
+  
+  describe("Two-Step Take Tests", () => {
+    let paymentVault: PublicKey; // Escrow-owned mint_b account holding the proposed payment
+    
+    const proposeTake = () =>
+      program.methods
+        .take(takeParams())
+        .accounts({
+          taker: taker.publicKey,
+          maker: maker.publicKey,
+          mintA: mintA,
+          mintB: mintB,
+          takerAtaA: takerAtaA,
+          takerAtaB: takerAtaB,
+          makerAtaB: makerAtaB,
+          config: config,
+          feeCollector: feeCollector,
+          feeCollectorAtaB: feeCollectorAtaB,
+          escrow: escrow,
+          vault: vault,
+          paymentVault: paymentVault,
+          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([taker])
+        .rpc();
+    
+    const cancelTake = (signer: Keypair) =>
+      program.methods
+        .cancelTake()
+        .accounts({
+          signer: signer.publicKey,
+          taker: taker.publicKey,
+          maker: maker.publicKey,
+          mintB: mintB,
+          takerAtaB: takerAtaB,
+          escrow: escrow,
+          paymentVault: paymentVault,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .signers([signer])
+        .rpc();
+    
+    beforeEach(async () => {
+      paymentVault = await getAssociatedTokenAddress(mintB, escrow, true);
+      
+      await program.methods
+        .make(seed, receiveAmount, depositAmount, null)
+        .accounts({
+          maker: maker.publicKey,
+          mintA: mintA,
+          mintB: mintB,
+          makerAtaA: makerAtaA,
+          escrow: escrow,
+          vault: vault,
+          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([maker])
+        .rpc();
+      
+      await program.methods
+        .setTwoStep(true)
+        .accounts({
+          maker: maker.publicKey,
+          escrow: escrow,
+        })
+        .signers([maker])
+        .rpc();
+    });
+    
+    it("Should hold the payment until the maker confirms the taker", async () => {
+      console.log("\n🤝 Testing propose and confirm...");
+      
+      await proposeTake();
+      
+      // Nothing is swapped yet: the payment sits in the escrow's payment vault
+      let escrowAccount = await program.account.escrow.fetch(escrow);
+      assert.equal(escrowAccount.pendingTaker.toString(), taker.publicKey.toString());
+      assert.equal(escrowAccount.pendingPayment.toString(), receiveAmount.toString());
+      
+      const heldPayment = await getAccount(provider.connection, paymentVault);
+      assert.equal(heldPayment.amount.toString(), receiveAmount.toString());
+      
+      const vaultAccount = await getAccount(provider.connection, vault);
+      assert.equal(vaultAccount.amount.toString(), depositAmount.toString());
+      
+      // A second proposal is rejected while the first is pending
+      try {
+        await proposeTake();
+        assert.fail("Should have failed with TakePending");
+      } catch (error) {
+        assert(error.message.includes("TakePending"));
+      }
+      
+      await program.methods
+        .confirmTake()
+        .accounts({
+          maker: maker.publicKey,
+          taker: taker.publicKey,
+          mintA: mintA,
+          mintB: mintB,
+          takerAtaA: takerAtaA,
+          makerAtaB: makerAtaB,
+          config: config,
+          feeCollector: feeCollector,
+          feeCollectorAtaB: feeCollectorAtaB,
+          escrow: escrow,
+          vault: vault,
+          paymentVault: paymentVault,
+          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([maker])
+        .rpc();
+      
+      const takerBalanceA = await getAccount(provider.connection, takerAtaA);
+      assert.equal(takerBalanceA.amount.toString(), depositAmount.toString());
+      
+      const makerBalanceB = await getAccount(provider.connection, makerAtaB);
+      assert.equal(makerBalanceB.amount.toString(), receiveAmount.toString());
+      
+      // Escrow and both vaults are closed
+      for (const account of [escrow, vault, paymentVault]) {
+        assert.isNull(await provider.connection.getAccountInfo(account));
+      }
+      
+      console.log("✅ Confirmed take completed the swap!");
+    });
+    
+    it("Should return the payment when the take is cancelled", async () => {
+      console.log("\n🤝 Testing propose and cancel...");
+      
+      const takerBefore = await getAccount(provider.connection, takerAtaB);
+      
+      await proposeTake();
+      
+      // The maker can't pull the deposit out from under a pending taker
+      try {
+        await program.methods
+          .refund()
+          .accounts({
+            maker: maker.publicKey,
+            mintA: mintA,
+            makerAtaA: makerAtaA,
+            escrow: escrow,
+            vault: vault,
+            associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+            tokenProgram: TOKEN_PROGRAM_ID,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([maker])
+          .rpc();
+        assert.fail("Should have failed with TakePending");
+      } catch (error) {
+        assert(error.message.includes("TakePending"));
+      }
+      
+      // Strangers can't cancel someone else's take
+      const stranger = Keypair.generate();
+      try {
+        await cancelTake(stranger);
+        assert.fail("Should have failed with UnauthorizedCancel");
+      } catch (error) {
+        assert(error.message.includes("UnauthorizedCancel"));
+      }
+      
+      await cancelTake(maker);
+      
+      const takerAfter = await getAccount(provider.connection, takerAtaB);
+      assert.equal(takerAfter.amount.toString(), takerBefore.amount.toString());
+      assert.isNull(await provider.connection.getAccountInfo(paymentVault));
+      
+      // The escrow stays open with its deposit, ready for another taker
+      const escrowAccount = await program.account.escrow.fetch(escrow);
+      assert.isNull(escrowAccount.pendingTaker);
+      assert.equal(escrowAccount.pendingPayment.toNumber(), 0);
+      
+      const vaultAccount = await getAccount(provider.connection, vault);
+      assert.equal(vaultAccount.amount.toString(), depositAmount.toString());
+      
+      console.log("✅ Cancelled take returned the payment!");
+    });
+    
+    it("Should cancel even after dust is sent to the payment vault", async () => {
+      console.log("\n🤝 Testing cancel with a dusted payment vault...");
+      
+      const takerBefore = await getAccount(provider.connection, takerAtaB);
+      
+      await proposeTake();
+      
+      // Anyone can send tokens to the escrow's public payment vault
+      await mintTo(provider.connection, taker, mintB, paymentVault, taker, 1);
+      
+      await cancelTake(taker);
+      
+      // The payment and the dust go back to the taker and the vault is closed
+      const takerAfter = await getAccount(provider.connection, takerAtaB);
+      assert.equal(takerAfter.amount - takerBefore.amount, BigInt(1));
+      assert.isNull(await provider.connection.getAccountInfo(paymentVault));
+      
+      const escrowAccount = await program.account.escrow.fetch(escrow);
+      assert.isNull(escrowAccount.pendingTaker);
+      
+      console.log("✅ Dust couldn't lock the pending take!");
+    });
+  });
+  
+  describe("Basket Escrow Tests", () => {
+    let mintC: PublicKey; // Second token offered in the basket
+    let makerAtaC: PublicKey;
+    let basket: PublicKey;
+    const basketSeed = new anchor.BN(99);
+    const amountA = new anchor.BN(100_000_000);
+    const amountC = new anchor.BN(250_000_000);
+    
+    // Each offered token needs its mint, then two token accounts, in basket order
+    const itemAccounts = (owner: PublicKey, ownerFirst: boolean) =>
+      [mintA, mintC].flatMap(mint => {
+        const vaultForMint = anchor.utils.token.associatedAddress({ mint, owner: basket });
+        const ownerAta = anchor.utils.token.associatedAddress({ mint, owner });
+        const [first, second] = ownerFirst ? [ownerAta, vaultForMint] : [vaultForMint, ownerAta];
+        return [
+          { pubkey: mint, isSigner: false, isWritable: false },
+          { pubkey: first, isSigner: false, isWritable: true },
+          { pubkey: second, isSigner: false, isWritable: true },
+        ];
+      });
+    
+    beforeEach(async () => {
+      // The maker holds a second token to bundle with mintA
+      mintC = await createMint(provider.connection, maker, maker.publicKey, null, 6);
+      makerAtaC = await createAccount(provider.connection, maker, mintC, maker.publicKey);
+      await mintTo(provider.connection, maker, mintC, makerAtaC, maker, 1000_000_000);
+      
+      [basket] = PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("basket"),
+          maker.publicKey.toBuffer(),
+          basketSeed.toArrayLike(Buffer, "le", 8)
+        ],
+        program.programId
+      );
+      
+      await program.methods
+        .makeBasket(basketSeed, receiveAmount, [
+          { mint: mintA, amount: amountA },
+          { mint: mintC, amount: amountC },
+        ])
+        .accounts({
+          maker: maker.publicKey,
+          mintB: mintB,
+          basketEscrow: basket,
+          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          systemProgram: SystemProgram.programId,
+        })
+        .remainingAccounts(itemAccounts(maker.publicKey, true))
+        .signers([maker])
+        .rpc();
+      
+      console.log("🧺 Basket escrow created with two tokens");
+    });
+    
+    it("Should lock each offered token in its own vault", async () => {
+      const basketAccount = await program.account.basketEscrow.fetch(basket);
+      assert.equal(basketAccount.offered.length, 2);
+      assert.equal(basketAccount.receive.toString(), receiveAmount.toString());
+      
+      const vaultA = await getAccount(provider.connection, await getAssociatedTokenAddress(mintA, basket, true));
+      const vaultC = await getAccount(provider.connection, await getAssociatedTokenAddress(mintC, basket, true));
+      assert.equal(vaultA.amount.toString(), amountA.toString());
+      assert.equal(vaultC.amount.toString(), amountC.toString());
+    });
+    
+    it("Should deliver both tokens to the taker for a single payment", async () => {
+      console.log("\n🧺 Testing basket take...");
+      
+      await program.methods
+        .takeBasket()
+        .accounts({
+          taker: taker.publicKey,
+          maker: maker.publicKey,
+          mintB: mintB,
+          takerAtaB: takerAtaB,
+          makerAtaB: makerAtaB,
+          basketEscrow: basket,
+          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          systemProgram: SystemProgram.programId,
+        })
+        .remainingAccounts(itemAccounts(taker.publicKey, false))
+        .signers([taker])
+        .rpc();
+      
+      // Taker received every offered token (their token accounts were created on the fly)
+      const takerA = await getAccount(provider.connection, await getAssociatedTokenAddress(mintA, taker.publicKey));
+      const takerC = await getAccount(provider.connection, await getAssociatedTokenAddress(mintC, taker.publicKey));
+      assert.equal(takerA.amount.toString(), amountA.toString());
+      assert.equal(takerC.amount.toString(), amountC.toString());
+      
+      // Maker was paid once for the whole basket
+      const makerB = await getAccount(provider.connection, makerAtaB);
+      assert.equal(makerB.amount.toString(), receiveAmount.toString());
+      
+      // Every vault and the basket escrow itself are closed
+      for (const mint of [mintA, mintC]) {
+        const vaultInfo = await provider.connection.getAccountInfo(await getAssociatedTokenAddress(mint, basket, true));
+        assert.isNull(vaultInfo);
+      }
+      assert.isNull(await provider.connection.getAccountInfo(basket));
+      
+      console.log("✅ Basket take passed!");
+    });
+    
+    it("Should return every token to the maker on refund", async () => {
+      await program.methods
+        .refundBasket()
+        .accounts({
+          maker: maker.publicKey,
+          basketEscrow: basket,
+          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          systemProgram: SystemProgram.programId,
+        })
+        .remainingAccounts(itemAccounts(maker.publicKey, false))
+        .signers([maker])
+        .rpc();
+      
+      const makerA = await getAccount(provider.connection, makerAtaA);
+      const makerC = await getAccount(provider.connection, makerAtaC);
+      assert.equal(makerA.amount.toString(), "1000000000");
+      assert.equal(makerC.amount.toString(), "1000000000");
+      assert.isNull(await provider.connection.getAccountInfo(basket));
+    });
+    
+    it("Should reject a basket offering the same token twice", async () => {
+      const [dupBasket] = PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("basket"),
+          maker.publicKey.toBuffer(),
+          new anchor.BN(100).toArrayLike(Buffer, "le", 8)
+        ],
+        program.programId
+      );
+      
+      try {
+        await program.methods
+          .makeBasket(new anchor.BN(100), receiveAmount, [
+            { mint: mintA, amount: amountA },
+            { mint: mintA, amount: amountA },
+          ])
+          .accounts({
+            maker: maker.publicKey,
+            mintB: mintB,
+            basketEscrow: dupBasket,
+            associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+            tokenProgram: TOKEN_PROGRAM_ID,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([maker])
+          .rpc();
+        assert.fail("Should have failed with InvalidBasket");
+      } catch (error) {
+        console.log(`✅ Correctly rejected duplicate token: ${error.message}`);
+        assert(error.message.includes("InvalidBasket"));
+      }
+    });
+  });
+  
   describe("Error Handling Tests", () => {
     beforeEach(async () => {
       // Create escrow for error tests
       await program.methods
-        .make(seed, receiveAmount, depositAmount)
+        .make(seed, receiveAmount, depositAmount, null)
         .accounts({
           maker: maker.publicKey,
           mintA: mintA,
@@ -435,7 +1944,7 @@ describe("Escrow Program Tests", () => {
       
       try {
         await program.methods
-          .take()
+          .take(takeParams())
           .accounts({
             taker: poorTaker.publicKey,
             maker: maker.publicKey,
@@ -444,8 +1953,12 @@ describe("Escrow Program Tests", () => {
             takerAtaA: poorTakerAtaA,
             takerAtaB: poorTakerAtaB,
             makerAtaB: makerAtaB,
+            config: config,
+            feeCollector: feeCollector,
+            feeCollectorAtaB: feeCollectorAtaB,
             escrow: escrow,
             vault: vault,
+            paymentVault: null, // One-step takes have no payment vault
             associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
             tokenProgram: TOKEN_PROGRAM_ID,
             systemProgram: SystemProgram.programId,
@@ -1,4 +1,8 @@
 use anchor_lang::prelude::*;
 
 #[constant]
-pub const SEED: &str = "anchor";
\ No newline at end of file
+pub const SEED: &str = "anchor";
+
+pub const MAX_NAME_LEN: usize = 32;
+
+pub const MAX_FEE_BPS: u16 = 10_000;
\ No newline at end of file
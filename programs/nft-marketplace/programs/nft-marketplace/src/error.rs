@@ -8,4 +8,12 @@ pub enum MarketplaceError {
     NameTooLong,
     #[msg("Error while performing arithmetic probable overflow")]
     MathOverflowError,
+    #[msg("Listing price must be greater than zero")]
+    InvalidPrice,
+    #[msg("Fee cannot exceed 10,000 basis points")]
+    InvalidFee,
+    #[msg("Offer must be greater than zero and below the asking price")]
+    InvalidOfferAmount,
+    #[msg("NFT is not a verified member of the collection")]
+    InvalidCollection,
 }
\ No newline at end of file
@@ -0,0 +1,144 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token_interface::{
+        close_account, transfer_checked, CloseAccount, Mint, TokenAccount, TokenInterface,
+        TransferChecked,
+    },
+};
+
+use crate::error::MarketplaceError;
+use crate::{calculate_fee, Listing, Marketplace, Offer};
+
+#[derive(Accounts)]
+pub struct AcceptOffer<'info> {
+    #[account(mut)]
+    pub seller: Signer<'info>,
+    #[account(mut)]
+    pub bidder: SystemAccount<'info>,
+
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        init_if_needed,
+        payer = seller,
+        associated_token::mint = mint,
+        associated_token::authority = bidder,
+        associated_token::token_program = token_program
+    )]
+    pub bidder_ata: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        seeds = [b"listing", seller.key().as_ref(), mint.key().as_ref()],
+        bump = listing.bump,
+        close = seller
+    )]
+    pub listing: Account<'info, Listing>,
+
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = listing,
+        associated_token::token_program = token_program
+    )]
+    pub listing_ata: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        seeds = [b"offer", listing.key().as_ref(), bidder.key().as_ref()],
+        bump = offer.bump,
+        has_one = bidder,
+        close = bidder
+    )]
+    pub offer: Account<'info, Offer>,
+
+    #[account(
+        seeds = [b"marketplace", marketplace.name.as_bytes()],
+        bump = marketplace.bump,
+    )]
+    pub marketplace: Account<'info, Marketplace>,
+
+    #[account(
+        mut,
+        seeds = [b"treasury", marketplace.key().as_ref()],
+        bump = marketplace.treasury_bump,
+    )]
+    pub treasury: SystemAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+impl<'info> AcceptOffer<'info> {
+    pub fn pay_from_escrow(&mut self) -> Result<()> {
+        let amount = self.offer.amount;
+
+        let fee = calculate_fee(amount, self.marketplace.fee_bps)?;
+        let amount_to_seller = amount
+            .checked_sub(fee)
+            .ok_or(MarketplaceError::MathOverflowError)?;
+
+        // The offer account is program-owned, so the escrow can be debited directly
+        let offer_info = self.offer.to_account_info();
+        **offer_info.try_borrow_mut_lamports()? = offer_info
+            .lamports()
+            .checked_sub(amount)
+            .ok_or(MarketplaceError::MathOverflowError)?;
+
+        let treasury_info = self.treasury.to_account_info();
+        **treasury_info.try_borrow_mut_lamports()? = treasury_info
+            .lamports()
+            .checked_add(fee)
+            .ok_or(MarketplaceError::MathOverflowError)?;
+
+        let seller_info = self.seller.to_account_info();
+        **seller_info.try_borrow_mut_lamports()? = seller_info
+            .lamports()
+            .checked_add(amount_to_seller)
+            .ok_or(MarketplaceError::MathOverflowError)?;
+
+        Ok(())
+    }
+
+    pub fn transfer_nft(&mut self) -> Result<()> {
+        let signer_seeds: &[&[&[u8]]] = &[&[
+            b"listing",
+            &self.seller.key().to_bytes(),
+            &self.mint.key().to_bytes(),
+            &[self.listing.bump],
+        ]];
+
+        let cpi_accounts = TransferChecked {
+            authority: self.listing.to_account_info(),
+            from: self.listing_ata.to_account_info(),
+            to: self.bidder_ata.to_account_info(),
+            mint: self.mint.to_account_info(),
+        };
+
+        transfer_checked(
+            CpiContext::new_with_signer(
+                self.token_program.to_account_info(),
+                cpi_accounts,
+                signer_seeds,
+            ),
+            1,
+            self.mint.decimals,
+        )?;
+
+        let cpi_close_accounts = CloseAccount {
+            account: self.listing_ata.to_account_info(),
+            authority: self.listing.to_account_info(),
+            destination: self.seller.to_account_info(),
+        };
+
+        close_account(CpiContext::new_with_signer(
+            self.token_program.to_account_info(),
+            cpi_close_accounts,
+            signer_seeds,
+        ))?;
+
+        Ok(())
+    }
+}
\ No newline at end of file
@@ -0,0 +1,19 @@
+use anchor_lang::prelude::*;
+
+use crate::Offer;
+
+#[derive(Accounts)]
+pub struct CancelOffer<'info> {
+    #[account(mut)]
+    pub bidder: Signer<'info>,
+
+    // Closing returns the escrowed bid along with the rent
+    #[account(
+        mut,
+        seeds = [b"offer", offer.listing.as_ref(), bidder.key().as_ref()],
+        bump = offer.bump,
+        has_one = bidder,
+        close = bidder
+    )]
+    pub offer: Account<'info, Offer>,
+}
\ No newline at end of file
@@ -0,0 +1,95 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token_interface::{
+        close_account, transfer_checked, CloseAccount, Mint, TokenAccount, TokenInterface,
+        TransferChecked,
+    },
+};
+
+use crate::{Listing, Marketplace};
+
+#[derive(Accounts)]
+pub struct Delist<'info> {
+    #[account(mut)]
+    pub seller: Signer<'info>,
+
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        init_if_needed,
+        payer = seller,
+        associated_token::mint = mint,
+        associated_token::authority = seller,
+        associated_token::token_program = token_program
+    )]
+    pub seller_ata: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        seeds = [b"marketplace", marketplace.name.as_bytes()],
+        bump = marketplace.bump,
+    )]
+    pub marketplace: Account<'info, Marketplace>,
+
+    #[account(
+        mut,
+        seeds = [b"listing", seller.key().as_ref(), mint.key().as_ref()],
+        bump = listing.bump,
+        close = seller
+    )]
+    pub listing: Account<'info, Listing>,
+
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = listing,
+        associated_token::token_program = token_program
+    )]
+    pub listing_ata: InterfaceAccount<'info, TokenAccount>,
+
+    pub system_program: Program<'info, System>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+impl<'info> Delist<'info> {
+    pub fn return_nft(&mut self) -> Result<()> {
+        let signer_seeds: &[&[&[u8]]] = &[&[
+            b"listing",
+            &self.seller.key().to_bytes(),
+            &self.mint.key().to_bytes(),
+            &[self.listing.bump],
+        ]];
+
+        let cpi_accounts = TransferChecked {
+            authority: self.listing.to_account_info(),
+            from: self.listing_ata.to_account_info(),
+            to: self.seller_ata.to_account_info(),
+            mint: self.mint.to_account_info(),
+        };
+
+        transfer_checked(
+            CpiContext::new_with_signer(
+                self.token_program.to_account_info(),
+                cpi_accounts,
+                signer_seeds,
+            ),
+            1,
+            self.mint.decimals,
+        )?;
+
+        let cpi_close_accounts = CloseAccount {
+            account: self.listing_ata.to_account_info(),
+            authority: self.listing.to_account_info(),
+            destination: self.seller.to_account_info(),
+        };
+
+        close_account(CpiContext::new_with_signer(
+            self.token_program.to_account_info(),
+            cpi_close_accounts,
+            signer_seeds,
+        ))?;
+
+        Ok(())
+    }
+}
\ No newline at end of file
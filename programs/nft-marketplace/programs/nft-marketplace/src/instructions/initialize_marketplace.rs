@@ -1,7 +1,8 @@
 use anchor_lang::prelude::*;
+use anchor_lang::system_program::{transfer, Transfer};
 use anchor_spl::token_interface::{Mint, TokenInterface};
 
-use crate::{error::MarketplaceError, Marketplace};
+use crate::{error::MarketplaceError, Marketplace, MAX_FEE_BPS, MAX_NAME_LEN};
 
 #[derive(Accounts)]
 #[instruction(name: String)]
@@ -42,10 +43,52 @@ pub struct InitializeMarketplace<'info> {
 impl<'info> InitializeMarketplace<'info> {
     pub fn handle(&mut self, name: String, fee_bps: u16, bumps: &InitializeMarketplaceBumps) -> Result<()>{
 
-        require!(name.len() < 4 + 32, MarketplaceError::NameTooLong);
-        require!(name.len() > 0, MarketplaceError::UndefinedName);
+        validate_name(&name)?;
+        require!(fee_bps <= MAX_FEE_BPS, MarketplaceError::InvalidFee);
         self.marketplace.set_inner(Marketplace { admin: self.admin.key(), treasury_bump: bumps.treasury, rewards_bump: bumps.reward_mint, bump: bumps.marketplace, fee_bps, name });
 
+        self.fund_treasury()
+    }
+
+    /// Fees are paid into the treasury as plain lamports, so it starts out rent exempt;
+    /// otherwise a first sale whose fee is below the rent minimum would fail
+    fn fund_treasury(&mut self) -> Result<()> {
+        let rent_exempt = Rent::get()?.minimum_balance(0);
+        let shortfall = rent_exempt.saturating_sub(self.treasury.lamports());
+
+        if shortfall > 0 {
+            let cpi_accounts = Transfer {
+                from: self.admin.to_account_info(),
+                to: self.treasury.to_account_info(),
+            };
+
+            transfer(
+                CpiContext::new(self.system_program.to_account_info(), cpi_accounts),
+                shortfall,
+            )?;
+        }
+
         Ok(())
     }
+}
+
+/// Names are used as a PDA seed, so they are limited to one seed's worth of bytes
+pub fn validate_name(name: &str) -> Result<()> {
+    require!(!name.is_empty(), MarketplaceError::UndefinedName);
+    require!(name.len() <= MAX_NAME_LEN, MarketplaceError::NameTooLong);
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_validate_name() {
+        assert_eq!(validate_name(""), Err(MarketplaceError::UndefinedName.into()));
+        assert_eq!(validate_name(&"a".repeat(33)), Err(MarketplaceError::NameTooLong.into()));
+
+        assert!(validate_name("turbin3").is_ok());
+        assert!(validate_name(&"a".repeat(32)).is_ok());
+    }
 }
\ No newline at end of file
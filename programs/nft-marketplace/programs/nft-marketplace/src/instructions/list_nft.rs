@@ -5,7 +5,7 @@ use anchor_spl::{
     metadata::{MasterEditionAccount, Metadata, MetadataAccount},
 };
 
-use crate::{Listing, Marketplace};
+use crate::{error::MarketplaceError, Listing, Marketplace};
 
 #[derive(Accounts)]
 pub struct ListNFT<'info> {
@@ -15,12 +15,10 @@ pub struct ListNFT<'info> {
     pub collection_mint: InterfaceAccount<'info, Mint>,
 
     #[account(
-        init_if_needed,
-        payer = seller,
+        mut,
         associated_token::mint = mint,
         associated_token::authority = seller,
         associated_token::token_program = token_program
-
     )]
     pub seller_ata: InterfaceAccount<'info, TokenAccount>,
 
@@ -33,14 +31,15 @@ pub struct ListNFT<'info> {
     #[account(
         init,
         payer = seller,
-        seeds = [b"listing", marketplace.key().as_ref(), mint.key().as_ref()],
+        seeds = [b"listing", seller.key().as_ref(), mint.key().as_ref()],
         bump,
         space = Listing::DISCRIMINATOR.len() + Listing::INIT_SPACE
     )]
     pub listing: Account<'info, Listing>,
 
+    // Vault holding the NFT while it is listed, owned by the listing PDA
     #[account{
-        init_if_needed,
+        init,
         payer = seller,
         associated_token::authority = listing,
         associated_token::mint = mint,
@@ -56,7 +55,11 @@ pub struct ListNFT<'info> {
         ],
         bump,
         seeds::program = metadata_program.key(),
-        constraint = metadata.collection.as_ref().unwrap().key.as_ref() == collection_mint.key().as_ref(),
+        constraint = metadata
+            .collection
+            .as_ref()
+            .is_some_and(|collection| collection.verified && collection.key == collection_mint.key())
+            @ MarketplaceError::InvalidCollection,
         constraint = metadata.mint.key() == mint.key(),
     )]
     pub metadata: Account<'info, MetadataAccount>,
@@ -81,12 +84,13 @@ pub struct ListNFT<'info> {
 
 impl<'info> ListNFT<'info> {
     pub fn initialize_listing(&mut self, price: u64, bumps: &ListNFTBumps) -> Result<()> {
+        require!(price > 0, MarketplaceError::InvalidPrice);
+
         self.listing.set_inner(Listing {
             seller: self.seller.key(),
-            mint: self.mint.key(),
+            nft_mint: self.mint.key(),
             price,
             bump: bumps.listing,
-            is_active: true,
         });
         Ok(())
     }
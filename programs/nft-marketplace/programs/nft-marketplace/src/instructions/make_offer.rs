@@ -0,0 +1,55 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{transfer, Transfer};
+
+use crate::error::MarketplaceError;
+use crate::{Listing, Offer};
+
+#[derive(Accounts)]
+pub struct MakeOffer<'info> {
+    #[account(mut)]
+    pub bidder: Signer<'info>,
+
+    pub listing: Account<'info, Listing>,
+
+    #[account(
+        init,
+        payer = bidder,
+        seeds = [b"offer", listing.key().as_ref(), bidder.key().as_ref()],
+        bump,
+        space = Offer::DISCRIMINATOR.len() + Offer::INIT_SPACE
+    )]
+    pub offer: Account<'info, Offer>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> MakeOffer<'info> {
+    pub fn make_offer(&mut self, amount: u64, bumps: &MakeOfferBumps) -> Result<()> {
+        // Offers are bids below the asking price; at the ask, just purchase
+        require!(
+            amount > 0 && amount < self.listing.price,
+            MarketplaceError::InvalidOfferAmount
+        );
+
+        self.offer.set_inner(Offer {
+            bidder: self.bidder.key(),
+            listing: self.listing.key(),
+            amount,
+            bump: bumps.offer,
+        });
+
+        // Escrow the bid in the offer account itself
+        transfer(
+            CpiContext::new(
+                self.system_program.to_account_info(),
+                Transfer {
+                    from: self.bidder.to_account_info(),
+                    to: self.offer.to_account_info(),
+                },
+            ),
+            amount,
+        )?;
+
+        Ok(())
+    }
+}
\ No newline at end of file
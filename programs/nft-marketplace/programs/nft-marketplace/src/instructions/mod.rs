@@ -1,5 +1,13 @@
+pub mod accept_offer;
+pub use accept_offer::*;
+pub mod cancel_offer;
+pub use cancel_offer::*;
+pub mod delist;
+pub use delist::*;
 pub mod initialize_marketplace;
 pub mod list_nft;
+pub mod make_offer;
+pub use make_offer::*;
 pub use initialize_marketplace::*;
 pub mod purchase;
 pub use list_nft::*;
@@ -31,14 +31,6 @@ pub struct Purchase<'info> {
     )]
     pub buyer_ata: InterfaceAccount<'info, TokenAccount>,
 
-    #[account(
-        mut,
-        associated_token::mint = mint,
-        associated_token::authority = seller,
-        associated_token::token_program = token_program
-    )]
-    pub seller_ata: InterfaceAccount<'info, TokenAccount>,
-
     #[account(
         mut,
         associated_token::mint = mint,
@@ -49,14 +41,14 @@ pub struct Purchase<'info> {
 
     #[account(
         mut,
-        seeds = [b"listing", marketplace.key().as_ref(), mint.key().as_ref()],
+        seeds = [b"listing", seller.key().as_ref(), mint.key().as_ref()],
         bump = listing.bump,
         close = seller
     )]
     pub listing: Account<'info, Listing>,
 
     #[account(
-        seeds = [b"marketplace", marketplace.name.as_str().as_bytes()],
+        seeds = [b"marketplace", marketplace.name.as_bytes()],
         bump = marketplace.bump,
     )]
     pub marketplace: Account<'info, Marketplace>,
@@ -69,7 +61,11 @@ pub struct Purchase<'info> {
         ],
         seeds::program = metadata_program.key(),
         bump,
-        constraint = metadata.collection.as_ref().unwrap().key.as_ref() == collection_mint.key().as_ref(),
+        constraint = metadata
+            .collection
+            .as_ref()
+            .is_some_and(|collection| collection.verified && collection.key == collection_mint.key())
+            @ MarketplaceError::InvalidCollection,
         constraint = metadata.mint.key() == mint.key(),
     )]
     pub metadata: Account<'info, MetadataAccount>,
@@ -102,15 +98,13 @@ pub struct Purchase<'info> {
 impl<'info> Purchase<'info> {
     pub fn make_payment(&mut self) -> Result<()> {
         let token_price = self.listing.price;
-        let marketplace_fee = self.marketplace.fee_bps as u64;
 
-        let amount_to_transfer_as_fee = token_price
-            .checked_mul(marketplace_fee)
-            .and_then(|mul_result| mul_result.checked_div(10_000))
-            .ok_or_else(|| error!(MarketplaceError::MathOverflowError));
+        let amount_to_transfer_as_fee =
+            calculate_fee(token_price, self.marketplace.fee_bps)?;
 
-        let amount_to_transfer_to_seller =
-            token_price.checked_sub(*amount_to_transfer_as_fee.as_ref().unwrap());
+        let amount_to_transfer_to_seller = token_price
+            .checked_sub(amount_to_transfer_as_fee)
+            .ok_or(MarketplaceError::MathOverflowError)?;
 
         let cpi_account_fee_ix = Transfer {
             from: self.buyer.to_account_info(),
@@ -124,14 +118,14 @@ impl<'info> Purchase<'info> {
 
         transfer(
             CpiContext::new(self.system_program.to_account_info(), cpi_account_fee_ix),
-            amount_to_transfer_as_fee.unwrap(),
+            amount_to_transfer_as_fee,
         )?;
         transfer(
             CpiContext::new(
                 self.system_program.to_account_info(),
                 cpi_account_amount_seller_ix,
             ),
-            amount_to_transfer_to_seller.unwrap(),
+            amount_to_transfer_to_seller,
         )?;
 
         Ok(())
@@ -147,7 +141,7 @@ impl<'info> Purchase<'info> {
 
         let signer_seeds: &[&[&[u8]]] = &[&[
             b"listing",
-            &self.marketplace.key().to_bytes(),
+            &self.seller.key().to_bytes(),
             &self.mint.key().to_bytes(),
             &[self.listing.bump],
         ]];
@@ -160,10 +154,11 @@ impl<'info> Purchase<'info> {
 
         transfer_checked(cpi_context, 1, self.mint.decimals)?;
 
+        // The seller paid for the vault, so its rent goes back to them
         let cpi_close_accounts = CloseAccount {
             account: self.listing_ata.to_account_info(),
             authority: self.listing.to_account_info(),
-            destination: self.buyer.to_account_info(),
+            destination: self.seller.to_account_info(),
         };
 
         let cpi_close_context = CpiContext::new_with_signer(
@@ -175,4 +170,32 @@ impl<'info> Purchase<'info> {
 
         Ok(())
     }
+}
+
+/// Marketplace fee on a sale price, rounded down
+pub fn calculate_fee(price: u64, fee_bps: u16) -> Result<u64> {
+    price
+        .checked_mul(fee_bps as u64)
+        .and_then(|mul_result| mul_result.checked_div(10_000))
+        .ok_or_else(|| error!(MarketplaceError::MathOverflowError))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_calculate_fee() {
+        assert_eq!(calculate_fee(1_000_000_000, 250).unwrap(), 25_000_000);
+        assert_eq!(calculate_fee(1_000_000_000, 0).unwrap(), 0);
+        assert_eq!(calculate_fee(1_000_000_000, 10_000).unwrap(), 1_000_000_000);
+
+        // Rounds down in the seller's favour
+        assert_eq!(calculate_fee(399, 250).unwrap(), 9);
+    }
+
+    #[test]
+    fn test_calculate_fee_overflow() {
+        assert!(calculate_fee(u64::MAX, 250).is_err());
+    }
 }
\ No newline at end of file
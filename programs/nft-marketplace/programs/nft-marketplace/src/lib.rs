@@ -1,3 +1,5 @@
+#![allow(unexpected_cfgs, deprecated)]
+
 use anchor_lang::prelude::*;
 
 pub mod constants;
@@ -14,33 +16,45 @@ declare_id!("CfcoUQwn2VQvk3hvvcbihNG76qXNitLESStbkyfyCDZE");
 pub mod anchor_marketplace {
     use super::*;
 
-    pub fn initialize(ctx: Context<InitializeMarketplace>, params: InitializeParams) -> Result<()> {
-        ctx.accounts
-            .handle(params.name, params.fee_bps, &ctx.bumps)?;
+    pub fn initialize_marketplace(
+        ctx: Context<InitializeMarketplace>,
+        name: String,
+        fee_bps: u16,
+    ) -> Result<()> {
+        ctx.accounts.handle(name, fee_bps, &ctx.bumps)?;
 
         Ok(())
     }
 
-    pub fn purchase_nft(ctx: Context<Purchase>) -> Result<()> {
+    pub fn purchase(ctx: Context<Purchase>) -> Result<()> {
         ctx.accounts.make_payment()?;
         ctx.accounts.transfer_nft()?;
         Ok(())
     }
 
-    pub fn list_nft(ctx: Context<ListNFT>, params: InitializListingeParams) -> Result<()> {
-        ctx.accounts.initialize_listing(params.price, &ctx.bumps)?;
+    pub fn delist(ctx: Context<Delist>) -> Result<()> {
+        ctx.accounts.return_nft()?;
+        Ok(())
+    }
+
+    pub fn list(ctx: Context<ListNFT>, price: u64) -> Result<()> {
+        ctx.accounts.initialize_listing(price, &ctx.bumps)?;
         ctx.accounts.list_nft()?;
         Ok(())
     }
-}
 
-#[derive(AnchorDeserialize, AnchorSerialize, PartialEq)]
-pub struct InitializeParams {
-    name: String,
-    fee_bps: u16,
-}
+    pub fn make_offer(ctx: Context<MakeOffer>, amount: u64) -> Result<()> {
+        ctx.accounts.make_offer(amount, &ctx.bumps)?;
+        Ok(())
+    }
+
+    pub fn accept_offer(ctx: Context<AcceptOffer>) -> Result<()> {
+        ctx.accounts.pay_from_escrow()?;
+        ctx.accounts.transfer_nft()?;
+        Ok(())
+    }
 
-#[derive(AnchorDeserialize, AnchorSerialize, PartialEq)]
-pub struct InitializListingeParams {
-    price: u64,
+    pub fn cancel_offer(_ctx: Context<CancelOffer>) -> Result<()> {
+        Ok(())
+    }
 }
\ No newline at end of file
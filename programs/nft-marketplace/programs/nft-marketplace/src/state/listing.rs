@@ -4,8 +4,7 @@ use anchor_lang::prelude::*;
 #[derive(InitSpace)]
 pub struct Listing {
     pub seller: Pubkey,
-    pub mint: Pubkey,
+    pub nft_mint: Pubkey,
     pub price: u64,
     pub bump: u8,
-    pub is_active: bool,
 }
\ No newline at end of file
@@ -1,4 +1,6 @@
 pub mod marketplace;
 pub use marketplace::*;
 pub mod listing;
-pub use listing::*;
\ No newline at end of file
+pub use listing::*;
+pub mod offer;
+pub use offer::*;
\ No newline at end of file
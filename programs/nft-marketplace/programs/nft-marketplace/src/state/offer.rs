@@ -0,0 +1,11 @@
+use anchor_lang::prelude::*;
+
+#[account]
+#[derive(InitSpace)]
+pub struct Offer {
+    pub bidder: Pubkey,
+    pub listing: Pubkey,
+    // Lamports escrowed in this account on top of its rent
+    pub amount: u64,
+    pub bump: u8,
+}
\ No newline at end of file
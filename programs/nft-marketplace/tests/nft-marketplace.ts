@@ -1,16 +1,542 @@
 import * as anchor from "@coral-xyz/anchor";
 import { Program } from "@coral-xyz/anchor";
+import { PublicKey, SystemProgram } from "@solana/web3.js";
+import { assert } from "chai";
+import {
+  createNft,
+  findMasterEditionPda,
+  findMetadataPda,
+  mplTokenMetadata,
+  verifySizedCollectionItem,
+  MPL_TOKEN_METADATA_PROGRAM_ID,
+} from "@metaplex-foundation/mpl-token-metadata";
+import {
+  createSignerFromKeypair,
+  generateSigner,
+  keypairIdentity,
+  percentAmount,
+  publicKey,
+  KeypairSigner,
+} from "@metaplex-foundation/umi";
+import { createUmi } from "@metaplex-foundation/umi-bundle-defaults";
 import { NftMarketplace } from "../target/types/nft_marketplace";
 
 describe("nft-marketplace", () => {
   // Configure the client to use the local cluster.
-  anchor.setProvider(anchor.AnchorProvider.env());
+  const provider = anchor.AnchorProvider.env();
+  anchor.setProvider(provider);
 
   const program = anchor.workspace.nftMarketplace as Program<NftMarketplace>;
+  const wallet = provider.wallet as anchor.Wallet;
 
-  it("Is initialized!", async () => {
-    // Add your test here.
-    const tx = await program.methods.initialize().rpc();
-    console.log("Your transaction signature", tx);
+  const TOKEN_PROGRAM_ID = anchor.utils.token.TOKEN_PROGRAM_ID;
+  const ASSOCIATED_TOKEN_PROGRAM_ID = anchor.utils.token.ASSOCIATED_PROGRAM_ID;
+  const METADATA_PROGRAM_ID = new PublicKey(MPL_TOKEN_METADATA_PROGRAM_ID);
+
+  const MARKETPLACE_NAME = "turbin3";
+  const FEE_BPS = 250; // 2.5%
+  const PRICE = anchor.web3.LAMPORTS_PER_SOL;
+
+  const umi = createUmi(provider.connection.rpcEndpoint).use(mplTokenMetadata());
+  const umiPayer = umi.eddsa.createKeypairFromSecretKey(wallet.payer.secretKey);
+  umi.use(keypairIdentity(createSignerFromKeypair(umi, umiPayer)));
+
+  const seller = wallet.payer;
+  let collectionMint: KeypairSigner;
+
+  const marketplace = findMarketplacePda(MARKETPLACE_NAME);
+  const [treasury] = PublicKey.findProgramAddressSync(
+    [Buffer.from("treasury"), marketplace.toBuffer()],
+    program.programId
+  );
+
+  function findMarketplacePda(name: string): PublicKey {
+    const [marketplacePda] = PublicKey.findProgramAddressSync(
+      [Buffer.from("marketplace"), Buffer.from(name)],
+      program.programId
+    );
+    return marketplacePda;
+  }
+
+  async function initializeMarketplace(name: string, feeBps: number) {
+    const marketplacePda = findMarketplacePda(name);
+
+    await program.methods
+      .initializeMarketplace(name, feeBps)
+      .accounts({
+        admin: seller.publicKey,
+        treasury: PublicKey.findProgramAddressSync(
+          [Buffer.from("treasury"), marketplacePda.toBuffer()],
+          program.programId
+        )[0],
+        marketplace: marketplacePda,
+        rewardMint: PublicKey.findProgramAddressSync(
+          [Buffer.from("rewards"), marketplacePda.toBuffer()],
+          program.programId
+        )[0],
+        systemProgram: SystemProgram.programId,
+        tokenProgram: TOKEN_PROGRAM_ID,
+      })
+      .signers([seller])
+      .rpc();
+  }
+
+  function findListingPda(owner: PublicKey, mint: PublicKey): PublicKey {
+    const [listingPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from("listing"), owner.toBuffer(), mint.toBuffer()],
+      program.programId
+    );
+    return listingPda;
+  }
+
+  function findMetadataAddress(mint: PublicKey): PublicKey {
+    return new PublicKey(findMetadataPda(umi, { mint: publicKey(mint.toBase58()) })[0]);
+  }
+
+  function findEditionAddress(mint: PublicKey): PublicKey {
+    return new PublicKey(findMasterEditionPda(umi, { mint: publicKey(mint.toBase58()) })[0]);
+  }
+
+  function ata(mint: PublicKey, owner: PublicKey): PublicKey {
+    return anchor.utils.token.associatedAddress({ mint, owner });
+  }
+
+  async function tokenAmount(address: PublicKey): Promise<string> {
+    return (await provider.connection.getTokenAccountBalance(address)).value.amount;
+  }
+
+  /**
+   * Mint an NFT to the seller as part of the test collection, verified unless stated otherwise
+   */
+  async function mintNft(verified: boolean = true): Promise<PublicKey> {
+    const mint = generateSigner(umi);
+
+    await createNft(umi, {
+      mint,
+      name: "Marketplace NFT",
+      symbol: "MKT",
+      uri: "https://example.com/nft.json",
+      sellerFeeBasisPoints: percentAmount(0),
+      collection: { verified: false, key: collectionMint.publicKey },
+    }).sendAndConfirm(umi);
+
+    if (verified) {
+      await verifySizedCollectionItem(umi, {
+        metadata: findMetadataPda(umi, { mint: mint.publicKey }),
+        collectionAuthority: umi.identity,
+        collectionMint: collectionMint.publicKey,
+        collection: findMetadataPda(umi, { mint: collectionMint.publicKey }),
+        collectionMasterEditionAccount: findMasterEditionPda(umi, {
+          mint: collectionMint.publicKey,
+        }),
+      }).sendAndConfirm(umi);
+    }
+
+    return new PublicKey(mint.publicKey);
+  }
+
+  async function list(mint: PublicKey, price: number) {
+    const listing = findListingPda(seller.publicKey, mint);
+
+    await program.methods
+      .list(new anchor.BN(price))
+      .accounts({
+        seller: seller.publicKey,
+        mint,
+        collectionMint: new PublicKey(collectionMint.publicKey),
+        sellerAta: ata(mint, seller.publicKey),
+        marketplace,
+        listing,
+        listingAta: ata(mint, listing),
+        metadata: findMetadataAddress(mint),
+        edition: findEditionAddress(mint),
+        metadataProgram: METADATA_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+      })
+      .signers([seller])
+      .rpc();
+  }
+
+  async function purchase(buyer: anchor.web3.Keypair, mint: PublicKey) {
+    const listing = findListingPda(seller.publicKey, mint);
+
+    await program.methods
+      .purchase()
+      .accounts({
+        buyer: buyer.publicKey,
+        seller: seller.publicKey,
+        mint,
+        collectionMint: new PublicKey(collectionMint.publicKey),
+        buyerAta: ata(mint, buyer.publicKey),
+        listingAta: ata(mint, listing),
+        listing,
+        marketplace,
+        metadata: findMetadataAddress(mint),
+        edition: findEditionAddress(mint),
+        treasury,
+        metadataProgram: METADATA_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+        tokenProgram: TOKEN_PROGRAM_ID,
+      })
+      .signers([buyer])
+      .rpc();
+  }
+
+  async function delist(mint: PublicKey) {
+    const listing = findListingPda(seller.publicKey, mint);
+
+    await program.methods
+      .delist()
+      .accounts({
+        seller: seller.publicKey,
+        mint,
+        sellerAta: ata(mint, seller.publicKey),
+        marketplace,
+        listing,
+        listingAta: ata(mint, listing),
+        systemProgram: SystemProgram.programId,
+        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+        tokenProgram: TOKEN_PROGRAM_ID,
+      })
+      .signers([seller])
+      .rpc();
+  }
+
+  function findOfferPda(listing: PublicKey, bidder: PublicKey): PublicKey {
+    const [offerPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from("offer"), listing.toBuffer(), bidder.toBuffer()],
+      program.programId
+    );
+    return offerPda;
+  }
+
+  async function makeOffer(bidder: anchor.web3.Keypair, mint: PublicKey, amount: number) {
+    const listing = findListingPda(seller.publicKey, mint);
+
+    await program.methods
+      .makeOffer(new anchor.BN(amount))
+      .accounts({
+        bidder: bidder.publicKey,
+        listing,
+        offer: findOfferPda(listing, bidder.publicKey),
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([bidder])
+      .rpc();
+  }
+
+  async function acceptOffer(bidder: PublicKey, mint: PublicKey) {
+    const listing = findListingPda(seller.publicKey, mint);
+
+    await program.methods
+      .acceptOffer()
+      .accounts({
+        seller: seller.publicKey,
+        bidder,
+        mint,
+        bidderAta: ata(mint, bidder),
+        listing,
+        listingAta: ata(mint, listing),
+        offer: findOfferPda(listing, bidder),
+        marketplace,
+        treasury,
+        systemProgram: SystemProgram.programId,
+        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+        tokenProgram: TOKEN_PROGRAM_ID,
+      })
+      .signers([seller])
+      .rpc();
+  }
+
+  async function cancelOffer(bidder: anchor.web3.Keypair, mint: PublicKey) {
+    const listing = findListingPda(seller.publicKey, mint);
+
+    await program.methods
+      .cancelOffer()
+      .accounts({
+        bidder: bidder.publicKey,
+        offer: findOfferPda(listing, bidder.publicKey),
+      })
+      .signers([bidder])
+      .rpc();
+  }
+
+  async function fundedKeypair(lamports: number): Promise<anchor.web3.Keypair> {
+    const keypair = anchor.web3.Keypair.generate();
+    const signature = await provider.connection.requestAirdrop(keypair.publicKey, lamports);
+    await provider.connection.confirmTransaction(signature);
+    return keypair;
+  }
+
+  before(async () => {
+    collectionMint = generateSigner(umi);
+    await createNft(umi, {
+      mint: collectionMint,
+      name: "Marketplace Collection",
+      symbol: "MKTC",
+      uri: "https://example.com/collection.json",
+      sellerFeeBasisPoints: percentAmount(0),
+      isCollection: true,
+    }).sendAndConfirm(umi);
+
+    await initializeMarketplace(MARKETPLACE_NAME, FEE_BPS);
+  });
+
+  describe("initialize_marketplace", () => {
+    it("stores the name, fee and authority", async () => {
+      const marketplaceAccount = await program.account.marketplace.fetch(marketplace);
+
+      assert.equal(marketplaceAccount.name, MARKETPLACE_NAME);
+      assert.equal(marketplaceAccount.feeBps, FEE_BPS);
+      assert.isTrue(marketplaceAccount.admin.equals(seller.publicKey));
+    });
+
+    it("funds the treasury to rent exemption", async () => {
+      const rentExempt = await provider.connection.getMinimumBalanceForRentExemption(0);
+      assert.isAtLeast(await provider.connection.getBalance(treasury), rentExempt);
+    });
+
+    it("rejects an empty name", async () => {
+      try {
+        await initializeMarketplace("", FEE_BPS);
+        assert.fail("Should have failed with undefined name");
+      } catch (error) {
+        assert(error.message.includes("UndefinedName"));
+      }
+    });
+
+    // Names over 32 bytes can't be used as a PDA seed, so clients can't even
+    // derive the address; NameTooLong is covered by the program's unit tests
+    it("rejects a name too long to be a seed", async () => {
+      assert.throws(() => findMarketplacePda("a".repeat(33)));
+    });
+
+    it("rejects a fee above 100%", async () => {
+      try {
+        await initializeMarketplace("overpriced", 10_001);
+        assert.fail("Should have failed with invalid fee");
+      } catch (error) {
+        assert(error.message.includes("InvalidFee"));
+      }
+    });
+  });
+
+  describe("list", () => {
+    it("moves the NFT into the listing vault and records the listing", async () => {
+      const mint = await mintNft();
+      const listing = findListingPda(seller.publicKey, mint);
+
+      await list(mint, PRICE);
+
+      const listingAccount = await program.account.listing.fetch(listing);
+      assert.isTrue(listingAccount.seller.equals(seller.publicKey));
+      assert.isTrue(listingAccount.nftMint.equals(mint));
+      assert.equal(listingAccount.price.toNumber(), PRICE);
+
+      assert.equal(await tokenAmount(ata(mint, listing)), "1");
+      assert.equal(await tokenAmount(ata(mint, seller.publicKey)), "0");
+    });
+
+    it("rejects a zero price", async () => {
+      const mint = await mintNft();
+
+      try {
+        await list(mint, 0);
+        assert.fail("Should have failed with invalid price");
+      } catch (error) {
+        assert(error.message.includes("InvalidPrice"));
+      }
+    });
+
+    it("rejects an NFT whose collection is not verified", async () => {
+      const mint = await mintNft(false);
+
+      try {
+        await list(mint, PRICE);
+        assert.fail("Should have failed with invalid collection");
+      } catch (error) {
+        assert(error.message.includes("InvalidCollection"));
+      }
+    });
+  });
+
+  describe("purchase", () => {
+    it("sells at a price whose fee is below the rent minimum", async () => {
+      // The treasury was funded to rent exemption at initialization, so a tiny fee still lands
+      const smallPrice = 1_000;
+      const mint = await mintNft();
+      await list(mint, smallPrice);
+
+      const buyer = await fundedKeypair(2 * PRICE);
+      const treasuryBefore = await provider.connection.getBalance(treasury);
+
+      await purchase(buyer, mint);
+
+      const fee = Math.floor((smallPrice * FEE_BPS) / 10_000);
+      const treasuryAfter = await provider.connection.getBalance(treasury);
+
+      assert.equal(treasuryAfter - treasuryBefore, fee);
+      assert.equal(await tokenAmount(ata(mint, buyer.publicKey)), "1");
+    });
+
+    it("sells the NFT to the buyer and pays the seller and treasury", async () => {
+      const mint = await mintNft();
+      const listing = findListingPda(seller.publicKey, mint);
+      await list(mint, PRICE);
+
+      const buyer = await fundedKeypair(2 * PRICE);
+      const sellerBefore = await provider.connection.getBalance(seller.publicKey);
+      const treasuryBefore = await provider.connection.getBalance(treasury);
+      const listingRent = await provider.connection.getBalance(listing);
+      const vaultRent = await provider.connection.getBalance(ata(mint, listing));
+
+      await purchase(buyer, mint);
+
+      const fee = Math.floor((PRICE * FEE_BPS) / 10_000);
+      const sellerAfter = await provider.connection.getBalance(seller.publicKey);
+      const treasuryAfter = await provider.connection.getBalance(treasury);
+
+      assert.equal(treasuryAfter - treasuryBefore, fee);
+      // Sale proceeds plus the rent of the closed listing and vault
+      assert.equal(sellerAfter - sellerBefore, PRICE - fee + listingRent + vaultRent);
+
+      assert.equal(await tokenAmount(ata(mint, buyer.publicKey)), "1");
+      assert.isNull(await provider.connection.getAccountInfo(listing));
+      assert.isNull(await provider.connection.getAccountInfo(ata(mint, listing)));
+    });
+  });
+
+  describe("delist", () => {
+    it("returns the NFT to the seller and closes the listing", async () => {
+      const mint = await mintNft();
+      const listing = findListingPda(seller.publicKey, mint);
+      await list(mint, PRICE);
+
+      await delist(mint);
+
+      assert.equal(await tokenAmount(ata(mint, seller.publicKey)), "1");
+      assert.isNull(await provider.connection.getAccountInfo(listing));
+      assert.isNull(await provider.connection.getAccountInfo(ata(mint, listing)));
+    });
+
+    it("can't be purchased once delisted", async () => {
+      const mint = await mintNft();
+      await list(mint, PRICE);
+      await delist(mint);
+
+      const buyer = await fundedKeypair(2 * PRICE);
+
+      try {
+        await purchase(buyer, mint);
+        assert.fail("Should have failed with the listing closed");
+      } catch (error) {
+        assert(error.message.includes("AccountNotInitialized"));
+      }
+    });
+  });
+
+  describe("offers", () => {
+    const OFFER = PRICE / 2;
+
+    it("escrows the bid in the offer account", async () => {
+      const mint = await mintNft();
+      const listing = findListingPda(seller.publicKey, mint);
+      await list(mint, PRICE);
+
+      const bidder = await fundedKeypair(2 * PRICE);
+      await makeOffer(bidder, mint, OFFER);
+
+      const offer = findOfferPda(listing, bidder.publicKey);
+      const offerAccount = await program.account.offer.fetch(offer);
+      assert.isTrue(offerAccount.bidder.equals(bidder.publicKey));
+      assert.isTrue(offerAccount.listing.equals(listing));
+      assert.equal(offerAccount.amount.toNumber(), OFFER);
+
+      const offerInfo = await provider.connection.getAccountInfo(offer);
+      const rent = await provider.connection.getMinimumBalanceForRentExemption(offerInfo.data.length);
+      assert.equal(offerInfo.lamports, rent + OFFER);
+    });
+
+    it("sells the NFT at the offered price when the seller accepts", async () => {
+      const mint = await mintNft();
+      const listing = findListingPda(seller.publicKey, mint);
+      await list(mint, PRICE);
+
+      const bidder = await fundedKeypair(2 * PRICE);
+      await makeOffer(bidder, mint, OFFER);
+      const offer = findOfferPda(listing, bidder.publicKey);
+
+      const bidderBefore = await provider.connection.getBalance(bidder.publicKey);
+      const treasuryBefore = await provider.connection.getBalance(treasury);
+      const offerBalance = await provider.connection.getBalance(offer);
+
+      await acceptOffer(bidder.publicKey, mint);
+
+      const fee = Math.floor((OFFER * FEE_BPS) / 10_000);
+      const bidderAfter = await provider.connection.getBalance(bidder.publicKey);
+      const treasuryAfter = await provider.connection.getBalance(treasury);
+
+      assert.equal(treasuryAfter - treasuryBefore, fee);
+      // The bidder gets back the offer account's rent, not the escrowed bid
+      assert.equal(bidderAfter - bidderBefore, offerBalance - OFFER);
+
+      assert.equal(await tokenAmount(ata(mint, bidder.publicKey)), "1");
+      assert.isNull(await provider.connection.getAccountInfo(offer));
+      assert.isNull(await provider.connection.getAccountInfo(listing));
+      assert.isNull(await provider.connection.getAccountInfo(ata(mint, listing)));
+    });
+
+    it("refunds the bid when the bidder cancels", async () => {
+      const mint = await mintNft();
+      const listing = findListingPda(seller.publicKey, mint);
+      await list(mint, PRICE);
+
+      const bidder = await fundedKeypair(2 * PRICE);
+      await makeOffer(bidder, mint, OFFER);
+      const offer = findOfferPda(listing, bidder.publicKey);
+
+      const bidderBefore = await provider.connection.getBalance(bidder.publicKey);
+      const offerBalance = await provider.connection.getBalance(offer);
+
+      await cancelOffer(bidder, mint);
+
+      const bidderAfter = await provider.connection.getBalance(bidder.publicKey);
+      assert.equal(bidderAfter - bidderBefore, offerBalance - 5000);
+      assert.isNull(await provider.connection.getAccountInfo(offer));
+
+      // The NFT stays listed
+      assert.equal(await tokenAmount(ata(mint, listing)), "1");
+    });
+
+    it("rejects an offer at or above the asking price", async () => {
+      const mint = await mintNft();
+      await list(mint, PRICE);
+
+      const bidder = await fundedKeypair(2 * PRICE);
+
+      try {
+        await makeOffer(bidder, mint, PRICE);
+        assert.fail("Should have failed with invalid offer amount");
+      } catch (error) {
+        assert(error.message.includes("InvalidOfferAmount"));
+      }
+    });
+
+    it("rejects a zero offer", async () => {
+      const mint = await mintNft();
+      await list(mint, PRICE);
+
+      const bidder = await fundedKeypair(2 * PRICE);
+
+      try {
+        await makeOffer(bidder, mint, 0);
+        assert.fail("Should have failed with invalid offer amount");
+      } catch (error) {
+        assert(error.message.includes("InvalidOfferAmount"));
+      }
+    });
   });
 });
@@ -1,4 +1,6 @@
 use anchor_lang::prelude::*;
 
 #[constant]
-pub const SEED: &str = "anchor";
\ No newline at end of file
+pub const SEED: &str = "anchor";
+
+pub const SECONDS_PER_DAY: i64 = 24 * 60 * 60;
\ No newline at end of file
@@ -8,4 +8,16 @@ pub enum StakeError {
     MaxStakeReached,
     #[msg("Insufficient previous stakes")]
     InsufficientPreviousStakes,
+    #[msg("Math overflow")]
+    MathOverflow,
+    #[msg("Invalid config")]
+    InvalidConfig,
+    #[msg("Unauthorized")]
+    Unauthorized,
+    #[msg("Points amount must be greater than zero")]
+    InvalidPointsAmount,
+    #[msg("Insufficient points")]
+    InsufficientPoints,
+    #[msg("NFT is not a verified member of the allowed collection")]
+    InvalidCollection,
 }
\ No newline at end of file
@@ -1,4 +1,74 @@
 use anchor_lang::prelude::*;
-use anchor_spl::{associated_token::AssociatedToken, token::{mint_to, Mint, MintTo, Token, TokenAccount}};
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token_interface::{mint_to, Mint, MintTo, TokenAccount, TokenInterface},
+};
 
-use crate::state::{StakeConfig, UserAccount};
\ No newline at end of file
+use crate::{errors::StakeError, StakeConfig, UserState};
+
+#[derive(Accounts)]
+pub struct Claim<'info> {
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump
+    )]
+    pub config: Account<'info, StakeConfig>,
+
+    #[account(
+        mut,
+        seeds = [b"user", user.key().as_ref()],
+        bump = user_state.bump
+    )]
+    pub user_state: Account<'info, UserState>,
+
+    #[account(
+        mut,
+        address = config.reward_mint
+    )]
+    pub reward_mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        init_if_needed,
+        payer = user,
+        associated_token::mint = reward_mint,
+        associated_token::authority = user,
+        associated_token::token_program = token_program
+    )]
+    pub user_reward_ata: InterfaceAccount<'info, TokenAccount>,
+
+    pub system_program: Program<'info, System>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+impl<'info> Claim<'info> {
+    pub fn claim_handler(&mut self, points: u64) -> Result<()> {
+        self.user_state.spend_points(points)?;
+
+        let amount = points
+            .checked_mul(self.config.rewards_per_point)
+            .ok_or(StakeError::MathOverflow)?;
+
+        let signer_seeds: &[&[&[u8]]] = &[&[b"config", &[self.config.bump]]];
+
+        let cpi_accounts = MintTo {
+            mint: self.reward_mint.to_account_info(),
+            to: self.user_reward_ata.to_account_info(),
+            authority: self.config.to_account_info(),
+        };
+
+        mint_to(
+            CpiContext::new_with_signer(
+                self.token_program.to_account_info(),
+                cpi_accounts,
+                signer_seeds,
+            ),
+            amount,
+        )?;
+
+        Ok(())
+    }
+}
\ No newline at end of file
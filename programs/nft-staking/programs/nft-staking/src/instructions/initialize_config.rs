@@ -1,29 +1,29 @@
 use anchor_lang::prelude::*;
 use anchor_spl::token_interface::{Mint, TokenInterface};
 
-use crate::GlobalState;
+use crate::{errors::StakeError, StakeConfig};
 
 #[derive(Accounts)]
-pub struct InitializeGlobalState<'info> {
+pub struct InitializeConfig<'info> {
     #[account(mut)]
     pub admin: Signer<'info>,
 
     #[account(
         init,
         payer = admin,
-        seeds = [b"global_state"],
+        seeds = [b"config"],
         bump,
-        space = GlobalState::DISCRIMINATOR.len() + GlobalState::INIT_SPACE
+        space = StakeConfig::DISCRIMINATOR.len() + StakeConfig::INIT_SPACE
     )]
-    pub global_state: Account<'info, GlobalState>,
+    pub config: Account<'info, StakeConfig>,
 
     #[account(
         init_if_needed,
         payer = admin,
-        seeds = [b"rewards", global_state.key().as_ref()],
+        seeds = [b"rewards", config.key().as_ref()],
         bump,
         mint::decimals = 6,
-        mint::authority = global_state
+        mint::authority = config
 
     )]
     pub reward_mint: InterfaceAccount<'info, Mint>,
@@ -33,20 +33,28 @@ pub struct InitializeGlobalState<'info> {
     pub token_program: Interface<'info, TokenInterface>,
 }
 
-impl<'info> InitializeGlobalState<'info> {
+impl<'info> InitializeConfig<'info> {
     pub fn handle_init(
         &mut self,
         max_stake: u8,
-        points_per_stake: u8,
-        freeze_period: u32,
-        bumps: &InitializeGlobalStateBumps,
+        points_per_stake: u32,
+        freeze_period: i64,
+        rewards_per_point: u64,
+        allowed_collection: Pubkey,
+        bumps: &InitializeConfigBumps,
     ) -> Result<()> {
-        self.global_state.set_inner(GlobalState {
+        require!(freeze_period >= 0, StakeError::InvalidConfig);
+
+        self.config.set_inner(StakeConfig {
+            authority: self.admin.key(),
             points_per_stake,
             max_stake,
             freeze_period,
+            reward_mint: self.reward_mint.key(),
+            rewards_per_point,
+            allowed_collection,
             rewards_bump: bumps.reward_mint,
-            global_bump: bumps.global_state,
+            bump: bumps.config,
         });
         Ok(())
     }
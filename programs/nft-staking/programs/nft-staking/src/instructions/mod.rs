@@ -3,10 +3,12 @@ pub mod initialize_config;
 pub mod initialize_user;
 pub mod stake;
 pub mod unstake;
+pub mod update_config;
 
 pub use claim::*;
 pub use initialize_config::*;
 pub use initialize_user::*;
 pub use stake::*;
 pub use unstake::*;
+pub use update_config::*;
 
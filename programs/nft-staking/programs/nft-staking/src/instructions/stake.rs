@@ -11,7 +11,7 @@ use anchor_spl::{
     token_interface::{approve, Approve, Mint, TokenAccount, TokenInterface},
 };
 
-use crate::{error::StakeProgramError, GlobalState, StakeState, UserState};
+use crate::{errors::StakeError, StakeConfig, StakeState, UserState};
 
 #[derive(Accounts)]
 pub struct Stake<'info> {
@@ -20,8 +20,6 @@ pub struct Stake<'info> {
 
     pub mint: InterfaceAccount<'info, Mint>,
 
-    pub collection_mint: InterfaceAccount<'info, Mint>,
-
     #[account(
         mut,
         associated_token::mint = mint,
@@ -38,8 +36,11 @@ pub struct Stake<'info> {
         ],
         seeds::program = metadata_program.key(),
         bump,
-        constraint = metadata.collection.as_ref().unwrap().key.as_ref() == collection_mint.key().as_ref(),
-        constraint = metadata.collection.as_ref().unwrap().verified == true
+        constraint = metadata
+            .collection
+            .as_ref()
+            .is_some_and(|collection| collection.verified && collection.key == config.allowed_collection)
+            @ StakeError::InvalidCollection
     }]
     pub metadata: Account<'info, MetadataAccount>,
 
@@ -53,13 +54,13 @@ pub struct Stake<'info> {
         seeds::program = metadata_program.key(),
         bump,
     }]
-    pub edition: Account<'info, MetadataAccount>,
+    pub edition: Account<'info, MasterEditionAccount>,
 
     #[account(
-        seeds = [b"global_state"],
-        bump = global_state.global_bump
+        seeds = [b"config"],
+        bump = config.bump
     )]
-    pub global_state: Account<'info, GlobalState>,
+    pub config: Account<'info, StakeConfig>,
 
     #[account(
         mut,
@@ -69,9 +70,9 @@ pub struct Stake<'info> {
     pub user_state: Account<'info, UserState>,
 
     #[account(
-        init_if_needed,
+        init,
         payer = user,
-        seeds = [b"stake", mint.key().as_ref(), global_state.key().as_ref()],
+        seeds = [b"stake", mint.key().as_ref(), user.key().as_ref()],
         bump,
         space = StakeState::DISCRIMINATOR.len() + StakeState::INIT_SPACE
     )]
@@ -86,8 +87,8 @@ pub struct Stake<'info> {
 impl<'info> Stake<'info> {
     pub fn stake_handler(&mut self, bumps: &StakeBumps) -> Result<()> {
         require!(
-            self.user_state.amount_staked < self.global_state.max_stake,
-            StakeProgramError::MaxStakeReached
+            self.user_state.amount_staked < self.config.max_stake,
+            StakeError::MaxStakeReached
         );
 
         self.stake_account.set_inner(StakeState {
@@ -110,8 +111,8 @@ impl<'info> Stake<'info> {
 
         let signer_seeds: &[&[&[u8]]] = &[&[
             b"stake",
-            self.mint.to_account_info().key().as_ref(),
-            self.global_state.to_account_info().key().as_ref(),
+            self.mint.to_account_info().key.as_ref(),
+            self.user.to_account_info().key.as_ref(),
             &[self.stake_account.bump],
         ]];
 
@@ -125,7 +126,7 @@ impl<'info> Stake<'info> {
                 token_program: &self.token_program.to_account_info(),
             },
         )
-        .invoke_signed(signers_seeds)?;
+        .invoke_signed(signer_seeds)?;
 
         self.user_state.amount_staked += 1;
 
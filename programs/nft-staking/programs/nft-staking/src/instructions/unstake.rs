@@ -6,12 +6,12 @@ use anchor_spl::{
         mpl_token_metadata::instructions::{
             ThawDelegatedAccountCpi, ThawDelegatedAccountCpiAccounts,
         },
-        MasterEditionAccount, Metadata, MetadataAccount,
+        MasterEditionAccount, Metadata,
     },
     token_interface::{revoke, Mint, Revoke, TokenAccount, TokenInterface},
 };
 
-use crate::{error::StakeProgramError, GlobalState, StakeState, UserState};
+use crate::{errors::StakeError, StakeConfig, StakeState, UserState};
 
 #[derive(Accounts)]
 pub struct UnStake<'info> {
@@ -38,13 +38,13 @@ pub struct UnStake<'info> {
         seeds::program = metadata_program.key(),
         bump,
     }]
-    pub edition: Account<'info, MetadataAccount>,
+    pub edition: Account<'info, MasterEditionAccount>,
 
     #[account(
-        seeds = [b"global_state"],
-        bump = global_state.global_bump
+        seeds = [b"config"],
+        bump = config.bump
     )]
-    pub global_state: Account<'info, GlobalState>,
+    pub config: Account<'info, StakeConfig>,
 
     #[account(
         mut,
@@ -56,8 +56,8 @@ pub struct UnStake<'info> {
     #[account(
         mut,
         close = user,
-        seeds = [b"stake", mint.key().as_ref(), global_state.key().as_ref()],
-        bump,
+        seeds = [b"stake", mint.key().as_ref(), user.key().as_ref()],
+        bump = stake_account.bump,
     )]
     pub stake_account: Account<'info, StakeState>,
 
@@ -69,25 +69,32 @@ pub struct UnStake<'info> {
 
 impl<'info> UnStake<'info> {
     pub fn unstake_handler(&mut self) -> Result<()> {
-        require(
+        require!(
             self.user_state.amount_staked >= 1,
-            StakeProgramError::InsufficientPreviousStakes,
+            StakeError::InsufficientPreviousStakes
         );
 
-        let time_elapsed: u32 =
-            ((Clock::get()?.unix_timestamp - self.stake_account.staked_at) / 86400) as u32;
+        let now = Clock::get()?.unix_timestamp;
 
         require!(
-            time_elapsed > self.global_state.freeze_period,
-            StakeProgramError::UnFreezeTimeNotSatisfied
+            self.stake_account.staked_seconds(now) >= self.config.freeze_period,
+            StakeError::FreezePeriodNotPassed
         );
 
-        self.user_state.points += (self.global_state.points_per_stake as u32) * time_elapsed;
+        let points = self
+            .stake_account
+            .earned_points(now, self.config.points_per_stake)
+            .ok_or(StakeError::MathOverflow)?;
+        self.user_state.points = self
+            .user_state
+            .points
+            .checked_add(points)
+            .ok_or(StakeError::MathOverflow)?;
 
         let signer_seeds: &[&[&[u8]]] = &[&[
             b"stake",
-            self.mint.to_account_info().key().as_ref(),
-            self.global_state.to_account_info().key().as_ref(),
+            self.mint.to_account_info().key.as_ref(),
+            self.user.to_account_info().key.as_ref(),
             &[self.stake_account.bump],
         ]];
 
@@ -113,9 +120,7 @@ impl<'info> UnStake<'info> {
             cpi_revoke_accounts,
         ))?;
 
-        if self.user_state.amount_staked > 0 {
-            self.user_state.amount_staked -= 1
-        };
+        self.user_state.amount_staked -= 1;
 
         Ok(())
     }
@@ -0,0 +1,37 @@
+use anchor_lang::prelude::*;
+
+use crate::{errors::StakeError, StakeConfig};
+
+#[derive(Accounts)]
+pub struct UpdateConfig<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"config"],
+        bump = config.bump,
+        has_one = authority @ StakeError::Unauthorized
+    )]
+    pub config: Account<'info, StakeConfig>,
+}
+
+impl<'info> UpdateConfig<'info> {
+    pub fn handle_update(
+        &mut self,
+        max_stake: u8,
+        points_per_stake: u32,
+        freeze_period: i64,
+        rewards_per_point: u64,
+    ) -> Result<()> {
+        require!(freeze_period >= 0, StakeError::InvalidConfig);
+
+        // Takes effect immediately, including for existing stakes: unstake reads
+        // freeze_period and points_per_stake, and claim reads rewards_per_point, from
+        // the config at that time. max_stake only gates new stakes
+        self.config.max_stake = max_stake;
+        self.config.points_per_stake = points_per_stake;
+        self.config.freeze_period = freeze_period;
+        self.config.rewards_per_point = rewards_per_point;
+        Ok(())
+    }
+}
\ No newline at end of file
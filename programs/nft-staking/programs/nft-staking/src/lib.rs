@@ -2,7 +2,7 @@
 
 use anchor_lang::prelude::*;
 pub mod constants;
-pub mod error;
+pub mod errors;
 pub mod instructions;
 pub mod state;
 pub use constants::*;
@@ -15,7 +15,48 @@ declare_id!("GRsjtj5TQwRuXsNfsG6A7mP39ccuNCiaU86FiSGMKAiG");
 pub mod anchor_staking {
     use super::*;
 
-    pub fn initialize(ctx: Context<Initialize>) -> Result<()> {
-        initialize_global_state::handler(ctx)
+    pub fn initialize(
+        ctx: Context<InitializeConfig>,
+        max_stake: u8,
+        points_per_stake: u32,
+        freeze_period: i64,
+        rewards_per_point: u64,
+        allowed_collection: Pubkey,
+    ) -> Result<()> {
+        ctx.accounts.handle_init(
+            max_stake,
+            points_per_stake,
+            freeze_period,
+            rewards_per_point,
+            allowed_collection,
+            &ctx.bumps,
+        )
+    }
+
+    pub fn initialize_user(ctx: Context<InitializeUser>) -> Result<()> {
+        ctx.accounts.handle_initialize(&ctx.bumps)
+    }
+
+    pub fn stake(ctx: Context<Stake>) -> Result<()> {
+        ctx.accounts.stake_handler(&ctx.bumps)
+    }
+
+    pub fn unstake(ctx: Context<UnStake>) -> Result<()> {
+        ctx.accounts.unstake_handler()
+    }
+
+    pub fn update_config(
+        ctx: Context<UpdateConfig>,
+        max_stake: u8,
+        points_per_stake: u32,
+        freeze_period: i64,
+        rewards_per_point: u64,
+    ) -> Result<()> {
+        ctx.accounts
+            .handle_update(max_stake, points_per_stake, freeze_period, rewards_per_point)
+    }
+
+    pub fn claim_points(ctx: Context<Claim>, points: u64) -> Result<()> {
+        ctx.accounts.claim_handler(points)
     }
 }
\ No newline at end of file
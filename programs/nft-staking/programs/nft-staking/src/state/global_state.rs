@@ -1,11 +0,0 @@
-use anchor_lang::prelude::*;
-
-#[account]
-#[derive(InitSpace)]
-pub struct GlobalState {
-    pub points_per_stake: u8,
-    pub max_stake: u8,
-    pub freeze_period: u32,
-    pub rewards_bump: u8,
-    pub global_bump: u8,
-}
\ No newline at end of file
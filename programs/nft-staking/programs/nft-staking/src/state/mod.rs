@@ -1,7 +1,7 @@
-pub mod global_state;
+pub mod stake_config;
 pub mod user_state;
 pub mod stake_state;
 
-pub use global_state::*;
+pub use stake_config::*;
 pub use user_state::*;
 pub use stake_state::*;
\ No newline at end of file
@@ -0,0 +1,15 @@
+use anchor_lang::prelude::*;
+
+#[account]
+#[derive(InitSpace)]
+pub struct StakeConfig {
+    pub authority: Pubkey,
+    pub points_per_stake: u32,
+    pub max_stake: u8,
+    pub freeze_period: i64,
+    pub reward_mint: Pubkey,
+    pub rewards_per_point: u64,
+    pub allowed_collection: Pubkey,
+    pub rewards_bump: u8,
+    pub bump: u8,
+}
\ No newline at end of file
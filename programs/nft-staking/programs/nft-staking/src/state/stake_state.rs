@@ -1,5 +1,7 @@
 use anchor_lang::prelude::*;
 
+use crate::constants::SECONDS_PER_DAY;
+
 #[account]
 #[derive(InitSpace)]
 pub struct StakeState {
@@ -7,4 +9,50 @@ pub struct StakeState {
     pub owner: Pubkey,
     pub mint: Pubkey,
     pub staked_at: i64,
+}
+
+impl StakeState {
+    pub fn staked_seconds(&self, now: i64) -> i64 {
+        (now - self.staked_at).max(0)
+    }
+
+    // Points are earned per full day staked
+    pub fn earned_points(&self, now: i64, points_per_stake: u32) -> Option<u32> {
+        let days = u32::try_from(self.staked_seconds(now) / SECONDS_PER_DAY).ok()?;
+        points_per_stake.checked_mul(days)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const START: i64 = 1_000_000;
+
+    fn mock_stake() -> StakeState {
+        StakeState {
+            bump: 0,
+            owner: Pubkey::default(),
+            mint: Pubkey::default(),
+            staked_at: START,
+        }
+    }
+
+    #[test]
+    fn test_points_per_full_day() {
+        let stake = mock_stake();
+
+        assert_eq!(stake.earned_points(START, 10), Some(0));
+        assert_eq!(stake.earned_points(START + SECONDS_PER_DAY - 1, 10), Some(0));
+        assert_eq!(stake.earned_points(START + SECONDS_PER_DAY, 10), Some(10));
+        assert_eq!(stake.earned_points(START + 7 * SECONDS_PER_DAY + 5, 10), Some(70));
+    }
+
+    #[test]
+    fn test_points_overflow() {
+        let stake = mock_stake();
+
+        assert_eq!(stake.earned_points(i64::MAX, 10), None);
+        assert_eq!(stake.earned_points(START - 1, 10), Some(0));
+    }
 }
\ No newline at end of file
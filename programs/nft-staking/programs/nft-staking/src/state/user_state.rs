@@ -1,9 +1,63 @@
 use anchor_lang::prelude::*;
 
+use crate::errors::StakeError;
+
 #[account]
 #[derive(InitSpace)]
 pub struct UserState {
     pub bump: u8,
     pub points: u32,
     pub amount_staked: u8,
+}
+
+impl UserState {
+    pub fn spend_points(&mut self, points: u64) -> Result<()> {
+        require!(points > 0, StakeError::InvalidPointsAmount);
+        require!(
+            points <= self.points as u64,
+            StakeError::InsufficientPoints
+        );
+
+        self.points -= points as u32;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn mock_user(points: u32) -> UserState {
+        UserState {
+            bump: 0,
+            points,
+            amount_staked: 0,
+        }
+    }
+
+    #[test]
+    fn test_spend_all_points() {
+        let mut user = mock_user(70);
+
+        assert!(user.spend_points(70).is_ok());
+        assert_eq!(user.points, 0);
+    }
+
+    #[test]
+    fn test_spend_some_points() {
+        let mut user = mock_user(70);
+
+        assert!(user.spend_points(30).is_ok());
+        assert_eq!(user.points, 40);
+    }
+
+    #[test]
+    fn test_spend_rejects_invalid_amounts() {
+        let mut user = mock_user(70);
+
+        assert!(user.spend_points(0).is_err());
+        assert!(user.spend_points(71).is_err());
+        assert!(user.spend_points(u64::MAX).is_err());
+        assert_eq!(user.points, 70);
+    }
 }
\ No newline at end of file
@@ -1,16 +1,439 @@
 import * as anchor from "@coral-xyz/anchor";
 import { Program } from "@coral-xyz/anchor";
+import { PublicKey, SystemProgram } from "@solana/web3.js";
+import { assert } from "chai";
+import {
+  createNft,
+  findMasterEditionPda,
+  findMetadataPda,
+  mplTokenMetadata,
+  verifySizedCollectionItem,
+  MPL_TOKEN_METADATA_PROGRAM_ID,
+} from "@metaplex-foundation/mpl-token-metadata";
+import {
+  createSignerFromKeypair,
+  generateSigner,
+  keypairIdentity,
+  percentAmount,
+  publicKey,
+  KeypairSigner,
+} from "@metaplex-foundation/umi";
+import { createUmi } from "@metaplex-foundation/umi-bundle-defaults";
 import { NftStaking } from "../target/types/nft_staking";
 
 describe("nft-staking", () => {
   // Configure the client to use the local cluster.
-  anchor.setProvider(anchor.AnchorProvider.env());
+  const provider = anchor.AnchorProvider.env();
+  anchor.setProvider(provider);
 
   const program = anchor.workspace.nftStaking as Program<NftStaking>;
+  const wallet = provider.wallet as anchor.Wallet;
 
-  it("Is initialized!", async () => {
-    // Add your test here.
-    const tx = await program.methods.initialize().rpc();
-    console.log("Your transaction signature", tx);
+  const TOKEN_PROGRAM_ID = anchor.utils.token.TOKEN_PROGRAM_ID;
+  const ASSOCIATED_TOKEN_PROGRAM_ID = anchor.utils.token.ASSOCIATED_PROGRAM_ID;
+  const METADATA_PROGRAM_ID = new PublicKey(MPL_TOKEN_METADATA_PROGRAM_ID.toString());
+
+  const MAX_STAKE = 2;
+  const POINTS_PER_STAKE = 10;
+  const FREEZE_PERIOD = 5; // seconds
+  const REWARDS_PER_POINT = 1_000_000; // one reward token per point
+  const SECONDS_PER_DAY = 24 * 60 * 60;
+
+  const umi = createUmi(provider.connection.rpcEndpoint).use(mplTokenMetadata());
+  const umiPayer = umi.eddsa.createKeypairFromSecretKey(wallet.payer.secretKey);
+  umi.use(keypairIdentity(createSignerFromKeypair(umi, umiPayer)));
+
+  const user = wallet.payer;
+  let collectionMint: KeypairSigner;
+  const stakedMints: PublicKey[] = [];
+
+  const [config] = PublicKey.findProgramAddressSync(
+    [Buffer.from("config")],
+    program.programId
+  );
+  const [rewardMint] = PublicKey.findProgramAddressSync(
+    [Buffer.from("rewards"), config.toBuffer()],
+    program.programId
+  );
+  const [userState] = PublicKey.findProgramAddressSync(
+    [Buffer.from("user"), user.publicKey.toBuffer()],
+    program.programId
+  );
+
+  function findStakePda(mint: PublicKey, owner: PublicKey): PublicKey {
+    const [stakePda] = PublicKey.findProgramAddressSync(
+      [Buffer.from("stake"), mint.toBuffer(), owner.toBuffer()],
+      program.programId
+    );
+    return stakePda;
+  }
+
+  function findMetadataAddress(mint: PublicKey): PublicKey {
+    return new PublicKey(findMetadataPda(umi, { mint: publicKey(mint.toBase58()) })[0]);
+  }
+
+  function findEditionAddress(mint: PublicKey): PublicKey {
+    return new PublicKey(findMasterEditionPda(umi, { mint: publicKey(mint.toBase58()) })[0]);
+  }
+
+  async function createCollection(): Promise<KeypairSigner> {
+    const mint = generateSigner(umi);
+
+    await createNft(umi, {
+      mint,
+      name: "Staking Collection",
+      symbol: "STKC",
+      uri: "https://example.com/collection.json",
+      sellerFeeBasisPoints: percentAmount(0),
+      isCollection: true,
+    }).sendAndConfirm(umi);
+
+    return mint;
+  }
+
+  /**
+   * Mint an NFT into a collection, verified unless stated otherwise
+   */
+  async function mintCollectionNft(
+    collection: KeypairSigner = collectionMint,
+    verified: boolean = true
+  ): Promise<PublicKey> {
+    const mint = generateSigner(umi);
+
+    await createNft(umi, {
+      mint,
+      name: "Staked NFT",
+      symbol: "STK",
+      uri: "https://example.com/nft.json",
+      sellerFeeBasisPoints: percentAmount(0),
+      collection: { verified: false, key: collection.publicKey },
+    }).sendAndConfirm(umi);
+
+    if (verified) {
+      await verifySizedCollectionItem(umi, {
+        metadata: findMetadataPda(umi, { mint: mint.publicKey }),
+        collectionAuthority: umi.identity,
+        collectionMint: collection.publicKey,
+        collection: findMetadataPda(umi, { mint: collection.publicKey }),
+        collectionMasterEditionAccount: findMasterEditionPda(umi, {
+          mint: collection.publicKey,
+        }),
+      }).sendAndConfirm(umi);
+    }
+
+    return new PublicKey(mint.publicKey);
+  }
+
+  async function stake(mint: PublicKey) {
+    await program.methods
+      .stake()
+      .accounts({
+        user: user.publicKey,
+        mint,
+        userMintAta: anchor.utils.token.associatedAddress({
+          mint,
+          owner: user.publicKey,
+        }),
+        metadata: findMetadataAddress(mint),
+        edition: findEditionAddress(mint),
+        config,
+        userState,
+        stakeAccount: findStakePda(mint, user.publicKey),
+        systemProgram: SystemProgram.programId,
+        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        metadataProgram: METADATA_PROGRAM_ID,
+      })
+      .signers([user])
+      .rpc();
+    stakedMints.push(mint);
+  }
+
+  async function unstake(mint: PublicKey) {
+    await program.methods
+      .unstake()
+      .accounts({
+        user: user.publicKey,
+        mint,
+        userMintAta: anchor.utils.token.associatedAddress({
+          mint,
+          owner: user.publicKey,
+        }),
+        edition: findEditionAddress(mint),
+        config,
+        userState,
+        stakeAccount: findStakePda(mint, user.publicKey),
+        systemProgram: SystemProgram.programId,
+        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        metadataProgram: METADATA_PROGRAM_ID,
+      })
+      .signers([user])
+      .rpc();
+  }
+
+  async function chainTime(): Promise<number> {
+    const slot = await provider.connection.getSlot();
+    return await provider.connection.getBlockTime(slot);
+  }
+
+  before(async () => {
+    collectionMint = await createCollection();
+
+    await program.methods
+      .initialize(
+        MAX_STAKE,
+        POINTS_PER_STAKE,
+        new anchor.BN(FREEZE_PERIOD),
+        new anchor.BN(REWARDS_PER_POINT),
+        new PublicKey(collectionMint.publicKey)
+      )
+      .accounts({
+        admin: user.publicKey,
+        config,
+        rewardMint,
+        systemProgram: SystemProgram.programId,
+        rent: anchor.web3.SYSVAR_RENT_PUBKEY,
+        tokenProgram: TOKEN_PROGRAM_ID,
+      })
+      .signers([user])
+      .rpc();
+
+    await program.methods
+      .initializeUser()
+      .accounts({
+        user: user.publicKey,
+        userAccount: userState,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([user])
+      .rpc();
+  });
+
+  describe("stake", () => {
+    it("stakes an NFT and freezes it in the user's wallet", async () => {
+      const mint = await mintCollectionNft();
+
+      await stake(mint);
+
+      const stakeState = await program.account.stakeState.fetch(findStakePda(mint, user.publicKey));
+      assert.isTrue(stakeState.owner.equals(user.publicKey));
+      assert.isTrue(stakeState.mint.equals(mint));
+      assert.isAbove(stakeState.stakedAt.toNumber(), 0);
+
+      const userAccount = await program.account.userState.fetch(userState);
+      assert.equal(userAccount.amountStaked, 1);
+
+      // The NFT stays with the user but can't be moved while staked
+      const ata = await provider.connection.getParsedAccountInfo(
+        anchor.utils.token.associatedAddress({ mint, owner: user.publicKey })
+      );
+      const info = (ata.value.data as anchor.web3.ParsedAccountData).parsed.info;
+      assert.equal(info.state, "frozen");
+      assert.equal(info.tokenAmount.amount, "1");
+    });
+
+    it("rejects NFTs from another collection", async () => {
+      const otherCollection = await createCollection();
+
+      try {
+        await stake(await mintCollectionNft(otherCollection));
+        assert.fail("Should have failed with invalid collection");
+      } catch (error) {
+        assert(error.message.includes("InvalidCollection"));
+      }
+    });
+
+    it("rejects unverified members of the allowed collection", async () => {
+      try {
+        await stake(await mintCollectionNft(collectionMint, false));
+        assert.fail("Should have failed with invalid collection");
+      } catch (error) {
+        assert(error.message.includes("InvalidCollection"));
+      }
+    });
+
+    it("rejects staking beyond the max stake", async () => {
+      await stake(await mintCollectionNft());
+
+      const userAccount = await program.account.userState.fetch(userState);
+      assert.equal(userAccount.amountStaked, MAX_STAKE);
+
+      try {
+        await stake(await mintCollectionNft());
+        assert.fail("Should have failed with max stake reached");
+      } catch (error) {
+        assert(error.message.includes("MaxStakeReached"));
+      }
+    });
+  });
+
+  describe("unstake", () => {
+    it("rejects unstaking before the freeze period has passed", async () => {
+      const mint = stakedMints[stakedMints.length - 1];
+
+      try {
+        await unstake(mint);
+        assert.fail("Should have failed with freeze period not passed");
+      } catch (error) {
+        assert(error.message.includes("FreezePeriodNotPassed"));
+      }
+    });
+
+    it("thaws the NFT and awards points once the freeze period has passed", async () => {
+      const mint = stakedMints[0];
+      const stakePda = findStakePda(mint, user.publicKey);
+      const stakeState = await program.account.stakeState.fetch(stakePda);
+      const before = await program.account.userState.fetch(userState);
+
+      while ((await chainTime()) < stakeState.stakedAt.toNumber() + FREEZE_PERIOD) {
+        await new Promise((resolve) => setTimeout(resolve, 500));
+      }
+
+      await unstake(mint);
+      const unstakedAt = await chainTime();
+
+      const after = await program.account.userState.fetch(userState);
+      assert.equal(after.amountStaked, before.amountStaked - 1);
+
+      // Points accrue per full day staked
+      const stakedDays = Math.floor((unstakedAt - stakeState.stakedAt.toNumber()) / SECONDS_PER_DAY);
+      assert.equal(after.points - before.points, POINTS_PER_STAKE * stakedDays);
+
+      assert.isNull(await provider.connection.getAccountInfo(stakePda));
+
+      const ata = await provider.connection.getParsedAccountInfo(
+        anchor.utils.token.associatedAddress({ mint, owner: user.publicKey })
+      );
+      const info = (ata.value.data as anchor.web3.ParsedAccountData).parsed.info;
+      assert.equal(info.state, "initialized");
+      assert.isUndefined(info.delegate);
+    });
+  });
+
+  describe("config", () => {
+    async function updateConfig(maxStake: number, pointsPerStake: number, freezePeriod: number) {
+      await program.methods
+        .updateConfig(
+          maxStake,
+          pointsPerStake,
+          new anchor.BN(freezePeriod),
+          new anchor.BN(REWARDS_PER_POINT)
+        )
+        .accounts({
+          authority: user.publicKey,
+          config,
+        })
+        .signers([user])
+        .rpc();
+    }
+
+    after(async () => {
+      await updateConfig(MAX_STAKE, POINTS_PER_STAKE, FREEZE_PERIOD);
+    });
+
+    it("stores the values passed to initialize", async () => {
+      const configAccount = await program.account.stakeConfig.fetch(config);
+
+      assert.isTrue(configAccount.authority.equals(user.publicKey));
+      assert.equal(configAccount.maxStake, MAX_STAKE);
+      assert.equal(configAccount.pointsPerStake, POINTS_PER_STAKE);
+      assert.equal(configAccount.freezePeriod.toNumber(), FREEZE_PERIOD);
+      assert.isTrue(configAccount.rewardMint.equals(rewardMint));
+      assert.equal(configAccount.rewardsPerPoint.toNumber(), REWARDS_PER_POINT);
+      assert.isTrue(configAccount.allowedCollection.equals(new PublicKey(collectionMint.publicKey)));
+    });
+
+    it("enforces an updated max stake", async () => {
+      const userAccount = await program.account.userState.fetch(userState);
+      await updateConfig(userAccount.amountStaked, POINTS_PER_STAKE, FREEZE_PERIOD);
+
+      try {
+        await stake(await mintCollectionNft());
+        assert.fail("Should have failed with max stake reached");
+      } catch (error) {
+        assert(error.message.includes("MaxStakeReached"));
+      }
+
+      await updateConfig(userAccount.amountStaked + 1, POINTS_PER_STAKE, FREEZE_PERIOD);
+      await stake(await mintCollectionNft());
+
+      const after = await program.account.userState.fetch(userState);
+      assert.equal(after.amountStaked, userAccount.amountStaked + 1);
+    });
+
+    it("enforces an updated freeze period", async () => {
+      await updateConfig(MAX_STAKE + 1, POINTS_PER_STAKE, 0);
+
+      // Staked moments ago, but no longer frozen
+      await unstake(stakedMints[stakedMints.length - 1]);
+    });
+
+    it("rejects updates from anyone but the authority", async () => {
+      const stranger = anchor.web3.Keypair.generate();
+
+      try {
+        await program.methods
+          .updateConfig(
+            MAX_STAKE,
+            POINTS_PER_STAKE,
+            new anchor.BN(FREEZE_PERIOD),
+            new anchor.BN(REWARDS_PER_POINT)
+          )
+          .accounts({
+            authority: stranger.publicKey,
+            config,
+          })
+          .signers([stranger])
+          .rpc();
+        assert.fail("Should have failed with unauthorized");
+      } catch (error) {
+        assert(error.message.includes("Unauthorized"));
+      }
+    });
+  });
+
+  describe("claim_points", () => {
+    async function claimPoints(points: number) {
+      await program.methods
+        .claimPoints(new anchor.BN(points))
+        .accounts({
+          user: user.publicKey,
+          config,
+          userState,
+          rewardMint,
+          userRewardAta: anchor.utils.token.associatedAddress({
+            mint: rewardMint,
+            owner: user.publicKey,
+          }),
+          systemProgram: SystemProgram.programId,
+          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .signers([user])
+        .rpc();
+    }
+
+    // Points accrue per full day staked, so full and partial claims of earned
+    // points are covered by the UserState unit tests
+    it("rejects claiming more points than the user holds", async () => {
+      const userAccount = await program.account.userState.fetch(userState);
+
+      try {
+        await claimPoints(userAccount.points + 1);
+        assert.fail("Should have failed with insufficient points");
+      } catch (error) {
+        assert(error.message.includes("InsufficientPoints"));
+      }
+    });
+
+    it("rejects claiming zero points", async () => {
+      try {
+        await claimPoints(0);
+        assert.fail("Should have failed with invalid points amount");
+      } catch (error) {
+        assert(error.message.includes("InvalidPointsAmount"));
+      }
+    });
   });
 });
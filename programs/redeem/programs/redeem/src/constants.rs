@@ -1,4 +1,5 @@
 use anchor_lang::prelude::*;
+use anchor_lang::solana_program::program_option::COption;
 
 /// PDA SEEDS - These are the deterministic seeds used to derive Program Derived Addresses (PDAs)
 /// PDAs are accounts owned by the program that can be derived deterministically from seeds
@@ -20,9 +21,13 @@ pub const PRODUCT_SEED: &[u8] = b"product";
 pub const USER_REDEEM_SEED: &[u8] = b"user_redeem";
 
 /// Redemption record PDA seed - creates audit trail for each redemption
-/// Combined with user, product_id, and timestamp for unique records
+/// Combined with user, product_id, and the user's redemption nonce for unique records
 pub const REDEMPTION_SEED: &[u8] = b"redemption";
 
+/// Per-user product claim PDA seed - counts a user's redemptions of one product
+/// Combined with user's public key and product_id to enforce per-user limits
+pub const CLAIM_SEED: &[u8] = b"claim";
+
 /// SYSTEM CONSTRAINTS - These define the operational limits of the program
 
 /// Minimum SOL per ticket rate (0.001 SOL = 1,000,000 lamports)
@@ -35,20 +40,50 @@ pub const MAX_SOL_PER_TICKET: u64 = 1_000_000_000;
 
 /// Minimum tickets that can be purchased in a single transaction
 /// Prevents spam transactions and ensures meaningful purchases
+/// Counted in base units, so fractional mints can buy their smallest unit
 pub const MIN_TICKET_PURCHASE: u64 = 1;
 
 /// Maximum tickets that can be purchased in a single transaction
 /// Prevents large purchases that could drain the system or cause overflow
+/// Counted in whole tickets and scaled by the mint's decimals
 pub const MAX_TICKET_PURCHASE: u64 = 1_000;
 
+/// Maximum decimals for the ticket mint
+/// Matches SOL's 9 decimals and keeps the ticket scale well within u64
+pub const MAX_TICKET_DECIMALS: u8 = 9;
+
 /// Minimum ticket cost for a product
 /// Ensures products have meaningful value in the token economy
 pub const MIN_PRODUCT_TICKET_COST: u64 = 1;
 
+/// Maximum number of products in a single bulk redemption
+/// Each product adds three accounts, so this keeps batches within transaction limits
+pub const MAX_BULK_REDEMPTION: usize = 5;
+
 /// Maximum ticket cost for a product
 /// Prevents products from being priced too high
+/// Counted in whole tickets and scaled by the mint's decimals
 pub const MAX_PRODUCT_TICKET_COST: u64 = 10_000;
 
+/// Maximum SOL surcharge for a product (1 SOL = 1,000,000,000 lamports)
+/// Surcharges cover real fulfillment costs such as shipping, not product value
+pub const MAX_PRODUCT_SOL_SURCHARGE: u64 = 1_000_000_000;
+
+/// Fee kept by the vault when tickets are refunded (500 bps = 5%)
+/// Discourages using tickets as a free round trip for SOL
+pub const REFUND_FEE_BPS: u64 = 500;
+
+/// Basis point denominator (10,000 bps = 100%)
+pub const BPS_DENOMINATOR: u64 = 10_000;
+
+/// Maximum number of volume discount tiers on the system state
+/// Bounds the Redeem account size
+pub const MAX_DISCOUNT_TIERS: usize = 4;
+
+/// Maximum volume discount (500 bps = 5%)
+/// Kept at the refund fee so buying in bulk and refunding can't profit
+pub const MAX_DISCOUNT_BPS: u16 = REFUND_FEE_BPS as u16;
+
 /// Maximum product quantity that can be added
 /// Prevents inventory overflow and ensures reasonable stock levels
 pub const MAX_PRODUCT_QUANTITY: u32 = 10_000;
@@ -61,6 +96,28 @@ pub const MAX_PRODUCT_NAME_LEN: usize = 32;
 /// Ensures descriptions fit within account size constraints
 pub const MAX_PRODUCT_DESCRIPTION_LEN: usize = 64;
 
+// PRODUCT CATEGORIES - Stored in `Product::category` for browsing the catalog
+
+/// Products that don't belong to a specific category
+pub const CATEGORY_GENERAL: u8 = 0;
+
+/// Physical merchandise such as shirts, stickers and mugs
+pub const CATEGORY_MERCH: u8 = 1;
+
+/// Digital goods such as codes, downloads and NFTs
+pub const CATEGORY_DIGITAL: u8 = 2;
+
+/// Events and experiences such as tickets and meetups
+pub const CATEGORY_EXPERIENCE: u8 = 3;
+
+/// Highest category value accepted by add_product
+pub const MAX_CATEGORY: u8 = CATEGORY_EXPERIENCE;
+
+/// Byte offset of `category` in a Product account
+/// 8 (discriminator) + 8 (id); kept ahead of the variable-length name so clients
+/// can filter products with a getProgramAccounts memcmp at this offset
+pub const PRODUCT_CATEGORY_OFFSET: usize = 16;
+
 /// VALIDATION FUNCTIONS - These provide reusable validation logic
 
 /// Validates that a SOL per ticket rate is within acceptable bounds
@@ -77,18 +134,32 @@ pub fn is_valid_sol_per_ticket(sol_per_ticket: u64) -> bool {
 /// Validates that a ticket purchase amount is within acceptable bounds
 /// 
 /// # Arguments
-/// * `amount` - The number of tickets to validate
+/// * `amount` - The number of tickets to validate, in base units
+/// * `decimals` - Decimals of the ticket mint
 /// 
 /// # Returns
 /// * `bool` - true if the amount is valid, false otherwise
-pub fn is_valid_ticket_amount(amount: u64) -> bool {
-    amount >= MIN_TICKET_PURCHASE && amount <= MAX_TICKET_PURCHASE
+pub fn is_valid_ticket_amount(amount: u64, decimals: u8) -> bool {
+    let max_amount = MAX_TICKET_PURCHASE.saturating_mul(ticket_scale(decimals));
+    amount >= MIN_TICKET_PURCHASE && amount <= max_amount
+}
+
+/// Validates that ticket mint decimals are within acceptable bounds
+/// 
+/// # Arguments
+/// * `decimals` - The decimals to validate
+/// 
+/// # Returns
+/// * `bool` - true if the decimals are valid, false otherwise
+pub fn is_valid_ticket_decimals(decimals: u8) -> bool {
+    decimals <= MAX_TICKET_DECIMALS
 }
 
 /// Validates that a product configuration is acceptable
 /// 
 /// # Arguments
-/// * `ticket_cost` - The ticket cost for the product
+/// * `ticket_cost` - The ticket cost for the product, in base units
+/// * `decimals` - Decimals of the ticket mint
 /// * `quantity` - The total quantity of the product
 /// * `name` - The product name
 /// * `description` - The product description
@@ -97,12 +168,14 @@ pub fn is_valid_ticket_amount(amount: u64) -> bool {
 /// * `bool` - true if all parameters are valid, false otherwise
 pub fn is_valid_product(
     ticket_cost: u64,
+    decimals: u8,
     quantity: u32,
     name: &str,
     description: &str,
 ) -> bool {
+    let max_ticket_cost = MAX_PRODUCT_TICKET_COST.saturating_mul(ticket_scale(decimals));
     ticket_cost >= MIN_PRODUCT_TICKET_COST
-        && ticket_cost <= MAX_PRODUCT_TICKET_COST
+        && ticket_cost <= max_ticket_cost
         && quantity > 0
         && quantity <= MAX_PRODUCT_QUANTITY
         && !name.is_empty()
@@ -110,6 +183,17 @@ pub fn is_valid_product(
         && description.len() <= MAX_PRODUCT_DESCRIPTION_LEN
 }
 
+/// Validates that a product category is one of the known categories
+/// 
+/// # Arguments
+/// * `category` - The category to validate
+/// 
+/// # Returns
+/// * `bool` - true if the category is known, false otherwise
+pub fn is_valid_category(category: u8) -> bool {
+    category <= MAX_CATEGORY
+}
+
 /// UTILITY FUNCTIONS - Helper functions for common operations
 
 /// Converts a string to a fixed-size byte array for storage
@@ -140,17 +224,101 @@ pub fn bytes_to_string(bytes: &[u8]) -> String {
         .to_string()
 }
 
+/// Number of base units in one whole ticket
+/// 
+/// # Arguments
+/// * `decimals` - Decimals of the ticket mint (at most MAX_TICKET_DECIMALS)
+/// 
+/// # Returns
+/// * `u64` - 10^decimals
+pub fn ticket_scale(decimals: u8) -> u64 {
+    10u64.pow(decimals as u32)
+}
+
 /// Calculates the total SOL cost for a given number of tickets
-/// Uses checked arithmetic to prevent overflow
+/// The rate is per whole ticket; fractional costs round up to the next lamport
+/// so no nonzero amount of tickets is ever free
 /// 
 /// # Arguments
-/// * `ticket_amount` - Number of tickets
-/// * `sol_per_ticket` - Rate in lamports per ticket
+/// * `ticket_amount` - Number of tickets in base units
+/// * `sol_per_ticket` - Rate in lamports per whole ticket
+/// * `decimals` - Decimals of the ticket mint
 /// 
 /// # Returns
 /// * `Option<u64>` - The total cost in lamports, or None if overflow
-pub fn calculate_total_cost(ticket_amount: u64, sol_per_ticket: u64) -> Option<u64> {
-    ticket_amount.checked_mul(sol_per_ticket)
+pub fn calculate_total_cost(ticket_amount: u64, sol_per_ticket: u64, decimals: u8) -> Option<u64> {
+    let scale = ticket_scale(decimals) as u128;
+    (ticket_amount as u128)
+        .checked_mul(sol_per_ticket as u128)?
+        .checked_add(scale - 1)?
+        .checked_div(scale)?
+        .try_into()
+        .ok()
+}
+
+/// Calculates the SOL paid back for refunded tickets
+/// Applies the refund fee to the current exchange rate
+/// Fractional lamports round down, so a refund never pays back more per
+/// ticket than a purchase cost
+/// 
+/// # Arguments
+/// * `ticket_amount` - Number of tickets being refunded, in base units
+/// * `sol_per_ticket` - Rate in lamports per whole ticket
+/// * `decimals` - Decimals of the ticket mint
+/// 
+/// # Returns
+/// * `Option<u64>` - The refund in lamports, or None if overflow
+pub fn calculate_refund_amount(ticket_amount: u64, sol_per_ticket: u64, decimals: u8) -> Option<u64> {
+    let gross: u64 = (ticket_amount as u128)
+        .checked_mul(sol_per_ticket as u128)?
+        .checked_div(ticket_scale(decimals) as u128)?
+        .try_into()
+        .ok()?;
+    let fee = (gross as u128)
+        .checked_mul(REFUND_FEE_BPS as u128)?
+        .checked_div(BPS_DENOMINATOR as u128)? as u64;
+    gross.checked_sub(fee)
+}
+
+/// Lamports the SOL vault must hold to stay rent exempt
+/// 
+/// The vault is a data-less system account, so this is the rent-exempt
+/// minimum for zero bytes of data at the current rent rate.
+/// 
+/// # Returns
+/// * `Result<u64>` - Minimum vault balance in lamports
+pub fn min_vault_balance() -> Result<u64> {
+    Ok(Rent::get()?.minimum_balance(0))
+}
+
+/// Checks that paying `amount` out of the vault leaves it rent exempt
+/// 
+/// # Arguments
+/// * `vault_balance` - Current vault balance in lamports
+/// * `amount` - Lamports leaving the vault
+/// * `min_balance` - Minimum balance from `min_vault_balance`
+/// 
+/// # Returns
+/// * `bool` - true if the post-withdraw balance is at least `min_balance`
+pub fn keeps_vault_rent_exempt(vault_balance: u64, amount: u64, min_balance: u64) -> bool {
+    vault_balance
+        .checked_sub(amount)
+        .is_some_and(|remaining| remaining >= min_balance)
+}
+
+/// Checks that the program can mint tickets from an existing mint
+/// 
+/// The redeem PDA signs every ticket mint, so it must be the mint's
+/// authority. Mints with no authority (fixed supply) are rejected too.
+/// 
+/// # Arguments
+/// * `mint_authority` - The mint's `mint_authority` field
+/// * `redeem` - Address of the redeem PDA
+/// 
+/// # Returns
+/// * `bool` - true if the redeem PDA is the mint authority
+pub fn program_can_mint(mint_authority: &COption<Pubkey>, redeem: &Pubkey) -> bool {
+    *mint_authority == COption::Some(*redeem)
 }
 
 /// Checks if a user has sufficient tickets for a redemption
@@ -166,20 +334,20 @@ pub fn has_sufficient_tickets(user_balance: u64, required_tickets: u64) -> bool
 }
 
 /// Generates a unique seed for redemption records
-/// Combines user, product, and timestamp to ensure uniqueness
+/// Combines user, product, and the user's redemption nonce to ensure uniqueness
 /// 
 /// # Arguments
 /// * `user` - User's public key
 /// * `product_id` - Product identifier
-/// * `timestamp` - Current timestamp
+/// * `redemption_nonce` - User's redemption count before this redemption
 /// 
 /// # Returns
 /// * `Vec<Vec<u8>>` - Array of seeds for PDA derivation
-pub fn redemption_seeds(user: &Pubkey, product_id: u64, timestamp: i64) -> Vec<Vec<u8>> {
+pub fn redemption_seeds(user: &Pubkey, product_id: u64, redemption_nonce: u64) -> Vec<Vec<u8>> {
     vec![
         REDEMPTION_SEED.to_vec(),
         user.to_bytes().to_vec(),
         product_id.to_le_bytes().to_vec(),
-        timestamp.to_le_bytes().to_vec(),
+        redemption_nonce.to_le_bytes().to_vec(),
     ]
 }
\ No newline at end of file
@@ -0,0 +1,53 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::constants::*;
+
+/// Accept a proposed authority transfer
+///
+/// This instruction completes the handover started by `transfer_authority`:
+/// 1. Validates the caller is the pending authority
+/// 2. Makes the caller the system authority
+/// 3. Clears the pending authority
+#[derive(Accounts)]
+pub struct AcceptAuthority<'info> {
+    /// Proposed authority (must match redeem.pending_authority)
+    pub new_authority: Signer<'info>,
+
+    /// Main system state (PDA)
+    /// Holds the current and pending authority
+    ///
+    /// Seeds: ["redeem"]
+    /// Constraint: Signer must be the pending authority
+    #[account(
+        mut,
+        seeds = [REDEEM_SEED],
+        bump = redeem.bump,
+        constraint = redeem.pending_authority == Some(new_authority.key()) @ ErrorCode::NotPendingAuthority
+    )]
+    pub redeem: Account<'info, Redeem>,
+}
+
+/// Accept authority instruction handler
+///
+/// # Arguments
+/// * `ctx` - The instruction context containing all accounts
+///
+/// # Security Checks
+/// 1. Validates caller is the pending authority
+///
+/// # State Changes
+/// 1. Sets `authority` to the caller
+/// 2. Clears `pending_authority`
+pub fn handler(ctx: Context<AcceptAuthority>) -> Result<()> {
+    let redeem = &mut ctx.accounts.redeem;
+    let previous_authority = redeem.authority;
+
+    redeem.authority = ctx.accounts.new_authority.key();
+    redeem.pending_authority = None;
+
+    msg!("✅ Authority transfer accepted");
+    msg!("   Previous Authority: {}", previous_authority);
+    msg!("   New Authority: {}", redeem.authority);
+
+    Ok(())
+}
@@ -9,6 +9,7 @@ use crate::constants::*;
 /// 2. Creates a new Product account with unique PDA
 /// 3. Sets product configuration and availability
 /// 4. Links product to the system authority
+/// 5. Enforces the catalog size cap and updates the product counter
 /// 
 /// Only the system authority can call this instruction.
 #[derive(Accounts)]
@@ -20,11 +21,12 @@ pub struct AddProduct<'info> {
     pub authority: Signer<'info>,
 
     /// Main system state (PDA)
-    /// Used to verify authority and ensure system is active
+    /// Used to verify authority, ensure system is active, and track catalog size
     /// 
     /// Seeds: ["redeem"]
     /// Constraint: Authority must match and system must be active
     #[account(
+        mut,
         seeds = [REDEEM_SEED],
         bump = redeem.bump,
         constraint = redeem.authority == authority.key() @ ErrorCode::Unauthorized,
@@ -59,63 +61,118 @@ pub struct AddProduct<'info> {
 /// * `name` - Product name (max 32 bytes)
 /// * `description` - Product description (max 64 bytes)
 /// * `ticket_cost` - Number of tickets required to redeem this product
+/// * `sol_surcharge` - Extra lamports charged on redemption (0 for none)
 /// * `total_quantity` - Total inventory available for redemption
+/// * `max_per_user` - Maximum redemptions per user (0 for unlimited)
+/// * `expires_at` - Unix timestamp when redemptions stop (0 for never)
+/// * `category` - Catalog category (one of the CATEGORY_* constants)
 /// 
 /// # Security Checks
 /// 1. Validates caller is the system authority
 /// 2. Ensures system is active
 /// 3. Validates all product parameters are within bounds
 /// 4. Ensures product_id is unique (handled by PDA init)
+/// 5. Ensures the catalog is below its maximum size
+/// 6. Ensures any expiry is in the future
+/// 7. Ensures the category is a known one
 /// 
 /// # State Changes
 /// 1. Creates new Product account with provided configuration
 /// 2. Sets product as active and available
 /// 3. Links product to the authority that created it
+/// 4. Increments the system product counter
+#[allow(clippy::too_many_arguments)]
 pub fn handler(
     ctx: Context<AddProduct>,
     product_id: u64,
     name: String,
     description: String,
     ticket_cost: u64,
+    sol_surcharge: u64,
     total_quantity: u32,
+    max_per_user: u32,
+    expires_at: i64,
+    category: u8,
 ) -> Result<()> {
     msg!("📦 Adding new product to catalog");
     msg!("   Product ID: {}", product_id);
     msg!("   Name: {}", name);
     msg!("   Description: {}", description);
     msg!("   Ticket Cost: {}", ticket_cost);
+    msg!("   SOL Surcharge: {} lamports", sol_surcharge);
     msg!("   Total Quantity: {}", total_quantity);
+    msg!("   Max Per User: {}", max_per_user);
+    msg!("   Expires At: {}", expires_at);
+    msg!("   Category: {}", category);
     
     // Validate product parameters using our utility function
     require!(
-        is_valid_product(ticket_cost, total_quantity, &name, &description),
+        is_valid_product(
+            ticket_cost,
+            ctx.accounts.redeem.ticket_decimals,
+            total_quantity,
+            &name,
+            &description,
+        ),
         ErrorCode::InvalidProduct
     );
     
     // Additional validation for product ID (must be non-zero)
     require!(product_id > 0, ErrorCode::InvalidProduct);
     
+    // Surcharge is optional but capped
+    require!(
+        sol_surcharge <= MAX_PRODUCT_SOL_SURCHARGE,
+        ErrorCode::InvalidProduct
+    );
+    
+    // Expiry is optional but can't already have passed
+    let current_timestamp = Clock::get()?.unix_timestamp;
+    require!(
+        expires_at == 0 || expires_at > current_timestamp,
+        ErrorCode::InvalidProduct
+    );
+    
+    // Category must be one clients know how to display
+    require!(is_valid_category(category), ErrorCode::InvalidProduct);
+    
+    // Catalog size is capped unless max_products is zero
+    require!(
+        ctx.accounts.redeem.can_add_product(),
+        ErrorCode::ProductLimitReached
+    );
+    
     // Get account references
+    let redeem = &mut ctx.accounts.redeem;
     let product = &mut ctx.accounts.product;
     let authority = &ctx.accounts.authority;
     
     // Initialize product account
     product.id = product_id;
+    product.category = category;
     product.name = name.clone();
     product.description = description.clone();
     product.ticket_cost = ticket_cost;
+    product.sol_surcharge = sol_surcharge;
     product.total_quantity = total_quantity;
     product.redeemed_quantity = 0; // No redemptions yet
+    product.max_per_user = max_per_user;
+    product.expires_at = expires_at;
     product.is_active = true; // Product is immediately available
     product.authority = authority.key();
     product.bump = ctx.bumps.product;
     
+    // New products are active, so they count toward the catalog cap
+    redeem.product_count = redeem.product_count
+        .checked_add(1)
+        .ok_or(ErrorCode::MathOverflow)?;
+    
     // Log product creation details
     msg!("✅ Product added successfully");
     msg!("   Product Address: {}", product.key());
     msg!("   Authority: {}", authority.key());
     msg!("   Available Quantity: {}", product.remaining_quantity());
-    msg!("   Is Available: {}", product.is_available());
+    msg!("   Is Available: {}", product.is_available(current_timestamp));
     
     // Calculate economics for logging
     let total_ticket_value = ticket_cost
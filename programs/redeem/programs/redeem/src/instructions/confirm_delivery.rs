@@ -0,0 +1,82 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+
+/// Confirm delivery of a redeemed product
+///
+/// This instruction lets the recipient acknowledge they received the product,
+/// closing the fulfillment loop for dispute resolution:
+/// 1. Validates the signer is the user who made the redemption
+/// 2. Ensures the redemption has been fulfilled by the authority
+/// 3. Ensures delivery has not already been confirmed
+/// 4. Sets the delivery confirmation flag and timestamp
+///
+/// Only the recipient can call this instruction.
+#[derive(Accounts)]
+pub struct ConfirmDelivery<'info> {
+    /// Recipient confirming delivery
+    /// Must be the user recorded on the redemption
+    pub user: Signer<'info>,
+
+    /// Redemption record being confirmed
+    /// Its PDA seeds include the user's redemption nonce at the time, so the
+    /// address is passed in directly and ownership is checked by Anchor
+    ///
+    /// Constraints: Must belong to the signer, be fulfilled, and not yet confirmed
+    #[account(
+        mut,
+        has_one = user @ ErrorCode::Unauthorized,
+        constraint = redemption_record.is_fulfilled @ ErrorCode::NotFulfilled,
+        constraint = !redemption_record.delivery_confirmed @ ErrorCode::DeliveryAlreadyConfirmed
+    )]
+    pub redemption_record: Account<'info, RedemptionRecord>,
+}
+
+/// Delivery confirmation event - emitted for off-chain tracking
+#[event]
+pub struct DeliveryConfirmed {
+    /// User who confirmed delivery
+    pub user: Pubkey,
+    /// Product that was delivered
+    pub product_id: u64,
+    /// Timestamp of confirmation
+    pub timestamp: i64,
+    /// Address of redemption record
+    pub redemption_record: Pubkey,
+}
+
+/// Confirm delivery instruction handler
+///
+/// # Arguments
+/// * `ctx` - The instruction context containing all accounts
+///
+/// # Security Checks
+/// 1. Validates signer is the recipient on the redemption record
+/// 2. Ensures the redemption has been fulfilled
+/// 3. Prevents confirming the same delivery twice
+///
+/// # State Changes
+/// 1. Sets `delivery_confirmed` and `delivery_confirmed_at` on the redemption record
+/// 2. Emits delivery confirmation event
+pub fn handler(ctx: Context<ConfirmDelivery>) -> Result<()> {
+    let redemption_record = &mut ctx.accounts.redemption_record;
+    let current_timestamp = Clock::get()?.unix_timestamp;
+
+    msg!("📬 Confirming delivery");
+    msg!("   Record: {}", redemption_record.key());
+    msg!("   User: {}", redemption_record.user);
+    msg!("   Product ID: {}", redemption_record.product_id);
+
+    redemption_record.delivery_confirmed = true;
+    redemption_record.delivery_confirmed_at = current_timestamp;
+
+    msg!("✅ Delivery confirmed at {}", current_timestamp);
+
+    emit!(DeliveryConfirmed {
+        user: redemption_record.user,
+        product_id: redemption_record.product_id,
+        timestamp: current_timestamp,
+        redemption_record: redemption_record.key(),
+    });
+
+    Ok(())
+}
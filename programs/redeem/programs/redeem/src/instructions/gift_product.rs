@@ -0,0 +1,266 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Mint, Token, TokenAccount, Burn, burn};
+use crate::state::*;
+use crate::constants::*;
+use crate::instructions::redeem_product::ProductRedeemed;
+
+/// Redeem a product on behalf of another user
+///
+/// This instruction lets a buyer gift a product to a recipient:
+/// 1. Validates the payer has sufficient tickets and product is available
+/// 2. Burns ticket tokens from the payer's account
+/// 3. Updates the payer's ticket balance and redemption history
+/// 4. Counts the redemption toward the recipient's per-product limit
+/// 5. Creates a redemption record attributed to the recipient
+/// 6. Updates product inventory and system statistics
+///
+/// The recipient doesn't sign and doesn't need a ticket account; the record
+/// names them as the user, so they confirm delivery like any other redemption.
+#[derive(Accounts)]
+#[instruction(product_id: u64, recipient: Pubkey)]
+pub struct GiftProduct<'info> {
+    /// User paying for the gift
+    /// Must have sufficient tickets and sign the transaction
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// Main system state (PDA)
+    /// Used for validation and statistics updates
+    ///
+    /// Seeds: ["redeem"]
+    /// Constraint: System must be active
+    #[account(
+        mut,
+        seeds = [REDEEM_SEED],
+        bump = redeem.bump,
+        constraint = redeem.is_active @ ErrorCode::SystemNotActive
+    )]
+    pub redeem: Account<'info, Redeem>,
+
+    /// Product being gifted (PDA)
+    ///
+    /// Seeds: ["product", product_id]
+    /// Constraints: Product must be in stock
+    /// Availability and expiry are checked in the handler against the clock
+    #[account(
+        mut,
+        seeds = [PRODUCT_SEED, product_id.to_le_bytes().as_ref()],
+        bump = product.bump,
+        constraint = product.remaining_quantity() > 0 @ ErrorCode::ProductOutOfStock
+    )]
+    pub product: Account<'info, Product>,
+
+    /// Payer's ticket account (PDA) - debited for the gift
+    ///
+    /// Seeds: ["user_redeem", payer.key()]
+    /// Constraint: Payer must have sufficient tickets
+    #[account(
+        mut,
+        seeds = [USER_REDEEM_SEED, payer.key().as_ref()],
+        bump = user_redeem_account.bump,
+        constraint = user_redeem_account.can_redeem(product.ticket_cost) @ ErrorCode::InsufficientTickets
+    )]
+    pub user_redeem_account: Account<'info, UserRedeemAccount>,
+
+    /// Ticket token mint the burned tickets are removed from
+    ///
+    /// Constraint: Must match the mint in system state
+    #[account(
+        mut,
+        constraint = ticket_mint.key() == redeem.ticket_mint @ ErrorCode::InvalidProduct
+    )]
+    pub ticket_mint: Account<'info, Mint>,
+
+    /// Payer's SPL token account for tickets
+    ///
+    /// Constraint: Must belong to payer and correct mint
+    #[account(
+        mut,
+        constraint = payer_ticket_token_account.owner == payer.key() @ ErrorCode::Unauthorized,
+        constraint = payer_ticket_token_account.mint == redeem.ticket_mint @ ErrorCode::InvalidProduct
+    )]
+    pub payer_ticket_token_account: Account<'info, TokenAccount>,
+
+    /// Redemption record (PDA) - attributed to the recipient
+    /// Derived from the payer's key and nonce, which are unique per redemption
+    ///
+    /// Seeds: ["redemption", payer.key(), product_id, payer's redemption_nonce]
+    /// Space: RedemptionRecord::LEN
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + RedemptionRecord::LEN,
+        seeds = [
+            REDEMPTION_SEED,
+            payer.key().as_ref(),
+            product_id.to_le_bytes().as_ref(),
+            user_redeem_account.redemption_nonce.to_le_bytes().as_ref()
+        ],
+        bump
+    )]
+    pub redemption_record: Account<'info, RedemptionRecord>,
+
+    /// Recipient's claim counter for this product (PDA)
+    /// Gifts count toward the recipient's per-user limit, not the payer's
+    ///
+    /// Seeds: ["claim", recipient, product_id]
+    /// Constraint: Recipient must be under the product's per-user limit
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = 8 + UserProductClaim::LEN,
+        seeds = [CLAIM_SEED, recipient.as_ref(), product_id.to_le_bytes().as_ref()],
+        bump,
+        constraint = product.allows_claim(recipient_product_claim.redeemed_count) @ ErrorCode::RedemptionLimitReached
+    )]
+    pub recipient_product_claim: Account<'info, UserProductClaim>,
+
+    /// SOL vault that collects product surcharges (PDA)
+    ///
+    /// Seeds: ["sol_vault", redeem.key()]
+    /// Constraint: Must match vault in system state
+    #[account(
+        mut,
+        seeds = [SOL_VAULT_SEED, redeem.key().as_ref()],
+        bump,
+        constraint = sol_vault.key() == redeem.sol_vault @ ErrorCode::InvalidProduct
+    )]
+    pub sol_vault: SystemAccount<'info>,
+
+    /// Required system programs
+    pub system_program: Program<'info, System>,
+    pub token_program: Program<'info, Token>,
+}
+
+/// Gift product instruction handler
+///
+/// # Arguments
+/// * `ctx` - The instruction context containing all accounts
+/// * `product_id` - ID of the product being gifted
+/// * `recipient` - User the redemption is attributed to
+///
+/// # Security Checks
+/// 1. Validates system is active
+/// 2. Ensures product is available, unexpired, and in stock
+/// 3. Verifies payer has sufficient ticket balance
+/// 4. Checks payer owns the token account
+/// 5. Ensures recipient is under the product's per-user limit
+/// 6. Verifies payer has enough SOL for the product surcharge
+///
+/// # State Changes
+/// 1. Collects the product's SOL surcharge (if any) from the payer
+/// 2. Reserves one unit of product inventory
+/// 3. Burns the payer's ticket tokens and updates their balance
+/// 4. Updates the recipient's claim counter
+/// 5. Creates a redemption record with the recipient as user
+/// 6. Updates system statistics
+/// 7. Emits redemption event with the recipient as user
+pub fn handler(ctx: Context<GiftProduct>, product_id: u64, recipient: Pubkey) -> Result<()> {
+    msg!("🎀 Processing product gift");
+    msg!("   Payer: {}", ctx.accounts.payer.key());
+    msg!("   Recipient: {}", recipient);
+    msg!("   Product ID: {}", product_id);
+
+    let redeem = &mut ctx.accounts.redeem;
+    let product = &mut ctx.accounts.product;
+    let user_redeem_account = &mut ctx.accounts.user_redeem_account;
+    let payer = &ctx.accounts.payer;
+    let redemption_record = &mut ctx.accounts.redemption_record;
+
+    let ticket_cost = product.ticket_cost;
+    let clock = Clock::get()?;
+    let current_timestamp = clock.unix_timestamp;
+
+    require!(!product.is_expired(current_timestamp), ErrorCode::ProductExpired);
+    require!(product.is_available(current_timestamp), ErrorCode::ProductNotAvailable);
+
+    // The payer covers the surcharge along with the tickets
+    let sol_surcharge = product.sol_surcharge;
+    if sol_surcharge > 0 {
+        require!(
+            payer.lamports() >= sol_surcharge,
+            ErrorCode::InsufficientSolForSurcharge
+        );
+
+        let transfer_instruction = anchor_lang::system_program::Transfer {
+            from: payer.to_account_info(),
+            to: ctx.accounts.sol_vault.to_account_info(),
+        };
+
+        anchor_lang::system_program::transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                transfer_instruction,
+            ),
+            sol_surcharge,
+        )?;
+
+        msg!("✅ Collected SOL surcharge: {} lamports", sol_surcharge);
+    }
+
+    // Reserve the unit right before burning, as in redeem_product
+    product.reserve_one()?;
+
+    // Burn ticket tokens from the payer's account
+    let burn_instruction = Burn {
+        mint: ctx.accounts.ticket_mint.to_account_info(),
+        from: ctx.accounts.payer_ticket_token_account.to_account_info(),
+        authority: payer.to_account_info(),
+    };
+
+    burn(
+        CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            burn_instruction,
+        ),
+        ticket_cost,
+    )?;
+
+    // The payer's history records the spend and advances their nonce
+    user_redeem_account.redeem_tickets(ticket_cost)?;
+
+    msg!("✅ Burned {} ticket tokens, payer balance: {}", ticket_cost, user_redeem_account.ticket_balance);
+
+    // Count the gift toward the recipient's per-product limit
+    let recipient_product_claim = &mut ctx.accounts.recipient_product_claim;
+    if recipient_product_claim.user == Pubkey::default() {
+        recipient_product_claim.user = recipient;
+        recipient_product_claim.product_id = product_id;
+        recipient_product_claim.bump = ctx.bumps.recipient_product_claim;
+    }
+    recipient_product_claim.redeemed_count = recipient_product_claim.redeemed_count
+        .checked_add(1)
+        .ok_or(ErrorCode::MathOverflow)?;
+
+    // The redemption belongs to the recipient
+    redemption_record.user = recipient;
+    redemption_record.product_id = product_id;
+    redemption_record.tickets_used = ticket_cost;
+    redemption_record.redeemed_at = current_timestamp;
+    redemption_record.redeemed_slot = clock.slot;
+    redemption_record.is_processed = true;
+    redemption_record.is_fulfilled = false;
+    redemption_record.fulfilled_at = 0;
+    redemption_record.delivery_confirmed = false;
+    redemption_record.delivery_confirmed_at = 0;
+    redemption_record.bump = ctx.bumps.redemption_record;
+
+    msg!("✅ Created redemption record: {}", redemption_record.key());
+
+    // Update system statistics
+    redeem.total_tickets_redeemed = redeem.total_tickets_redeemed
+        .checked_add(ticket_cost)
+        .ok_or(ErrorCode::MathOverflow)?;
+
+    emit!(ProductRedeemed {
+        user: recipient,
+        product_id,
+        tickets_used: ticket_cost,
+        timestamp: current_timestamp,
+        redemption_record: redemption_record.key(),
+    });
+
+    msg!("🎉 Product gift completed successfully!");
+
+    Ok(())
+}
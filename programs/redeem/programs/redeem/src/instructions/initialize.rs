@@ -13,6 +13,7 @@ use crate::constants::*;
 /// 
 /// Only the authority can call this instruction, and it can only be called once.
 #[derive(Accounts)]
+#[instruction(sol_per_ticket: u64, max_ticket_supply: u64, decimals: u8)]
 pub struct Initialize<'info> {
     /// The authority that will manage the system
     /// Must sign the transaction to prove ownership
@@ -38,12 +39,12 @@ pub struct Initialize<'info> {
     /// This is the "factory" that creates ticket tokens
     /// 
     /// Authority: redeem PDA (so only the program can mint)
-    /// Decimals: 0 (tickets are whole numbers)
+    /// Decimals: chosen by the authority (0 = whole tickets only)
     /// Freeze authority: None (tickets can always be transferred)
     #[account(
         init,
         payer = authority,
-        mint::decimals = 0,
+        mint::decimals = decimals,
         mint::authority = redeem,
         mint::freeze_authority = redeem
     )]
@@ -74,17 +75,20 @@ pub struct Initialize<'info> {
 /// # Arguments
 /// * `ctx` - The instruction context containing all accounts
 /// * `sol_per_ticket` - Exchange rate in lamports per ticket
+/// * `max_ticket_supply` - Cap on tickets in circulation (0 for unlimited)
+/// * `decimals` - Decimals of the ticket mint (0 for whole tickets, at most 9)
 /// 
 /// # Security Checks
 /// 1. Validates exchange rate is within acceptable bounds
-/// 2. Ensures authority signature
-/// 3. Verifies PDA derivations are correct
+/// 2. Validates ticket decimals are within acceptable bounds
+/// 3. Ensures authority signature
+/// 4. Verifies PDA derivations are correct
 /// 
 /// # State Changes
 /// 1. Initializes Redeem account with configuration
 /// 2. Creates ticket mint with program as authority
 /// 3. Creates SOL vault for payment collection
-pub fn handler(ctx: Context<Initialize>, sol_per_ticket: u64) -> Result<()> {
+pub fn handler(ctx: Context<Initialize>, sol_per_ticket: u64, max_ticket_supply: u64, decimals: u8) -> Result<()> {
     msg!("🏗️ Initializing Redeem System");
     
     // Validate exchange rate is within acceptable bounds
@@ -93,6 +97,12 @@ pub fn handler(ctx: Context<Initialize>, sol_per_ticket: u64) -> Result<()> {
         ErrorCode::InvalidTicketAmount
     );
     
+    // Fractional tickets are allowed down to the same precision as SOL
+    require!(
+        is_valid_ticket_decimals(decimals),
+        ErrorCode::InvalidTicketDecimals
+    );
+    
     // Get account references
     let redeem = &mut ctx.accounts.redeem;
     let authority = &ctx.accounts.authority;
@@ -101,21 +111,29 @@ pub fn handler(ctx: Context<Initialize>, sol_per_ticket: u64) -> Result<()> {
     
     // Initialize the main system state
     redeem.authority = authority.key();
+    redeem.pending_authority = None;
     redeem.ticket_mint = ticket_mint.key();
+    redeem.ticket_decimals = decimals;
     redeem.sol_vault = sol_vault.key();
     redeem.sol_per_ticket = sol_per_ticket;
     redeem.total_tickets_minted = 0;
+    redeem.max_ticket_supply = max_ticket_supply;
     redeem.total_tickets_redeemed = 0;
     redeem.is_active = true;
+    redeem.max_products = 0; // Unlimited until the authority sets a cap
+    redeem.product_count = 0;
+    redeem.discount_tiers = Vec::new(); // No volume discounts until the authority sets them
     redeem.bump = ctx.bumps.redeem;
     
     // Log system initialization
     msg!("✅ System initialized successfully");
     msg!("   Authority: {}", authority.key());
     msg!("   Ticket Mint: {}", ticket_mint.key());
+    msg!("   Ticket decimals: {}", decimals);
     msg!("   SOL Vault: {}", sol_vault.key());
     msg!("   Exchange Rate: {} lamports per ticket", sol_per_ticket);
     msg!("   SOL per ticket: {} SOL", sol_per_ticket as f64 / 1_000_000_000.0);
+    msg!("   Max ticket supply: {} (0 = unlimited)", max_ticket_supply);
     
     Ok(())
 }
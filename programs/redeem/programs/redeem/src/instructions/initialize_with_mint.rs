@@ -0,0 +1,129 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Mint, Token};
+use crate::state::*;
+use crate::constants::*;
+
+/// Initialize the redeem system around an existing ticket mint
+/// 
+/// Same as `initialize`, but for projects that already have a token:
+/// 1. Creates the main system state account (Redeem)
+/// 2. Adopts the existing mint as the ticket mint
+/// 3. Creates the SOL vault for collecting payments
+/// 4. Sets the initial exchange rate and system parameters
+/// 
+/// The redeem PDA must already be the mint's authority, since it signs
+/// every ticket mint. Mints the program can't mint from are rejected.
+#[derive(Accounts)]
+pub struct InitializeWithMint<'info> {
+    /// The authority that will manage the system
+    /// Must sign the transaction to prove ownership
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    /// Main system state account (PDA)
+    /// This holds all global configuration and statistics
+    /// 
+    /// Seeds: ["redeem"]
+    /// Space: Redeem::LEN
+    /// Payer: authority (pays for account creation)
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + Redeem::LEN,
+        seeds = [REDEEM_SEED],
+        bump
+    )]
+    pub redeem: Account<'info, Redeem>,
+
+    /// Existing SPL Token mint used for ticket tokens
+    /// 
+    /// Constraint: Mint authority must be the redeem PDA
+    #[account(
+        constraint = program_can_mint(&ticket_mint.mint_authority, &redeem.key()) @ ErrorCode::TicketMintAuthorityMismatch
+    )]
+    pub ticket_mint: Account<'info, Mint>,
+
+    /// SOL vault (PDA) that collects all payments
+    /// This is where user SOL payments are stored
+    /// 
+    /// Seeds: ["sol_vault", redeem]
+    /// Owner: System Program (regular SOL account)
+    #[account(
+        init,
+        payer = authority,
+        space = 0,
+        seeds = [SOL_VAULT_SEED, redeem.key().as_ref()],
+        bump
+    )]
+    pub sol_vault: SystemAccount<'info>,
+
+    /// Required system programs
+    pub system_program: Program<'info, System>,
+    pub token_program: Program<'info, Token>,
+}
+
+/// Initialize with mint instruction handler
+/// 
+/// # Arguments
+/// * `ctx` - The instruction context containing all accounts
+/// * `sol_per_ticket` - Exchange rate in lamports per ticket
+/// * `max_ticket_supply` - Cap on tickets in circulation (0 for unlimited)
+/// 
+/// # Security Checks
+/// 1. Validates exchange rate is within acceptable bounds
+/// 2. Ensures authority signature
+/// 3. Verifies the redeem PDA is the mint authority
+/// 4. Validates the mint's decimals are within acceptable bounds
+/// 
+/// # State Changes
+/// 1. Initializes Redeem account with configuration and the existing mint
+/// 2. Creates SOL vault for payment collection
+pub fn handler(ctx: Context<InitializeWithMint>, sol_per_ticket: u64, max_ticket_supply: u64) -> Result<()> {
+    msg!("🏗️ Initializing Redeem System with an existing mint");
+    
+    // Validate exchange rate is within acceptable bounds
+    require!(
+        is_valid_sol_per_ticket(sol_per_ticket),
+        ErrorCode::InvalidTicketAmount
+    );
+    
+    // Pricing scales by the mint's decimals, which are capped like in `initialize`
+    require!(
+        is_valid_ticket_decimals(ctx.accounts.ticket_mint.decimals),
+        ErrorCode::InvalidTicketDecimals
+    );
+    
+    // Get account references
+    let redeem = &mut ctx.accounts.redeem;
+    let authority = &ctx.accounts.authority;
+    let ticket_mint = &ctx.accounts.ticket_mint;
+    let sol_vault = &ctx.accounts.sol_vault;
+    
+    // Initialize the main system state
+    redeem.authority = authority.key();
+    redeem.pending_authority = None;
+    redeem.ticket_mint = ticket_mint.key();
+    redeem.ticket_decimals = ticket_mint.decimals;
+    redeem.sol_vault = sol_vault.key();
+    redeem.sol_per_ticket = sol_per_ticket;
+    redeem.total_tickets_minted = 0; // Only tickets minted by this program count toward the cap
+    redeem.max_ticket_supply = max_ticket_supply;
+    redeem.total_tickets_redeemed = 0;
+    redeem.is_active = true;
+    redeem.max_products = 0; // Unlimited until the authority sets a cap
+    redeem.product_count = 0;
+    redeem.discount_tiers = Vec::new(); // No volume discounts until the authority sets them
+    redeem.bump = ctx.bumps.redeem;
+    
+    // Log system initialization
+    msg!("✅ System initialized successfully");
+    msg!("   Authority: {}", authority.key());
+    msg!("   Ticket Mint (existing): {}", ticket_mint.key());
+    msg!("   Existing supply: {}", ticket_mint.supply);
+    msg!("   Ticket decimals: {}", ticket_mint.decimals);
+    msg!("   SOL Vault: {}", sol_vault.key());
+    msg!("   Exchange Rate: {} lamports per ticket", sol_per_ticket);
+    msg!("   Max ticket supply: {} (0 = unlimited)", max_ticket_supply);
+    
+    Ok(())
+}
@@ -0,0 +1,91 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::constants::*;
+
+/// Mark a redemption as fulfilled
+///
+/// This instruction lets the system authority record that a redeemed product has been
+/// shipped or handed over to the user:
+/// 1. Validates the caller is the system authority
+/// 2. Ensures the redemption has not already been fulfilled
+/// 3. Sets the fulfilled flag and timestamp on the redemption record
+///
+/// The recipient can then acknowledge receipt with `confirm_delivery`.
+#[derive(Accounts)]
+pub struct MarkFulfilled<'info> {
+    /// System authority (must match redeem.authority)
+    /// Only this account can mark redemptions as fulfilled
+    pub authority: Signer<'info>,
+
+    /// Main system state (PDA)
+    /// Used to verify authority
+    ///
+    /// Seeds: ["redeem"]
+    /// Constraint: Authority must match
+    #[account(
+        seeds = [REDEEM_SEED],
+        bump = redeem.bump,
+        constraint = redeem.authority == authority.key() @ ErrorCode::Unauthorized
+    )]
+    pub redeem: Account<'info, Redeem>,
+
+    /// Redemption record being fulfilled
+    /// Its PDA seeds include the user's redemption nonce, so the address is
+    /// passed in directly and ownership is checked by Anchor
+    ///
+    /// Constraint: Must not already be fulfilled
+    #[account(
+        mut,
+        constraint = !redemption_record.is_fulfilled @ ErrorCode::AlreadyFulfilled
+    )]
+    pub redemption_record: Account<'info, RedemptionRecord>,
+}
+
+/// Fulfillment event - emitted for off-chain tracking
+#[event]
+pub struct RedemptionFulfilled {
+    /// User who redeemed the product
+    pub user: Pubkey,
+    /// Product that was fulfilled
+    pub product_id: u64,
+    /// Timestamp of fulfillment
+    pub timestamp: i64,
+    /// Address of redemption record
+    pub redemption_record: Pubkey,
+}
+
+/// Mark fulfilled instruction handler
+///
+/// # Arguments
+/// * `ctx` - The instruction context containing all accounts
+///
+/// # Security Checks
+/// 1. Validates caller is the system authority
+/// 2. Ensures the redemption has not already been fulfilled
+///
+/// # State Changes
+/// 1. Sets `is_fulfilled` and `fulfilled_at` on the redemption record
+/// 2. Emits fulfillment event
+pub fn handler(ctx: Context<MarkFulfilled>) -> Result<()> {
+    let redemption_record = &mut ctx.accounts.redemption_record;
+    let current_timestamp = Clock::get()?.unix_timestamp;
+
+    msg!("📦 Marking redemption as fulfilled");
+    msg!("   Record: {}", redemption_record.key());
+    msg!("   User: {}", redemption_record.user);
+    msg!("   Product ID: {}", redemption_record.product_id);
+
+    redemption_record.is_fulfilled = true;
+    redemption_record.fulfilled_at = current_timestamp;
+
+    msg!("✅ Redemption fulfilled at {}", current_timestamp);
+
+    emit!(RedemptionFulfilled {
+        user: redemption_record.user,
+        product_id: redemption_record.product_id,
+        timestamp: current_timestamp,
+        redemption_record: redemption_record.key(),
+    });
+
+    Ok(())
+}
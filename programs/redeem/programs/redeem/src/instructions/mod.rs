@@ -7,9 +7,39 @@ pub mod initialize;
 pub mod purchase_tickets;
 pub mod add_product;
 pub mod redeem_product;
+pub mod mark_fulfilled;
+pub mod confirm_delivery;
+pub mod set_max_products;
+pub mod withdraw_sol;
+pub mod update_exchange_rate;
+pub mod set_product_active;
+pub mod restock_product;
+pub mod refund_tickets;
+pub mod redeem_products;
+pub mod set_discount_tiers;
+pub mod set_system_active;
+pub mod transfer_authority;
+pub mod accept_authority;
+pub mod gift_product;
+pub mod initialize_with_mint;
 
 // Re-export instruction handlers for use in lib.rs
 pub use initialize::*;
 pub use purchase_tickets::*;
 pub use add_product::*;
 pub use redeem_product::*;
+pub use mark_fulfilled::*;
+pub use confirm_delivery::*;
+pub use set_max_products::*;
+pub use withdraw_sol::*;
+pub use update_exchange_rate::*;
+pub use set_product_active::*;
+pub use restock_product::*;
+pub use refund_tickets::*;
+pub use redeem_products::*;
+pub use set_discount_tiers::*;
+pub use set_system_active::*;
+pub use transfer_authority::*;
+pub use accept_authority::*;
+pub use gift_product::*;
+pub use initialize_with_mint::*;
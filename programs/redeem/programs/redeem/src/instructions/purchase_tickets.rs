@@ -91,12 +91,15 @@ pub struct PurchaseTickets<'info> {
 /// # Arguments
 /// * `ctx` - The instruction context containing all accounts
 /// * `ticket_amount` - Number of tickets to purchase
+/// * `nonce` - User's next purchase nonce (0 for a first purchase)
 /// 
 /// # Security Checks
 /// 1. Validates ticket amount is within bounds
 /// 2. Ensures system is active
-/// 3. Verifies user has sufficient SOL
-/// 4. Checks for math overflow in cost calculation
+/// 3. Keeps total minted tickets within the supply cap
+/// 4. Verifies user has sufficient SOL
+/// 5. Checks for math overflow in cost calculation
+/// 6. Rejects replayed purchases whose nonce was already used
 /// 
 /// # Process Flow
 /// 1. Calculate total SOL cost
@@ -104,17 +107,27 @@ pub struct PurchaseTickets<'info> {
 /// 3. Mint ticket tokens to user
 /// 4. Update user account (balance, history, timestamps)
 /// 5. Update system statistics
-pub fn handler(ctx: Context<PurchaseTickets>, ticket_amount: u64) -> Result<()> {
+pub fn handler(ctx: Context<PurchaseTickets>, ticket_amount: u64, nonce: u64) -> Result<()> {
     msg!("🎫 Processing ticket purchase");
     msg!("   User: {}", ctx.accounts.user.key());
     msg!("   Tickets requested: {}", ticket_amount);
     
     // Validate ticket amount
     require!(
-        is_valid_ticket_amount(ticket_amount),
+        is_valid_ticket_amount(ticket_amount, ctx.accounts.redeem.ticket_decimals),
         ErrorCode::InvalidTicketAmount
     );
     
+    // Total supply is capped unless max_ticket_supply is zero
+    require!(
+        ctx.accounts.redeem.can_mint_tickets(ticket_amount),
+        ErrorCode::SupplyCapReached
+    );
+    
+    // Each purchase must carry the account's next nonce, so a replayed
+    // instruction fails instead of buying twice (a new account starts at 0)
+    ctx.accounts.user_redeem_account.consume_purchase_nonce(nonce)?;
+    
     // Get account references
     let redeem = &mut ctx.accounts.redeem;
     let user_redeem_account = &mut ctx.accounts.user_redeem_account;
@@ -185,7 +198,11 @@ pub fn handler(ctx: Context<PurchaseTickets>, ticket_amount: u64) -> Result<()>
         user_redeem_account.total_purchased = 0;
         user_redeem_account.total_redeemed = 0;
         user_redeem_account.products_redeemed = 0;
-        user_redeem_account.created_at = Clock::get()?.unix_timestamp;
+        user_redeem_account.redemption_nonce = 0;
+        // purchase_nonce was already consumed above
+        let current_timestamp = Clock::get()?.unix_timestamp;
+        user_redeem_account.created_at = current_timestamp;
+        user_redeem_account.last_activity = current_timestamp;
         user_redeem_account.is_active = true;
         user_redeem_account.bump = ctx.bumps.user_redeem_account;
         
@@ -1,5 +1,5 @@
 use anchor_lang::prelude::*;
-use anchor_spl::token::{Token, TokenAccount, Burn, burn};
+use anchor_spl::token::{Mint, Token, TokenAccount, Burn, burn};
 use crate::state::*;
 use crate::constants::*;
 
@@ -7,9 +7,9 @@ use crate::constants::*;
 /// 
 /// This instruction allows users to exchange their ticket tokens for real products:
 /// 1. Validates user has sufficient tickets and product is available
-/// 2. Burns ticket tokens from user's account (removes them from circulation)
-/// 3. Updates user's ticket balance and redemption history
-/// 4. Updates product inventory (reduces available quantity)
+/// 2. Reserves one unit of inventory (reduces available quantity)
+/// 3. Burns ticket tokens from user's account (removes them from circulation)
+/// 4. Updates user's ticket balance and redemption history
 /// 5. Creates an immutable redemption record for audit trail
 /// 6. Updates system statistics
 /// 
@@ -39,12 +39,12 @@ pub struct RedeemProduct<'info> {
     /// Contains cost, availability, and inventory information
     /// 
     /// Seeds: ["product", product_id]
-    /// Constraints: Product must be available and in stock
+    /// Constraints: Product must be in stock
+    /// Availability and expiry are checked in the handler against the clock
     #[account(
         mut,
         seeds = [PRODUCT_SEED, product_id.to_le_bytes().as_ref()],
         bump = product.bump,
-        constraint = product.is_available() @ ErrorCode::ProductNotAvailable,
         constraint = product.remaining_quantity() > 0 @ ErrorCode::ProductOutOfStock
     )]
     pub product: Account<'info, Product>,
@@ -62,6 +62,15 @@ pub struct RedeemProduct<'info> {
     )]
     pub user_redeem_account: Account<'info, UserRedeemAccount>,
 
+    /// Ticket token mint the burned tickets are removed from
+    /// 
+    /// Constraint: Must match the mint in system state
+    #[account(
+        mut,
+        constraint = ticket_mint.key() == redeem.ticket_mint @ ErrorCode::InvalidProduct
+    )]
+    pub ticket_mint: Account<'info, Mint>,
+
     /// User's SPL token account for tickets
     /// Contains the actual ticket tokens that will be burned
     /// 
@@ -76,7 +85,7 @@ pub struct RedeemProduct<'info> {
     /// Redemption record (PDA) - creates audit trail
     /// Each redemption gets a unique record for compliance and tracking
     /// 
-    /// Seeds: ["redemption", user.key(), product_id, current_timestamp]
+    /// Seeds: ["redemption", user.key(), product_id, user's redemption_nonce]
     /// Space: RedemptionRecord::LEN
     #[account(
         init,
@@ -86,12 +95,39 @@ pub struct RedeemProduct<'info> {
             REDEMPTION_SEED,
             user.key().as_ref(),
             product_id.to_le_bytes().as_ref(),
-            &Clock::get()?.unix_timestamp.to_le_bytes()
+            user_redeem_account.redemption_nonce.to_le_bytes().as_ref()
         ],
         bump
     )]
     pub redemption_record: Account<'info, RedemptionRecord>,
 
+    /// User's claim counter for this product (PDA)
+    /// Created on the user's first redemption of the product
+    /// 
+    /// Seeds: ["claim", user.key(), product_id]
+    /// Constraint: User must be under the product's per-user limit
+    #[account(
+        init_if_needed,
+        payer = user,
+        space = 8 + UserProductClaim::LEN,
+        seeds = [CLAIM_SEED, user.key().as_ref(), product_id.to_le_bytes().as_ref()],
+        bump,
+        constraint = product.allows_claim(user_product_claim.redeemed_count) @ ErrorCode::RedemptionLimitReached
+    )]
+    pub user_product_claim: Account<'info, UserProductClaim>,
+
+    /// SOL vault that collects product surcharges (PDA)
+    /// 
+    /// Seeds: ["sol_vault", redeem.key()]
+    /// Constraint: Must match vault in system state
+    #[account(
+        mut,
+        seeds = [SOL_VAULT_SEED, redeem.key().as_ref()],
+        bump,
+        constraint = sol_vault.key() == redeem.sol_vault @ ErrorCode::InvalidProduct
+    )]
+    pub sol_vault: SystemAccount<'info>,
+
     /// Required system programs
     pub system_program: Program<'info, System>,
     pub token_program: Program<'info, Token>,
@@ -123,18 +159,20 @@ pub struct ProductRedeemed {
 /// 
 /// # Security Checks
 /// 1. Validates system is active
-/// 2. Ensures product is available and in stock
+/// 2. Ensures product is available, unexpired, and in stock
 /// 3. Verifies user has sufficient ticket balance
 /// 4. Checks user owns the token account
 /// 5. Validates all PDAs are correctly derived
+/// 6. Verifies user has enough SOL for the product surcharge
 /// 
 /// # Process Flow
-/// 1. Burn ticket tokens from user's account
-/// 2. Update user's ticket balance and statistics
-/// 3. Update product inventory
-/// 4. Create redemption record for audit
-/// 5. Update system statistics
-/// 6. Emit redemption event
+/// 1. Collect the product's SOL surcharge (if any) into the vault
+/// 2. Re-check stock and reserve one unit of inventory
+/// 3. Burn ticket tokens from user's account
+/// 4. Update user's ticket balance and statistics
+/// 5. Create redemption record for audit
+/// 6. Update system statistics
+/// 7. Emit redemption event
 pub fn handler(ctx: Context<RedeemProduct>, product_id: u64) -> Result<()> {
     msg!("🎁 Processing product redemption");
     msg!("   User: {}", ctx.accounts.user.key());
@@ -149,17 +187,55 @@ pub fn handler(ctx: Context<RedeemProduct>, product_id: u64) -> Result<()> {
     let redemption_record = &mut ctx.accounts.redemption_record;
     
     let ticket_cost = product.ticket_cost;
-    let current_timestamp = Clock::get()?.unix_timestamp;
+    let clock = Clock::get()?;
+    let current_timestamp = clock.unix_timestamp;
+    
+    // Limited-time products can't be redeemed once they expire
+    require!(!product.is_expired(current_timestamp), ErrorCode::ProductExpired);
+    require!(product.is_available(current_timestamp), ErrorCode::ProductNotAvailable);
     
     msg!("   Product: {}", product.name);
     msg!("   Ticket Cost: {}", ticket_cost);
     msg!("   User Balance: {}", user_redeem_account.ticket_balance);
     msg!("   Remaining Stock: {}", product.remaining_quantity());
     
+    // Collect the SOL surcharge for products with fulfillment costs
+    let sol_surcharge = product.sol_surcharge;
+    if sol_surcharge > 0 {
+        require!(
+            user.lamports() >= sol_surcharge,
+            ErrorCode::InsufficientSolForSurcharge
+        );
+        
+        let transfer_instruction = anchor_lang::system_program::Transfer {
+            from: user.to_account_info(),
+            to: ctx.accounts.sol_vault.to_account_info(),
+        };
+        
+        anchor_lang::system_program::transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                transfer_instruction,
+            ),
+            sol_surcharge,
+        )?;
+        
+        msg!("✅ Collected SOL surcharge: {} lamports", sol_surcharge);
+    }
+    
+    // Reserve the unit immediately before burning: the stock is re-checked
+    // against the current product state rather than trusted from account
+    // validation, and if the burn fails the reservation is rolled back with it
+    product.reserve_one()?;
+    
+    msg!("✅ Reserved product inventory:");
+    msg!("   Redeemed: {}/{}", product.redeemed_quantity, product.total_quantity);
+    msg!("   Remaining: {}", product.remaining_quantity());
+    
     // Burn ticket tokens from user's account
     // This permanently removes tokens from circulation
     let burn_instruction = Burn {
-        mint: redeem.to_account_info(), // ticket_mint is owned by redeem
+        mint: ctx.accounts.ticket_mint.to_account_info(),
         from: user_ticket_token_account.to_account_info(),
         authority: user.to_account_info(),
     };
@@ -183,23 +259,31 @@ pub fn handler(ctx: Context<RedeemProduct>, product_id: u64) -> Result<()> {
     msg!("   Total redeemed: {}", user_redeem_account.total_redeemed);
     msg!("   Products redeemed: {}", user_redeem_account.products_redeemed);
     
-    // Update product inventory
-    product.redeemed_quantity = product.redeemed_quantity
+    // Count the redemption toward the user's per-product limit
+    let user_product_claim = &mut ctx.accounts.user_product_claim;
+    if user_product_claim.user == Pubkey::default() {
+        user_product_claim.user = user.key();
+        user_product_claim.product_id = product_id;
+        user_product_claim.bump = ctx.bumps.user_product_claim;
+    }
+    user_product_claim.redeemed_count = user_product_claim.redeemed_count
         .checked_add(1)
         .ok_or(ErrorCode::MathOverflow)?;
     
-    msg!("✅ Updated product inventory:");
-    msg!("   Redeemed: {}/{}", product.redeemed_quantity, product.total_quantity);
-    msg!("   Remaining: {}", product.remaining_quantity());
-    msg!("   Still available: {}", product.is_available());
+    msg!("✅ User has redeemed this product {} time(s)", user_product_claim.redeemed_count);
+    msg!("   Product still available: {}", product.is_available(current_timestamp));
     
     // Create redemption record for audit trail
     redemption_record.user = user.key();
     redemption_record.product_id = product_id;
     redemption_record.tickets_used = ticket_cost;
     redemption_record.redeemed_at = current_timestamp;
-    redemption_record.transaction_signature = [0u8; 64]; // Placeholder for tx sig
+    redemption_record.redeemed_slot = clock.slot;
     redemption_record.is_processed = true;
+    redemption_record.is_fulfilled = false; // Set later by mark_fulfilled
+    redemption_record.fulfilled_at = 0;
+    redemption_record.delivery_confirmed = false; // Set later by confirm_delivery
+    redemption_record.delivery_confirmed_at = 0;
     redemption_record.bump = ctx.bumps.redemption_record;
     
     msg!("✅ Created redemption record: {}", redemption_record.key());
@@ -209,11 +293,11 @@ pub fn handler(ctx: Context<RedeemProduct>, product_id: u64) -> Result<()> {
         .checked_add(ticket_cost)
         .ok_or(ErrorCode::MathOverflow)?;
     
+    let stats = system_stats(redeem);
     msg!("📊 Updated system statistics:");
-    msg!("   Total minted: {}", redeem.total_tickets_minted);
-    msg!("   Total redeemed: {}", redeem.total_tickets_redeemed);
-    msg!("   Tickets in circulation: {}", 
-         redeem.total_tickets_minted - redeem.total_tickets_redeemed);
+    msg!("   Total minted: {}", stats.total_minted);
+    msg!("   Total redeemed: {}", stats.total_redeemed);
+    msg!("   Tickets in circulation: {}", stats.circulating_supply);
     
     // Emit redemption event for off-chain tracking
     emit!(ProductRedeemed {
@@ -0,0 +1,422 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{
+    allocate, assign, create_account, transfer, Allocate, Assign, CreateAccount, Transfer,
+};
+use anchor_spl::token::{Mint, Token, TokenAccount, Burn, burn};
+use crate::state::*;
+use crate::constants::*;
+use crate::instructions::redeem_product::ProductRedeemed;
+
+/// Redeem tickets for several products at once
+/// 
+/// Works like `redeem_product` for each product in the batch, but burns the
+/// combined ticket cost once. Because the number of products varies, each
+/// product's accounts are passed as remaining accounts, three per product
+/// in `product_ids` order:
+/// 1. Product (PDA, writable)
+/// 2. Redemption record (PDA, writable, created here)
+/// 3. User's product claim counter (PDA, writable, created if needed)
+/// 
+/// Every product, limit, and the user's total balance is checked before
+/// anything changes, so the batch succeeds or fails as a whole.
+#[derive(Accounts)]
+pub struct RedeemProducts<'info> {
+    /// User redeeming the products
+    /// Must have sufficient tickets for the whole batch and sign the transaction
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    /// Main system state (PDA)
+    /// Used for validation and statistics updates
+    /// 
+    /// Seeds: ["redeem"]
+    /// Constraint: System must be active
+    #[account(
+        mut,
+        seeds = [REDEEM_SEED],
+        bump = redeem.bump,
+        constraint = redeem.is_active @ ErrorCode::SystemNotActive
+    )]
+    pub redeem: Account<'info, Redeem>,
+
+    /// User's ticket account (PDA) - tracks balance and history
+    /// 
+    /// Seeds: ["user_redeem", user.key()]
+    #[account(
+        mut,
+        seeds = [USER_REDEEM_SEED, user.key().as_ref()],
+        bump = user_redeem_account.bump
+    )]
+    pub user_redeem_account: Account<'info, UserRedeemAccount>,
+
+    /// Ticket token mint the burned tickets are removed from
+    /// 
+    /// Constraint: Must match the mint in system state
+    #[account(
+        mut,
+        constraint = ticket_mint.key() == redeem.ticket_mint @ ErrorCode::InvalidProduct
+    )]
+    pub ticket_mint: Account<'info, Mint>,
+
+    /// User's SPL token account for tickets
+    /// 
+    /// Constraint: Must belong to user and correct mint
+    #[account(
+        mut,
+        constraint = user_ticket_token_account.owner == user.key() @ ErrorCode::Unauthorized,
+        constraint = user_ticket_token_account.mint == redeem.ticket_mint @ ErrorCode::InvalidProduct
+    )]
+    pub user_ticket_token_account: Account<'info, TokenAccount>,
+
+    /// SOL vault that collects product surcharges (PDA)
+    /// 
+    /// Seeds: ["sol_vault", redeem.key()]
+    /// Constraint: Must match vault in system state
+    #[account(
+        mut,
+        seeds = [SOL_VAULT_SEED, redeem.key().as_ref()],
+        bump,
+        constraint = sol_vault.key() == redeem.sol_vault @ ErrorCode::InvalidProduct
+    )]
+    pub sol_vault: SystemAccount<'info>,
+
+    /// Required system programs
+    pub system_program: Program<'info, System>,
+    pub token_program: Program<'info, Token>,
+}
+
+/// A product in the batch after validation
+struct BatchItem<'info> {
+    product: Account<'info, Product>,
+    record_info: &'info AccountInfo<'info>,
+    record_bump: u8,
+    claim_info: &'info AccountInfo<'info>,
+    claim_bump: u8,
+}
+
+/// Redeem products instruction handler
+/// 
+/// # Arguments
+/// * `ctx` - The instruction context, with three remaining accounts per product
+/// * `product_ids` - IDs of the products being redeemed (no duplicates)
+/// 
+/// # Security Checks
+/// 1. Validates the batch size and that accounts match `product_ids`
+/// 2. Validates every product, record, and claim PDA is correctly derived
+/// 3. Ensures every product is available, unexpired, in stock, and under its per-user limit
+/// 4. Verifies the user's balance covers the combined ticket cost
+/// 5. Verifies the user has enough SOL for the combined surcharge
+/// 
+/// # Process Flow
+/// 1. Collect the combined SOL surcharge into the vault
+/// 2. Re-check stock and reserve one unit of every product
+/// 3. Burn the combined ticket cost once
+/// 4. For each product: update the user's claim and balance,
+///    create the redemption record, and emit an event
+/// 5. Update system statistics
+pub fn handler<'info>(
+    ctx: Context<'_, '_, 'info, 'info, RedeemProducts<'info>>,
+    product_ids: Vec<u64>,
+) -> Result<()> {
+    msg!("🎁 Processing bulk redemption");
+    msg!("   User: {}", ctx.accounts.user.key());
+    msg!("   Products: {:?}", product_ids);
+
+    require!(
+        !product_ids.is_empty() && product_ids.len() <= MAX_BULK_REDEMPTION,
+        ErrorCode::InvalidBatch
+    );
+    require!(
+        ctx.remaining_accounts.len() == product_ids.len() * 3,
+        ErrorCode::InvalidBatch
+    );
+
+    let user_key = ctx.accounts.user.key();
+    let clock = Clock::get()?;
+    let current_timestamp = clock.unix_timestamp;
+    let slot = clock.slot;
+    let first_nonce = ctx.accounts.user_redeem_account.redemption_nonce;
+
+    // Validate the whole batch before changing anything
+    let mut items: Vec<BatchItem<'info>> = Vec::with_capacity(product_ids.len());
+    let mut total_tickets: u64 = 0;
+    let mut total_surcharge: u64 = 0;
+
+    for (i, (product_id, accounts)) in product_ids
+        .iter()
+        .zip(ctx.remaining_accounts.chunks(3))
+        .enumerate()
+    {
+        require!(
+            !product_ids[..i].contains(product_id),
+            ErrorCode::InvalidBatch
+        );
+
+        let product = Account::<Product>::try_from(&accounts[0])?;
+        let (product_pda, _) = Pubkey::find_program_address(
+            &[PRODUCT_SEED, product_id.to_le_bytes().as_ref()],
+            &crate::ID,
+        );
+        require_keys_eq!(product.key(), product_pda, ErrorCode::InvalidProduct);
+        require!(!product.is_expired(current_timestamp), ErrorCode::ProductExpired);
+        require!(product.is_available(current_timestamp), ErrorCode::ProductNotAvailable);
+        require!(product.remaining_quantity() > 0, ErrorCode::ProductOutOfStock);
+
+        // Each record takes the next redemption nonce
+        let nonce = first_nonce
+            .checked_add(i as u64)
+            .ok_or(ErrorCode::MathOverflow)?;
+        let (record_pda, record_bump) = Pubkey::find_program_address(
+            &[
+                REDEMPTION_SEED,
+                user_key.as_ref(),
+                product_id.to_le_bytes().as_ref(),
+                nonce.to_le_bytes().as_ref(),
+            ],
+            &crate::ID,
+        );
+        require_keys_eq!(accounts[1].key(), record_pda, ErrorCode::InvalidBatch);
+
+        let (claim_pda, claim_bump) = Pubkey::find_program_address(
+            &[CLAIM_SEED, user_key.as_ref(), product_id.to_le_bytes().as_ref()],
+            &crate::ID,
+        );
+        require_keys_eq!(accounts[2].key(), claim_pda, ErrorCode::InvalidBatch);
+
+        let claimed = if accounts[2].data_is_empty() {
+            0
+        } else {
+            Account::<UserProductClaim>::try_from(&accounts[2])?.redeemed_count
+        };
+        require!(product.allows_claim(claimed), ErrorCode::RedemptionLimitReached);
+
+        total_tickets = total_tickets
+            .checked_add(product.ticket_cost)
+            .ok_or(ErrorCode::MathOverflow)?;
+        total_surcharge = total_surcharge
+            .checked_add(product.sol_surcharge)
+            .ok_or(ErrorCode::MathOverflow)?;
+
+        items.push(BatchItem {
+            product,
+            record_info: &accounts[1],
+            record_bump,
+            claim_info: &accounts[2],
+            claim_bump,
+        });
+    }
+
+    require!(
+        ctx.accounts.user_redeem_account.can_redeem(total_tickets),
+        ErrorCode::InsufficientTickets
+    );
+
+    msg!("   Total Ticket Cost: {}", total_tickets);
+    msg!("   Total SOL Surcharge: {} lamports", total_surcharge);
+
+    // Collect the combined SOL surcharge
+    if total_surcharge > 0 {
+        require!(
+            ctx.accounts.user.lamports() >= total_surcharge,
+            ErrorCode::InsufficientSolForSurcharge
+        );
+
+        anchor_lang::system_program::transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                anchor_lang::system_program::Transfer {
+                    from: ctx.accounts.user.to_account_info(),
+                    to: ctx.accounts.sol_vault.to_account_info(),
+                },
+            ),
+            total_surcharge,
+        )?;
+
+        msg!("✅ Collected SOL surcharge: {} lamports", total_surcharge);
+    }
+
+    // Reserve a unit of every product right before the burn, as in redeem_product,
+    // so the stock is re-checked against the current product state
+    for item in items.iter_mut() {
+        item.product.reserve_one()?;
+        item.product.exit(&crate::ID)?;
+    }
+
+    // Burn the whole batch's tickets in one go
+    burn(
+        CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            Burn {
+                mint: ctx.accounts.ticket_mint.to_account_info(),
+                from: ctx.accounts.user_ticket_token_account.to_account_info(),
+                authority: ctx.accounts.user.to_account_info(),
+            },
+        ),
+        total_tickets,
+    )?;
+
+    msg!("✅ Burned {} ticket tokens", total_tickets);
+
+    for (product_id, item) in product_ids.into_iter().zip(items) {
+        let ticket_cost = item.product.ticket_cost;
+        let nonce = ctx.accounts.user_redeem_account.redemption_nonce;
+
+        // Count the redemption toward the user's per-product limit
+        if item.claim_info.data_is_empty() {
+            create_pda_account(
+                &ctx,
+                item.claim_info,
+                8 + UserProductClaim::LEN,
+                &[
+                    CLAIM_SEED,
+                    user_key.as_ref(),
+                    product_id.to_le_bytes().as_ref(),
+                    &[item.claim_bump],
+                ],
+            )?;
+            write_account(item.claim_info, &UserProductClaim {
+                user: user_key,
+                product_id,
+                redeemed_count: 1,
+                bump: item.claim_bump,
+            })?;
+        } else {
+            let mut claim = Account::<UserProductClaim>::try_from(item.claim_info)?;
+            claim.redeemed_count = claim.redeemed_count
+                .checked_add(1)
+                .ok_or(ErrorCode::MathOverflow)?;
+            claim.exit(&crate::ID)?;
+        }
+
+        // Update user's balance, history, and redemption nonce
+        ctx.accounts.user_redeem_account.redeem_tickets(ticket_cost)?;
+
+        // Create redemption record for audit trail
+        create_pda_account(
+            &ctx,
+            item.record_info,
+            8 + RedemptionRecord::LEN,
+            &[
+                REDEMPTION_SEED,
+                user_key.as_ref(),
+                product_id.to_le_bytes().as_ref(),
+                nonce.to_le_bytes().as_ref(),
+                &[item.record_bump],
+            ],
+        )?;
+        write_account(item.record_info, &RedemptionRecord {
+            user: user_key,
+            product_id,
+            tickets_used: ticket_cost,
+            redeemed_at: current_timestamp,
+            redeemed_slot: slot,
+            is_processed: true,
+            is_fulfilled: false,
+            fulfilled_at: 0,
+            delivery_confirmed: false,
+            delivery_confirmed_at: 0,
+            bump: item.record_bump,
+        })?;
+
+        msg!("✅ Redeemed {} for {} tickets", item.product.name, ticket_cost);
+
+        emit!(ProductRedeemed {
+            user: user_key,
+            product_id,
+            tickets_used: ticket_cost,
+            timestamp: current_timestamp,
+            redemption_record: item.record_info.key(),
+        });
+    }
+
+    // Update system statistics
+    let redeem = &mut ctx.accounts.redeem;
+    redeem.total_tickets_redeemed = redeem.total_tickets_redeemed
+        .checked_add(total_tickets)
+        .ok_or(ErrorCode::MathOverflow)?;
+
+    msg!("📊 Updated system statistics:");
+    msg!("   Total minted: {}", redeem.total_tickets_minted);
+    msg!("   Total redeemed: {}", redeem.total_tickets_redeemed);
+
+    msg!("🎉 Bulk redemption completed successfully!");
+
+    Ok(())
+}
+
+/// Create a program-owned PDA account, paid for by the user
+/// 
+/// Record addresses are predictable, so anyone can send lamports to one
+/// before it exists. `create_account` refuses funded addresses, so those are
+/// topped up to rent exemption, allocated, and assigned instead, the same
+/// way Anchor's `init` handles them.
+fn create_pda_account<'info>(
+    ctx: &Context<'_, '_, 'info, 'info, RedeemProducts<'info>>,
+    account: &AccountInfo<'info>,
+    space: usize,
+    seeds: &[&[u8]],
+) -> Result<()> {
+    let system_program = ctx.accounts.system_program.to_account_info();
+    let rent_exempt_balance = Rent::get()?.minimum_balance(space);
+    let current_balance = account.lamports();
+
+    if current_balance == 0 {
+        return create_account(
+            CpiContext::new_with_signer(
+                system_program,
+                CreateAccount {
+                    from: ctx.accounts.user.to_account_info(),
+                    to: account.clone(),
+                },
+                &[seeds],
+            ),
+            rent_exempt_balance,
+            space as u64,
+            &crate::ID,
+        );
+    }
+
+    // Pre-funded address: the user only covers what's missing for rent
+    let top_up = rent_exempt_balance.saturating_sub(current_balance);
+    if top_up > 0 {
+        transfer(
+            CpiContext::new(
+                system_program.clone(),
+                Transfer {
+                    from: ctx.accounts.user.to_account_info(),
+                    to: account.clone(),
+                },
+            ),
+            top_up,
+        )?;
+    }
+
+    allocate(
+        CpiContext::new_with_signer(
+            system_program.clone(),
+            Allocate {
+                account_to_allocate: account.clone(),
+            },
+            &[seeds],
+        ),
+        space as u64,
+    )?;
+
+    assign(
+        CpiContext::new_with_signer(
+            system_program,
+            Assign {
+                account_to_assign: account.clone(),
+            },
+            &[seeds],
+        ),
+        &crate::ID,
+    )
+}
+
+/// Write an account's discriminator and data into a freshly created account
+fn write_account<T: AccountSerialize>(account: &AccountInfo, value: &T) -> Result<()> {
+    let mut data = account.try_borrow_mut_data()?;
+    let mut writer: &mut [u8] = &mut data;
+    value.try_serialize(&mut writer)
+}
@@ -0,0 +1,174 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{transfer, Transfer};
+use anchor_spl::token::{Mint, Token, TokenAccount, Burn, burn};
+use crate::state::*;
+use crate::constants::*;
+
+/// Sell unused tickets back for SOL
+/// 
+/// This instruction lets users return tickets they no longer need:
+/// 1. Validates the user holds enough tickets
+/// 2. Burns the refunded ticket tokens from the user's account
+/// 3. Pays the current exchange rate, minus the refund fee, from the vault
+/// 4. Updates the user's ticket balance and system statistics
+#[derive(Accounts)]
+pub struct RefundTickets<'info> {
+    /// User refunding tickets
+    /// Must sign and receives the SOL
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    /// Main system state (PDA)
+    /// Contains exchange rate and system statistics
+    /// 
+    /// Seeds: ["redeem"]
+    /// Constraint: System must be active
+    #[account(
+        mut,
+        seeds = [REDEEM_SEED],
+        bump = redeem.bump,
+        constraint = redeem.is_active @ ErrorCode::SystemNotActive
+    )]
+    pub redeem: Account<'info, Redeem>,
+
+    /// User's ticket account (PDA) - tracks balance and history
+    /// 
+    /// Seeds: ["user_redeem", user.key()]
+    #[account(
+        mut,
+        seeds = [USER_REDEEM_SEED, user.key().as_ref()],
+        bump = user_redeem_account.bump
+    )]
+    pub user_redeem_account: Account<'info, UserRedeemAccount>,
+
+    /// Ticket token mint the refunded tickets are burned from
+    /// 
+    /// Constraint: Must match the mint in system state
+    #[account(
+        mut,
+        constraint = ticket_mint.key() == redeem.ticket_mint @ ErrorCode::InvalidProduct
+    )]
+    pub ticket_mint: Account<'info, Mint>,
+
+    /// User's SPL token account for tickets
+    /// 
+    /// Constraint: Must belong to user and correct mint
+    #[account(
+        mut,
+        constraint = user_ticket_token_account.owner == user.key() @ ErrorCode::Unauthorized,
+        constraint = user_ticket_token_account.mint == redeem.ticket_mint @ ErrorCode::InvalidProduct
+    )]
+    pub user_ticket_token_account: Account<'info, TokenAccount>,
+
+    /// SOL vault that pays the refund (PDA)
+    /// 
+    /// Seeds: ["sol_vault", redeem.key()]
+    /// Constraint: Must match vault in system state
+    #[account(
+        mut,
+        seeds = [SOL_VAULT_SEED, redeem.key().as_ref()],
+        bump,
+        constraint = sol_vault.key() == redeem.sol_vault @ ErrorCode::InvalidProduct
+    )]
+    pub sol_vault: SystemAccount<'info>,
+
+    /// Required system programs
+    pub system_program: Program<'info, System>,
+    pub token_program: Program<'info, Token>,
+}
+
+/// Refund tickets instruction handler
+/// 
+/// # Arguments
+/// * `ctx` - The instruction context containing all accounts
+/// * `ticket_amount` - Number of tickets to refund
+/// 
+/// # Security Checks
+/// 1. Validates the user holds at least `ticket_amount` tickets
+/// 2. Checks for math overflow in the refund calculation
+/// 3. Keeps the vault rent exempt after paying the refund
+/// 
+/// # Process Flow
+/// 1. Burn ticket tokens from user's account
+/// 2. Transfer the refund from the vault to the user
+/// 3. Update user's ticket balance
+/// 4. Update system statistics
+pub fn handler(ctx: Context<RefundTickets>, ticket_amount: u64) -> Result<()> {
+    msg!("↩️ Processing ticket refund");
+    msg!("   User: {}", ctx.accounts.user.key());
+    msg!("   Tickets: {}", ticket_amount);
+
+    require!(ticket_amount > 0, ErrorCode::InvalidTicketAmount);
+    require!(
+        has_sufficient_tickets(ctx.accounts.user_redeem_account.ticket_balance, ticket_amount),
+        ErrorCode::InsufficientTickets
+    );
+
+    let refund_amount = calculate_refund_amount(
+        ticket_amount,
+        ctx.accounts.redeem.sol_per_ticket,
+        ctx.accounts.redeem.ticket_decimals,
+    )
+    .ok_or(ErrorCode::MathOverflow)?;
+
+    // The vault must stay rent exempt so it keeps existing for future payments
+    require!(
+        keeps_vault_rent_exempt(ctx.accounts.sol_vault.lamports(), refund_amount, min_vault_balance()?),
+        ErrorCode::WouldBreakRentExemption
+    );
+
+    // Burn the refunded tickets so they can't be spent again
+    burn(
+        CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            Burn {
+                mint: ctx.accounts.ticket_mint.to_account_info(),
+                from: ctx.accounts.user_ticket_token_account.to_account_info(),
+                authority: ctx.accounts.user.to_account_info(),
+            },
+        ),
+        ticket_amount,
+    )?;
+
+    msg!("✅ Burned {} ticket tokens", ticket_amount);
+
+    // Pay the refund from the vault PDA
+    let redeem_key = ctx.accounts.redeem.key();
+    let signer_seeds: &[&[&[u8]]] = &[&[
+        SOL_VAULT_SEED,
+        redeem_key.as_ref(),
+        &[ctx.bumps.sol_vault],
+    ]];
+
+    transfer(
+        CpiContext::new_with_signer(
+            ctx.accounts.system_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.sol_vault.to_account_info(),
+                to: ctx.accounts.user.to_account_info(),
+            },
+            signer_seeds,
+        ),
+        refund_amount,
+    )?;
+
+    msg!("✅ Refunded {} lamports (fee {} bps)", refund_amount, REFUND_FEE_BPS);
+
+    // Update user's ticket account
+    let user_redeem_account = &mut ctx.accounts.user_redeem_account;
+    user_redeem_account.refund_tickets(ticket_amount)?;
+
+    msg!("   New balance: {}", user_redeem_account.ticket_balance);
+
+    // Refunded tickets no longer count as minted
+    let redeem = &mut ctx.accounts.redeem;
+    redeem.total_tickets_minted = redeem.total_tickets_minted
+        .checked_sub(ticket_amount)
+        .ok_or(ErrorCode::MathOverflow)?;
+
+    msg!("📊 Updated system statistics:");
+    msg!("   Total minted: {}", redeem.total_tickets_minted);
+    msg!("   Total redeemed: {}", redeem.total_tickets_redeemed);
+
+    Ok(())
+}
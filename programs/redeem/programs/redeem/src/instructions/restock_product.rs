@@ -0,0 +1,76 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::constants::*;
+
+/// Add inventory to an existing product
+///
+/// This instruction lets the system authority restock the catalog:
+/// 1. Validates the caller is the system authority
+/// 2. Adds the new units to the product's total quantity
+/// 3. Enforces the maximum product quantity
+#[derive(Accounts)]
+#[instruction(product_id: u64)]
+pub struct RestockProduct<'info> {
+    /// System authority (must match redeem.authority)
+    /// May differ from product.authority after an authority transfer
+    pub authority: Signer<'info>,
+
+    /// Main system state (PDA)
+    /// Used to verify the authority
+    ///
+    /// Seeds: ["redeem"]
+    /// Constraint: Authority must match
+    #[account(
+        seeds = [REDEEM_SEED],
+        bump = redeem.bump,
+        constraint = redeem.authority == authority.key() @ ErrorCode::Unauthorized
+    )]
+    pub redeem: Account<'info, Redeem>,
+
+    /// Product being restocked (PDA)
+    ///
+    /// Seeds: ["product", product_id]
+    #[account(
+        mut,
+        seeds = [PRODUCT_SEED, product_id.to_le_bytes().as_ref()],
+        bump = product.bump
+    )]
+    pub product: Account<'info, Product>,
+}
+
+/// Restock product instruction handler
+///
+/// # Arguments
+/// * `ctx` - The instruction context containing all accounts
+/// * `product_id` - ID of the product to restock
+/// * `additional_quantity` - Units to add to the inventory
+///
+/// # Security Checks
+/// 1. Validates caller is the system authority
+/// 2. Keeps the total quantity within `MAX_PRODUCT_QUANTITY`
+///
+/// # State Changes
+/// 1. Increases `total_quantity` on the product
+pub fn handler(ctx: Context<RestockProduct>, product_id: u64, additional_quantity: u32) -> Result<()> {
+    let product = &mut ctx.accounts.product;
+
+    require!(additional_quantity > 0, ErrorCode::InvalidProduct);
+
+    let new_total = product.total_quantity
+        .checked_add(additional_quantity)
+        .ok_or(ErrorCode::MathOverflow)?;
+    require!(new_total <= MAX_PRODUCT_QUANTITY, ErrorCode::ProductQuantityExceeded);
+
+    msg!("📦 Restocking product");
+    msg!("   Product ID: {}", product_id);
+    msg!("   Previous Total: {}", product.total_quantity);
+    msg!("   Adding: {}", additional_quantity);
+
+    product.total_quantity = new_total;
+
+    msg!("✅ Product restocked");
+    msg!("   Redeemed: {}/{}", product.redeemed_quantity, product.total_quantity);
+    msg!("   Remaining: {}", product.remaining_quantity());
+
+    Ok(())
+}
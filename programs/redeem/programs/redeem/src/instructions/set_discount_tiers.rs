@@ -0,0 +1,67 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::constants::*;
+
+/// Replace the volume discount table
+///
+/// This instruction lets the system authority reward larger purchases:
+/// 1. Validates the caller is the system authority
+/// 2. Validates the number of tiers and each tier's discount
+/// 3. Replaces the discount tiers on the system state
+///
+/// An empty list removes all discounts. When a purchase qualifies for
+/// several tiers, the largest discount applies.
+#[derive(Accounts)]
+pub struct SetDiscountTiers<'info> {
+    /// System authority (must match redeem.authority)
+    /// Only this account can change the discount tiers
+    pub authority: Signer<'info>,
+
+    /// Main system state (PDA)
+    /// Holds the discount tiers
+    ///
+    /// Seeds: ["redeem"]
+    /// Constraint: Authority must match
+    #[account(
+        mut,
+        seeds = [REDEEM_SEED],
+        bump = redeem.bump,
+        constraint = redeem.authority == authority.key() @ ErrorCode::Unauthorized
+    )]
+    pub redeem: Account<'info, Redeem>,
+}
+
+/// Set discount tiers instruction handler
+///
+/// # Arguments
+/// * `ctx` - The instruction context containing all accounts
+/// * `tiers` - New discount tiers (empty for none)
+///
+/// # Security Checks
+/// 1. Validates caller is the system authority
+/// 2. Allows at most `MAX_DISCOUNT_TIERS` tiers
+/// 3. Requires positive thresholds and discounts up to `MAX_DISCOUNT_BPS`
+///
+/// # State Changes
+/// 1. Replaces `discount_tiers` on the system state
+pub fn handler(ctx: Context<SetDiscountTiers>, tiers: Vec<DiscountTier>) -> Result<()> {
+    require!(tiers.len() <= MAX_DISCOUNT_TIERS, ErrorCode::InvalidDiscountTiers);
+    require!(
+        tiers.iter().all(|tier| tier.threshold > 0 && tier.discount_bps <= MAX_DISCOUNT_BPS),
+        ErrorCode::InvalidDiscountTiers
+    );
+
+    let redeem = &mut ctx.accounts.redeem;
+
+    msg!("⚙️ Updating discount tiers");
+    msg!("   Previous Tiers: {}", redeem.discount_tiers.len());
+    for tier in tiers.iter() {
+        msg!("   {}+ tickets: {} bps off", tier.threshold, tier.discount_bps);
+    }
+
+    redeem.discount_tiers = tiers;
+
+    msg!("✅ Discount tiers updated");
+
+    Ok(())
+}
@@ -0,0 +1,60 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::constants::*;
+
+/// Adjust the maximum catalog size
+///
+/// This instruction lets the system authority change how many products
+/// can be added to the catalog:
+/// 1. Validates the caller is the system authority
+/// 2. Updates the product cap on the system state
+///
+/// A cap of zero means the catalog is unlimited. Only active products count
+/// toward the cap. Lowering the cap below the current count does not
+/// deactivate products, it only blocks new or reactivated ones.
+#[derive(Accounts)]
+pub struct SetMaxProducts<'info> {
+    /// System authority (must match redeem.authority)
+    /// Only this account can change the catalog cap
+    pub authority: Signer<'info>,
+
+    /// Main system state (PDA)
+    /// Holds the catalog cap and product counter
+    ///
+    /// Seeds: ["redeem"]
+    /// Constraint: Authority must match
+    #[account(
+        mut,
+        seeds = [REDEEM_SEED],
+        bump = redeem.bump,
+        constraint = redeem.authority == authority.key() @ ErrorCode::Unauthorized
+    )]
+    pub redeem: Account<'info, Redeem>,
+}
+
+/// Set max products instruction handler
+///
+/// # Arguments
+/// * `ctx` - The instruction context containing all accounts
+/// * `max_products` - New catalog cap (0 for unlimited)
+///
+/// # Security Checks
+/// 1. Validates caller is the system authority
+///
+/// # State Changes
+/// 1. Sets `max_products` on the system state
+pub fn handler(ctx: Context<SetMaxProducts>, max_products: u32) -> Result<()> {
+    let redeem = &mut ctx.accounts.redeem;
+
+    msg!("⚙️ Updating catalog cap");
+    msg!("   Previous Cap: {}", redeem.max_products);
+    msg!("   New Cap: {}", max_products);
+
+    redeem.max_products = max_products;
+
+    msg!("✅ Catalog cap updated");
+    msg!("   Active Products: {}", redeem.product_count);
+    msg!("   Can Add Product: {}", redeem.can_add_product());
+
+    Ok(())
+}
@@ -0,0 +1,76 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::constants::*;
+
+/// Take a product off the catalog or put it back
+///
+/// This instruction lets the system authority control product availability:
+/// 1. Validates the caller is the system authority
+/// 2. Sets the product's active flag
+///
+/// Inactive products cannot be redeemed; existing redemption records are unaffected.
+/// Only active products count toward the catalog cap, so deactivating a product
+/// frees a slot and reactivating one needs a free slot.
+#[derive(Accounts)]
+#[instruction(product_id: u64)]
+pub struct SetProductActive<'info> {
+    /// System authority (must match redeem.authority)
+    /// May differ from product.authority after an authority transfer
+    pub authority: Signer<'info>,
+
+    /// Main system state (PDA)
+    /// Used to verify the authority and track the active product count
+    ///
+    /// Seeds: ["redeem"]
+    /// Constraint: Authority must match
+    #[account(
+        mut,
+        seeds = [REDEEM_SEED],
+        bump = redeem.bump,
+        constraint = redeem.authority == authority.key() @ ErrorCode::Unauthorized
+    )]
+    pub redeem: Account<'info, Redeem>,
+
+    /// Product being toggled (PDA)
+    ///
+    /// Seeds: ["product", product_id]
+    #[account(
+        mut,
+        seeds = [PRODUCT_SEED, product_id.to_le_bytes().as_ref()],
+        bump = product.bump
+    )]
+    pub product: Account<'info, Product>,
+}
+
+/// Set product active instruction handler
+///
+/// # Arguments
+/// * `ctx` - The instruction context containing all accounts
+/// * `product_id` - ID of the product to update
+/// * `active` - Whether the product can be redeemed
+///
+/// # Security Checks
+/// 1. Validates caller is the system authority
+/// 2. Reactivation fails if the catalog cap is already reached
+///
+/// # State Changes
+/// 1. Sets `is_active` on the product
+/// 2. Adjusts the active product count on the system state
+pub fn handler(ctx: Context<SetProductActive>, product_id: u64, active: bool) -> Result<()> {
+    let redeem = &mut ctx.accounts.redeem;
+    let product = &mut ctx.accounts.product;
+
+    msg!("⚙️ Updating product availability");
+    msg!("   Product ID: {}", product_id);
+    msg!("   Previous: {}", if product.is_active { "active" } else { "inactive" });
+    msg!("   New: {}", if active { "active" } else { "inactive" });
+
+    redeem.update_product_count(product.is_active, active)?;
+    product.is_active = active;
+
+    msg!("✅ Product availability updated");
+    msg!("   Active Products: {}", redeem.product_count);
+    msg!("   Available for redemption: {}", product.is_available(Clock::get()?.unix_timestamp));
+
+    Ok(())
+}
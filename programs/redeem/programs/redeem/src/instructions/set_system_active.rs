@@ -0,0 +1,56 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::constants::*;
+
+/// Pause or resume the redemption system
+///
+/// This instruction lets the system authority halt activity during an incident:
+/// 1. Validates the caller is the system authority
+/// 2. Sets the system's active flag
+///
+/// While paused, ticket purchases, refunds and redemptions are rejected.
+/// Authority actions such as withdrawals and catalog changes still work.
+#[derive(Accounts)]
+pub struct SetSystemActive<'info> {
+    /// System authority (must match redeem.authority)
+    /// Only this account can pause or resume the system
+    pub authority: Signer<'info>,
+
+    /// Main system state (PDA)
+    /// Holds the active flag
+    ///
+    /// Seeds: ["redeem"]
+    /// Constraint: Authority must match
+    #[account(
+        mut,
+        seeds = [REDEEM_SEED],
+        bump = redeem.bump,
+        constraint = redeem.authority == authority.key() @ ErrorCode::Unauthorized
+    )]
+    pub redeem: Account<'info, Redeem>,
+}
+
+/// Set system active instruction handler
+///
+/// # Arguments
+/// * `ctx` - The instruction context containing all accounts
+/// * `active` - `true` to resume the system, `false` to pause it
+///
+/// # Security Checks
+/// 1. Validates caller is the system authority
+///
+/// # State Changes
+/// 1. Sets `is_active` on the system state
+pub fn handler(ctx: Context<SetSystemActive>, active: bool) -> Result<()> {
+    let redeem = &mut ctx.accounts.redeem;
+
+    redeem.is_active = active;
+
+    if active {
+        msg!("▶️ Redemption system resumed");
+    } else {
+        msg!("⏸️ Redemption system paused");
+    }
+
+    Ok(())
+}
@@ -0,0 +1,54 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::constants::*;
+
+/// Propose a new system authority
+///
+/// This instruction starts a two-step authority handover:
+/// 1. Validates the caller is the system authority
+/// 2. Records the proposed authority as pending
+///
+/// The current authority keeps full control until the new one calls
+/// `accept_authority`, so proposing an unreachable key can be undone
+/// by proposing again.
+#[derive(Accounts)]
+pub struct TransferAuthority<'info> {
+    /// System authority (must match redeem.authority)
+    pub authority: Signer<'info>,
+
+    /// Main system state (PDA)
+    /// Holds the current and pending authority
+    ///
+    /// Seeds: ["redeem"]
+    /// Constraint: Authority must match
+    #[account(
+        mut,
+        seeds = [REDEEM_SEED],
+        bump = redeem.bump,
+        constraint = redeem.authority == authority.key() @ ErrorCode::Unauthorized
+    )]
+    pub redeem: Account<'info, Redeem>,
+}
+
+/// Transfer authority instruction handler
+///
+/// # Arguments
+/// * `ctx` - The instruction context containing all accounts
+/// * `new_authority` - Key that may accept the authority role
+///
+/// # Security Checks
+/// 1. Validates caller is the system authority
+///
+/// # State Changes
+/// 1. Sets `pending_authority` on the system state
+pub fn handler(ctx: Context<TransferAuthority>, new_authority: Pubkey) -> Result<()> {
+    let redeem = &mut ctx.accounts.redeem;
+
+    redeem.pending_authority = Some(new_authority);
+
+    msg!("🔑 Authority transfer proposed");
+    msg!("   Current Authority: {}", redeem.authority);
+    msg!("   Pending Authority: {}", new_authority);
+
+    Ok(())
+}
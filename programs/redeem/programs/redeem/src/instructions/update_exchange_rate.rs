@@ -0,0 +1,62 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::constants::*;
+
+/// Change the SOL price of a ticket
+///
+/// This instruction lets the system authority respond to SOL price swings:
+/// 1. Validates the caller is the system authority
+/// 2. Validates the new rate is within the allowed bounds
+/// 3. Updates the exchange rate on the system state
+///
+/// Only future purchases are affected; tickets already bought keep their value.
+#[derive(Accounts)]
+pub struct UpdateExchangeRate<'info> {
+    /// System authority (must match redeem.authority)
+    /// Only this account can change the exchange rate
+    pub authority: Signer<'info>,
+
+    /// Main system state (PDA)
+    /// Holds the exchange rate
+    ///
+    /// Seeds: ["redeem"]
+    /// Constraint: Authority must match
+    #[account(
+        mut,
+        seeds = [REDEEM_SEED],
+        bump = redeem.bump,
+        constraint = redeem.authority == authority.key() @ ErrorCode::Unauthorized
+    )]
+    pub redeem: Account<'info, Redeem>,
+}
+
+/// Update exchange rate instruction handler
+///
+/// # Arguments
+/// * `ctx` - The instruction context containing all accounts
+/// * `new_rate` - New exchange rate in lamports per ticket
+///
+/// # Security Checks
+/// 1. Validates caller is the system authority
+/// 2. Validates the rate with `is_valid_sol_per_ticket`
+///
+/// # State Changes
+/// 1. Sets `sol_per_ticket` on the system state
+pub fn handler(ctx: Context<UpdateExchangeRate>, new_rate: u64) -> Result<()> {
+    require!(
+        is_valid_sol_per_ticket(new_rate),
+        ErrorCode::InvalidExchangeRate
+    );
+
+    let redeem = &mut ctx.accounts.redeem;
+
+    msg!("⚙️ Updating exchange rate");
+    msg!("   Previous Rate: {} lamports per ticket", redeem.sol_per_ticket);
+    msg!("   New Rate: {} lamports per ticket", new_rate);
+
+    redeem.sol_per_ticket = new_rate;
+
+    msg!("✅ Exchange rate updated");
+
+    Ok(())
+}
@@ -0,0 +1,104 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{transfer, Transfer};
+use crate::state::*;
+use crate::constants::*;
+
+/// Withdraw collected SOL from the vault
+///
+/// This instruction lets the system authority collect ticket sale and
+/// surcharge proceeds:
+/// 1. Validates the caller is the system authority
+/// 2. Ensures the vault keeps its rent-exempt minimum after the withdrawal
+/// 3. Transfers lamports from the vault PDA to the authority
+///
+/// The vault is a system-owned PDA, so the transfer is signed with its seeds.
+#[derive(Accounts)]
+pub struct WithdrawSol<'info> {
+    /// System authority (must match redeem.authority)
+    /// Receives the withdrawn SOL
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    /// Main system state (PDA)
+    /// Used to verify the authority and the vault address
+    ///
+    /// Seeds: ["redeem"]
+    /// Constraint: Authority must match
+    #[account(
+        seeds = [REDEEM_SEED],
+        bump = redeem.bump,
+        constraint = redeem.authority == authority.key() @ ErrorCode::Unauthorized
+    )]
+    pub redeem: Account<'info, Redeem>,
+
+    /// SOL vault holding the proceeds (PDA)
+    ///
+    /// Seeds: ["sol_vault", redeem.key()]
+    /// Constraint: Must match vault in system state
+    #[account(
+        mut,
+        seeds = [SOL_VAULT_SEED, redeem.key().as_ref()],
+        bump,
+        constraint = sol_vault.key() == redeem.sol_vault @ ErrorCode::InvalidProduct
+    )]
+    pub sol_vault: SystemAccount<'info>,
+
+    /// Required system program
+    pub system_program: Program<'info, System>,
+}
+
+/// Withdraw SOL instruction handler
+///
+/// # Arguments
+/// * `ctx` - The instruction context containing all accounts
+/// * `amount` - Lamports to withdraw
+///
+/// # Security Checks
+/// 1. Validates caller is the system authority
+/// 2. Rejects withdrawals that would leave the vault below rent exemption
+///
+/// # State Changes
+/// 1. Moves `amount` lamports from the SOL vault to the authority
+pub fn handler(ctx: Context<WithdrawSol>, amount: u64) -> Result<()> {
+    let sol_vault = &ctx.accounts.sol_vault;
+    let authority = &ctx.accounts.authority;
+
+    require!(amount > 0, ErrorCode::InvalidWithdrawAmount);
+
+    // The vault must stay rent exempt so it keeps existing for future payments
+    let min_balance = min_vault_balance()?;
+    let withdrawable = sol_vault.lamports().saturating_sub(min_balance);
+
+    msg!("💰 Withdrawing SOL from vault");
+    msg!("   Vault Balance: {} lamports", sol_vault.lamports());
+    msg!("   Withdrawable: {} lamports", withdrawable);
+    msg!("   Requested: {} lamports", amount);
+
+    require!(
+        keeps_vault_rent_exempt(sol_vault.lamports(), amount, min_balance),
+        ErrorCode::WouldBreakRentExemption
+    );
+
+    let redeem_key = ctx.accounts.redeem.key();
+    let signer_seeds: &[&[&[u8]]] = &[&[
+        SOL_VAULT_SEED,
+        redeem_key.as_ref(),
+        &[ctx.bumps.sol_vault],
+    ]];
+
+    transfer(
+        CpiContext::new_with_signer(
+            ctx.accounts.system_program.to_account_info(),
+            Transfer {
+                from: sol_vault.to_account_info(),
+                to: authority.to_account_info(),
+            },
+            signer_seeds,
+        ),
+        amount,
+    )?;
+
+    msg!("✅ Withdrew {} lamports to {}", amount, authority.key());
+
+    Ok(())
+}
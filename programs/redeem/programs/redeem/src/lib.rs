@@ -39,11 +39,29 @@ pub mod redeem {
     /// # Arguments
     /// * `ctx` - Instruction context with required accounts
     /// * `sol_per_ticket` - Exchange rate in lamports per ticket
+    /// * `max_ticket_supply` - Cap on tickets in circulation (0 for unlimited)
+    /// * `decimals` - Decimals of the ticket mint (0 for whole tickets, at most 9)
     /// 
     /// # Access Control
     /// Only the authority can call this instruction
-    pub fn initialize(ctx: Context<Initialize>, sol_per_ticket: u64) -> Result<()> {
-        instructions::initialize::handler(ctx, sol_per_ticket)
+    pub fn initialize(ctx: Context<Initialize>, sol_per_ticket: u64, max_ticket_supply: u64, decimals: u8) -> Result<()> {
+        instructions::initialize::handler(ctx, sol_per_ticket, max_ticket_supply, decimals)
+    }
+
+    /// Initialize the redeem system with an existing ticket mint
+    /// 
+    /// Same as `initialize`, but adopts a mint the project already has
+    /// instead of creating one. The redeem PDA must be its mint authority.
+    /// 
+    /// # Arguments
+    /// * `ctx` - Instruction context with required accounts
+    /// * `sol_per_ticket` - Exchange rate in lamports per ticket
+    /// * `max_ticket_supply` - Cap on tickets in circulation (0 for unlimited)
+    /// 
+    /// # Access Control
+    /// Only the authority can call this instruction
+    pub fn initialize_with_mint(ctx: Context<InitializeWithMint>, sol_per_ticket: u64, max_ticket_supply: u64) -> Result<()> {
+        instructions::initialize_with_mint::handler(ctx, sol_per_ticket, max_ticket_supply)
     }
 
     /// Purchase ticket tokens with SOL
@@ -54,11 +72,27 @@ pub mod redeem {
     /// # Arguments
     /// * `ctx` - Instruction context with required accounts
     /// * `ticket_amount` - Number of tickets to purchase
+    /// * `nonce` - User's next purchase nonce, rejecting replays
     /// 
     /// # Access Control
     /// Any user can call this instruction
-    pub fn purchase_tickets(ctx: Context<PurchaseTickets>, ticket_amount: u64) -> Result<()> {
-        instructions::purchase_tickets::handler(ctx, ticket_amount)
+    pub fn purchase_tickets(ctx: Context<PurchaseTickets>, ticket_amount: u64, nonce: u64) -> Result<()> {
+        instructions::purchase_tickets::handler(ctx, ticket_amount, nonce)
+    }
+
+    /// Sell unused ticket tokens back for SOL
+    /// 
+    /// Burns the tickets and pays the current exchange rate,
+    /// minus the refund fee, from the SOL vault.
+    /// 
+    /// # Arguments
+    /// * `ctx` - Instruction context with required accounts
+    /// * `ticket_amount` - Number of tickets to refund
+    /// 
+    /// # Access Control
+    /// Any user holding enough tickets can call this instruction
+    pub fn refund_tickets(ctx: Context<RefundTickets>, ticket_amount: u64) -> Result<()> {
+        instructions::refund_tickets::handler(ctx, ticket_amount)
     }
 
     /// Add a new product to the catalog
@@ -72,19 +106,28 @@ pub mod redeem {
     /// * `name` - Product name (max 32 bytes)
     /// * `description` - Product description (max 64 bytes)
     /// * `ticket_cost` - Tickets required to redeem this product
+    /// * `sol_surcharge` - Extra lamports charged on redemption (0 for none)
     /// * `total_quantity` - Total inventory available
+    /// * `max_per_user` - Maximum redemptions per user (0 for unlimited)
+    /// * `expires_at` - Unix timestamp when redemptions stop (0 for never)
+    /// * `category` - Catalog category (one of the CATEGORY_* constants)
     /// 
     /// # Access Control
     /// Only the system authority can call this instruction
+    #[allow(clippy::too_many_arguments)]
     pub fn add_product(
         ctx: Context<AddProduct>,
         product_id: u64,
         name: String,
         description: String,
         ticket_cost: u64,
+        sol_surcharge: u64,
         total_quantity: u32,
+        max_per_user: u32,
+        expires_at: i64,
+        category: u8,
     ) -> Result<()> {
-        instructions::add_product::handler(ctx, product_id, name, description, ticket_cost, total_quantity)
+        instructions::add_product::handler(ctx, product_id, name, description, ticket_cost, sol_surcharge, total_quantity, max_per_user, expires_at, category)
     }
 
     /// Redeem ticket tokens for a product
@@ -101,4 +144,202 @@ pub mod redeem {
     pub fn redeem_product(ctx: Context<RedeemProduct>, product_id: u64) -> Result<()> {
         instructions::redeem_product::handler(ctx, product_id)
     }
+
+    /// Redeem ticket tokens for several products in one transaction
+    /// 
+    /// Burns the combined ticket cost once and creates a redemption
+    /// record per product. Each product's product, record, and claim
+    /// accounts are passed as remaining accounts.
+    /// 
+    /// # Arguments
+    /// * `ctx` - Instruction context with required accounts
+    /// * `product_ids` - IDs of the products to redeem
+    /// 
+    /// # Access Control
+    /// Any user with sufficient tickets for the whole batch can call this instruction
+    pub fn redeem_products<'info>(
+        ctx: Context<'_, '_, 'info, 'info, RedeemProducts<'info>>,
+        product_ids: Vec<u64>,
+    ) -> Result<()> {
+        instructions::redeem_products::handler(ctx, product_ids)
+    }
+
+    /// Redeem a product as a gift for another user
+    /// 
+    /// Burns the payer's ticket tokens but attributes the redemption
+    /// record and event to the recipient, who can then confirm delivery.
+    /// 
+    /// # Arguments
+    /// * `ctx` - Instruction context with required accounts
+    /// * `product_id` - ID of the product to gift
+    /// * `recipient` - User the redemption is attributed to
+    /// 
+    /// # Access Control
+    /// Any user with sufficient tickets can call this instruction
+    pub fn gift_product(ctx: Context<GiftProduct>, product_id: u64, recipient: Pubkey) -> Result<()> {
+        instructions::gift_product::handler(ctx, product_id, recipient)
+    }
+
+    /// Mark a redemption as fulfilled
+    /// 
+    /// Records that the product has been shipped or handed over.
+    /// Sets the fulfilled flag and timestamp on the redemption record.
+    /// 
+    /// # Arguments
+    /// * `ctx` - Instruction context with required accounts
+    /// 
+    /// # Access Control
+    /// Only the system authority can call this instruction
+    pub fn mark_fulfilled(ctx: Context<MarkFulfilled>) -> Result<()> {
+        instructions::mark_fulfilled::handler(ctx)
+    }
+
+    /// Confirm delivery of a fulfilled redemption
+    /// 
+    /// Recipient acknowledges receiving the product, closing the
+    /// fulfillment loop for dispute resolution.
+    /// 
+    /// # Arguments
+    /// * `ctx` - Instruction context with required accounts
+    /// 
+    /// # Access Control
+    /// Only the user who made the redemption can call this instruction
+    pub fn confirm_delivery(ctx: Context<ConfirmDelivery>) -> Result<()> {
+        instructions::confirm_delivery::handler(ctx)
+    }
+
+    /// Adjust the maximum number of products in the catalog
+    /// 
+    /// Bounds catalog size and associated state. A cap of zero
+    /// means the catalog is unlimited.
+    /// 
+    /// # Arguments
+    /// * `ctx` - Instruction context with required accounts
+    /// * `max_products` - New catalog cap (0 for unlimited)
+    /// 
+    /// # Access Control
+    /// Only the system authority can call this instruction
+    pub fn set_max_products(ctx: Context<SetMaxProducts>, max_products: u32) -> Result<()> {
+        instructions::set_max_products::handler(ctx, max_products)
+    }
+
+    /// Withdraw collected SOL from the vault
+    /// 
+    /// Sends ticket sale and surcharge proceeds to the authority,
+    /// always leaving the vault's rent-exempt minimum behind.
+    /// 
+    /// # Arguments
+    /// * `ctx` - Instruction context with required accounts
+    /// * `amount` - Lamports to withdraw
+    /// 
+    /// # Access Control
+    /// Only the system authority can call this instruction
+    pub fn withdraw_sol(ctx: Context<WithdrawSol>, amount: u64) -> Result<()> {
+        instructions::withdraw_sol::handler(ctx, amount)
+    }
+
+    /// Change the exchange rate
+    /// 
+    /// Lets the operator reprice tickets as SOL's value moves.
+    /// Only purchases made after the update use the new rate.
+    /// 
+    /// # Arguments
+    /// * `ctx` - Instruction context with required accounts
+    /// * `new_rate` - New exchange rate in lamports per ticket
+    /// 
+    /// # Access Control
+    /// Only the system authority can call this instruction
+    pub fn update_exchange_rate(ctx: Context<UpdateExchangeRate>, new_rate: u64) -> Result<()> {
+        instructions::update_exchange_rate::handler(ctx, new_rate)
+    }
+
+    /// Replace the volume discount tiers
+    /// 
+    /// Purchases of at least a tier's threshold get its discount;
+    /// the best matching tier applies. An empty list removes discounts.
+    /// 
+    /// # Arguments
+    /// * `ctx` - Instruction context with required accounts
+    /// * `tiers` - New discount tiers (at most 4)
+    /// 
+    /// # Access Control
+    /// Only the system authority can call this instruction
+    pub fn set_discount_tiers(ctx: Context<SetDiscountTiers>, tiers: Vec<DiscountTier>) -> Result<()> {
+        instructions::set_discount_tiers::handler(ctx, tiers)
+    }
+
+    /// Pause or resume the redemption system
+    /// 
+    /// While paused, users cannot purchase, refund or redeem tickets.
+    /// 
+    /// # Arguments
+    /// * `ctx` - Instruction context with required accounts
+    /// * `active` - Whether the system should accept user activity
+    /// 
+    /// # Access Control
+    /// Only the system authority can call this instruction
+    pub fn set_system_active(ctx: Context<SetSystemActive>, active: bool) -> Result<()> {
+        instructions::set_system_active::handler(ctx, active)
+    }
+
+    /// Activate or deactivate a product
+    /// 
+    /// Takes a product off the catalog without deleting it,
+    /// or makes a deactivated product redeemable again.
+    /// 
+    /// # Arguments
+    /// * `ctx` - Instruction context with required accounts
+    /// * `product_id` - ID of the product to update
+    /// * `active` - Whether the product can be redeemed
+    /// 
+    /// # Access Control
+    /// Only the system authority can call this instruction
+    pub fn set_product_active(ctx: Context<SetProductActive>, product_id: u64, active: bool) -> Result<()> {
+        instructions::set_product_active::handler(ctx, product_id, active)
+    }
+
+    /// Add inventory to a product
+    /// 
+    /// Increases the product's total quantity, up to the
+    /// maximum product quantity.
+    /// 
+    /// # Arguments
+    /// * `ctx` - Instruction context with required accounts
+    /// * `product_id` - ID of the product to restock
+    /// * `additional_quantity` - Units to add
+    /// 
+    /// # Access Control
+    /// Only the system authority can call this instruction
+    pub fn restock_product(ctx: Context<RestockProduct>, product_id: u64, additional_quantity: u32) -> Result<()> {
+        instructions::restock_product::handler(ctx, product_id, additional_quantity)
+    }
+
+    /// Propose a new system authority
+    /// 
+    /// The transfer only takes effect once the new authority accepts it,
+    /// so a mistyped key can't lock the system. Proposing again replaces
+    /// the pending authority.
+    /// 
+    /// # Arguments
+    /// * `ctx` - Instruction context with required accounts
+    /// * `new_authority` - Key that may accept the authority role
+    /// 
+    /// # Access Control
+    /// Only the system authority can call this instruction
+    pub fn transfer_authority(ctx: Context<TransferAuthority>, new_authority: Pubkey) -> Result<()> {
+        instructions::transfer_authority::handler(ctx, new_authority)
+    }
+
+    /// Accept a proposed authority transfer
+    /// 
+    /// Makes the signer the system authority and clears the pending authority.
+    /// 
+    /// # Arguments
+    /// * `ctx` - Instruction context with required accounts
+    /// 
+    /// # Access Control
+    /// Only the pending authority can call this instruction
+    pub fn accept_authority(ctx: Context<AcceptAuthority>) -> Result<()> {
+        instructions::accept_authority::handler(ctx)
+    }
 }
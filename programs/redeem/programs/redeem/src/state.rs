@@ -1,41 +1,120 @@
 use anchor_lang::prelude::*;
+use crate::constants::{calculate_total_cost, BPS_DENOMINATOR, MAX_DISCOUNT_TIERS};
 
 // Main program state managing the token exchange system
 #[account]
 pub struct Redeem {
     // Authority that can manage the system
     pub authority: Pubkey,
+    // Proposed new authority, set until they accept the transfer
+    pub pending_authority: Option<Pubkey>,
     // Mint address for the ticket tokens
     pub ticket_mint: Pubkey,
+    // Decimals of the ticket mint; ticket amounts are in its base units
+    pub ticket_decimals: u8,
     // Vault to collect SOL payments
     pub sol_vault: Pubkey,
     // SOL lamports per ticket token
     pub sol_per_ticket: u64,
     // Total tickets minted
     pub total_tickets_minted: u64,
+    // Cap on total_tickets_minted (0 = unlimited)
+    pub max_ticket_supply: u64,
     // Total tickets redeemed
     pub total_tickets_redeemed: u64,
     // System is active
     pub is_active: bool,
+    // Maximum number of products in the catalog (0 = unlimited)
+    pub max_products: u32,
+    // Number of active products in the catalog
+    pub product_count: u32,
+    // Volume discounts for ticket purchases (at most MAX_DISCOUNT_TIERS)
+    pub discount_tiers: Vec<DiscountTier>,
     // Bump seed for PDA
     pub bump: u8,
 }
 
+// Volume discount applied to purchases of at least `threshold` tickets
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct DiscountTier {
+    // Minimum tickets in a single purchase
+    pub threshold: u64,
+    // Discount on the total cost in basis points
+    pub discount_bps: u16,
+}
+
+impl DiscountTier {
+    pub const LEN: usize = 8 + // threshold
+        2;   // discount_bps
+}
+
 impl Redeem {
     pub const LEN: usize = 8 + // discriminator
         32 + // authority
+        1 + 32 + // pending_authority
         32 + // ticket_mint
+        1 +  // ticket_decimals
         32 + // sol_vault
         8 +  // sol_per_ticket
         8 +  // total_tickets_minted
+        8 +  // max_ticket_supply
         8 +  // total_tickets_redeemed
         1 +  // is_active
+        4 +  // max_products
+        4 +  // product_count
+        4 + MAX_DISCOUNT_TIERS * DiscountTier::LEN + // discount_tiers
         1;   // bump
 
+    pub fn can_add_product(&self) -> bool {
+        self.max_products == 0 || self.product_count < self.max_products
+    }
+
+    // Keep product_count in step with a product's active flag
+    // Reactivating takes a catalog slot like adding a new product does
+    pub fn update_product_count(&mut self, was_active: bool, active: bool) -> Result<()> {
+        match (was_active, active) {
+            (false, true) => {
+                require!(self.can_add_product(), ErrorCode::ProductLimitReached);
+                self.product_count = self.product_count
+                    .checked_add(1)
+                    .ok_or(ErrorCode::MathOverflow)?;
+            }
+            (true, false) => {
+                self.product_count = self.product_count
+                    .checked_sub(1)
+                    .ok_or(ErrorCode::MathOverflow)?;
+            }
+            _ => {}
+        }
+        Ok(())
+    }
+
+    pub fn can_mint_tickets(&self, ticket_amount: u64) -> bool {
+        self.max_ticket_supply == 0
+            || self.total_tickets_minted
+                .checked_add(ticket_amount)
+                .is_some_and(|total| total <= self.max_ticket_supply)
+    }
+
     pub fn calculate_sol_cost(&self, ticket_amount: u64) -> Result<u64> {
-        self.sol_per_ticket
-            .checked_mul(ticket_amount)
-            .ok_or(ErrorCode::MathOverflow.into())
+        // The rate is per whole ticket; amounts are in the mint's base units
+        let total_cost = calculate_total_cost(ticket_amount, self.sol_per_ticket, self.ticket_decimals)
+            .ok_or(ErrorCode::MathOverflow)?;
+
+        // Best discount among the tiers this purchase qualifies for
+        let discount_bps = self.discount_tiers
+            .iter()
+            .filter(|tier| ticket_amount >= tier.threshold)
+            .map(|tier| tier.discount_bps)
+            .max()
+            .unwrap_or(0);
+
+        let discount = (total_cost as u128)
+            .checked_mul(discount_bps as u128)
+            .and_then(|x| x.checked_div(BPS_DENOMINATOR as u128))
+            .ok_or(ErrorCode::MathOverflow)? as u64;
+
+        Ok(total_cost - discount)
     }
 }
 
@@ -44,16 +123,25 @@ impl Redeem {
 pub struct Product {
     // Product ID (unique identifier)
     pub id: u64,
+    // Catalog category (one of the CATEGORY_* constants)
+    // Must stay ahead of `name` so it sits at PRODUCT_CATEGORY_OFFSET
+    pub category: u8,
     // Product name (32 bytes max)
     pub name: String,
     // Product description (64 bytes max)
     pub description: String,
     // Ticket cost to redeem this product
     pub ticket_cost: u64,
+    // Extra SOL (lamports) charged on redemption, e.g. to cover shipping
+    pub sol_surcharge: u64,
     // Total quantity available
     pub total_quantity: u32,
     // Quantity already redeemed
     pub redeemed_quantity: u32,
+    // Maximum redemptions per user (0 = unlimited)
+    pub max_per_user: u32,
+    // Unix timestamp after which the product can't be redeemed (0 = never)
+    pub expires_at: i64,
     // Product is active and available
     pub is_active: bool,
     // Authority that created this product
@@ -65,22 +153,99 @@ pub struct Product {
 impl Product {
     pub const LEN: usize = 8 +
         8 + // id
+        1 +  // category
         32 + // name
         64 + // description
         8 +  // ticket_cost
+        8 +  // sol_surcharge
         4 +  // total_quantity
         4 +  // redeemed_quantity
+        4 +  // max_per_user
+        8 +  // expires_at
         1 +  // is_active
         32 + // authority
         1;   // bump
 
-    pub fn is_available(&self) -> bool {
-        self.is_active && self.redeemed_quantity < self.total_quantity
+    pub fn is_available(&self, current_time: i64) -> bool {
+        self.is_active
+            && self.redeemed_quantity < self.total_quantity
+            && !self.is_expired(current_time)
+    }
+
+    pub fn is_expired(&self, current_time: i64) -> bool {
+        self.expires_at != 0 && current_time >= self.expires_at
     }
 
     pub fn remaining_quantity(&self) -> u32 {
         self.total_quantity.saturating_sub(self.redeemed_quantity)
     }
+
+    // Claim one unit of inventory for a redemption
+    // Re-checks stock against the current state, so it must run right before
+    // the tickets are burned; a failure aborts the whole transaction
+    pub fn reserve_one(&mut self) -> Result<()> {
+        require!(self.remaining_quantity() > 0, ErrorCode::ProductOutOfStock);
+
+        self.redeemed_quantity = self.redeemed_quantity
+            .checked_add(1)
+            .ok_or(ErrorCode::MathOverflow)?;
+
+        Ok(())
+    }
+
+    pub fn allows_claim(&self, claimed: u32) -> bool {
+        self.max_per_user == 0 || claimed < self.max_per_user
+    }
+}
+
+// Whether a product is listed under `category`
+// Clients filtering many products do the same check with a memcmp at PRODUCT_CATEGORY_OFFSET
+pub fn product_matches_category(product: &Product, category: u8) -> bool {
+    product.category == category
+}
+
+// Snapshot of the ticket economy derived from the system state
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct SystemStats {
+    // Tickets minted but not yet redeemed
+    pub circulating_supply: u64,
+    // Total tickets minted
+    pub total_minted: u64,
+    // Total tickets redeemed
+    pub total_redeemed: u64,
+    // Current SOL lamports per ticket token
+    pub sol_per_ticket: u64,
+}
+
+// Circulation figures for logging and off-chain reporting
+pub fn system_stats(redeem: &Redeem) -> SystemStats {
+    SystemStats {
+        circulating_supply: redeem.total_tickets_minted.saturating_sub(redeem.total_tickets_redeemed),
+        total_minted: redeem.total_tickets_minted,
+        total_redeemed: redeem.total_tickets_redeemed,
+        sol_per_ticket: redeem.sol_per_ticket,
+    }
+}
+
+// How many times a user has redeemed a specific product
+#[account]
+pub struct UserProductClaim {
+    // User who redeemed the product
+    pub user: Pubkey,
+    // Product being tracked
+    pub product_id: u64,
+    // Number of redemptions so far
+    pub redeemed_count: u32,
+    // Bump seed for PDA
+    pub bump: u8,
+}
+
+impl UserProductClaim {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // user
+        8 +  // product_id
+        4 +  // redeemed_count
+        1;   // bump
 }
 
 #[account]
@@ -95,6 +260,10 @@ pub struct UserRedeemAccount {
     pub total_redeemed: u64,
     // Number of products redeemed
     pub products_redeemed: u32,
+    // Next redemption record index; seeds each record PDA so records never collide
+    pub redemption_nonce: u64,
+    // Nonce the next purchase must supply; rejects replayed purchase instructions
+    pub purchase_nonce: u64,
     // Account creation timestamp
     pub created_at: i64,
     // Last activity timestamp
@@ -112,6 +281,8 @@ impl UserRedeemAccount {
         8 +  // total_purchased
         8 +  // total_redeemed
         4 +  // products_redeemed
+        8 +  // redemption_nonce
+        8 +  // purchase_nonce
         8 +  // created_at
         8 +  // last_activity
         1 +  // is_active
@@ -127,11 +298,33 @@ impl UserRedeemAccount {
         self.ticket_balance = self.ticket_balance.saturating_sub(amount);
         self.total_redeemed = self.total_redeemed.saturating_add(amount);
         self.products_redeemed = self.products_redeemed.saturating_add(1);
+        self.redemption_nonce = self.redemption_nonce
+            .checked_add(1)
+            .ok_or(ErrorCode::MathOverflow)?;
+        self.last_activity = Clock::get()?.unix_timestamp;
+        
+        Ok(())
+    }
+
+    pub fn refund_tickets(&mut self, amount: u64) -> Result<()> {
+        require!(self.ticket_balance >= amount, ErrorCode::InsufficientTickets);
+        
+        self.ticket_balance = self.ticket_balance.saturating_sub(amount);
         self.last_activity = Clock::get()?.unix_timestamp;
         
         Ok(())
     }
 
+    pub fn consume_purchase_nonce(&mut self, nonce: u64) -> Result<()> {
+        require!(nonce == self.purchase_nonce, ErrorCode::StaleNonce);
+        
+        self.purchase_nonce = self.purchase_nonce
+            .checked_add(1)
+            .ok_or(ErrorCode::MathOverflow)?;
+        
+        Ok(())
+    }
+
     pub fn add_tickets(&mut self, amount: u64) -> Result<()> {
         self.ticket_balance = self.ticket_balance
             .checked_add(amount)
@@ -155,10 +348,20 @@ pub struct RedemptionRecord {
     pub tickets_used: u64,
     // Timestamp of redemption
     pub redeemed_at: i64,
-    // Transaction signature (for reference)
-    pub transaction_signature: [u8; 64],
+    // Slot the redemption landed in; together with the user it locates the
+    // transaction on-chain (a program can't read its own signature, and a
+    // client can't pass it in since the signature covers the instruction data)
+    pub redeemed_slot: u64,
     // Redemption is valid and processed
     pub is_processed: bool,
+    // Authority has shipped / handed over the product
+    pub is_fulfilled: bool,
+    // Timestamp of fulfillment (0 until fulfilled)
+    pub fulfilled_at: i64,
+    // Recipient has acknowledged receiving the product
+    pub delivery_confirmed: bool,
+    // Timestamp of delivery confirmation (0 until confirmed)
+    pub delivery_confirmed_at: i64,
     // Bump seed for PDA
     pub bump: u8,
 }
@@ -169,8 +372,12 @@ impl RedemptionRecord {
         8 +  // product_id
         8 +  // tickets_used
         8 +  // redeemed_at
-        64 + // transaction_signature
+        8 +  // redeemed_slot
         1 +  // is_processed
+        1 +  // is_fulfilled
+        8 +  // fulfilled_at
+        1 +  // delivery_confirmed
+        8 +  // delivery_confirmed_at
         1;   // bump
 }
 
@@ -194,4 +401,350 @@ pub enum ErrorCode {
     InvalidProduct,
     #[msg("User account not found")]
     UserAccountNotFound,
+    #[msg("Insufficient SOL to pay the product surcharge")]
+    InsufficientSolForSurcharge,
+    #[msg("Redemption has already been fulfilled")]
+    AlreadyFulfilled,
+    #[msg("Redemption has not been fulfilled yet")]
+    NotFulfilled,
+    #[msg("Delivery has already been confirmed")]
+    DeliveryAlreadyConfirmed,
+    #[msg("Product catalog has reached its maximum size")]
+    ProductLimitReached,
+    #[msg("Withdrawal amount must be greater than zero")]
+    InvalidWithdrawAmount,
+    #[msg("SOL vault cannot cover the withdrawal and stay rent exempt")]
+    InsufficientVaultBalance,
+    #[msg("Exchange rate is outside the allowed range")]
+    InvalidExchangeRate,
+    #[msg("User has reached this product's redemption limit")]
+    RedemptionLimitReached,
+    #[msg("Bulk redemption accounts do not match the product list")]
+    InvalidBatch,
+    #[msg("Invalid discount tier configuration")]
+    InvalidDiscountTiers,
+    #[msg("Product has expired")]
+    ProductExpired,
+    #[msg("Product quantity would exceed the maximum allowed")]
+    ProductQuantityExceeded,
+    #[msg("Purchase would exceed the maximum ticket supply")]
+    SupplyCapReached,
+    #[msg("Signer is not the pending authority")]
+    NotPendingAuthority,
+    #[msg("Purchase nonce does not match the account's next nonce")]
+    StaleNonce,
+    #[msg("Withdrawal would leave the SOL vault below rent exemption")]
+    WouldBreakRentExemption,
+    #[msg("Ticket mint authority must be the redeem program")]
+    TicketMintAuthorityMismatch,
+    #[msg("Ticket mint decimals must be at most 9")]
+    InvalidTicketDecimals,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anchor_lang::solana_program::program_option::COption;
+    use crate::constants::{
+        calculate_refund_amount, calculate_total_cost, is_valid_product, is_valid_ticket_amount, is_valid_ticket_decimals, keeps_vault_rent_exempt, program_can_mint,
+        ticket_scale, CATEGORY_DIGITAL, CATEGORY_MERCH, MAX_PRODUCT_TICKET_COST, MAX_TICKET_DECIMALS, MAX_TICKET_PURCHASE, PRODUCT_CATEGORY_OFFSET,
+    };
+
+    const RATE: u64 = 10_000_000;
+
+    fn mock_redeem(discount_tiers: Vec<DiscountTier>) -> Redeem {
+        Redeem {
+            authority: Pubkey::default(),
+            pending_authority: None,
+            ticket_mint: Pubkey::default(),
+            ticket_decimals: 0,
+            sol_vault: Pubkey::default(),
+            sol_per_ticket: RATE,
+            total_tickets_minted: 0,
+            max_ticket_supply: 0,
+            total_tickets_redeemed: 0,
+            is_active: true,
+            max_products: 0,
+            product_count: 0,
+            discount_tiers,
+            bump: 0,
+        }
+    }
+
+    fn tiered_redeem() -> Redeem {
+        mock_redeem(vec![
+            DiscountTier { threshold: 100, discount_bps: 300 },
+            DiscountTier { threshold: 500, discount_bps: 500 },
+        ])
+    }
+
+    #[test]
+    fn test_no_tiers_charges_full_price() {
+        let redeem = mock_redeem(Vec::new());
+        assert_eq!(redeem.calculate_sol_cost(1).unwrap(), RATE);
+        assert_eq!(redeem.calculate_sol_cost(1_000).unwrap(), RATE * 1_000);
+    }
+
+    #[test]
+    fn test_first_tier_boundary() {
+        let redeem = tiered_redeem();
+        assert_eq!(redeem.calculate_sol_cost(99).unwrap(), RATE * 99);
+        // 3% off from 100 tickets
+        assert_eq!(redeem.calculate_sol_cost(100).unwrap(), RATE * 100 * 97 / 100);
+    }
+
+    #[test]
+    fn test_best_tier_applies() {
+        let redeem = tiered_redeem();
+        assert_eq!(redeem.calculate_sol_cost(499).unwrap(), RATE * 499 * 97 / 100);
+        // 5% off from 500 tickets, even though the 3% tier also matches
+        assert_eq!(redeem.calculate_sol_cost(500).unwrap(), RATE * 500 * 95 / 100);
+    }
+
+    #[test]
+    fn test_cost_overflow_is_an_error() {
+        let redeem = tiered_redeem();
+        assert!(redeem.calculate_sol_cost(u64::MAX).is_err());
+
+        // The discount math itself can't overflow once the total fits in a u64
+        let mut redeem = tiered_redeem();
+        redeem.sol_per_ticket = 1;
+        assert_eq!(redeem.calculate_sol_cost(u64::MAX).unwrap(), u64::MAX - u64::MAX / 20);
+    }
+
+    #[test]
+    fn test_six_decimal_mint_prices_per_whole_ticket() {
+        let mut redeem = mock_redeem(Vec::new());
+        redeem.ticket_decimals = 6;
+
+        // One whole ticket is 10^6 base units and costs the full rate
+        assert_eq!(redeem.calculate_sol_cost(1_000_000).unwrap(), RATE);
+        assert_eq!(redeem.calculate_sol_cost(2_500_000).unwrap(), RATE * 5 / 2);
+        assert_eq!(redeem.calculate_sol_cost(500_000).unwrap(), RATE / 2);
+
+        // Fractions of a lamport round up, so no purchase is ever free
+        assert_eq!(redeem.calculate_sol_cost(1).unwrap(), RATE / 1_000_000);
+        redeem.sol_per_ticket = 1_500_000;
+        assert_eq!(redeem.calculate_sol_cost(1).unwrap(), 2);
+    }
+
+    #[test]
+    fn test_six_decimal_refund_and_limits() {
+        let one_ticket = ticket_scale(6);
+        assert_eq!(one_ticket, 1_000_000);
+
+        // Refunds use the same per-whole-ticket rate as purchases
+        assert_eq!(
+            calculate_refund_amount(one_ticket / 2, RATE, 6),
+            calculate_refund_amount(1, RATE / 2, 0)
+        );
+
+        // Purchases round a fractional lamport up, refunds round it down
+        assert_eq!(calculate_total_cost(1, 1_500_000, 6), Some(2));
+        assert_eq!(calculate_refund_amount(1, 1_500_000, 6), Some(1));
+        assert_eq!(calculate_refund_amount(1, 999_999, 6), Some(0));
+
+        // The per-purchase cap is in whole tickets
+        assert!(is_valid_ticket_amount(MAX_TICKET_PURCHASE * one_ticket, 6));
+        assert!(!is_valid_ticket_amount(MAX_TICKET_PURCHASE * one_ticket + 1, 6));
+        assert!(!is_valid_ticket_amount(MAX_TICKET_PURCHASE + 1, 0));
+
+        // So is the product price cap
+        assert!(is_valid_product(MAX_PRODUCT_TICKET_COST * one_ticket, 6, 1, "Hoodie", ""));
+        assert!(!is_valid_product(MAX_PRODUCT_TICKET_COST * one_ticket + 1, 6, 1, "Hoodie", ""));
+        assert!(!is_valid_product(MAX_PRODUCT_TICKET_COST + 1, 0, 1, "Hoodie", ""));
+
+        assert!(is_valid_ticket_decimals(MAX_TICKET_DECIMALS));
+        assert!(!is_valid_ticket_decimals(MAX_TICKET_DECIMALS + 1));
+    }
+
+    #[test]
+    fn test_unlimited_supply_by_default() {
+        let mut redeem = mock_redeem(Vec::new());
+        redeem.total_tickets_minted = u64::MAX - 1;
+
+        assert!(redeem.can_mint_tickets(1));
+    }
+
+    #[test]
+    fn test_supply_cap_boundary() {
+        let mut redeem = mock_redeem(Vec::new());
+        redeem.max_ticket_supply = 1_000;
+        redeem.total_tickets_minted = 900;
+
+        // Up to the cap is allowed, one ticket beyond it is not
+        assert!(redeem.can_mint_tickets(100));
+        assert!(!redeem.can_mint_tickets(101));
+        assert!(!redeem.can_mint_tickets(u64::MAX));
+    }
+
+    #[test]
+    fn test_deactivated_products_free_catalog_slots() {
+        let mut redeem = mock_redeem(Vec::new());
+        redeem.max_products = 2;
+        redeem.product_count = 2;
+        assert!(!redeem.can_add_product());
+
+        // Deactivating frees a slot; repeating it changes nothing
+        redeem.update_product_count(true, false).unwrap();
+        assert_eq!(redeem.product_count, 1);
+        redeem.update_product_count(false, false).unwrap();
+        assert_eq!(redeem.product_count, 1);
+        assert!(redeem.can_add_product());
+
+        // Reactivating takes the slot back, and fails once the catalog is full
+        redeem.update_product_count(false, true).unwrap();
+        assert_eq!(redeem.product_count, 2);
+        assert_eq!(
+            redeem.update_product_count(false, true).unwrap_err(),
+            ErrorCode::ProductLimitReached.into()
+        );
+        assert_eq!(redeem.product_count, 2);
+    }
+
+    fn mock_product(expires_at: i64) -> Product {
+        Product {
+            id: 1,
+            category: CATEGORY_MERCH,
+            name: String::from("Sticker"),
+            description: String::new(),
+            ticket_cost: 5,
+            sol_surcharge: 0,
+            total_quantity: 10,
+            redeemed_quantity: 0,
+            max_per_user: 0,
+            expires_at,
+            is_active: true,
+            authority: Pubkey::default(),
+            bump: 0,
+        }
+    }
+
+    #[test]
+    fn test_product_expiry_boundary() {
+        let product = mock_product(1_000);
+
+        assert!(product.is_available(999));
+        assert!(!product.is_expired(999));
+
+        // Expires exactly at `expires_at`
+        assert!(product.is_expired(1_000));
+        assert!(!product.is_available(1_000));
+    }
+
+    #[test]
+    fn test_product_without_expiry_never_expires() {
+        let product = mock_product(0);
+
+        assert!(!product.is_expired(i64::MAX));
+        assert!(product.is_available(i64::MAX));
+    }
+
+    #[test]
+    fn test_last_unit_can_only_be_reserved_once() {
+        let mut product = mock_product(0);
+        product.total_quantity = 1;
+
+        // The first redemption takes the last unit
+        product.reserve_one().unwrap();
+        assert_eq!(product.remaining_quantity(), 0);
+
+        // A second redemption that passed the account check earlier still fails
+        // and leaves the inventory untouched
+        assert!(product.reserve_one().is_err());
+        assert_eq!(product.redeemed_quantity, 1);
+    }
+
+    #[test]
+    fn test_product_matches_only_its_category() {
+        let product = mock_product(0);
+
+        assert!(product_matches_category(&product, CATEGORY_MERCH));
+        assert!(!product_matches_category(&product, CATEGORY_DIGITAL));
+    }
+
+    #[test]
+    fn test_category_offset_in_account_data() {
+        let mut product = mock_product(0);
+        product.category = CATEGORY_DIGITAL;
+
+        let mut data = Vec::new();
+        product.try_serialize(&mut data).unwrap();
+
+        // The offset clients filter on holds the category whatever the name length
+        assert_eq!(data[PRODUCT_CATEGORY_OFFSET], CATEGORY_DIGITAL);
+        product.name = String::from("A much longer product name");
+        data.clear();
+        product.try_serialize(&mut data).unwrap();
+        assert_eq!(data[PRODUCT_CATEGORY_OFFSET], CATEGORY_DIGITAL);
+    }
+
+    #[test]
+    fn test_purchase_nonce_rejects_replays() {
+        let mut account = UserRedeemAccount {
+            user: Pubkey::default(),
+            ticket_balance: 0,
+            total_purchased: 0,
+            total_redeemed: 0,
+            products_redeemed: 0,
+            redemption_nonce: 0,
+            purchase_nonce: 0,
+            created_at: 0,
+            last_activity: 0,
+            is_active: true,
+            bump: 0,
+        };
+
+        account.consume_purchase_nonce(0).unwrap();
+        assert_eq!(account.purchase_nonce, 1);
+
+        // Replaying the same nonce, or skipping ahead, fails without advancing
+        assert!(account.consume_purchase_nonce(0).is_err());
+        assert!(account.consume_purchase_nonce(2).is_err());
+        assert_eq!(account.purchase_nonce, 1);
+
+        account.consume_purchase_nonce(1).unwrap();
+        assert_eq!(account.purchase_nonce, 2);
+    }
+
+    #[test]
+    fn test_withdrawals_keep_vault_rent_exempt() {
+        let min_balance = 890_880;
+        let vault_balance = min_balance + 1_000;
+
+        // Down to exactly the minimum is allowed, one lamport more is not
+        assert!(keeps_vault_rent_exempt(vault_balance, 1_000, min_balance));
+        assert!(!keeps_vault_rent_exempt(vault_balance, 1_001, min_balance));
+        assert!(!keeps_vault_rent_exempt(vault_balance, u64::MAX, min_balance));
+    }
+
+    #[test]
+    fn test_program_can_mint_only_as_mint_authority() {
+        let redeem = Pubkey::new_unique();
+        let other = Pubkey::new_unique();
+
+        assert!(program_can_mint(&COption::Some(redeem), &redeem));
+        assert!(!program_can_mint(&COption::Some(other), &redeem));
+
+        // A mint with its authority revoked can never issue tickets
+        assert!(!program_can_mint(&COption::None, &redeem));
+    }
+
+    #[test]
+    fn test_system_stats_circulating_supply() {
+        let mut redeem = mock_redeem(Vec::new());
+        redeem.total_tickets_minted = 1_000;
+        redeem.total_tickets_redeemed = 350;
+
+        assert_eq!(system_stats(&redeem), SystemStats {
+            circulating_supply: 650,
+            total_minted: 1_000,
+            total_redeemed: 350,
+            sol_per_ticket: RATE,
+        });
+
+        // Refunds can pull minted below redeemed; circulation bottoms out at zero
+        redeem.total_tickets_minted = 300;
+        assert_eq!(system_stats(&redeem).circulating_supply, 0);
+    }
 }
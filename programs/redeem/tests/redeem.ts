@@ -1,16 +1,1596 @@
 import * as anchor from "@coral-xyz/anchor";
 import { Program } from "@coral-xyz/anchor";
 import { Redeem } from "../target/types/redeem";
+import {
+  PublicKey,
+  Keypair,
+  SystemProgram,
+  LAMPORTS_PER_SOL,
+  SYSVAR_RENT_PUBKEY,
+  Transaction,
+  TransactionInstruction,
+} from "@solana/web3.js";
+import { assert } from "chai";
 
-describe("redeem", () => {
-  // Configure the client to use the local cluster.
+describe("🎫 Redeem System Tests", () => {
+  // Configure the client to use the local cluster
   anchor.setProvider(anchor.AnchorProvider.env());
-
+  const provider = anchor.getProvider() as anchor.AnchorProvider;
+  const connection = provider.connection;
   const program = anchor.workspace.redeem as Program<Redeem>;
 
-  it("Is initialized!", async () => {
-    // Add your test here.
-    const tx = await program.methods.initialize().rpc();
-    console.log("Your transaction signature", tx);
+  const TOKEN_PROGRAM_ID = anchor.utils.token.TOKEN_PROGRAM_ID;
+  const ASSOCIATED_TOKEN_PROGRAM_ID = anchor.utils.token.ASSOCIATED_PROGRAM_ID;
+
+  // System configuration
+  const SOL_PER_TICKET = new anchor.BN(10_000_000); // 0.01 SOL per ticket
+  const MAX_TICKET_SUPPLY = new anchor.BN(1_000); // Total tickets the suite can mint
+
+  // Product categories (mirror the CATEGORY_* program constants)
+  const CATEGORY_GENERAL = 0;
+  const CATEGORY_MERCH = 1;
+  const CATEGORY_DIGITAL = 2;
+  const PRODUCT_CATEGORY_OFFSET = 16;
+
+  // Test accounts
+  let authority: Keypair;
+  let user: Keypair;
+  let ticketMint: Keypair;
+  let externalMintError: string; // Error from initialize_with_mint with a foreign mint
+  let invalidDecimalsError: string; // Error from initialize with too many ticket decimals
+
+  // Derived addresses
+  let redeemPda: PublicKey;
+  let solVaultPda: PublicKey;
+
+  console.log("🎫 Starting Redeem System Tests");
+  console.log("Program ID:", program.programId.toString());
+
+  /**
+   * Helper function to fund accounts with SOL
+   */
+  async function fundAccount(publicKey: PublicKey, lamports: number) {
+    const signature = await connection.requestAirdrop(publicKey, lamports);
+    await connection.confirmTransaction(signature);
+  }
+
+  /**
+   * Helper function to derive a product PDA
+   */
+  function findProductPda(productId: anchor.BN): PublicKey {
+    const [productPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from("product"), productId.toArrayLike(Buffer, "le", 8)],
+      program.programId
+    );
+    return productPda;
+  }
+
+  /**
+   * Helper function to derive a user's redeem account PDA
+   */
+  function findUserRedeemPda(owner: PublicKey): PublicKey {
+    const [userRedeemPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from("user_redeem"), owner.toBuffer()],
+      program.programId
+    );
+    return userRedeemPda;
+  }
+
+  /**
+   * Helper function to derive a user's claim counter PDA for a product
+   */
+  function findUserProductClaimPda(owner: PublicKey, productId: anchor.BN): PublicKey {
+    const [claimPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from("claim"), owner.toBuffer(), productId.toArrayLike(Buffer, "le", 8)],
+      program.programId
+    );
+    return claimPda;
+  }
+
+  /**
+   * Helper function to derive a redemption record PDA
+   * Seeds include the user's redemption nonce, read from their redeem account
+   */
+  async function findRedemptionRecordPda(
+    owner: PublicKey,
+    productId: anchor.BN
+  ): Promise<PublicKey> {
+    const userAccount = await program.account.userRedeemAccount.fetch(findUserRedeemPda(owner));
+    const [recordPda] = PublicKey.findProgramAddressSync(
+      [
+        Buffer.from("redemption"),
+        owner.toBuffer(),
+        productId.toArrayLike(Buffer, "le", 8),
+        userAccount.redemptionNonce.toArrayLike(Buffer, "le", 8),
+      ],
+      program.programId
+    );
+    return recordPda;
+  }
+
+  /**
+   * Helper function to add a product to the catalog
+   */
+  async function addProduct(
+    productId: anchor.BN,
+    ticketCost: number,
+    solSurcharge: number,
+    quantity: number,
+    maxPerUser: number = 0,
+    expiresAt: number = 0,
+    category: number = CATEGORY_GENERAL
+  ) {
+    await program.methods
+      .addProduct(
+        productId,
+        `Product ${productId.toString()}`,
+        "Test product",
+        new anchor.BN(ticketCost),
+        new anchor.BN(solSurcharge),
+        quantity,
+        maxPerUser,
+        new anchor.BN(expiresAt),
+        category
+      )
+      .accounts({
+        authority: authority.publicKey,
+        redeem: redeemPda,
+        product: findProductPda(productId),
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([authority])
+      .rpc();
+  }
+
+  /**
+   * Helper function to purchase tickets for a user
+   */
+  async function purchaseTickets(buyer: Keypair, ticketAmount: number, nonce?: anchor.BN) {
+    if (nonce === undefined) {
+      nonce = await nextPurchaseNonce(buyer.publicKey);
+    }
+    await program.methods
+      .purchaseTickets(new anchor.BN(ticketAmount), nonce)
+      .accounts({
+        user: buyer.publicKey,
+        redeem: redeemPda,
+        userRedeemAccount: findUserRedeemPda(buyer.publicKey),
+        ticketMint: ticketMint.publicKey,
+        userTicketTokenAccount: anchor.utils.token.associatedAddress({
+          mint: ticketMint.publicKey,
+          owner: buyer.publicKey,
+        }),
+        solVault: solVaultPda,
+        systemProgram: SystemProgram.programId,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+        rent: SYSVAR_RENT_PUBKEY,
+      })
+      .signers([buyer])
+      .rpc();
+  }
+
+  /**
+   * Helper function to read the nonce a user's next purchase must carry
+   * A user without a redeem account starts at 0
+   */
+  async function nextPurchaseNonce(owner: PublicKey): Promise<anchor.BN> {
+    const userAccount = await program.account.userRedeemAccount.fetchNullable(findUserRedeemPda(owner));
+    return userAccount ? userAccount.purchaseNonce : new anchor.BN(0);
+  }
+
+  /**
+   * Helper function to redeem a product for a user
+   * Returns the address of the created redemption record
+   */
+  async function redeemProduct(redeemer: Keypair, productId: anchor.BN): Promise<PublicKey> {
+    const redemptionRecord = await findRedemptionRecordPda(redeemer.publicKey, productId);
+    await program.methods
+      .redeemProduct(productId)
+      .accounts({
+        user: redeemer.publicKey,
+        redeem: redeemPda,
+        product: findProductPda(productId),
+        userRedeemAccount: findUserRedeemPda(redeemer.publicKey),
+        ticketMint: ticketMint.publicKey,
+        userTicketTokenAccount: anchor.utils.token.associatedAddress({
+          mint: ticketMint.publicKey,
+          owner: redeemer.publicKey,
+        }),
+        redemptionRecord,
+        userProductClaim: findUserProductClaimPda(redeemer.publicKey, productId),
+        solVault: solVaultPda,
+        systemProgram: SystemProgram.programId,
+        tokenProgram: TOKEN_PROGRAM_ID,
+      })
+      .signers([redeemer])
+      .rpc();
+    return redemptionRecord;
+  }
+
+  /**
+   * Helper function to set the catalog cap (0 = unlimited)
+   */
+  async function setMaxProducts(maxProducts: number) {
+    await program.methods
+      .setMaxProducts(maxProducts)
+      .accounts({
+        authority: authority.publicKey,
+        redeem: redeemPda,
+      })
+      .signers([authority])
+      .rpc();
+  }
+
+  /**
+   * Helper function to create a 0-decimal SPL mint controlled by `mintAuthority`
+   * Built from raw instructions (SPL Token InitializeMint2) to avoid an extra dependency
+   */
+  async function createMint(payer: Keypair, mintAuthority: PublicKey): Promise<PublicKey> {
+    const mint = Keypair.generate();
+    const MINT_SIZE = 82;
+    const lamports = await connection.getMinimumBalanceForRentExemption(MINT_SIZE);
+
+    const initializeMint2 = new TransactionInstruction({
+      programId: TOKEN_PROGRAM_ID,
+      keys: [{ pubkey: mint.publicKey, isSigner: false, isWritable: true }],
+      // [instruction 20, decimals 0, mint authority, no freeze authority]
+      data: Buffer.concat([Buffer.from([20, 0]), mintAuthority.toBuffer(), Buffer.from([0])]),
+    });
+
+    const tx = new Transaction().add(
+      SystemProgram.createAccount({
+        fromPubkey: payer.publicKey,
+        newAccountPubkey: mint.publicKey,
+        lamports,
+        space: MINT_SIZE,
+        programId: TOKEN_PROGRAM_ID,
+      }),
+      initializeMint2
+    );
+    await provider.sendAndConfirm(tx, [payer, mint]);
+    return mint.publicKey;
+  }
+
+  /**
+   * Helper function to mark a redemption as fulfilled by the authority
+   */
+  async function markFulfilled(redemptionRecord: PublicKey) {
+    await program.methods
+      .markFulfilled()
+      .accounts({
+        authority: authority.publicKey,
+        redeem: redeemPda,
+        redemptionRecord,
+      })
+      .signers([authority])
+      .rpc();
+  }
+
+  /**
+   * SETUP PHASE: Initialize the system once for all tests
+   * The redeem state is a singleton PDA, so it can only be created once
+   */
+  before("🔧 Setup Test Environment", async () => {
+    authority = Keypair.generate();
+    user = Keypair.generate();
+    ticketMint = Keypair.generate();
+
+    await fundAccount(authority.publicKey, 5 * LAMPORTS_PER_SOL);
+    await fundAccount(user.publicKey, 5 * LAMPORTS_PER_SOL);
+
+    [redeemPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from("redeem")],
+      program.programId
+    );
+    [solVaultPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from("sol_vault"), redeemPda.toBuffer()],
+      program.programId
+    );
+
+    console.log("👤 Authority:", authority.publicKey.toString());
+    console.log("👤 User:", user.publicKey.toString());
+    console.log("🏠 Redeem PDA:", redeemPda.toString());
+    console.log("🏦 SOL Vault PDA:", solVaultPda.toString());
+
+    // An existing mint the program can't mint from must be rejected
+    // (tried before initialize, since the redeem state can only be created once)
+    const foreignMint = await createMint(authority, authority.publicKey);
+    try {
+      await program.methods
+        .initializeWithMint(SOL_PER_TICKET, MAX_TICKET_SUPPLY)
+        .accounts({
+          authority: authority.publicKey,
+          redeem: redeemPda,
+          ticketMint: foreignMint,
+          solVault: solVaultPda,
+          systemProgram: SystemProgram.programId,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .signers([authority])
+        .rpc();
+      externalMintError = "";
+    } catch (error) {
+      externalMintError = error.message;
+    }
+
+    // More ticket decimals than SOL has must be rejected
+    const tooPreciseMint = Keypair.generate();
+    try {
+      await program.methods
+        .initialize(SOL_PER_TICKET, MAX_TICKET_SUPPLY, 10)
+        .accounts({
+          authority: authority.publicKey,
+          redeem: redeemPda,
+          ticketMint: tooPreciseMint.publicKey,
+          solVault: solVaultPda,
+          systemProgram: SystemProgram.programId,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          rent: SYSVAR_RENT_PUBKEY,
+        })
+        .signers([authority, tooPreciseMint])
+        .rpc();
+      invalidDecimalsError = "";
+    } catch (error) {
+      invalidDecimalsError = error.message;
+    }
+
+    // Whole tickets only (0 decimals), as the rest of the suite assumes
+    const tx = await program.methods
+      .initialize(SOL_PER_TICKET, MAX_TICKET_SUPPLY, 0)
+      .accounts({
+        authority: authority.publicKey,
+        redeem: redeemPda,
+        ticketMint: ticketMint.publicKey,
+        solVault: solVaultPda,
+        systemProgram: SystemProgram.programId,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        rent: SYSVAR_RENT_PUBKEY,
+      })
+      .signers([authority, ticketMint])
+      .rpc();
+
+    console.log("✅ System initialized! Transaction:", tx);
+  });
+
+  describe("🏗️ Initialization Tests", () => {
+    it("✅ Should initialize the system", async () => {
+      const redeemAccount = await program.account.redeem.fetch(redeemPda);
+
+      assert.equal(redeemAccount.authority.toString(), authority.publicKey.toString());
+      assert.equal(redeemAccount.ticketMint.toString(), ticketMint.publicKey.toString());
+      assert.equal(redeemAccount.solPerTicket.toString(), SOL_PER_TICKET.toString());
+      assert.equal(redeemAccount.totalTicketsMinted.toString(), "0");
+      assert.equal(redeemAccount.maxTicketSupply.toString(), MAX_TICKET_SUPPLY.toString());
+      assert.isTrue(redeemAccount.isActive);
+      assert.equal(redeemAccount.maxProducts, 0);
+      assert.equal(redeemAccount.productCount, 0);
+      assert.equal(redeemAccount.ticketDecimals, 0);
+    });
+
+    it("❌ Should reject more than 9 ticket decimals", async () => {
+      console.log("✅ Correctly rejected ticket decimals:", invalidDecimalsError);
+      assert.include(invalidDecimalsError, "InvalidTicketDecimals");
+    });
+
+    it("❌ Should reject an existing mint the program isn't authority of", async () => {
+      console.log("✅ Correctly rejected foreign mint:", externalMintError);
+      assert.include(externalMintError, "TicketMintAuthorityMismatch");
+    });
+  });
+
+  describe("🎫 Ticket Purchase Tests", () => {
+    it("✅ Should purchase tickets with SOL", async () => {
+      const ticketAmount = 100;
+      const vaultBefore = await connection.getBalance(solVaultPda);
+
+      await purchaseTickets(user, ticketAmount);
+
+      const userAccount = await program.account.userRedeemAccount.fetch(
+        findUserRedeemPda(user.publicKey)
+      );
+      const vaultAfter = await connection.getBalance(solVaultPda);
+
+      assert.equal(userAccount.ticketBalance.toString(), ticketAmount.toString());
+      assert.equal(userAccount.totalPurchased.toString(), ticketAmount.toString());
+      assert.equal(vaultAfter - vaultBefore, SOL_PER_TICKET.toNumber() * ticketAmount);
+    });
+
+    it("✅ Should stamp last activity when a user account is created", async () => {
+      const newUser = Keypair.generate();
+      await fundAccount(newUser.publicKey, LAMPORTS_PER_SOL);
+
+      await purchaseTickets(newUser, 1);
+
+      const userAccount = await program.account.userRedeemAccount.fetch(
+        findUserRedeemPda(newUser.publicKey)
+      );
+      assert.notEqual(userAccount.createdAt.toNumber(), 0);
+      assert.notEqual(userAccount.lastActivity.toNumber(), 0);
+      assert.equal(userAccount.lastActivity.toNumber(), userAccount.createdAt.toNumber());
+    });
+  });
+
+  describe("🔥 Ticket Burn Tests", () => {
+    it("✅ Should burn redeemed tickets from the ticket supply", async () => {
+      const productId = new anchor.BN(Math.floor(Math.random() * 1_000_000) + 1);
+      const ticketCost = 3;
+
+      await addProduct(productId, ticketCost, 0, 5);
+      await purchaseTickets(user, ticketCost);
+
+      const supplyBefore = await connection.getTokenSupply(ticketMint.publicKey);
+      await redeemProduct(user, productId);
+      const supplyAfter = await connection.getTokenSupply(ticketMint.publicKey);
+
+      console.log(`🔥 Supply went from ${supplyBefore.value.amount} to ${supplyAfter.value.amount}`);
+      assert.equal(
+        BigInt(supplyBefore.value.amount) - BigInt(supplyAfter.value.amount),
+        BigInt(ticketCost),
+        "Redeemed tickets should be burned"
+      );
+    });
+  });
+
+  describe("🧾 Redemption Record Tests", () => {
+    it("✅ Should record the slot the redemption landed in", async () => {
+      const productId = new anchor.BN(Math.floor(Math.random() * 1_000_000) + 1);
+      await addProduct(productId, 1, 0, 5);
+      await purchaseTickets(user, 1);
+
+      const redemptionRecord = await redeemProduct(user, productId);
+      const record = await program.account.redemptionRecord.fetch(redemptionRecord);
+
+      // The record's slot locates the redemption transaction
+      const signatures = await connection.getSignaturesForAddress(redemptionRecord, {}, "confirmed");
+      const createdIn = signatures[signatures.length - 1];
+
+      console.log(`🧾 Redemption recorded at slot ${record.redeemedSlot.toString()}`);
+      assert.isTrue(record.redeemedSlot.gtn(0), "Slot should be recorded");
+      assert.equal(record.redeemedSlot.toNumber(), createdIn.slot);
+    });
+  });
+
+  describe("⚡ Rapid Redemption Tests", () => {
+    it("✅ Should allow back-to-back redemptions of the same product", async () => {
+      const productId = new anchor.BN(Math.floor(Math.random() * 1_000_000) + 1);
+      await addProduct(productId, 1, 0, 5);
+      await purchaseTickets(user, 2);
+
+      const firstRecord = await redeemProduct(user, productId);
+      const secondRecord = await redeemProduct(user, productId);
+
+      assert.notEqual(firstRecord.toString(), secondRecord.toString());
+
+      const product = await program.account.product.fetch(findProductPda(productId));
+      assert.equal(product.redeemedQuantity, 2);
+    });
+  });
+
+  describe("🏁 Last Unit Race Tests", () => {
+    it("✅ Should hand the last unit to one redeemer without burning the other's tickets", async () => {
+      const productId = new anchor.BN(Math.floor(Math.random() * 1_000_000) + 1);
+      const ticketCost = 2;
+      await addProduct(productId, ticketCost, 0, 1);
+
+      const rival = Keypair.generate();
+      await fundAccount(rival.publicKey, LAMPORTS_PER_SOL);
+      await purchaseTickets(user, ticketCost);
+      await purchaseTickets(rival, ticketCost);
+
+      const balanceOf = async (owner: PublicKey) => {
+        const account = anchor.utils.token.associatedAddress({ mint: ticketMint.publicKey, owner });
+        return BigInt((await connection.getTokenAccountBalance(account)).value.amount);
+      };
+      const userBefore = await balanceOf(user.publicKey);
+      const rivalBefore = await balanceOf(rival.publicKey);
+
+      // Both redemptions are in flight at once for a single remaining unit
+      const results = await Promise.allSettled([
+        redeemProduct(user, productId),
+        redeemProduct(rival, productId),
+      ]);
+
+      const winners = results.filter((result) => result.status === "fulfilled");
+      const losers = results.filter((result) => result.status === "rejected") as PromiseRejectedResult[];
+      assert.equal(winners.length, 1);
+      assert.equal(losers.length, 1);
+      console.log("✅ Losing redemption failed with:", losers[0].reason.message);
+      assert.include(losers[0].reason.message, "ProductOutOfStock");
+
+      const product = await program.account.product.fetch(findProductPda(productId));
+      assert.equal(product.redeemedQuantity, 1);
+
+      // Only the winner's tickets were burned
+      const burned = (userBefore - (await balanceOf(user.publicKey)))
+        + (rivalBefore - (await balanceOf(rival.publicKey)));
+      assert.equal(burned, BigInt(ticketCost));
+    });
+  });
+
+  describe("🚦 Per-User Limit Tests", () => {
+    it("❌ Should stop a user at the product's redemption limit", async () => {
+      const productId = new anchor.BN(Math.floor(Math.random() * 1_000_000) + 1);
+      await addProduct(productId, 1, 0, 10, 2);
+      await purchaseTickets(user, 3);
+
+      await redeemProduct(user, productId);
+      await redeemProduct(user, productId);
+
+      const claim = await program.account.userProductClaim.fetch(
+        findUserProductClaimPda(user.publicKey, productId)
+      );
+      assert.equal(claim.redeemedCount, 2);
+
+      try {
+        await redeemProduct(user, productId);
+        assert.fail("Should have failed past the per-user limit");
+      } catch (error) {
+        console.log("✅ Correctly failed with error:", error.message);
+        assert(error.message.includes("RedemptionLimitReached"));
+      }
+    });
+
+    it("✅ Should not limit redemptions of unlimited products", async () => {
+      const productId = new anchor.BN(Math.floor(Math.random() * 1_000_000) + 1);
+      await addProduct(productId, 1, 0, 10);
+      await purchaseTickets(user, 3);
+
+      for (let i = 0; i < 3; i++) {
+        await redeemProduct(user, productId);
+      }
+
+      const claim = await program.account.userProductClaim.fetch(
+        findUserProductClaimPda(user.publicKey, productId)
+      );
+      assert.equal(claim.redeemedCount, 3);
+    });
+  });
+
+  describe("🛒 Bulk Redemption Tests", () => {
+    it("✅ Should redeem two products in one transaction", async () => {
+      const firstId = new anchor.BN(Math.floor(Math.random() * 1_000_000) + 1);
+      const secondId = firstId.addn(1);
+      await addProduct(firstId, 2, 0, 5);
+      await addProduct(secondId, 3, 0, 5);
+      await purchaseTickets(user, 5);
+
+      const userAccountBefore = await program.account.userRedeemAccount.fetch(
+        findUserRedeemPda(user.publicKey)
+      );
+      const supplyBefore = await connection.getTokenSupply(ticketMint.publicKey);
+
+      // Records take consecutive redemption nonces
+      const productIds = [firstId, secondId];
+      const remainingAccounts = productIds.flatMap((productId, i) => {
+        const [record] = PublicKey.findProgramAddressSync(
+          [
+            Buffer.from("redemption"),
+            user.publicKey.toBuffer(),
+            productId.toArrayLike(Buffer, "le", 8),
+            userAccountBefore.redemptionNonce.addn(i).toArrayLike(Buffer, "le", 8),
+          ],
+          program.programId
+        );
+        return [findProductPda(productId), record, findUserProductClaimPda(user.publicKey, productId)].map(
+          (pubkey) => ({ pubkey, isSigner: false, isWritable: true })
+        );
+      });
+
+      await program.methods
+        .redeemProducts(productIds)
+        .accounts({
+          user: user.publicKey,
+          redeem: redeemPda,
+          userRedeemAccount: findUserRedeemPda(user.publicKey),
+          ticketMint: ticketMint.publicKey,
+          userTicketTokenAccount: anchor.utils.token.associatedAddress({
+            mint: ticketMint.publicKey,
+            owner: user.publicKey,
+          }),
+          solVault: solVaultPda,
+          systemProgram: SystemProgram.programId,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .remainingAccounts(remainingAccounts)
+        .signers([user])
+        .rpc();
+
+      const userAccountAfter = await program.account.userRedeemAccount.fetch(
+        findUserRedeemPda(user.publicKey)
+      );
+      const supplyAfter = await connection.getTokenSupply(ticketMint.publicKey);
+
+      assert.equal(userAccountBefore.ticketBalance.sub(userAccountAfter.ticketBalance).toNumber(), 5);
+      assert.equal(userAccountAfter.productsRedeemed - userAccountBefore.productsRedeemed, 2);
+      assert.equal(BigInt(supplyBefore.value.amount) - BigInt(supplyAfter.value.amount), BigInt(5));
+
+      for (const productId of productIds) {
+        const product = await program.account.product.fetch(findProductPda(productId));
+        assert.equal(product.redeemedQuantity, 1);
+      }
+
+      const secondRecord = await program.account.redemptionRecord.fetch(remainingAccounts[4].pubkey);
+      assert.equal(secondRecord.productId.toString(), secondId.toString());
+      assert.equal(secondRecord.ticketsUsed.toNumber(), 3);
+    });
+
+    it("✅ Should redeem when a record address was pre-funded", async () => {
+      const productId = new anchor.BN(Math.floor(Math.random() * 1_000_000) + 1);
+      await addProduct(productId, 1, 0, 5);
+      await purchaseTickets(user, 1);
+
+      const userAccountBefore = await program.account.userRedeemAccount.fetch(
+        findUserRedeemPda(user.publicKey)
+      );
+      const [record] = PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("redemption"),
+          user.publicKey.toBuffer(),
+          productId.toArrayLike(Buffer, "le", 8),
+          userAccountBefore.redemptionNonce.toArrayLike(Buffer, "le", 8),
+        ],
+        program.programId
+      );
+
+      // Anyone can send lamports to a PDA before it is created
+      await provider.sendAndConfirm(
+        new Transaction().add(
+          SystemProgram.transfer({
+            fromPubkey: provider.wallet.publicKey,
+            toPubkey: record,
+            lamports: 1_000,
+          })
+        )
+      );
+
+      await program.methods
+        .redeemProducts([productId])
+        .accounts({
+          user: user.publicKey,
+          redeem: redeemPda,
+          userRedeemAccount: findUserRedeemPda(user.publicKey),
+          ticketMint: ticketMint.publicKey,
+          userTicketTokenAccount: anchor.utils.token.associatedAddress({
+            mint: ticketMint.publicKey,
+            owner: user.publicKey,
+          }),
+          solVault: solVaultPda,
+          systemProgram: SystemProgram.programId,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .remainingAccounts(
+          [findProductPda(productId), record, findUserProductClaimPda(user.publicKey, productId)].map(
+            (pubkey) => ({ pubkey, isSigner: false, isWritable: true })
+          )
+        )
+        .signers([user])
+        .rpc();
+
+      const recordAccount = await program.account.redemptionRecord.fetch(record);
+      assert.equal(recordAccount.productId.toString(), productId.toString());
+      assert.equal(recordAccount.ticketsUsed.toNumber(), 1);
+    });
+  });
+
+  describe("💸 Product Surcharge Tests", () => {
+    it("✅ Should collect the SOL surcharge when redeeming a surcharged product", async () => {
+      const productId = new anchor.BN(Math.floor(Math.random() * 1_000_000) + 1);
+      const surcharge = 0.05 * LAMPORTS_PER_SOL;
+
+      await addProduct(productId, 10, surcharge, 5);
+
+      const product = await program.account.product.fetch(findProductPda(productId));
+      assert.equal(product.solSurcharge.toNumber(), surcharge);
+
+      const vaultBefore = await connection.getBalance(solVaultPda);
+      await redeemProduct(user, productId);
+      const vaultAfter = await connection.getBalance(solVaultPda);
+
+      console.log(`💸 Vault received ${vaultAfter - vaultBefore} lamports`);
+      assert.equal(vaultAfter - vaultBefore, surcharge, "Vault should receive the surcharge");
+
+      const userAccount = await program.account.userRedeemAccount.fetch(
+        findUserRedeemPda(user.publicKey)
+      );
+      assert.equal(userAccount.productsRedeemed, 1);
+    });
+
+    it("❌ Should reject redemption when the user lacks SOL for the surcharge", async () => {
+      const productId = new anchor.BN(Math.floor(Math.random() * 1_000_000) + 1);
+
+      // Surcharge higher than what the user will hold after buying tickets
+      await addProduct(productId, 1, LAMPORTS_PER_SOL, 5);
+
+      const poorUser = Keypair.generate();
+      await fundAccount(poorUser.publicKey, 0.5 * LAMPORTS_PER_SOL);
+      await purchaseTickets(poorUser, 5);
+
+      try {
+        await redeemProduct(poorUser, productId);
+        assert.fail("Should have failed with insufficient SOL for surcharge");
+      } catch (error) {
+        console.log("✅ Correctly failed with error:", error.message);
+        assert(error.message.includes("InsufficientSolForSurcharge"));
+      }
+    });
+  });
+
+  describe("📬 Delivery Confirmation Tests", () => {
+    let productId: anchor.BN;
+    let redemptionRecord: PublicKey;
+
+    beforeEach(async () => {
+      productId = new anchor.BN(Math.floor(Math.random() * 1_000_000) + 1);
+      await addProduct(productId, 1, 0, 5);
+      await purchaseTickets(user, 1);
+      redemptionRecord = await redeemProduct(user, productId);
+      await markFulfilled(redemptionRecord);
+    });
+
+    it("✅ Should let the recipient confirm delivery", async () => {
+      const fulfilled = await program.account.redemptionRecord.fetch(redemptionRecord);
+      assert.isTrue(fulfilled.isFulfilled);
+      assert.isFalse(fulfilled.deliveryConfirmed);
+
+      await program.methods
+        .confirmDelivery()
+        .accounts({
+          user: user.publicKey,
+          redemptionRecord,
+        })
+        .signers([user])
+        .rpc();
+
+      const record = await program.account.redemptionRecord.fetch(redemptionRecord);
+      console.log(`📬 Delivery confirmed at ${record.deliveryConfirmedAt.toString()}`);
+      assert.isTrue(record.deliveryConfirmed);
+      assert.isAtLeast(record.deliveryConfirmedAt.toNumber(), record.fulfilledAt.toNumber());
+    });
+
+    it("❌ Should reject delivery confirmation from a non-recipient", async () => {
+      const stranger = Keypair.generate();
+      await fundAccount(stranger.publicKey, LAMPORTS_PER_SOL);
+
+      try {
+        await program.methods
+          .confirmDelivery()
+          .accounts({
+            user: stranger.publicKey,
+            redemptionRecord,
+          })
+          .signers([stranger])
+          .rpc();
+        assert.fail("Should have failed with unauthorized confirmation");
+      } catch (error) {
+        console.log("✅ Correctly failed with error:", error.message);
+        assert(error.message.includes("Unauthorized"));
+      }
+
+      const record = await program.account.redemptionRecord.fetch(redemptionRecord);
+      assert.isFalse(record.deliveryConfirmed);
+      assert.equal(record.deliveryConfirmedAt.toString(), "0");
+    });
+  });
+
+  describe("📚 Catalog Limit Tests", () => {
+    const randomProductId = () => new anchor.BN(Math.floor(Math.random() * 1_000_000) + 1);
+
+    after(async () => {
+      // Leave the catalog unlimited for any later tests
+      await setMaxProducts(0);
+    });
+
+    it("✅ Should fill the catalog to the cap, reject the next product, then allow more after raising it", async () => {
+      // The redeem state is shared, so cap relative to the products already added
+      const startCount = (await program.account.redeem.fetch(redeemPda)).productCount;
+      const cap = startCount + 2;
+      await setMaxProducts(cap);
+
+      // Fill the catalog up to the cap
+      await addProduct(randomProductId(), 1, 0, 5);
+      await addProduct(randomProductId(), 1, 0, 5);
+
+      let redeemAccount = await program.account.redeem.fetch(redeemPda);
+      assert.equal(redeemAccount.maxProducts, cap);
+      assert.equal(redeemAccount.productCount, cap);
+
+      // One more is rejected
+      try {
+        await addProduct(randomProductId(), 1, 0, 5);
+        assert.fail("Should have failed with product limit reached");
+      } catch (error) {
+        console.log("✅ Correctly failed with error:", error.message);
+        assert(error.message.includes("ProductLimitReached"));
+      }
+
+      // Raising the cap allows another product
+      await setMaxProducts(cap + 1);
+      await addProduct(randomProductId(), 1, 0, 5);
+
+      redeemAccount = await program.account.redeem.fetch(redeemPda);
+      assert.equal(redeemAccount.productCount, cap + 1);
+    });
+
+    it("✅ Should only count active products toward the cap", async () => {
+      async function setActive(productId: anchor.BN, active: boolean) {
+        await program.methods
+          .setProductActive(productId, active)
+          .accounts({
+            authority: authority.publicKey,
+            redeem: redeemPda,
+            product: findProductPda(productId),
+          })
+          .signers([authority])
+          .rpc();
+      }
+
+      const startCount = (await program.account.redeem.fetch(redeemPda)).productCount;
+      const cap = startCount + 1;
+      await setMaxProducts(cap);
+
+      const retiredId = randomProductId();
+      await addProduct(retiredId, 1, 0, 5);
+
+      // Deactivating a product frees its slot for a new one
+      await setActive(retiredId, false);
+      assert.equal((await program.account.redeem.fetch(redeemPda)).productCount, startCount);
+      await addProduct(randomProductId(), 1, 0, 5);
+      assert.equal((await program.account.redeem.fetch(redeemPda)).productCount, cap);
+
+      // Reactivating needs a free slot too
+      try {
+        await setActive(retiredId, true);
+        assert.fail("Should have failed with product limit reached");
+      } catch (error) {
+        console.log("✅ Correctly failed with error:", error.message);
+        assert(error.message.includes("ProductLimitReached"));
+      }
+
+      await setMaxProducts(cap + 1);
+      await setActive(retiredId, true);
+      assert.equal((await program.account.redeem.fetch(redeemPda)).productCount, cap + 1);
+    });
+
+    it("❌ Should reject cap changes from a non-authority", async () => {
+      const stranger = Keypair.generate();
+      await fundAccount(stranger.publicKey, LAMPORTS_PER_SOL);
+
+      try {
+        await program.methods
+          .setMaxProducts(1)
+          .accounts({
+            authority: stranger.publicKey,
+            redeem: redeemPda,
+          })
+          .signers([stranger])
+          .rpc();
+        assert.fail("Should have failed with unauthorized access");
+      } catch (error) {
+        console.log("✅ Correctly failed with error:", error.message);
+        assert(error.message.includes("Unauthorized"));
+      }
+    });
+  });
+
+  describe("🏦 SOL Withdrawal Tests", () => {
+    async function withdrawSol(signer: Keypair, amount: number) {
+      await program.methods
+        .withdrawSol(new anchor.BN(amount))
+        .accounts({
+          authority: signer.publicKey,
+          redeem: redeemPda,
+          solVault: solVaultPda,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([signer])
+        .rpc();
+    }
+
+    it("✅ Should let the authority withdraw ticket proceeds", async () => {
+      const ticketAmount = 10;
+      const proceeds = SOL_PER_TICKET.toNumber() * ticketAmount;
+      await purchaseTickets(user, ticketAmount);
+
+      const vaultBefore = await connection.getBalance(solVaultPda);
+      const authorityBefore = await connection.getBalance(authority.publicKey);
+
+      await withdrawSol(authority, proceeds);
+
+      const vaultAfter = await connection.getBalance(solVaultPda);
+      const authorityAfter = await connection.getBalance(authority.publicKey);
+
+      console.log(`💰 Withdrew ${proceeds} lamports, vault now holds ${vaultAfter}`);
+      assert.equal(vaultBefore - vaultAfter, proceeds);
+      assert.equal(authorityAfter - authorityBefore, proceeds);
+    });
+
+    it("❌ Should keep the vault rent exempt", async () => {
+      const vaultBalance = await connection.getBalance(solVaultPda);
+
+      try {
+        await withdrawSol(authority, vaultBalance);
+        assert.fail("Should have failed by draining the vault below rent exemption");
+      } catch (error) {
+        console.log("✅ Correctly failed with error:", error.message);
+        assert(error.message.includes("WouldBreakRentExemption"));
+      }
+    });
+
+    it("❌ Should reject a withdrawal leaving one lamport too few", async () => {
+      await purchaseTickets(user, 1);
+      const vaultBalance = await connection.getBalance(solVaultPda);
+      const minBalance = await connection.getMinimumBalanceForRentExemption(0);
+
+      try {
+        await withdrawSol(authority, vaultBalance - minBalance + 1);
+        assert.fail("Should have failed by leaving the vault below rent exemption");
+      } catch (error) {
+        console.log("✅ Correctly failed with error:", error.message);
+        assert(error.message.includes("WouldBreakRentExemption"));
+      }
+
+      // Withdrawing down to exactly the minimum still works
+      await withdrawSol(authority, vaultBalance - minBalance);
+      assert.equal(await connection.getBalance(solVaultPda), minBalance);
+    });
+
+    it("❌ Should reject withdrawals from a non-authority", async () => {
+      try {
+        await withdrawSol(user, 1);
+        assert.fail("Should have failed with unauthorized signer");
+      } catch (error) {
+        console.log("✅ Correctly failed with error:", error.message);
+        assert(error.message.includes("Unauthorized"));
+      }
+    });
+  });
+
+  describe("💱 Exchange Rate Tests", () => {
+    async function updateExchangeRate(newRate: anchor.BN) {
+      await program.methods
+        .updateExchangeRate(newRate)
+        .accounts({
+          authority: authority.publicKey,
+          redeem: redeemPda,
+        })
+        .signers([authority])
+        .rpc();
+    }
+
+    afterEach(async () => {
+      // Other tests assume the original rate
+      await updateExchangeRate(SOL_PER_TICKET);
+    });
+
+    it("✅ Should charge the new rate on later purchases", async () => {
+      const newRate = SOL_PER_TICKET.muln(2);
+      const ticketAmount = 5;
+
+      await updateExchangeRate(newRate);
+
+      const redeemAccount = await program.account.redeem.fetch(redeemPda);
+      assert.equal(redeemAccount.solPerTicket.toString(), newRate.toString());
+
+      const vaultBefore = await connection.getBalance(solVaultPda);
+      await purchaseTickets(user, ticketAmount);
+      const vaultAfter = await connection.getBalance(solVaultPda);
+
+      assert.equal(vaultAfter - vaultBefore, newRate.toNumber() * ticketAmount);
+    });
+
+    it("❌ Should reject an out-of-bounds rate", async () => {
+      try {
+        await updateExchangeRate(new anchor.BN(1));
+        assert.fail("Should have failed with a rate below the minimum");
+      } catch (error) {
+        console.log("✅ Correctly failed with error:", error.message);
+        assert(error.message.includes("InvalidExchangeRate"));
+      }
+    });
+  });
+
+  describe("🗂️ Product Management Tests", () => {
+    let productId: anchor.BN;
+
+    beforeEach(async () => {
+      productId = new anchor.BN(Math.floor(Math.random() * 1_000_000) + 1);
+      await addProduct(productId, 1, 0, 1);
+    });
+
+    it("❌ Should block redemption of a deactivated product", async () => {
+      await program.methods
+        .setProductActive(productId, false)
+        .accounts({
+          authority: authority.publicKey,
+          redeem: redeemPda,
+          product: findProductPda(productId),
+        })
+        .signers([authority])
+        .rpc();
+
+      const product = await program.account.product.fetch(findProductPda(productId));
+      assert.isFalse(product.isActive);
+
+      await purchaseTickets(user, 1);
+      try {
+        await redeemProduct(user, productId);
+        assert.fail("Should have failed for a deactivated product");
+      } catch (error) {
+        console.log("✅ Correctly failed with error:", error.message);
+        assert(error.message.includes("ProductNotAvailable"));
+      }
+    });
+
+    it("✅ Should restock a sold-out product", async () => {
+      await purchaseTickets(user, 2);
+      await redeemProduct(user, productId);
+
+      await program.methods
+        .restockProduct(productId, 2)
+        .accounts({
+          authority: authority.publicKey,
+          redeem: redeemPda,
+          product: findProductPda(productId),
+        })
+        .signers([authority])
+        .rpc();
+
+      let product = await program.account.product.fetch(findProductPda(productId));
+      assert.equal(product.totalQuantity, 3);
+      assert.equal(product.redeemedQuantity, 1);
+
+      await redeemProduct(user, productId);
+
+      product = await program.account.product.fetch(findProductPda(productId));
+      assert.equal(product.redeemedQuantity, 2);
+    });
+
+    it("❌ Should reject restocking past the maximum quantity", async () => {
+      try {
+        await program.methods
+          .restockProduct(productId, 10_000)
+          .accounts({
+            authority: authority.publicKey,
+            redeem: redeemPda,
+            product: findProductPda(productId),
+          })
+          .signers([authority])
+          .rpc();
+        assert.fail("Should have failed past the maximum quantity");
+      } catch (error) {
+        console.log("✅ Correctly failed with error:", error.message);
+        assert(error.message.includes("ProductQuantityExceeded"));
+      }
+    });
+  });
+
+  describe("↩️ Ticket Refund Tests", () => {
+    const REFUND_FEE_BPS = 500;
+    let refunder: Keypair;
+
+    async function refundTickets(ticketAmount: number) {
+      await program.methods
+        .refundTickets(new anchor.BN(ticketAmount))
+        .accounts({
+          user: refunder.publicKey,
+          redeem: redeemPda,
+          userRedeemAccount: findUserRedeemPda(refunder.publicKey),
+          ticketMint: ticketMint.publicKey,
+          userTicketTokenAccount: anchor.utils.token.associatedAddress({
+            mint: ticketMint.publicKey,
+            owner: refunder.publicKey,
+          }),
+          solVault: solVaultPda,
+          systemProgram: SystemProgram.programId,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .signers([refunder])
+        .rpc();
+    }
+
+    function expectedRefund(ticketAmount: number): number {
+      const gross = SOL_PER_TICKET.toNumber() * ticketAmount;
+      return gross - Math.floor((gross * REFUND_FEE_BPS) / 10_000);
+    }
+
+    beforeEach(async () => {
+      refunder = Keypair.generate();
+      await fundAccount(refunder.publicKey, 2 * LAMPORTS_PER_SOL);
+      await purchaseTickets(refunder, 10);
+    });
+
+    it("✅ Should refund all tickets", async () => {
+      const vaultBefore = await connection.getBalance(solVaultPda);
+      const mintedBefore = (await program.account.redeem.fetch(redeemPda)).totalTicketsMinted;
+
+      await refundTickets(10);
+
+      const vaultAfter = await connection.getBalance(solVaultPda);
+      const userAccount = await program.account.userRedeemAccount.fetch(
+        findUserRedeemPda(refunder.publicKey)
+      );
+      const redeemAccount = await program.account.redeem.fetch(redeemPda);
+
+      assert.equal(vaultBefore - vaultAfter, expectedRefund(10));
+      assert.equal(userAccount.ticketBalance.toNumber(), 0);
+      assert.equal(mintedBefore.sub(redeemAccount.totalTicketsMinted).toNumber(), 10);
+    });
+
+    it("✅ Should refund part of the tickets", async () => {
+      const vaultBefore = await connection.getBalance(solVaultPda);
+
+      await refundTickets(4);
+
+      const vaultAfter = await connection.getBalance(solVaultPda);
+      const userAccount = await program.account.userRedeemAccount.fetch(
+        findUserRedeemPda(refunder.publicKey)
+      );
+      const tokenBalance = await connection.getTokenAccountBalance(
+        anchor.utils.token.associatedAddress({
+          mint: ticketMint.publicKey,
+          owner: refunder.publicKey,
+        })
+      );
+
+      assert.equal(vaultBefore - vaultAfter, expectedRefund(4));
+      assert.equal(userAccount.ticketBalance.toNumber(), 6);
+      assert.equal(tokenBalance.value.amount, "6");
+    });
+
+    it("❌ Should reject refunding more than the balance", async () => {
+      try {
+        await refundTickets(11);
+        assert.fail("Should have failed with insufficient tickets");
+      } catch (error) {
+        console.log("✅ Correctly failed with error:", error.message);
+        assert(error.message.includes("InsufficientTickets"));
+      }
+    });
+  });
+
+  describe("🏷️ Volume Discount Tests", () => {
+    const TIERS = [
+      { threshold: new anchor.BN(10), discountBps: 300 },
+      { threshold: new anchor.BN(50), discountBps: 500 },
+    ];
+    let buyer: Keypair;
+
+    async function setDiscountTiers(tiers: { threshold: anchor.BN; discountBps: number }[]) {
+      await program.methods
+        .setDiscountTiers(tiers)
+        .accounts({
+          authority: authority.publicKey,
+          redeem: redeemPda,
+        })
+        .signers([authority])
+        .rpc();
+    }
+
+    async function vaultIncreaseFor(ticketAmount: number): Promise<number> {
+      const vaultBefore = await connection.getBalance(solVaultPda);
+      await purchaseTickets(buyer, ticketAmount);
+      return (await connection.getBalance(solVaultPda)) - vaultBefore;
+    }
+
+    beforeEach(async () => {
+      buyer = Keypair.generate();
+      await fundAccount(buyer.publicKey, 2 * LAMPORTS_PER_SOL);
+      await setDiscountTiers(TIERS);
+    });
+
+    afterEach(async () => {
+      await setDiscountTiers([]);
+    });
+
+    it("✅ Should charge full price below the first tier", async () => {
+      assert.equal(await vaultIncreaseFor(9), SOL_PER_TICKET.toNumber() * 9);
+    });
+
+    it("✅ Should apply the best matching tier", async () => {
+      const rate = SOL_PER_TICKET.toNumber();
+
+      assert.equal(await vaultIncreaseFor(10), (rate * 10 * 9_700) / 10_000);
+      assert.equal(await vaultIncreaseFor(50), (rate * 50 * 9_500) / 10_000);
+    });
+
+    it("❌ Should reject discounts above the refund fee", async () => {
+      try {
+        await setDiscountTiers([{ threshold: new anchor.BN(10), discountBps: 501 }]);
+        assert.fail("Should have failed with invalid discount tiers");
+      } catch (error) {
+        console.log("✅ Correctly failed with error:", error.message);
+        assert(error.message.includes("InvalidDiscountTiers"));
+      }
+    });
+  });
+
+  describe("⏸️ System Pause Tests", () => {
+    async function setSystemActive(active: boolean) {
+      await program.methods
+        .setSystemActive(active)
+        .accounts({
+          authority: authority.publicKey,
+          redeem: redeemPda,
+        })
+        .signers([authority])
+        .rpc();
+    }
+
+    afterEach(async () => {
+      await setSystemActive(true);
+    });
+
+    it("❌ Should block purchases while paused", async () => {
+      await setSystemActive(false);
+
+      const redeemAccount = await program.account.redeem.fetch(redeemPda);
+      assert.isFalse(redeemAccount.isActive);
+
+      try {
+        await purchaseTickets(user, 1);
+        assert.fail("Should have failed with system not active");
+      } catch (error) {
+        console.log("✅ Correctly failed with error:", error.message);
+        assert(error.message.includes("SystemNotActive"));
+      }
+    });
+
+    it("✅ Should allow purchases again once resumed", async () => {
+      await setSystemActive(false);
+      await setSystemActive(true);
+
+      const before = await program.account.userRedeemAccount.fetch(findUserRedeemPda(user.publicKey));
+      await purchaseTickets(user, 1);
+      const after = await program.account.userRedeemAccount.fetch(findUserRedeemPda(user.publicKey));
+
+      assert.equal(after.ticketBalance.sub(before.ticketBalance).toNumber(), 1);
+    });
+
+    it("❌ Should only let the authority pause the system", async () => {
+      const stranger = Keypair.generate();
+      await fundAccount(stranger.publicKey, LAMPORTS_PER_SOL);
+
+      try {
+        await program.methods
+          .setSystemActive(false)
+          .accounts({
+            authority: stranger.publicKey,
+            redeem: redeemPda,
+          })
+          .signers([stranger])
+          .rpc();
+        assert.fail("Should have failed with unauthorized");
+      } catch (error) {
+        console.log("✅ Correctly failed with error:", error.message);
+        assert(error.message.includes("Unauthorized"));
+      }
+    });
+  });
+
+  describe("⌛ Product Expiry Tests", () => {
+    const EXPIRY_SECONDS = 3;
+
+    async function chainTime(): Promise<number> {
+      const slot = await connection.getSlot();
+      return await connection.getBlockTime(slot);
+    }
+
+    it("✅ Should redeem a limited-time product before it expires", async () => {
+      const productId = new anchor.BN(Math.floor(Math.random() * 1_000_000) + 1);
+      await addProduct(productId, 1, 0, 5, 0, (await chainTime()) + 60);
+      await purchaseTickets(user, 1);
+
+      await redeemProduct(user, productId);
+
+      const product = await program.account.product.fetch(findProductPda(productId));
+      assert.equal(product.redeemedQuantity, 1);
+    });
+
+    it("❌ Should reject redemption once the product expires", async () => {
+      const productId = new anchor.BN(Math.floor(Math.random() * 1_000_000) + 1);
+      const expiresAt = (await chainTime()) + EXPIRY_SECONDS;
+      await addProduct(productId, 1, 0, 5, 0, expiresAt);
+      await purchaseTickets(user, 1);
+
+      while ((await chainTime()) <= expiresAt) {
+        await new Promise((resolve) => setTimeout(resolve, 500));
+      }
+
+      try {
+        await redeemProduct(user, productId);
+        assert.fail("Should have failed with product expired");
+      } catch (error) {
+        console.log("✅ Correctly failed with error:", error.message);
+        assert(error.message.includes("ProductExpired"));
+      }
+    });
+
+    it("❌ Should reject adding a product that has already expired", async () => {
+      const productId = new anchor.BN(Math.floor(Math.random() * 1_000_000) + 1);
+
+      try {
+        await addProduct(productId, 1, 0, 5, 0, (await chainTime()) - 1);
+        assert.fail("Should have failed with invalid product");
+      } catch (error) {
+        console.log("✅ Correctly failed with error:", error.message);
+        assert(error.message.includes("InvalidProduct"));
+      }
+    });
+  });
+
+  describe("🏷️ Product Category Tests", () => {
+    it("✅ Should store the category and find products by it", async () => {
+      const digitalId = new anchor.BN(Math.floor(Math.random() * 1_000_000) + 1);
+      const merchId = digitalId.addn(1);
+      await addProduct(digitalId, 1, 0, 5, 0, 0, CATEGORY_DIGITAL);
+      await addProduct(merchId, 1, 0, 5, 0, 0, CATEGORY_MERCH);
+
+      const product = await program.account.product.fetch(findProductPda(digitalId));
+      assert.equal(product.category, CATEGORY_DIGITAL);
+
+      // Clients browse a category with a memcmp filter on the category byte
+      const digitalProducts = await program.account.product.all([
+        {
+          memcmp: {
+            offset: PRODUCT_CATEGORY_OFFSET,
+            bytes: anchor.utils.bytes.bs58.encode(Buffer.from([CATEGORY_DIGITAL])),
+          },
+        },
+      ]);
+      const ids = digitalProducts.map((p) => p.account.id.toString());
+
+      assert.include(ids, digitalId.toString());
+      assert.notInclude(ids, merchId.toString());
+      digitalProducts.forEach((p) => assert.equal(p.account.category, CATEGORY_DIGITAL));
+    });
+
+    it("❌ Should reject an unknown category", async () => {
+      const productId = new anchor.BN(Math.floor(Math.random() * 1_000_000) + 1);
+
+      try {
+        await addProduct(productId, 1, 0, 5, 0, 0, 99);
+        assert.fail("Should have failed with invalid product");
+      } catch (error) {
+        console.log("✅ Correctly failed with error:", error.message);
+        assert(error.message.includes("InvalidProduct"));
+      }
+    });
+  });
+
+  describe("🎀 Gift Redemption Tests", () => {
+    let payer: Keypair;
+    let recipient: Keypair;
+    let productId: anchor.BN;
+
+    async function giftProduct(recipientKey: PublicKey): Promise<PublicKey> {
+      const redemptionRecord = await findRedemptionRecordPda(payer.publicKey, productId);
+      await program.methods
+        .giftProduct(productId, recipientKey)
+        .accounts({
+          payer: payer.publicKey,
+          redeem: redeemPda,
+          product: findProductPda(productId),
+          userRedeemAccount: findUserRedeemPda(payer.publicKey),
+          ticketMint: ticketMint.publicKey,
+          payerTicketTokenAccount: anchor.utils.token.associatedAddress({
+            mint: ticketMint.publicKey,
+            owner: payer.publicKey,
+          }),
+          redemptionRecord,
+          recipientProductClaim: findUserProductClaimPda(recipientKey, productId),
+          solVault: solVaultPda,
+          systemProgram: SystemProgram.programId,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .signers([payer])
+        .rpc();
+      return redemptionRecord;
+    }
+
+    beforeEach(async () => {
+      payer = Keypair.generate();
+      recipient = Keypair.generate();
+      await fundAccount(payer.publicKey, 2 * LAMPORTS_PER_SOL);
+      await fundAccount(recipient.publicKey, LAMPORTS_PER_SOL);
+      productId = new anchor.BN(Math.floor(Math.random() * 1_000_000) + 1);
+      await addProduct(productId, 3, 0, 5, 1);
+      await purchaseTickets(payer, 5);
+    });
+
+    it("✅ Should debit the payer and attribute the redemption to the recipient", async () => {
+      const redemptionRecord = await giftProduct(recipient.publicKey);
+
+      const record = await program.account.redemptionRecord.fetch(redemptionRecord);
+      assert.equal(record.user.toString(), recipient.publicKey.toString());
+      assert.equal(record.ticketsUsed.toNumber(), 3);
+
+      const payerAccount = await program.account.userRedeemAccount.fetch(findUserRedeemPda(payer.publicKey));
+      assert.equal(payerAccount.ticketBalance.toNumber(), 2);
+      assert.equal(payerAccount.productsRedeemed, 1);
+
+      // The gift counts toward the recipient's per-user limit
+      const claim = await program.account.userProductClaim.fetch(
+        findUserProductClaimPda(recipient.publicKey, productId)
+      );
+      assert.equal(claim.redeemedCount, 1);
+
+      // The recipient confirms delivery like any other redemption
+      await markFulfilled(redemptionRecord);
+      await program.methods
+        .confirmDelivery()
+        .accounts({
+          user: recipient.publicKey,
+          redemptionRecord,
+        })
+        .signers([recipient])
+        .rpc();
+      assert.isTrue((await program.account.redemptionRecord.fetch(redemptionRecord)).deliveryConfirmed);
+    });
+
+    it("❌ Should reject a gift the payer can't afford", async () => {
+      await giftProduct(Keypair.generate().publicKey);
+
+      try {
+        await giftProduct(Keypair.generate().publicKey);
+        assert.fail("Should have failed with insufficient tickets");
+      } catch (error) {
+        console.log("✅ Correctly failed with error:", error.message);
+        assert(error.message.includes("InsufficientTickets"));
+      }
+    });
+  });
+
+  describe("🔑 Authority Transfer Tests", () => {
+    async function transferAuthority(signer: Keypair, newAuthority: PublicKey) {
+      await program.methods
+        .transferAuthority(newAuthority)
+        .accounts({
+          authority: signer.publicKey,
+          redeem: redeemPda,
+        })
+        .signers([signer])
+        .rpc();
+    }
+
+    async function acceptAuthority(signer: Keypair) {
+      await program.methods
+        .acceptAuthority()
+        .accounts({
+          newAuthority: signer.publicKey,
+          redeem: redeemPda,
+        })
+        .signers([signer])
+        .rpc();
+    }
+
+    it("✅ Should hand over authority once the new authority accepts", async () => {
+      const newAuthority = Keypair.generate();
+      await fundAccount(newAuthority.publicKey, LAMPORTS_PER_SOL);
+
+      await transferAuthority(authority, newAuthority.publicKey);
+
+      // Nothing changes until the transfer is accepted
+      let redeemAccount = await program.account.redeem.fetch(redeemPda);
+      assert.equal(redeemAccount.authority.toString(), authority.publicKey.toString());
+      assert.equal(redeemAccount.pendingAuthority.toString(), newAuthority.publicKey.toString());
+
+      await acceptAuthority(newAuthority);
+
+      redeemAccount = await program.account.redeem.fetch(redeemPda);
+      assert.equal(redeemAccount.authority.toString(), newAuthority.publicKey.toString());
+      assert.isNull(redeemAccount.pendingAuthority);
+
+      // Hand it back so later tests keep using the original authority
+      await transferAuthority(newAuthority, authority.publicKey);
+      await acceptAuthority(authority);
+
+      redeemAccount = await program.account.redeem.fetch(redeemPda);
+      assert.equal(redeemAccount.authority.toString(), authority.publicKey.toString());
+    });
+
+    it("❌ Should reject a transfer from a non-authority", async () => {
+      const stranger = Keypair.generate();
+      await fundAccount(stranger.publicKey, LAMPORTS_PER_SOL);
+
+      try {
+        await transferAuthority(stranger, stranger.publicKey);
+        assert.fail("Should have failed with unauthorized");
+      } catch (error) {
+        console.log("✅ Correctly failed with error:", error.message);
+        assert(error.message.includes("Unauthorized"));
+      }
+    });
+
+    it("❌ Should reject an accept from anyone but the pending authority", async () => {
+      const proposed = Keypair.generate();
+      const stranger = Keypair.generate();
+      await fundAccount(stranger.publicKey, LAMPORTS_PER_SOL);
+
+      await transferAuthority(authority, proposed.publicKey);
+
+      try {
+        await acceptAuthority(stranger);
+        assert.fail("Should have failed with not pending authority");
+      } catch (error) {
+        console.log("✅ Correctly failed with error:", error.message);
+        assert(error.message.includes("NotPendingAuthority"));
+      }
+
+      const redeemAccount = await program.account.redeem.fetch(redeemPda);
+      assert.equal(redeemAccount.authority.toString(), authority.publicKey.toString());
+    });
+  });
+
+  // Runs last: it mints the rest of the supply, so later purchases would fail
+  describe("🔁 Purchase Nonce Tests", () => {
+    let buyer: Keypair;
+
+    beforeEach(async () => {
+      buyer = Keypair.generate();
+      await fundAccount(buyer.publicKey, LAMPORTS_PER_SOL);
+    });
+
+    it("✅ Should accept purchases carrying the next nonce", async () => {
+      await purchaseTickets(buyer, 1, new anchor.BN(0));
+      await purchaseTickets(buyer, 2, new anchor.BN(1));
+
+      const userAccount = await program.account.userRedeemAccount.fetch(findUserRedeemPda(buyer.publicKey));
+      assert.equal(userAccount.purchaseNonce.toNumber(), 2);
+      assert.equal(userAccount.ticketBalance.toNumber(), 3);
+    });
+
+    it("❌ Should reject a replayed purchase with a stale nonce", async () => {
+      await purchaseTickets(buyer, 1, new anchor.BN(0));
+
+      try {
+        await purchaseTickets(buyer, 1, new anchor.BN(0));
+        assert.fail("Should have failed with stale nonce");
+      } catch (error) {
+        console.log("✅ Correctly failed with error:", error.message);
+        assert(error.message.includes("StaleNonce"));
+      }
+
+      const userAccount = await program.account.userRedeemAccount.fetch(findUserRedeemPda(buyer.publicKey));
+      assert.equal(userAccount.ticketBalance.toNumber(), 1);
+    });
+  });
+
+  describe("🧢 Supply Cap Tests", () => {
+    let buyer: Keypair;
+    let remaining: number;
+
+    before(async () => {
+      const redeemAccount = await program.account.redeem.fetch(redeemPda);
+      remaining = MAX_TICKET_SUPPLY.sub(redeemAccount.totalTicketsMinted).toNumber();
+
+      buyer = Keypair.generate();
+      await fundAccount(
+        buyer.publicKey,
+        remaining * redeemAccount.solPerTicket.toNumber() + LAMPORTS_PER_SOL
+      );
+    });
+
+    it("✅ Should allow purchases up to the cap", async () => {
+      assert.isAbove(remaining, 0);
+
+      await purchaseTickets(buyer, remaining);
+
+      const redeemAccount = await program.account.redeem.fetch(redeemPda);
+      assert.equal(redeemAccount.totalTicketsMinted.toString(), MAX_TICKET_SUPPLY.toString());
+    });
+
+    it("❌ Should reject purchases beyond the cap", async () => {
+      try {
+        await purchaseTickets(buyer, 1);
+        assert.fail("Should have failed with supply cap reached");
+      } catch (error) {
+        console.log("✅ Correctly failed with error:", error.message);
+        assert(error.message.includes("SupplyCapReached"));
+      }
+
+      const redeemAccount = await program.account.redeem.fetch(redeemPda);
+      assert.equal(redeemAccount.totalTicketsMinted.toString(), MAX_TICKET_SUPPLY.toString());
+    });
   });
 });
@@ -8,10 +8,34 @@ pub const POLL_SEED: &[u8] = b"poll";
 // This ensures one vote receipt per voter per poll
 pub const VOTE_SEED: &[u8] = b"vote";
 
+// Seed for Runoff Vote Receipt PDAs: ["runoff_vote", poll.key(), voter.key()]
+// Runoff votes get their own receipt so first-round voters can vote again
+pub const RUNOFF_VOTE_SEED: &[u8] = b"runoff_vote";
+
+// Seed for Delegation PDAs: ["delegate", poll.key(), delegator.key()]
+// One delegation per delegator per poll; its existence blocks direct voting
+pub const DELEGATE_SEED: &[u8] = b"delegate";
+
+// Seed for Voter Pass PDAs: ["pass", poll.key(), voter.key()]
+// Issued by the creator to each wallet allowed to vote on a restricted poll
+pub const PASS_SEED: &[u8] = b"pass";
+
+// Seed for Option Account PDAs: ["option", poll.key(), index]
+// Large polls keep each option and its count in its own account
+pub const OPTION_SEED: &[u8] = b"option";
+
+// Seed for Creator Stats PDAs: ["creator_stats", creator.key()]
+// One per creator, counting their polls that haven't been closed yet
+pub const CREATOR_STATS_SEED: &[u8] = b"creator_stats";
+
 // Maximum values for validation
 pub const MAX_QUESTION_LENGTH: usize = 200;
 pub const MAX_OPTION_LENGTH: usize = 50;
 pub const MAX_OPTIONS_COUNT: usize = 10;
+pub const MAX_LARGE_POLL_OPTIONS: u8 = 100;
+
+// Maximum polls a single creator can have open at once
+pub const MAX_ACTIVE_POLLS: u32 = 10;
 
 // Minimum poll duration (1 hour in seconds)
 pub const MIN_POLL_DURATION: i64 = 3600;
@@ -19,5 +43,8 @@ pub const MIN_POLL_DURATION: i64 = 3600;
 // Maximum poll duration (30 days in seconds)
 pub const MAX_POLL_DURATION: i64 = 30 * 24 * 3600;
 
+// Basis-point denominator for result percentages (10000 = 100%)
+pub const BPS_DENOMINATOR: u64 = 10_000;
+
 // Anchor discriminator size (8 bytes)
 pub const DISCRIMINATOR_SIZE: usize = 8;
\ No newline at end of file
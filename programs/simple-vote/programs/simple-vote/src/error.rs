@@ -41,4 +41,115 @@ pub enum VoteError {
     
     #[msg("Vote counts and options length mismatch")]
     VoteCountMismatch,
+    
+    #[msg("Runoff duration must be 0 (disabled) or between 1 hour and 30 days")]
+    InvalidRunoffDuration,
+    
+    #[msg("Runoff can only be configured before any votes are cast")]
+    RunoffConfigLocked,
+    
+    #[msg("Poll is in a runoff round, use cast_runoff_vote")]
+    PollInRunoff,
+    
+    #[msg("Poll is not in a runoff round")]
+    PollNotInRunoff,
+    
+    #[msg("Poll must be closed before its accounts can be reclaimed")]
+    PollNotClosed,
+    
+    #[msg("New option is the same as the current vote")]
+    SameOption,
+    
+    #[msg("Commit and reveal durations must be positive and fit within 30 days")]
+    InvalidCommitRevealDuration,
+    
+    #[msg("Commit-reveal can only be configured once, before any votes are cast")]
+    CommitRevealConfigLocked,
+    
+    #[msg("Poll uses commit-reveal voting, use commit_vote and reveal_vote")]
+    CommitRevealRequired,
+    
+    #[msg("Poll does not use commit-reveal voting")]
+    NotCommitRevealPoll,
+    
+    #[msg("Commit window has closed")]
+    CommitWindowClosed,
+    
+    #[msg("Reveal window is not open")]
+    RevealWindowNotOpen,
+    
+    #[msg("Vote has already been revealed")]
+    AlreadyRevealed,
+    
+    #[msg("Revealed vote does not match the commitment")]
+    CommitmentMismatch,
+    
+    #[msg("Not enough votes were cast to meet the poll's quorum")]
+    QuorumNotMet,
+    
+    #[msg("Cannot delegate a vote to yourself")]
+    SelfDelegation,
+    
+    #[msg("Voter has delegated their vote and cannot vote directly")]
+    VoterHasDelegated,
+    
+    #[msg("Delegation is not for this poll and delegate")]
+    InvalidDelegation,
+    
+    #[msg("Delegation has already been used")]
+    DelegationAlreadyUsed,
+    
+    #[msg("Extension must be a positive number of seconds")]
+    InvalidExtension,
+    
+    #[msg("Voter is not on this poll's allowlist")]
+    NotEligible,
+    
+    #[msg("Poll is open to everyone and does not use voter passes")]
+    PollNotRestricted,
+    
+    #[msg("Too many options provided for a large poll (maximum 100)")]
+    TooManyLargePollOptions,
+    
+    #[msg("Poll stores its options inline and has no option accounts")]
+    NoOptionAccounts,
+    
+    #[msg("All of this poll's options have already been added")]
+    AllOptionsAdded,
+    
+    #[msg("Poll's options have not all been added yet")]
+    PollOptionsIncomplete,
+    
+    #[msg("Voting on a large poll requires the chosen option's account")]
+    OptionAccountRequired,
+    
+    #[msg("Option account does not match this poll and option")]
+    InvalidOptionAccount,
+    
+    #[msg("Not supported on polls with option accounts")]
+    UnsupportedForLargePoll,
+    
+    #[msg("Hidden counts can only be configured before any votes are cast")]
+    HiddenCountsConfigLocked,
+    
+    #[msg("Ranking must list distinct valid options, at most one per option")]
+    InvalidRanking,
+    
+    #[msg("Ranked ballots cannot be changed")]
+    RankedVoteFinal,
+    
+    #[msg("Vote deposit can only be configured before any votes are cast")]
+    VoteDepositConfigLocked,
+    
+    #[msg("No vote deposit left to reclaim")]
+    NoDepositToReclaim,
+    
+    #[msg("Voters still have deposits to reclaim from this poll")]
+    DepositsOutstanding,
+    
+    #[msg("Reclaim the vote deposit before closing the receipt")]
+    DepositNotReclaimed,
+    
+    #[msg("Creator already has the maximum number of active polls")]
+    TooManyActivePolls,
 }
\ No newline at end of file
@@ -0,0 +1,66 @@
+use anchor_lang::prelude::*;
+use crate::{constants::*, error::VoteError, state::{OptionAccount, Poll}};
+
+// Accounts needed for adding the next option to a large poll
+#[derive(Accounts)]
+pub struct AddPollOption<'info> {
+    // The poll creator (must sign the transaction and pays for the option)
+    #[account(mut)]
+    pub creator: Signer<'info>,
+    
+    // The large poll (must be owned by the creator)
+    #[account(
+        mut,
+        has_one = creator,                      // Verify creator ownership
+        seeds = [POLL_SEED, creator.key().as_ref(), poll.poll_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub poll: Account<'info, Poll>,
+    
+    // Option account PDA - options are added in index order
+    #[account(
+        init,                                    // Create new option account
+        payer = creator,                        // Creator pays for account creation
+        space = 8 + OptionAccount::INIT_SPACE, // 8 bytes discriminator + option data
+        seeds = [OPTION_SEED, poll.key().as_ref(), &[poll.option_accounts_added]],
+        bump                                    // Anchor finds the canonical bump
+    )]
+    pub option_account: Account<'info, OptionAccount>,
+    
+    // Required system program for account creation
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> AddPollOption<'info> {
+    pub fn add_poll_option(&mut self, label: String) -> Result<()> {
+        if !self.poll.has_option_accounts() {
+            return Err(VoteError::NoOptionAccounts.into());
+        }
+        
+        if self.poll.options_complete() {
+            return Err(VoteError::AllOptionsAdded.into());
+        }
+        
+        if label.len() > MAX_OPTION_LENGTH {
+            return Err(VoteError::OptionTooLong.into());
+        }
+        
+        let index = self.poll.option_accounts_added;
+        
+        self.option_account.set_inner(OptionAccount {
+            poll: self.poll.key(),
+            index,
+            label,
+            vote_count: 0,
+        });
+        
+        self.poll.option_accounts_added += 1;
+        
+        msg!("Option added!");
+        msg!("Poll: {}", self.poll.key());
+        msg!("Option {}: '{}'", index, self.option_account.label);
+        msg!("Options added: {} of {}", self.poll.option_accounts_added, self.poll.option_account_count);
+        
+        Ok(())
+    }
+}
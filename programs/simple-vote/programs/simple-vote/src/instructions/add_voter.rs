@@ -0,0 +1,55 @@
+use anchor_lang::prelude::*;
+use crate::{constants::*, error::VoteError, state::{Poll, VoterPass}};
+
+// Accounts needed for adding a voter to a restricted poll
+#[derive(Accounts)]
+#[instruction(voter: Pubkey)]
+pub struct AddVoter<'info> {
+    // The poll creator (must sign the transaction and pays for the pass)
+    #[account(mut)]
+    pub creator: Signer<'info>,
+    
+    // The restricted poll (must be owned by the creator)
+    #[account(
+        has_one = creator,                      // Verify creator ownership
+        seeds = [POLL_SEED, creator.key().as_ref(), poll.poll_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub poll: Account<'info, Poll>,
+    
+    // Voter pass PDA - its existence is what makes the voter eligible
+    #[account(
+        init,                                    // Create new voter pass
+        payer = creator,                        // Creator pays for account creation
+        space = 8 + VoterPass::INIT_SPACE,     // 8 bytes discriminator + pass data
+        seeds = [PASS_SEED, poll.key().as_ref(), voter.as_ref()],
+        bump                                    // Anchor finds the canonical bump
+    )]
+    pub voter_pass: Account<'info, VoterPass>,
+    
+    // Required system program for account creation
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> AddVoter<'info> {
+    pub fn add_voter(&mut self, voter: Pubkey) -> Result<()> {
+        if !self.poll.restricted {
+            return Err(VoteError::PollNotRestricted.into());
+        }
+        
+        // Get current time
+        let current_time = Clock::get()?.unix_timestamp;
+        
+        self.voter_pass.set_inner(VoterPass {
+            poll: self.poll.key(),
+            voter,
+            issued_at: current_time,
+        });
+        
+        msg!("Voter added!");
+        msg!("Poll: {}", self.poll.key());
+        msg!("Voter: {}", voter);
+        
+        Ok(())
+    }
+}
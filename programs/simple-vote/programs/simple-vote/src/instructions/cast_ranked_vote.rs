@@ -0,0 +1,135 @@
+use anchor_lang::prelude::*;
+use crate::{constants::*, error::VoteError, instructions::{collect_vote_deposit, VoteCast}, state::{Poll, VoteReceipt}};
+
+// Accounts needed for casting a ranked ballot
+#[derive(Accounts)]
+pub struct CastRankedVote<'info> {
+    // The person casting the ballot (must sign the transaction)
+    #[account(mut)]
+    pub voter: Signer<'info>,
+    
+    // The poll being voted on (first preference is added to its counts)
+    #[account(
+        mut,
+        seeds = [POLL_SEED, poll.creator.as_ref(), poll.poll_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub poll: Account<'info, Poll>,
+    
+    // Vote receipt PDA holding the ranking (shares the seeds of cast_vote, so voters pick one)
+    #[account(
+        init,                                    // Create new vote receipt
+        payer = voter,                          // Voter pays for account creation
+        space = 8 + VoteReceipt::INIT_SPACE,   // 8 bytes discriminator + receipt data
+        seeds = [VOTE_SEED, poll.key().as_ref(), voter.key().as_ref()],
+        bump                                    // Anchor finds the canonical bump
+    )]
+    pub vote_receipt: Account<'info, VoteReceipt>,
+    
+    // The voter's own delegation PDA - must not exist (delegators can't vote directly)
+    /// CHECK: only checked for being empty, never read
+    #[account(
+        seeds = [DELEGATE_SEED, poll.key().as_ref(), voter.key().as_ref()],
+        bump
+    )]
+    pub voter_delegation: UncheckedAccount<'info>,
+    
+    // The voter's pass, required only when the poll is restricted
+    /// CHECK: only checked for existence, never read
+    #[account(
+        seeds = [PASS_SEED, poll.key().as_ref(), voter.key().as_ref()],
+        bump
+    )]
+    pub voter_pass: UncheckedAccount<'info>,
+    
+    // Required system program for account creation and the vote deposit
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> CastRankedVote<'info> {
+    pub fn cast_ranked_vote(&mut self, ranking: Vec<u8>) -> Result<()> {
+        // Validate that voting is still open
+        if !self.poll.is_voting_open() {
+            return Err(VoteError::PollNotActive.into());
+        }
+        
+        // Restricted polls only accept voters the creator has added
+        if !self.poll.is_eligible(&self.voter_pass) {
+            return Err(VoteError::NotEligible.into());
+        }
+        
+        // Runoff votes go through cast_runoff_vote with their own receipt
+        if self.poll.is_runoff {
+            return Err(VoteError::PollInRunoff.into());
+        }
+        
+        // Private polls go through commit_vote / reveal_vote
+        if self.poll.is_commit_reveal() {
+            return Err(VoteError::CommitRevealRequired.into());
+        }
+        
+        // Rankings index the inline options, which large polls don't have
+        if self.poll.has_option_accounts() {
+            return Err(VoteError::UnsupportedForLargePoll.into());
+        }
+        
+        // A voter who handed their vote to a representative can't also vote
+        if !self.voter_delegation.data_is_empty() {
+            return Err(VoteError::VoterHasDelegated.into());
+        }
+        
+        // At least one preference, each a distinct valid option
+        if ranking.is_empty() || ranking.len() > self.poll.options.len() {
+            return Err(VoteError::InvalidRanking.into());
+        }
+        for (position, &option_index) in ranking.iter().enumerate() {
+            if !self.poll.is_valid_option(option_index) || ranking[..position].contains(&option_index) {
+                return Err(VoteError::InvalidRanking.into());
+            }
+        }
+        
+        // Escrow the poll's anti-spam deposit (refunded through reclaim_deposit)
+        let deposit_lamports = collect_vote_deposit(&mut self.poll, &self.voter, &self.system_program)?;
+        
+        let current_time = Clock::get()?.unix_timestamp;
+        let first_choice = ranking[0];
+        
+        self.vote_receipt.set_inner(VoteReceipt {
+            poll: self.poll.key(),
+            voter: self.voter.key(),
+            option_index: first_choice,
+            voted_at: current_time,
+            commitment: [0; 32],
+            is_revealed: true,
+            weight: 1,
+            ranking,
+            deposit_lamports,
+        });
+        
+        // First preferences keep the regular tally meaningful; tally_ranked runs the full count
+        self.poll.vote_counts[first_choice as usize] += 1;
+        self.poll.total_votes += 1;
+        
+        let new_count = if self.poll.counts_hidden() { 0 } else { self.poll.vote_counts[first_choice as usize] };
+        
+        emit!(VoteCast {
+            poll: self.poll.key(),
+            voter: self.voter.key(),
+            option_index: first_choice,
+            new_count,
+            timestamp: current_time,
+        });
+        
+        msg!("Ranked vote cast successfully!");
+        msg!("Voter: {}", self.voter.key());
+        msg!("Poll: {}", self.poll.key());
+        msg!("First choice: {}", self.poll.options[first_choice as usize]);
+        msg!("Options ranked: {}", self.vote_receipt.ranking.len());
+        if !self.poll.counts_hidden() {
+            msg!("New vote count for this option: {}", new_count);
+        }
+        msg!("Total votes in poll: {}", self.poll.total_votes);
+        
+        Ok(())
+    }
+}
@@ -0,0 +1,99 @@
+use anchor_lang::prelude::*;
+use crate::{constants::*, error::VoteError, instructions::VoteCast, state::{Poll, VoteReceipt}};
+
+// Accounts needed for casting a vote in a runoff round
+#[derive(Accounts)]
+pub struct CastRunoffVote<'info> {
+    // The person casting the vote (must sign the transaction)
+    #[account(mut)]
+    pub voter: Signer<'info>,
+    
+    // The poll in its runoff round (will be modified to increment vote count)
+    #[account(
+        mut,
+        seeds = [POLL_SEED, poll.creator.as_ref(), poll.poll_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub poll: Account<'info, Poll>,
+    
+    // Runoff receipt PDA - separate from the first-round receipt
+    #[account(
+        init,                                    // Create new vote receipt
+        payer = voter,                          // Voter pays for account creation
+        space = 8 + VoteReceipt::INIT_SPACE,   // 8 bytes discriminator + receipt data
+        seeds = [RUNOFF_VOTE_SEED, poll.key().as_ref(), voter.key().as_ref()],
+        bump                                    // Anchor finds the canonical bump
+    )]
+    pub runoff_receipt: Account<'info, VoteReceipt>,
+    
+    // The voter's pass, required only when the poll is restricted
+    /// CHECK: only checked for existence, never read
+    #[account(
+        seeds = [PASS_SEED, poll.key().as_ref(), voter.key().as_ref()],
+        bump
+    )]
+    pub voter_pass: UncheckedAccount<'info>,
+    
+    // Required system program for account creation
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> CastRunoffVote<'info> {
+    pub fn cast_runoff_vote(&mut self, option_index: u8) -> Result<()> {
+        // Only valid while the poll is in a runoff round
+        if !self.poll.is_runoff {
+            return Err(VoteError::PollNotInRunoff.into());
+        }
+        
+        // Validate that voting is still open
+        if !self.poll.is_voting_open() {
+            return Err(VoteError::PollNotActive.into());
+        }
+        
+        // Restricted polls only accept voters the creator has added
+        if !self.poll.is_eligible(&self.voter_pass) {
+            return Err(VoteError::NotEligible.into());
+        }
+        
+        // Only the tied options are accepted during a runoff
+        if !self.poll.is_valid_option(option_index) {
+            return Err(VoteError::InvalidOption.into());
+        }
+        
+        let current_time = Clock::get()?.unix_timestamp;
+        
+        self.runoff_receipt.set_inner(VoteReceipt {
+            poll: self.poll.key(),
+            voter: self.voter.key(),
+            option_index,
+            voted_at: current_time,
+            commitment: [0; 32],
+            is_revealed: true,
+            weight: 1,
+            ranking: Vec::new(),
+            deposit_lamports: 0,
+        });
+        
+        self.poll.vote_counts[option_index as usize] += 1;
+        self.poll.total_votes += 1;
+        
+        let new_count = if self.poll.counts_hidden() { 0 } else { self.poll.vote_counts[option_index as usize] };
+        
+        emit!(VoteCast {
+            poll: self.poll.key(),
+            voter: self.voter.key(),
+            option_index,
+            new_count,
+            timestamp: current_time,
+        });
+        
+        msg!("Runoff vote cast successfully!");
+        msg!("Voter: {}", self.voter.key());
+        msg!("Option: {}", self.poll.options[option_index as usize]);
+        if !self.poll.counts_hidden() {
+            msg!("New vote count for this option: {}", new_count);
+        }
+        
+        Ok(())
+    }
+}
@@ -1,5 +1,17 @@
 use anchor_lang::prelude::*;
-use crate::{constants::*, error::VoteError, state::{Poll, VoteReceipt}};
+use anchor_lang::system_program::{transfer, Transfer};
+use crate::{constants::*, error::VoteError, state::{Delegation, OptionAccount, Poll, VoteReceipt}};
+
+// Emitted for every counted vote so off-chain UIs can tally live
+#[event]
+pub struct VoteCast {
+    pub poll: Pubkey,
+    pub voter: Pubkey,
+    pub option_index: u8,
+    // 0 while the poll hides its counts until close
+    pub new_count: u64,
+    pub timestamp: i64,
+}
 
 // Accounts needed for casting a vote
 #[derive(Accounts)]
@@ -26,7 +38,27 @@ pub struct CastVote<'info> {
     )]
     pub vote_receipt: Account<'info, VoteReceipt>,
     
-    // Required system program for account creation
+    // The voter's own delegation PDA - must not exist (delegators can't vote directly)
+    /// CHECK: only checked for being empty, never read
+    #[account(
+        seeds = [DELEGATE_SEED, poll.key().as_ref(), voter.key().as_ref()],
+        bump
+    )]
+    pub voter_delegation: UncheckedAccount<'info>,
+    
+    // The voter's pass, required only when the poll is restricted
+    /// CHECK: only checked for existence, never read
+    #[account(
+        seeds = [PASS_SEED, poll.key().as_ref(), voter.key().as_ref()],
+        bump
+    )]
+    pub voter_pass: UncheckedAccount<'info>,
+    
+    // The chosen option's account, required only for large polls
+    #[account(mut)]
+    pub option_account: Option<Account<'info, OptionAccount>>,
+    
+    // Required system program for account creation and the vote deposit
     pub system_program: Program<'info, System>,
 }
 
@@ -35,17 +67,49 @@ impl<'info> CastVote<'info> {
         &mut self,
         option_index: u8,
         bumps: &CastVoteBumps,
+        delegations: &'info [AccountInfo<'info>],
     ) -> Result<()> {
         // Validate that voting is still open
         if !self.poll.is_voting_open() {
             return Err(VoteError::PollNotActive.into());
         }
         
+        // Restricted polls only accept voters the creator has added
+        if !self.poll.is_eligible(&self.voter_pass) {
+            return Err(VoteError::NotEligible.into());
+        }
+        
+        // Runoff votes go through cast_runoff_vote with their own receipt
+        if self.poll.is_runoff {
+            return Err(VoteError::PollInRunoff.into());
+        }
+        
+        // Private polls go through commit_vote / reveal_vote
+        if self.poll.is_commit_reveal() {
+            return Err(VoteError::CommitRevealRequired.into());
+        }
+        
+        // Large polls open for voting once every option has been added
+        if !self.poll.options_complete() {
+            return Err(VoteError::PollOptionsIncomplete.into());
+        }
+        
         // Validate the option index
         if !self.poll.is_valid_option(option_index) {
             return Err(VoteError::InvalidOption.into());
         }
         
+        // A voter who handed their vote to a representative can't also vote
+        if !self.voter_delegation.data_is_empty() {
+            return Err(VoteError::VoterHasDelegated.into());
+        }
+        
+        // Own vote plus every delegation passed in
+        let weight = 1 + self.use_delegations(delegations)?;
+        
+        // Escrow the poll's anti-spam deposit (refunded through reclaim_deposit)
+        let deposit_lamports = collect_vote_deposit(&mut self.poll, &self.voter, &self.system_program)?;
+        
         // Get current time
         let current_time = Clock::get()?.unix_timestamp;
         
@@ -56,22 +120,115 @@ impl<'info> CastVote<'info> {
             voter: self.voter.key(),
             option_index,
             voted_at: current_time,
+            commitment: [0; 32],
+            is_revealed: true,
+            weight,
+            ranking: Vec::new(),
+            deposit_lamports,
         });
         
         // Increment the vote count for the chosen option
-        self.poll.vote_counts[option_index as usize] += 1;
+        let new_count = self.count_vote(option_index, weight)?;
         
         // Increment total vote count
-        self.poll.total_votes += 1;
+        self.poll.total_votes += weight;
+        
+        // Polls hiding their tally keep the new count out of the logs
+        let hidden = self.poll.counts_hidden();
+        
+        emit!(VoteCast {
+            poll: self.poll.key(),
+            voter: self.voter.key(),
+            option_index,
+            new_count: if hidden { 0 } else { new_count },
+            timestamp: current_time,
+        });
         
         msg!("Vote cast successfully!");
         msg!("Voter: {}", self.voter.key());
         msg!("Poll: {}", self.poll.key());
         msg!("Option index: {}", option_index);
-        msg!("Option: {}", self.poll.options[option_index as usize]);
-        msg!("New vote count for this option: {}", self.poll.vote_counts[option_index as usize]);
+        msg!("Vote weight (including delegations): {}", weight);
+        if !hidden {
+            msg!("New vote count for this option: {}", new_count);
+        }
         msg!("Total votes in poll: {}", self.poll.total_votes);
+        if deposit_lamports > 0 {
+            msg!("Deposit held until the poll closes: {} lamports", deposit_lamports);
+        }
         
         Ok(())
     }
+    
+    // Add the vote to the option's count, inline or in its option account
+    // Returns the option's new count
+    fn count_vote(&mut self, option_index: u8, weight: u64) -> Result<u64> {
+        if !self.poll.has_option_accounts() {
+            msg!("Option: {}", self.poll.options[option_index as usize]);
+            let count = &mut self.poll.vote_counts[option_index as usize];
+            *count += weight;
+            return Ok(*count);
+        }
+        
+        let poll_key = self.poll.key();
+        let option_account = self.option_account
+            .as_mut()
+            .ok_or(VoteError::OptionAccountRequired)?;
+        
+        if option_account.poll != poll_key || option_account.index != option_index {
+            return Err(VoteError::InvalidOptionAccount.into());
+        }
+        
+        option_account.vote_count += weight;
+        msg!("Option: {}", option_account.label);
+        
+        Ok(option_account.vote_count)
+    }
+    
+    // Mark each delegation to this voter as used and return how many were counted
+    fn use_delegations(&self, delegations: &'info [AccountInfo<'info>]) -> Result<u64> {
+        let mut count = 0;
+        
+        for info in delegations {
+            let mut delegation = Account::<Delegation>::try_from(info)?;
+            
+            if delegation.poll != self.poll.key() || delegation.delegate != self.voter.key() {
+                return Err(VoteError::InvalidDelegation.into());
+            }
+            
+            // Also catches the same delegation passed twice
+            if delegation.is_used {
+                return Err(VoteError::DelegationAlreadyUsed.into());
+            }
+            
+            delegation.is_used = true;
+            delegation.exit(&crate::ID)?;
+            count += 1;
+        }
+        
+        Ok(count)
+    }
+}
+
+// Move the poll's vote deposit from the voter into the poll account
+// Returns the amount held so the caller can record it on the receipt
+pub fn collect_vote_deposit<'info>(
+    poll: &mut Account<'info, Poll>,
+    voter: &Signer<'info>,
+    system_program: &Program<'info, System>,
+) -> Result<u64> {
+    let deposit = poll.vote_deposit_lamports;
+    if deposit == 0 {
+        return Ok(0);
+    }
+    
+    let transfer_accounts = Transfer {
+        from: voter.to_account_info(),
+        to: poll.to_account_info(),
+    };
+    transfer(CpiContext::new(system_program.to_account_info(), transfer_accounts), deposit)?;
+    
+    poll.deposits_held += deposit;
+    
+    Ok(deposit)
 }
@@ -0,0 +1,87 @@
+use anchor_lang::prelude::*;
+use crate::{constants::*, error::VoteError, state::{Poll, VoteReceipt}};
+
+// Accounts needed for changing an existing vote
+#[derive(Accounts)]
+pub struct ChangeVote<'info> {
+    // The person changing their vote (must sign the transaction)
+    pub voter: Signer<'info>,
+    
+    // The poll being voted on (vote counts are moved between options)
+    #[account(
+        mut,
+        seeds = [POLL_SEED, poll.creator.as_ref(), poll.poll_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub poll: Account<'info, Poll>,
+    
+    // The voter's existing first-round receipt
+    #[account(
+        mut,
+        has_one = voter,                        // Only the voter can change their vote
+        has_one = poll,                         // Receipt must belong to this poll
+        seeds = [VOTE_SEED, poll.key().as_ref(), voter.key().as_ref()],
+        bump
+    )]
+    pub vote_receipt: Account<'info, VoteReceipt>,
+}
+
+impl<'info> ChangeVote<'info> {
+    pub fn change_vote(&mut self, new_option_index: u8) -> Result<()> {
+        // Validate that voting is still open
+        if !self.poll.is_voting_open() {
+            return Err(VoteError::PollNotActive.into());
+        }
+        
+        // First-round counts may have been reset for the runoff
+        if self.poll.is_runoff {
+            return Err(VoteError::PollInRunoff.into());
+        }
+        
+        // Moving a vote between option accounts isn't supported
+        if self.poll.has_option_accounts() {
+            return Err(VoteError::UnsupportedForLargePoll.into());
+        }
+        
+        // Committed votes are final once revealed
+        if self.poll.is_commit_reveal() {
+            return Err(VoteError::CommitRevealRequired.into());
+        }
+        
+        // Validate the option index
+        if !self.poll.is_valid_option(new_option_index) {
+            return Err(VoteError::InvalidOption.into());
+        }
+        
+        // A ranked ballot's later preferences would no longer match the moved vote
+        if !self.vote_receipt.ranking.is_empty() {
+            return Err(VoteError::RankedVoteFinal.into());
+        }
+        
+        let old_option_index = self.vote_receipt.option_index;
+        if new_option_index == old_option_index {
+            return Err(VoteError::SameOption.into());
+        }
+        
+        // Move the vote (with any delegated weight) to the new option (total votes unchanged)
+        let weight = self.vote_receipt.weight;
+        let old_count = &mut self.poll.vote_counts[old_option_index as usize];
+        *old_count = old_count
+            .checked_sub(weight)
+            .ok_or(VoteError::VoteCountMismatch)?;
+        self.poll.vote_counts[new_option_index as usize] += weight;
+        
+        // Update the receipt to reflect the new choice
+        self.vote_receipt.option_index = new_option_index;
+        self.vote_receipt.voted_at = Clock::get()?.unix_timestamp;
+        
+        msg!("Vote changed successfully!");
+        msg!("Voter: {}", self.voter.key());
+        msg!("Poll: {}", self.poll.key());
+        msg!("From option: {}", self.poll.options[old_option_index as usize]);
+        msg!("To option: {}", self.poll.options[new_option_index as usize]);
+        msg!("Total votes in poll: {}", self.poll.total_votes);
+        
+        Ok(())
+    }
+}
@@ -1,5 +1,16 @@
 use anchor_lang::prelude::*;
-use crate::{constants::*, error::VoteError, state::Poll};
+use crate::{constants::*, error::VoteError, state::{CreatorStats, Poll, WinnerResult}};
+
+// Emitted when voting ends (not when a tie reopens the poll for a runoff)
+// winner_index is None when there is no valid winner (no votes, a tie, or quorum not met)
+// or when the counts are kept in option accounts
+#[event]
+pub struct PollClosed {
+    pub poll: Pubkey,
+    pub total_votes: u64,
+    pub winner_index: Option<u8>,
+    pub winner_votes: u64,
+}
 
 // Accounts needed for closing a poll
 #[derive(Accounts)]
@@ -16,6 +27,14 @@ pub struct ClosePoll<'info> {
         bump
     )]
     pub poll: Account<'info, Poll>,
+    
+    // The creator's open-poll counter, freed up once the poll actually closes
+    #[account(
+        mut,
+        seeds = [CREATOR_STATS_SEED, creator.key().as_ref()],
+        bump = creator_stats.bump
+    )]
+    pub creator_stats: Account<'info, CreatorStats>,
 }
 
 impl<'info> ClosePoll<'info> {
@@ -33,10 +52,35 @@ impl<'info> ClosePoll<'info> {
         
         // Allow closing if:
         // 1. Poll has naturally expired, OR
-        // 2. Creator wants to close early (we'll allow this for flexibility)
+        // 2. Creator wants to close early and the poll was created to allow it
+        if !has_expired && !self.poll.allow_early_close {
+            return Err(VoteError::PollStillActive.into());
+        }
+        
+        // A tie reopens voting between the tied options if a runoff is configured
+        if self.poll.runoff_enabled() {
+            let tied_options = self.poll.get_tied_options();
+            if !tied_options.is_empty() {
+                return self.start_runoff(tied_options, current_time);
+            }
+        }
         
         // Mark poll as inactive
         self.poll.is_active = false;
+        self.creator_stats.record_poll_closed();
+        
+        let result = self.poll.get_winner();
+        let (winner_index, winner_votes) = match &result {
+            WinnerResult::Winner(index, votes) => (Some(*index as u8), *votes),
+            WinnerResult::Tie(_, votes) | WinnerResult::NoQuorum(votes) => (None, *votes),
+            WinnerResult::NoVotes => (None, 0),
+        };
+        emit!(PollClosed {
+            poll: self.poll.key(),
+            total_votes: self.poll.total_votes,
+            winner_index,
+            winner_votes,
+        });
         
         // Log the poll results
         msg!("Poll closed successfully!");
@@ -51,15 +95,56 @@ impl<'info> ClosePoll<'info> {
             msg!("Option {}: '{}' - {} votes", index, option, votes);
         }
         
-        // Announce the winner if there are votes
-        if let Some((winner_index, winner_votes)) = self.poll.get_winner() {
-            msg!("Winner: '{}' with {} votes!", 
-                self.poll.options[winner_index], 
-                winner_votes
-            );
-        } else {
-            msg!("No votes were cast on this poll.");
+        // Announce the result
+        match result {
+            WinnerResult::NoQuorum(_) => {
+                msg!("Quorum not met: {} of {} votes", self.poll.total_votes, self.poll.quorum);
+            }
+            // Large poll counts live in the option accounts, not the poll
+            _ if self.poll.has_option_accounts() => {
+                msg!("Results are in the poll's {} option accounts", self.poll.option_account_count);
+            }
+            WinnerResult::Winner(winner_index, winner_votes) => {
+                msg!("Winner: '{}' with {} votes!", 
+                    self.poll.options[winner_index], 
+                    winner_votes
+                );
+            }
+            WinnerResult::Tie(tied, votes) => {
+                let tied_list: Vec<String> = tied.iter().map(|index| index.to_string()).collect();
+                msg!("Tie between options {} with {} votes each", tied_list.join(" and "), votes);
+            }
+            WinnerResult::NoVotes => {
+                msg!("No votes were cast on this poll.");
+            }
+        }
+        
+        Ok(())
+    }
+    
+    // Reopen the poll for a runoff round between the tied options only
+    fn start_runoff(&mut self, tied_options: Vec<u8>, current_time: i64) -> Result<()> {
+        let poll = &mut self.poll;
+        
+        poll.is_runoff = true;
+        poll.runoff_options = tied_options;
+        poll.end_time = current_time
+            .checked_add(poll.runoff_duration)
+            .ok_or(VoteError::InvalidRunoffDuration)?;
+        
+        // Start the runoff from zero unless the creator chose to carry counts over
+        if !poll.preserve_counts_on_runoff {
+            poll.vote_counts.iter_mut().for_each(|count| *count = 0);
+            poll.total_votes = 0;
+        }
+        
+        msg!("Tie detected - poll reopened for a runoff!");
+        msg!("Poll ID: {}", poll.poll_id);
+        for &index in poll.runoff_options.iter() {
+            msg!("Runoff option {}: '{}'", index, poll.options[index as usize]);
         }
+        msg!("Counts preserved: {}", poll.preserve_counts_on_runoff);
+        msg!("Runoff ends at: {}", poll.end_time);
         
         Ok(())
     }
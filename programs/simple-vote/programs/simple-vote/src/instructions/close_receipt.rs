@@ -0,0 +1,47 @@
+use anchor_lang::prelude::*;
+use crate::{error::VoteError, state::{Poll, VoteReceipt}};
+
+// Accounts needed for closing a vote receipt
+#[derive(Accounts)]
+pub struct CloseReceipt<'info> {
+    // The voter who owns the receipt (must sign and receives the rent)
+    #[account(mut)]
+    pub voter: Signer<'info>,
+    
+    // The poll the receipt was issued for
+    /// CHECK: may already have been reclaimed by the creator, so it is read manually
+    #[account(address = vote_receipt.poll)]
+    pub poll: UncheckedAccount<'info>,
+    
+    // The receipt to close (first-round or runoff receipt)
+    #[account(
+        mut,
+        has_one = voter,                        // Only the voter can close their receipt
+        close = voter,                          // Return rent to the voter
+    )]
+    pub vote_receipt: Account<'info, VoteReceipt>,
+}
+
+impl<'info> CloseReceipt<'info> {
+    pub fn close_receipt(&mut self) -> Result<()> {
+        // The deposit is held by the poll, so closing the receipt would lose track of it
+        if self.vote_receipt.deposit_lamports > 0 {
+            return Err(VoteError::DepositNotReclaimed.into());
+        }
+        
+        // A poll that still exists must have been closed first;
+        // a reclaimed poll has no data left and is no longer owned by this program
+        if self.poll.owner == &crate::ID && !self.poll.data_is_empty() {
+            let poll = Poll::try_deserialize(&mut &self.poll.data.borrow()[..])?;
+            if poll.is_active {
+                return Err(VoteError::PollNotClosed.into());
+            }
+        }
+        
+        msg!("Vote receipt closed!");
+        msg!("Poll: {}", self.poll.key());
+        msg!("Returned to voter: {}", self.voter.key());
+        
+        Ok(())
+    }
+}
@@ -0,0 +1,83 @@
+use anchor_lang::prelude::*;
+use crate::{constants::*, error::VoteError, instructions::collect_vote_deposit, state::{Poll, VoteReceipt}};
+
+// Accounts needed for committing a hidden vote
+#[derive(Accounts)]
+pub struct CommitVote<'info> {
+    // The person committing the vote (must sign the transaction)
+    #[account(mut)]
+    pub voter: Signer<'info>,
+    
+    // The commit-reveal poll being voted on (only its held deposits change until the reveal)
+    #[account(
+        mut,
+        seeds = [POLL_SEED, poll.creator.as_ref(), poll.poll_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub poll: Account<'info, Poll>,
+    
+    // Vote receipt PDA holding the commitment (also prevents committing twice)
+    #[account(
+        init,                                    // Create new vote receipt
+        payer = voter,                          // Voter pays for account creation
+        space = 8 + VoteReceipt::INIT_SPACE,   // 8 bytes discriminator + receipt data
+        seeds = [VOTE_SEED, poll.key().as_ref(), voter.key().as_ref()],
+        bump                                    // Anchor finds the canonical bump
+    )]
+    pub vote_receipt: Account<'info, VoteReceipt>,
+    
+    // The voter's pass, required only when the poll is restricted
+    /// CHECK: only checked for existence, never read
+    #[account(
+        seeds = [PASS_SEED, poll.key().as_ref(), voter.key().as_ref()],
+        bump
+    )]
+    pub voter_pass: UncheckedAccount<'info>,
+    
+    // Required system program for account creation and the vote deposit
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> CommitVote<'info> {
+    pub fn commit_vote(&mut self, commitment: [u8; 32]) -> Result<()> {
+        if !self.poll.is_commit_reveal() {
+            return Err(VoteError::NotCommitRevealPoll.into());
+        }
+        
+        // Commitments are only accepted during the commit window
+        if !self.poll.is_commit_open() {
+            return Err(VoteError::CommitWindowClosed.into());
+        }
+        
+        // Restricted polls only accept voters the creator has added
+        if !self.poll.is_eligible(&self.voter_pass) {
+            return Err(VoteError::NotEligible.into());
+        }
+        
+        // Escrow the poll's anti-spam deposit (refunded through reclaim_deposit)
+        let deposit_lamports = collect_vote_deposit(&mut self.poll, &self.voter, &self.system_program)?;
+        
+        // Get current time
+        let current_time = Clock::get()?.unix_timestamp;
+        
+        // The chosen option stays hidden until the reveal
+        self.vote_receipt.set_inner(VoteReceipt {
+            poll: self.poll.key(),
+            voter: self.voter.key(),
+            option_index: 0,
+            voted_at: current_time,
+            commitment,
+            is_revealed: false,
+            weight: 1,
+            ranking: Vec::new(),
+            deposit_lamports,
+        });
+        
+        msg!("Vote committed successfully!");
+        msg!("Voter: {}", self.voter.key());
+        msg!("Poll: {}", self.poll.key());
+        msg!("Reveal opens at: {}", self.poll.commit_end_time);
+        
+        Ok(())
+    }
+}
@@ -0,0 +1,63 @@
+use anchor_lang::prelude::*;
+use crate::{constants::*, error::VoteError, state::Poll};
+
+// Accounts needed for switching a poll to commit-reveal voting
+#[derive(Accounts)]
+pub struct ConfigureCommitReveal<'info> {
+    // The poll creator (must sign the transaction)
+    pub creator: Signer<'info>,
+    
+    // The poll being configured (must be owned by the creator)
+    #[account(
+        mut,
+        has_one = creator,                      // Verify creator ownership
+        seeds = [POLL_SEED, creator.key().as_ref(), poll.poll_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub poll: Account<'info, Poll>,
+}
+
+impl<'info> ConfigureCommitReveal<'info> {
+    pub fn configure_commit_reveal(
+        &mut self,
+        commit_duration: i64,
+        reveal_duration: i64,
+    ) -> Result<()> {
+        // Rules can't change once voting is underway
+        if !self.poll.is_active || self.poll.is_runoff {
+            return Err(VoteError::PollNotActive.into());
+        }
+        
+        // Option accounts hold the counts, which ties and reveals can't read
+        if self.poll.has_option_accounts() {
+            return Err(VoteError::UnsupportedForLargePoll.into());
+        }
+        
+        if self.poll.total_votes > 0 || self.poll.is_commit_reveal() {
+            return Err(VoteError::CommitRevealConfigLocked.into());
+        }
+        
+        // Both windows must exist and together stay within the maximum poll duration
+        let total_duration = commit_duration
+            .checked_add(reveal_duration)
+            .ok_or(VoteError::InvalidCommitRevealDuration)?;
+        if commit_duration <= 0 || reveal_duration <= 0 || total_duration > MAX_POLL_DURATION {
+            return Err(VoteError::InvalidCommitRevealDuration.into());
+        }
+        
+        // Get current time
+        let current_time = Clock::get()?.unix_timestamp;
+        
+        // The poll ends when the reveal window does
+        self.poll.commit_end_time = current_time + commit_duration;
+        self.poll.reveal_end_time = self.poll.commit_end_time + reveal_duration;
+        self.poll.end_time = self.poll.reveal_end_time;
+        
+        msg!("Commit-reveal configured!");
+        msg!("Poll ID: {}", self.poll.poll_id);
+        msg!("Commit window ends at: {}", self.poll.commit_end_time);
+        msg!("Reveal window ends at: {}", self.poll.reveal_end_time);
+        
+        Ok(())
+    }
+}
@@ -0,0 +1,39 @@
+use anchor_lang::prelude::*;
+use crate::{constants::*, error::VoteError, state::Poll};
+
+// Accounts needed for hiding a poll's running tally
+#[derive(Accounts)]
+pub struct ConfigureHiddenCounts<'info> {
+    // The poll creator (must sign the transaction)
+    pub creator: Signer<'info>,
+    
+    // The poll being configured (must be owned by the creator)
+    #[account(
+        mut,
+        has_one = creator,                      // Verify creator ownership
+        seeds = [POLL_SEED, creator.key().as_ref(), poll.poll_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub poll: Account<'info, Poll>,
+}
+
+impl<'info> ConfigureHiddenCounts<'info> {
+    pub fn configure_hidden_counts(&mut self, hide_counts_until_close: bool) -> Result<()> {
+        if !self.poll.is_active {
+            return Err(VoteError::PollNotActive.into());
+        }
+        
+        // Once votes are in, earlier logs have already shown the tally
+        if self.poll.total_votes > 0 {
+            return Err(VoteError::HiddenCountsConfigLocked.into());
+        }
+        
+        self.poll.hide_counts_until_close = hide_counts_until_close;
+        
+        msg!("Hidden counts configured!");
+        msg!("Poll ID: {}", self.poll.poll_id);
+        msg!("Hide counts until close: {}", hide_counts_until_close);
+        
+        Ok(())
+    }
+}
@@ -0,0 +1,57 @@
+use anchor_lang::prelude::*;
+use crate::{constants::*, error::VoteError, state::Poll};
+
+// Accounts needed for configuring tie runoffs on a poll
+#[derive(Accounts)]
+pub struct ConfigureRunoff<'info> {
+    // The poll creator (must sign the transaction)
+    pub creator: Signer<'info>,
+    
+    // The poll being configured (must be owned by the creator)
+    #[account(
+        mut,
+        has_one = creator,                      // Verify creator ownership
+        seeds = [POLL_SEED, creator.key().as_ref(), poll.poll_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub poll: Account<'info, Poll>,
+}
+
+impl<'info> ConfigureRunoff<'info> {
+    pub fn configure_runoff(
+        &mut self,
+        runoff_duration: i64,
+        preserve_counts: bool,
+    ) -> Result<()> {
+        // Rules can't change once voting is underway
+        if !self.poll.is_active || self.poll.is_runoff {
+            return Err(VoteError::PollNotActive.into());
+        }
+        
+        // Option accounts hold the counts, which ties and reveals can't read
+        if self.poll.has_option_accounts() {
+            return Err(VoteError::UnsupportedForLargePoll.into());
+        }
+        
+        if self.poll.total_votes > 0 {
+            return Err(VoteError::RunoffConfigLocked.into());
+        }
+        
+        // 0 disables the runoff, otherwise it follows the normal poll duration limits
+        if runoff_duration != 0
+            && !(MIN_POLL_DURATION..=MAX_POLL_DURATION).contains(&runoff_duration)
+        {
+            return Err(VoteError::InvalidRunoffDuration.into());
+        }
+        
+        self.poll.runoff_duration = runoff_duration;
+        self.poll.preserve_counts_on_runoff = preserve_counts;
+        
+        msg!("Runoff configured!");
+        msg!("Poll ID: {}", self.poll.poll_id);
+        msg!("Runoff duration: {} seconds", runoff_duration);
+        msg!("Preserve counts: {}", preserve_counts);
+        
+        Ok(())
+    }
+}
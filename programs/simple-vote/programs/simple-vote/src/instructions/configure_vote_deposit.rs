@@ -0,0 +1,39 @@
+use anchor_lang::prelude::*;
+use crate::{constants::*, error::VoteError, state::Poll};
+
+// Accounts needed for requiring a refundable deposit per vote
+#[derive(Accounts)]
+pub struct ConfigureVoteDeposit<'info> {
+    // The poll creator (must sign the transaction)
+    pub creator: Signer<'info>,
+    
+    // The poll being configured (must be owned by the creator)
+    #[account(
+        mut,
+        has_one = creator,                      // Verify creator ownership
+        seeds = [POLL_SEED, creator.key().as_ref(), poll.poll_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub poll: Account<'info, Poll>,
+}
+
+impl<'info> ConfigureVoteDeposit<'info> {
+    pub fn configure_vote_deposit(&mut self, vote_deposit_lamports: u64) -> Result<()> {
+        if !self.poll.is_active {
+            return Err(VoteError::PollNotActive.into());
+        }
+        
+        // Every voter pays the same deposit, so it can't change once voting starts
+        if self.poll.total_votes > 0 || self.poll.deposits_held > 0 {
+            return Err(VoteError::VoteDepositConfigLocked.into());
+        }
+        
+        self.poll.vote_deposit_lamports = vote_deposit_lamports;
+        
+        msg!("Vote deposit configured!");
+        msg!("Poll ID: {}", self.poll.poll_id);
+        msg!("Deposit per vote: {} lamports", vote_deposit_lamports);
+        
+        Ok(())
+    }
+}
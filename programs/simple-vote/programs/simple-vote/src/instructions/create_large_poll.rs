@@ -0,0 +1,136 @@
+use anchor_lang::prelude::*;
+use crate::{constants::*, error::VoteError, state::{CreatorStats, Poll}};
+
+// Accounts needed for creating a poll whose options live in OptionAccount PDAs
+#[derive(Accounts)]
+#[instruction(poll_id: u64)]
+pub struct CreateLargePoll<'info> {
+    // The person creating the poll (must sign the transaction)
+    #[account(mut)]
+    pub creator: Signer<'info>,
+    
+    // The poll account (PDA) - will be created with no inline options
+    #[account(
+        init,                                    // Create new account
+        payer = creator,                        // Creator pays for account creation
+        space = 8 + Poll::INIT_SPACE,          // 8 bytes discriminator + poll data
+        seeds = [POLL_SEED, creator.key().as_ref(), poll_id.to_le_bytes().as_ref()],
+        bump                                    // Anchor finds the canonical bump
+    )]
+    pub poll: Account<'info, Poll>,
+    
+    // The creator's open-poll counter (PDA) - created with their first poll
+    #[account(
+        init_if_needed,
+        payer = creator,
+        space = 8 + CreatorStats::INIT_SPACE,
+        seeds = [CREATOR_STATS_SEED, creator.key().as_ref()],
+        bump
+    )]
+    pub creator_stats: Account<'info, CreatorStats>,
+    
+    // Required system program for account creation
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> CreateLargePoll<'info> {
+    #[allow(clippy::too_many_arguments)]
+    pub fn create_large_poll(
+        &mut self,
+        poll_id: u64,
+        question: String,
+        option_count: u8,
+        duration_seconds: i64,
+        quorum: u64,
+        restricted: bool,
+        allow_early_close: bool,
+        bumps: &CreateLargePollBumps,
+    ) -> Result<()> {
+        // Input validation
+        self.validate_inputs(&question, option_count, duration_seconds)?;
+        
+        // Count the poll against the creator's active limit
+        self.creator_stats.creator = self.creator.key();
+        self.creator_stats.bump = bumps.creator_stats;
+        self.creator_stats.record_poll_created()?;
+        
+        // Get current time
+        let current_time = Clock::get()?.unix_timestamp;
+        
+        // Calculate end time
+        let end_time = current_time + duration_seconds;
+        
+        // Options and counts are added afterwards with add_poll_option
+        self.poll.set_inner(Poll {
+            creator: self.creator.key(),
+            poll_id,
+            question,
+            options: Vec::new(),
+            vote_counts: Vec::new(),
+            end_time,
+            is_active: true,
+            total_votes: 0,
+            quorum,
+            quorum_met: false,
+            restricted,
+            allow_early_close,
+            created_at: current_time,
+            runoff_duration: 0,
+            preserve_counts_on_runoff: false,
+            is_runoff: false,
+            runoff_options: Vec::new(),
+            commit_end_time: 0,
+            reveal_end_time: 0,
+            option_account_count: option_count,
+            option_accounts_added: 0,
+            hide_counts_until_close: false,
+            vote_deposit_lamports: 0,
+            deposits_held: 0,
+        });
+        
+        msg!("Large poll created successfully!");
+        msg!("Poll ID: {}", poll_id);
+        msg!("Creator: {}", self.creator.key());
+        msg!("Options to add: {}", option_count);
+        msg!("End time: {}", end_time);
+        msg!("Quorum: {}", quorum);
+        msg!("Restricted: {}", restricted);
+        msg!("Early close allowed: {}", allow_early_close);
+        
+        Ok(())
+    }
+    
+    // Validation helper function
+    fn validate_inputs(
+        &self,
+        question: &str,
+        option_count: u8,
+        duration_seconds: i64,
+    ) -> Result<()> {
+        // Check question length
+        if question.len() > MAX_QUESTION_LENGTH {
+            return Err(VoteError::QuestionTooLong.into());
+        }
+        
+        // Check minimum options
+        if option_count < 2 {
+            return Err(VoteError::NotEnoughOptions.into());
+        }
+        
+        // Check maximum options
+        if option_count > MAX_LARGE_POLL_OPTIONS {
+            return Err(VoteError::TooManyLargePollOptions.into());
+        }
+        
+        // Check poll duration
+        if duration_seconds < MIN_POLL_DURATION {
+            return Err(VoteError::PollDurationTooShort.into());
+        }
+        
+        if duration_seconds > MAX_POLL_DURATION {
+            return Err(VoteError::PollDurationTooLong.into());
+        }
+        
+        Ok(())
+    }
+}
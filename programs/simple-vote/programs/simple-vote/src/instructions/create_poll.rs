@@ -1,5 +1,5 @@
 use anchor_lang::prelude::*;
-use crate::{constants::*, error::VoteError, state::Poll};
+use crate::{constants::*, error::VoteError, state::{CreatorStats, Poll}};
 
 // Accounts needed for creating a new poll
 #[derive(Accounts)]
@@ -19,22 +19,41 @@ pub struct CreatePoll<'info> {
     )]
     pub poll: Account<'info, Poll>,
     
+    // The creator's open-poll counter (PDA) - created with their first poll
+    #[account(
+        init_if_needed,
+        payer = creator,
+        space = 8 + CreatorStats::INIT_SPACE,
+        seeds = [CREATOR_STATS_SEED, creator.key().as_ref()],
+        bump
+    )]
+    pub creator_stats: Account<'info, CreatorStats>,
+    
     // Required system program for account creation
     pub system_program: Program<'info, System>,
 }
 
 impl<'info> CreatePoll<'info> {
+    #[allow(clippy::too_many_arguments)]
     pub fn create_poll(
         &mut self,
         poll_id: u64,
         question: String,
         options: Vec<String>,
         duration_seconds: i64,
+        quorum: u64,
+        restricted: bool,
+        allow_early_close: bool,
         bumps: &CreatePollBumps,
     ) -> Result<()> {
         // Input validation
         self.validate_inputs(&question, &options, duration_seconds)?;
         
+        // Count the poll against the creator's active limit
+        self.creator_stats.creator = self.creator.key();
+        self.creator_stats.bump = bumps.creator_stats;
+        self.creator_stats.record_poll_created()?;
+        
         // Get current time
         let current_time = Clock::get()?.unix_timestamp;
         
@@ -54,13 +73,31 @@ impl<'info> CreatePoll<'info> {
             end_time,
             is_active: true,
             total_votes: 0,
+            quorum,
+            quorum_met: false,
+            restricted,
+            allow_early_close,
             created_at: current_time,
+            runoff_duration: 0,
+            preserve_counts_on_runoff: false,
+            is_runoff: false,
+            runoff_options: Vec::new(),
+            commit_end_time: 0,
+            reveal_end_time: 0,
+            option_account_count: 0,
+            option_accounts_added: 0,
+            hide_counts_until_close: false,
+            vote_deposit_lamports: 0,
+            deposits_held: 0,
         });
         
         msg!("Poll created successfully!");
         msg!("Poll ID: {}", poll_id);
         msg!("Creator: {}", self.creator.key());
         msg!("End time: {}", end_time);
+        msg!("Quorum: {}", quorum);
+        msg!("Restricted: {}", restricted);
+        msg!("Early close allowed: {}", allow_early_close);
         
         Ok(())
     }
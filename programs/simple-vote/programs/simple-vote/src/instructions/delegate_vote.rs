@@ -0,0 +1,96 @@
+use anchor_lang::prelude::*;
+use crate::{constants::*, error::VoteError, state::{Delegation, Poll}};
+
+// Accounts needed for delegating a vote
+#[derive(Accounts)]
+pub struct DelegateVote<'info> {
+    // The person handing over their vote (must sign the transaction)
+    #[account(mut)]
+    pub delegator: Signer<'info>,
+    
+    // The poll the delegation applies to
+    #[account(
+        seeds = [POLL_SEED, poll.creator.as_ref(), poll.poll_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub poll: Account<'info, Poll>,
+    
+    // Delegation PDA - one per delegator per poll
+    #[account(
+        init,                                    // Create new delegation
+        payer = delegator,                      // Delegator pays for account creation
+        space = 8 + Delegation::INIT_SPACE,    // 8 bytes discriminator + delegation data
+        seeds = [DELEGATE_SEED, poll.key().as_ref(), delegator.key().as_ref()],
+        bump                                    // Anchor finds the canonical bump
+    )]
+    pub delegation: Account<'info, Delegation>,
+    
+    // The delegator's vote receipt PDA - must not exist (voters can't also delegate)
+    /// CHECK: only checked for being empty, never read
+    #[account(
+        seeds = [VOTE_SEED, poll.key().as_ref(), delegator.key().as_ref()],
+        bump
+    )]
+    pub delegator_receipt: UncheckedAccount<'info>,
+    
+    // The delegator's pass, required only when the poll is restricted
+    /// CHECK: only checked for existence, never read
+    #[account(
+        seeds = [PASS_SEED, poll.key().as_ref(), delegator.key().as_ref()],
+        bump
+    )]
+    pub delegator_pass: UncheckedAccount<'info>,
+    
+    // Required system program for account creation
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> DelegateVote<'info> {
+    pub fn delegate_vote(&mut self, delegate: Pubkey) -> Result<()> {
+        // Validate that voting is still open
+        if !self.poll.is_voting_open() {
+            return Err(VoteError::PollNotActive.into());
+        }
+        
+        // Restricted polls only accept voters the creator has added
+        if !self.poll.is_eligible(&self.delegator_pass) {
+            return Err(VoteError::NotEligible.into());
+        }
+        
+        // Delegated votes are only counted in the first round of open polls
+        if self.poll.is_runoff {
+            return Err(VoteError::PollInRunoff.into());
+        }
+        
+        if self.poll.is_commit_reveal() {
+            return Err(VoteError::CommitRevealRequired.into());
+        }
+        
+        if delegate == self.delegator.key() {
+            return Err(VoteError::SelfDelegation.into());
+        }
+        
+        // A voter who already voted directly can't hand the vote out again
+        if !self.delegator_receipt.data_is_empty() {
+            return Err(VoteError::AlreadyVoted.into());
+        }
+        
+        // Get current time
+        let current_time = Clock::get()?.unix_timestamp;
+        
+        self.delegation.set_inner(Delegation {
+            poll: self.poll.key(),
+            delegator: self.delegator.key(),
+            delegate,
+            is_used: false,
+            created_at: current_time,
+        });
+        
+        msg!("Vote delegated successfully!");
+        msg!("Delegator: {}", self.delegator.key());
+        msg!("Delegate: {}", delegate);
+        msg!("Poll: {}", self.poll.key());
+        
+        Ok(())
+    }
+}
@@ -0,0 +1,54 @@
+use anchor_lang::prelude::*;
+use crate::{constants::*, error::VoteError, state::Poll};
+
+// Accounts needed for extending a poll's deadline
+#[derive(Accounts)]
+pub struct ExtendPoll<'info> {
+    // The poll creator (must sign the transaction)
+    pub creator: Signer<'info>,
+    
+    // The poll being extended (must be owned by the creator)
+    #[account(
+        mut,
+        has_one = creator,                      // Verify creator ownership
+        seeds = [POLL_SEED, creator.key().as_ref(), poll.poll_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub poll: Account<'info, Poll>,
+}
+
+impl<'info> ExtendPoll<'info> {
+    pub fn extend_poll(&mut self, additional_seconds: i64) -> Result<()> {
+        // Closed or expired polls can't be reopened this way
+        if !self.poll.is_voting_open() {
+            return Err(VoteError::PollEnded.into());
+        }
+        
+        if additional_seconds <= 0 {
+            return Err(VoteError::InvalidExtension.into());
+        }
+        
+        let new_end_time = self.poll.end_time
+            .checked_add(additional_seconds)
+            .ok_or(VoteError::PollDurationTooLong)?;
+        
+        // The whole poll, measured from creation, must stay within the maximum duration
+        if new_end_time - self.poll.created_at > MAX_POLL_DURATION {
+            return Err(VoteError::PollDurationTooLong.into());
+        }
+        
+        self.poll.end_time = new_end_time;
+        
+        // Commit-reveal polls end with their reveal window, so extend the reveal
+        if self.poll.is_commit_reveal() {
+            self.poll.reveal_end_time = new_end_time;
+        }
+        
+        msg!("Poll extended!");
+        msg!("Poll ID: {}", self.poll.poll_id);
+        msg!("Added: {} seconds", additional_seconds);
+        msg!("New end time: {}", new_end_time);
+        
+        Ok(())
+    }
+}
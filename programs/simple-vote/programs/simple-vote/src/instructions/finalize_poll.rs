@@ -0,0 +1,48 @@
+use anchor_lang::prelude::*;
+use crate::{constants::*, error::VoteError, state::{Poll, WinnerResult}};
+
+// Accounts needed for finalizing a closed poll's result
+#[derive(Accounts)]
+pub struct FinalizePoll<'info> {
+    // The poll creator (must sign the transaction)
+    pub creator: Signer<'info>,
+    
+    // The closed poll (must be owned by the creator)
+    #[account(
+        mut,
+        has_one = creator,                      // Verify creator ownership
+        seeds = [POLL_SEED, creator.key().as_ref(), poll.poll_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub poll: Account<'info, Poll>,
+}
+
+impl<'info> FinalizePoll<'info> {
+    pub fn finalize_poll(&mut self) -> Result<()> {
+        // Results are only final once voting has been closed
+        if self.poll.is_active {
+            return Err(VoteError::PollNotClosed.into());
+        }
+        
+        // Below quorum the result doesn't count (quorum_met stays false)
+        if !self.poll.has_quorum() {
+            msg!("Quorum not met: {} of {} votes", self.poll.total_votes, self.poll.quorum);
+            return Err(VoteError::QuorumNotMet.into());
+        }
+        
+        self.poll.quorum_met = true;
+        
+        msg!("Poll finalized!");
+        msg!("Poll ID: {}", self.poll.poll_id);
+        msg!("Total votes: {} (quorum {})", self.poll.total_votes, self.poll.quorum);
+        
+        if let WinnerResult::Winner(winner_index, winner_votes) = self.poll.get_winner() {
+            msg!("Winner: '{}' with {} votes!", 
+                self.poll.options[winner_index], 
+                winner_votes
+            );
+        }
+        
+        Ok(())
+    }
+}
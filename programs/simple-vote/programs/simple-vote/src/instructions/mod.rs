@@ -1,10 +1,48 @@
 // Export all instruction modules
 
 pub mod create_poll;
+pub mod create_large_poll;
+pub mod add_poll_option;
+pub mod add_voter;
 pub mod cast_vote;
+pub mod delegate_vote;
+pub mod revoke_delegation;
 pub mod close_poll;
+pub mod finalize_poll;
+pub mod extend_poll;
+pub mod configure_runoff;
+pub mod cast_runoff_vote;
+pub mod reclaim_poll;
+pub mod close_receipt;
+pub mod change_vote;
+pub mod configure_commit_reveal;
+pub mod commit_vote;
+pub mod reveal_vote;
+pub mod configure_hidden_counts;
+pub mod cast_ranked_vote;
+pub mod configure_vote_deposit;
+pub mod reclaim_deposit;
 
 // Re-export the instruction structs for easy access
 pub use create_poll::*;
+pub use create_large_poll::*;
+pub use add_poll_option::*;
+pub use add_voter::*;
 pub use cast_vote::*;
-pub use close_poll::*;
\ No newline at end of file
+pub use delegate_vote::*;
+pub use revoke_delegation::*;
+pub use close_poll::*;
+pub use finalize_poll::*;
+pub use extend_poll::*;
+pub use configure_runoff::*;
+pub use cast_runoff_vote::*;
+pub use reclaim_poll::*;
+pub use close_receipt::*;
+pub use change_vote::*;
+pub use configure_commit_reveal::*;
+pub use commit_vote::*;
+pub use reveal_vote::*;
+pub use configure_hidden_counts::*;
+pub use cast_ranked_vote::*;
+pub use configure_vote_deposit::*;
+pub use reclaim_deposit::*;
\ No newline at end of file
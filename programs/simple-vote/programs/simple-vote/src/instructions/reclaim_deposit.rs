@@ -0,0 +1,56 @@
+use anchor_lang::prelude::*;
+use crate::{constants::*, error::VoteError, state::{Poll, VoteReceipt}};
+
+// Accounts needed for reclaiming a vote deposit
+#[derive(Accounts)]
+pub struct ReclaimDeposit<'info> {
+    // The voter who paid the deposit (must sign and receives the refund)
+    #[account(mut)]
+    pub voter: Signer<'info>,
+    
+    // The closed poll holding the deposit
+    #[account(
+        mut,
+        seeds = [POLL_SEED, poll.creator.as_ref(), poll.poll_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub poll: Account<'info, Poll>,
+    
+    // The voter's receipt recording how much they deposited
+    #[account(
+        mut,
+        has_one = voter,                        // Only the voter can reclaim their deposit
+        has_one = poll,                         // Receipt must be for this poll
+        seeds = [VOTE_SEED, poll.key().as_ref(), voter.key().as_ref()],
+        bump
+    )]
+    pub vote_receipt: Account<'info, VoteReceipt>,
+}
+
+impl<'info> ReclaimDeposit<'info> {
+    pub fn reclaim_deposit(&mut self) -> Result<()> {
+        // Deposits stay locked while voting (or a runoff) is still going
+        if self.poll.is_active {
+            return Err(VoteError::PollNotClosed.into());
+        }
+        
+        let deposit = self.vote_receipt.deposit_lamports;
+        if deposit == 0 {
+            return Err(VoteError::NoDepositToReclaim.into());
+        }
+        
+        // The poll is owned by this program, so its lamports can be moved directly;
+        // only the held deposits are taken, never the poll's rent
+        self.poll.sub_lamports(deposit)?;
+        self.voter.add_lamports(deposit)?;
+        
+        self.poll.deposits_held -= deposit;
+        self.vote_receipt.deposit_lamports = 0;
+        
+        msg!("Vote deposit reclaimed!");
+        msg!("Poll: {}", self.poll.key());
+        msg!("Returned to voter: {} ({} lamports)", self.voter.key(), deposit);
+        
+        Ok(())
+    }
+}
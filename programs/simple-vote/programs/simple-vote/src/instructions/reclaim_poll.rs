@@ -0,0 +1,41 @@
+use anchor_lang::prelude::*;
+use crate::{constants::*, error::VoteError, state::Poll};
+
+// Accounts needed for reclaiming a closed poll's rent
+#[derive(Accounts)]
+pub struct ReclaimPoll<'info> {
+    // The poll creator (must sign the transaction and receives the rent)
+    #[account(mut)]
+    pub creator: Signer<'info>,
+    
+    // The closed poll (account is closed and rent returned to the creator)
+    #[account(
+        mut,
+        has_one = creator,                      // Verify creator ownership
+        close = creator,                        // Return rent to the creator
+        seeds = [POLL_SEED, creator.key().as_ref(), poll.poll_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub poll: Account<'info, Poll>,
+}
+
+impl<'info> ReclaimPoll<'info> {
+    pub fn reclaim_poll(&mut self) -> Result<()> {
+        // Only polls that went through close_poll can be reclaimed
+        // (a tie with a runoff configured keeps the poll active)
+        if self.poll.is_active {
+            return Err(VoteError::PollNotClosed.into());
+        }
+        
+        // Closing the poll would sweep voters' unclaimed deposits to the creator
+        if self.poll.deposits_held > 0 {
+            return Err(VoteError::DepositsOutstanding.into());
+        }
+        
+        msg!("Poll rent reclaimed!");
+        msg!("Poll ID: {}", self.poll.poll_id);
+        msg!("Returned to creator: {}", self.creator.key());
+        
+        Ok(())
+    }
+}
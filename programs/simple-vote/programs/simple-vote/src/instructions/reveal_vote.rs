@@ -0,0 +1,87 @@
+use anchor_lang::prelude::*;
+use crate::{constants::*, error::VoteError, instructions::VoteCast, state::{compute_commitment, Poll, VoteReceipt}};
+
+// Accounts needed for revealing a committed vote
+#[derive(Accounts)]
+pub struct RevealVote<'info> {
+    // The person revealing their vote (must sign the transaction)
+    pub voter: Signer<'info>,
+    
+    // The poll being voted on (will be modified to increment vote count)
+    #[account(
+        mut,
+        seeds = [POLL_SEED, poll.creator.as_ref(), poll.poll_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub poll: Account<'info, Poll>,
+    
+    // The voter's receipt holding the commitment
+    #[account(
+        mut,
+        has_one = voter,                        // Only the voter can reveal their vote
+        has_one = poll,                         // Receipt must belong to this poll
+        seeds = [VOTE_SEED, poll.key().as_ref(), voter.key().as_ref()],
+        bump
+    )]
+    pub vote_receipt: Account<'info, VoteReceipt>,
+}
+
+impl<'info> RevealVote<'info> {
+    pub fn reveal_vote(&mut self, option_index: u8, salt: [u8; 32]) -> Result<()> {
+        if !self.poll.is_commit_reveal() {
+            return Err(VoteError::NotCommitRevealPoll.into());
+        }
+        
+        // Reveals are only accepted after commits close and before the poll ends
+        if !self.poll.is_reveal_open() {
+            return Err(VoteError::RevealWindowNotOpen.into());
+        }
+        
+        if self.vote_receipt.is_revealed {
+            return Err(VoteError::AlreadyRevealed.into());
+        }
+        
+        // The revealed choice must hash to what was committed
+        if compute_commitment(option_index, &salt) != self.vote_receipt.commitment {
+            return Err(VoteError::CommitmentMismatch.into());
+        }
+        
+        // Validate the option index
+        if !self.poll.is_valid_option(option_index) {
+            return Err(VoteError::InvalidOption.into());
+        }
+        
+        // Get current time
+        let current_time = Clock::get()?.unix_timestamp;
+        
+        // Record the revealed choice on the receipt
+        self.vote_receipt.option_index = option_index;
+        self.vote_receipt.voted_at = current_time;
+        self.vote_receipt.is_revealed = true;
+        
+        // Tally the vote
+        self.poll.vote_counts[option_index as usize] += 1;
+        self.poll.total_votes += 1;
+        
+        let new_count = if self.poll.counts_hidden() { 0 } else { self.poll.vote_counts[option_index as usize] };
+        
+        emit!(VoteCast {
+            poll: self.poll.key(),
+            voter: self.voter.key(),
+            option_index,
+            new_count,
+            timestamp: current_time,
+        });
+        
+        msg!("Vote revealed successfully!");
+        msg!("Voter: {}", self.voter.key());
+        msg!("Poll: {}", self.poll.key());
+        msg!("Option: {}", self.poll.options[option_index as usize]);
+        if !self.poll.counts_hidden() {
+            msg!("New vote count for this option: {}", new_count);
+        }
+        msg!("Total votes in poll: {}", self.poll.total_votes);
+        
+        Ok(())
+    }
+}
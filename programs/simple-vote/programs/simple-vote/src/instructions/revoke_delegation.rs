@@ -0,0 +1,35 @@
+use anchor_lang::prelude::*;
+use crate::{constants::*, error::VoteError, state::Delegation};
+
+// Accounts needed for revoking a delegation
+#[derive(Accounts)]
+pub struct RevokeDelegation<'info> {
+    // The delegator taking their vote back (must sign and receives the rent)
+    #[account(mut)]
+    pub delegator: Signer<'info>,
+    
+    // The delegation to revoke (closed and rent returned to the delegator)
+    #[account(
+        mut,
+        has_one = delegator,                    // Only the delegator can revoke
+        close = delegator,                      // Return rent to the delegator
+        seeds = [DELEGATE_SEED, delegation.poll.as_ref(), delegator.key().as_ref()],
+        bump
+    )]
+    pub delegation: Account<'info, Delegation>,
+}
+
+impl<'info> RevokeDelegation<'info> {
+    pub fn revoke_delegation(&mut self) -> Result<()> {
+        // A counted delegation is part of the tally and can't be taken back
+        if self.delegation.is_used {
+            return Err(VoteError::DelegationAlreadyUsed.into());
+        }
+        
+        msg!("Delegation revoked!");
+        msg!("Delegator: {}", self.delegator.key());
+        msg!("Poll: {}", self.delegation.poll);
+        
+        Ok(())
+    }
+}
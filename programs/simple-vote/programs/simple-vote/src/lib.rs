@@ -16,26 +16,178 @@ pub mod simple_vote {
     use super::*;
 
     // Create a new poll with question, options, and duration
+    #[allow(clippy::too_many_arguments)]
     pub fn create_poll(
         ctx: Context<CreatePoll>,
         poll_id: u64,
         question: String,
         options: Vec<String>,
         duration_seconds: i64,
+        quorum: u64,
+        restricted: bool,
+        allow_early_close: bool,
     ) -> Result<()> {
-        ctx.accounts.create_poll(poll_id, question, options, duration_seconds, &ctx.bumps)
+        ctx.accounts.create_poll(poll_id, question, options, duration_seconds, quorum, restricted, allow_early_close, &ctx.bumps)
     }
 
-    // Cast a vote on an existing poll
-    pub fn cast_vote(
-        ctx: Context<CastVote>,
+    // Create a poll whose options are stored in separate accounts (up to 100 options)
+    #[allow(clippy::too_many_arguments)]
+    pub fn create_large_poll(
+        ctx: Context<CreateLargePoll>,
+        poll_id: u64,
+        question: String,
+        option_count: u8,
+        duration_seconds: i64,
+        quorum: u64,
+        restricted: bool,
+        allow_early_close: bool,
+    ) -> Result<()> {
+        ctx.accounts.create_large_poll(poll_id, question, option_count, duration_seconds, quorum, restricted, allow_early_close, &ctx.bumps)
+    }
+
+    // Add the next option account to a large poll (creator only)
+    pub fn add_poll_option(
+        ctx: Context<AddPollOption>,
+        label: String,
+    ) -> Result<()> {
+        ctx.accounts.add_poll_option(label)
+    }
+
+    // Allow a wallet to vote on a restricted poll (creator only)
+    pub fn add_voter(
+        ctx: Context<AddVoter>,
+        voter: Pubkey,
+    ) -> Result<()> {
+        ctx.accounts.add_voter(voter)
+    }
+
+    // Cast a vote on an existing poll (large polls also pass the chosen option account)
+    // Delegations to the voter can be passed as remaining accounts to add their weight
+    pub fn cast_vote<'info>(
+        ctx: Context<'_, '_, 'info, 'info, CastVote<'info>>,
         option_index: u8,
     ) -> Result<()> {
-        ctx.accounts.cast_vote(option_index, &ctx.bumps)
+        ctx.accounts.cast_vote(option_index, &ctx.bumps, ctx.remaining_accounts)
+    }
+
+    // Cast a ranked ballot; the first preference is counted and the full order kept on the receipt
+    pub fn cast_ranked_vote(
+        ctx: Context<CastRankedVote>,
+        ranking: Vec<u8>,
+    ) -> Result<()> {
+        ctx.accounts.cast_ranked_vote(ranking)
+    }
+
+    // Hand this poll's vote to a representative
+    pub fn delegate_vote(
+        ctx: Context<DelegateVote>,
+        delegate: Pubkey,
+    ) -> Result<()> {
+        ctx.accounts.delegate_vote(delegate)
+    }
+
+    // Take back an unused delegation (delegator only)
+    pub fn revoke_delegation(ctx: Context<RevokeDelegation>) -> Result<()> {
+        ctx.accounts.revoke_delegation()
     }
 
     // Close a poll (creator only)
     pub fn close_poll(ctx: Context<ClosePoll>) -> Result<()> {
         ctx.accounts.close_poll()
     }
+
+    // Push back the voting deadline (creator only, while voting is open)
+    pub fn extend_poll(
+        ctx: Context<ExtendPoll>,
+        additional_seconds: i64,
+    ) -> Result<()> {
+        ctx.accounts.extend_poll(additional_seconds)
+    }
+
+    // Check a closed poll reached its quorum and record the result as valid
+    pub fn finalize_poll(ctx: Context<FinalizePoll>) -> Result<()> {
+        ctx.accounts.finalize_poll()
+    }
+
+    // Configure an automatic runoff between tied options (creator only, before any votes)
+    pub fn configure_runoff(
+        ctx: Context<ConfigureRunoff>,
+        runoff_duration: i64,
+        preserve_counts: bool,
+    ) -> Result<()> {
+        ctx.accounts.configure_runoff(runoff_duration, preserve_counts)
+    }
+
+    // Cast a vote in a poll's runoff round
+    pub fn cast_runoff_vote(
+        ctx: Context<CastRunoffVote>,
+        option_index: u8,
+    ) -> Result<()> {
+        ctx.accounts.cast_runoff_vote(option_index)
+    }
+
+    // Move an existing vote to a different option while voting is open
+    pub fn change_vote(
+        ctx: Context<ChangeVote>,
+        new_option_index: u8,
+    ) -> Result<()> {
+        ctx.accounts.change_vote(new_option_index)
+    }
+
+    // Switch a poll to commit-reveal voting (creator only, before any votes)
+    pub fn configure_commit_reveal(
+        ctx: Context<ConfigureCommitReveal>,
+        commit_duration: i64,
+        reveal_duration: i64,
+    ) -> Result<()> {
+        ctx.accounts.configure_commit_reveal(commit_duration, reveal_duration)
+    }
+
+    // Keep per-option counts out of logs and results until the poll closes (creator only, before any votes)
+    pub fn configure_hidden_counts(
+        ctx: Context<ConfigureHiddenCounts>,
+        hide_counts_until_close: bool,
+    ) -> Result<()> {
+        ctx.accounts.configure_hidden_counts(hide_counts_until_close)
+    }
+
+    // Commit to a hidden vote: hash(option_index || salt)
+    pub fn commit_vote(
+        ctx: Context<CommitVote>,
+        commitment: [u8; 32],
+    ) -> Result<()> {
+        ctx.accounts.commit_vote(commitment)
+    }
+
+    // Reveal a committed vote so it is tallied
+    pub fn reveal_vote(
+        ctx: Context<RevealVote>,
+        option_index: u8,
+        salt: [u8; 32],
+    ) -> Result<()> {
+        ctx.accounts.reveal_vote(option_index, salt)
+    }
+
+    // Require a refundable SOL deposit with every vote (creator only, before any votes)
+    pub fn configure_vote_deposit(
+        ctx: Context<ConfigureVoteDeposit>,
+        vote_deposit_lamports: u64,
+    ) -> Result<()> {
+        ctx.accounts.configure_vote_deposit(vote_deposit_lamports)
+    }
+
+    // Get a vote deposit back from a closed poll (voter only)
+    pub fn reclaim_deposit(ctx: Context<ReclaimDeposit>) -> Result<()> {
+        ctx.accounts.reclaim_deposit()
+    }
+
+    // Reclaim a closed poll's rent (creator only)
+    pub fn reclaim_poll(ctx: Context<ReclaimPoll>) -> Result<()> {
+        ctx.accounts.reclaim_poll()
+    }
+
+    // Reclaim a vote receipt's rent once its poll is closed (voter only)
+    pub fn close_receipt(ctx: Context<CloseReceipt>) -> Result<()> {
+        ctx.accounts.close_receipt()
+    }
 }
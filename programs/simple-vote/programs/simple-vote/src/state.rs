@@ -1,4 +1,7 @@
 use anchor_lang::prelude::*;
+use anchor_lang::solana_program::hash::hashv;
+use crate::constants::{BPS_DENOMINATOR, MAX_ACTIVE_POLLS};
+use crate::error::VoteError;
 
 // The Poll account stores all information about a voting poll
 #[account]
@@ -31,8 +34,73 @@ pub struct Poll {
     // Total number of votes cast
     pub total_votes: u64,
     
+    // Minimum total votes for the result to count (0 = no quorum)
+    pub quorum: u64,
+    
+    // Set by finalize_poll once a closed poll has reached its quorum
+    pub quorum_met: bool,
+    
+    // Whether only voters holding a VoterPass may vote
+    pub restricted: bool,
+    
+    // Whether the creator may close the poll before end_time
+    pub allow_early_close: bool,
+    
     // When this poll was created
     pub created_at: i64,
+    
+    // Runoff duration applied when close_poll detects a tie (0 = runoff disabled)
+    pub runoff_duration: i64,
+    
+    // Whether vote counts carry over into the runoff (false = counts reset to 0)
+    pub preserve_counts_on_runoff: bool,
+    
+    // Whether the poll is currently in its runoff round
+    pub is_runoff: bool,
+    
+    // The tied options still eligible during the runoff (indices into options)
+    #[max_len(10)]
+    pub runoff_options: Vec<u8>,
+    
+    // End of the commit window for commit-reveal voting (0 = commit-reveal disabled)
+    pub commit_end_time: i64,
+    
+    // End of the reveal window; only revealed votes are tallied
+    pub reveal_end_time: i64,
+    
+    // Number of OptionAccount PDAs holding this poll's options (0 = options stored inline)
+    pub option_account_count: u8,
+    
+    // How many of those option accounts the creator has added so far
+    pub option_accounts_added: u8,
+    
+    // Whether per-option counts stay out of logs and results until the poll closes
+    pub hide_counts_until_close: bool,
+    
+    // Refundable SOL deposit each voter pays into the poll when voting (0 = free to vote)
+    pub vote_deposit_lamports: u64,
+    
+    // Deposits held in the poll account until their voters reclaim them
+    pub deposits_held: u64,
+}
+
+// Option Account - one option of a large poll and its vote count
+// Lets polls have more options than fit in the Poll account
+#[account]
+#[derive(InitSpace)]
+pub struct OptionAccount {
+    // Which poll this option belongs to
+    pub poll: Pubkey,
+    
+    // Position of this option in the poll (votes refer to it by index)
+    pub index: u8,
+    
+    // The option text
+    #[max_len(50)]
+    pub label: String,
+    
+    // Votes cast for this option
+    pub vote_count: u64,
 }
 
 // Vote Receipt - proves that a user has voted on a specific poll
@@ -49,8 +117,191 @@ pub struct VoteReceipt {
     // Which option they voted for (index into poll.options)
     pub option_index: u8,
     
-    // When the vote was cast
+    // When the vote was cast (or revealed, for commit-reveal polls)
     pub voted_at: i64,
+    
+    // hash(option_index || salt) committed during the commit window (zeroed for open votes)
+    pub commitment: [u8; 32],
+    
+    // Whether the vote has been counted (always true for open votes)
+    pub is_revealed: bool,
+    
+    // Votes this receipt counts for: 1 plus any delegations used
+    pub weight: u64,
+    
+    // Full preference order for ranked ballots, most preferred first (empty for single-choice votes)
+    #[max_len(10)]
+    pub ranking: Vec<u8>,
+    
+    // Deposit this voter paid into the poll (0 once reclaimed, or if the poll charged none)
+    pub deposit_lamports: u64,
+}
+
+// Voter Pass - allows a wallet to vote on a restricted poll
+// Only the poll creator can issue passes
+#[account]
+#[derive(InitSpace)]
+pub struct VoterPass {
+    // Which poll this pass is valid for
+    pub poll: Pubkey,
+    
+    // The wallet allowed to vote
+    pub voter: Pubkey,
+    
+    // When the pass was issued
+    pub issued_at: i64,
+}
+
+// Delegation - hands a voter's vote on a poll to a representative
+// The delegate adds it to their own vote by passing it to cast_vote
+#[account]
+#[derive(InitSpace)]
+pub struct Delegation {
+    // Which poll this delegation applies to
+    pub poll: Pubkey,
+    
+    // Who gave away their vote
+    pub delegator: Pubkey,
+    
+    // Who votes on the delegator's behalf
+    pub delegate: Pubkey,
+    
+    // Whether the delegate has already counted this delegation
+    pub is_used: bool,
+    
+    // When the delegation was made
+    pub created_at: i64,
+}
+
+// Creator Stats - counts a creator's open polls so one wallet can't flood storage
+// Created on the creator's first poll
+#[account]
+#[derive(InitSpace)]
+pub struct CreatorStats {
+    // The creator these stats belong to
+    pub creator: Pubkey,
+    
+    // Polls created and not yet closed
+    pub active_polls: u32,
+    
+    // Bump seed for the PDA
+    pub bump: u8,
+}
+
+impl CreatorStats {
+    // Count a newly created poll, refusing once the creator is at MAX_ACTIVE_POLLS
+    pub fn record_poll_created(&mut self) -> Result<()> {
+        if self.active_polls >= MAX_ACTIVE_POLLS {
+            return Err(VoteError::TooManyActivePolls.into());
+        }
+        self.active_polls += 1;
+        Ok(())
+    }
+    
+    // Free up a slot once a poll closes
+    pub fn record_poll_closed(&mut self) {
+        self.active_polls = self.active_polls.saturating_sub(1);
+    }
+}
+
+// Outcome of a poll's tally
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum WinnerResult {
+    // A single option has the most votes (index and vote count)
+    Winner(usize, u64),
+    // Several options share the most votes (indices and vote count)
+    Tie(Vec<usize>, u64),
+    // Nothing has been voted for yet
+    NoVotes,
+    // Too few votes for the result to count (the leading vote count)
+    NoQuorum(u64),
+}
+
+// Commitment for a commit-reveal vote: hash(option_index || salt)
+pub fn compute_commitment(option_index: u8, salt: &[u8; 32]) -> [u8; 32] {
+    hashv(&[&[option_index], salt]).to_bytes()
+}
+
+// Each option's label, raw count, and share of total_votes in basis points
+// Shares round down, so they can sum to slightly less than 10000; all zero with no votes
+// Large polls keep their options in OptionAccount PDAs, so this returns an empty vec for them
+// Polls hiding their tally report zero counts until they are closed
+pub fn get_poll_results(poll: &Poll) -> Vec<(String, u64, u16)> {
+    let hidden = poll.counts_hidden();
+    poll.options
+        .iter()
+        .zip(poll.vote_counts.iter())
+        .map(|(label, &count)| {
+            if hidden {
+                return (label.clone(), 0, 0);
+            }
+            let bps = if poll.total_votes == 0 {
+                0
+            } else {
+                (count as u128 * BPS_DENOMINATOR as u128 / poll.total_votes as u128) as u16
+            };
+            (label.clone(), count, bps)
+        })
+        .collect()
+}
+
+// Instant-runoff tally of ranked ballots, meant to run off-chain over a poll's receipts
+// Each round counts every ballot for its highest-ranked option still standing;
+// an option with a majority of those ballots wins, otherwise the last-place option(s) are eliminated
+// Single-choice receipts count as a ballot ranking only their option_index; unrevealed ones are skipped
+pub fn tally_ranked(poll: &Poll, receipts: &[VoteReceipt]) -> WinnerResult {
+    let option_count = poll.options.len();
+    let mut eliminated = vec![false; option_count];
+    
+    loop {
+        let mut counts = vec![0u64; option_count];
+        for receipt in receipts.iter().filter(|receipt| receipt.is_revealed) {
+            let ballot = if receipt.ranking.is_empty() {
+                std::slice::from_ref(&receipt.option_index)
+            } else {
+                &receipt.ranking[..]
+            };
+            // Ballots with every ranked option eliminated are exhausted
+            if let Some(&choice) = ballot
+                .iter()
+                .find(|&&index| (index as usize) < option_count && !eliminated[index as usize])
+            {
+                counts[choice as usize] += receipt.weight;
+            }
+        }
+        
+        let active_ballots: u64 = counts.iter().sum();
+        if active_ballots == 0 {
+            return WinnerResult::NoVotes;
+        }
+        
+        let standing: Vec<usize> = (0..option_count).filter(|&index| !eliminated[index]).collect();
+        let (leader, leader_votes) = standing
+            .iter()
+            .map(|&index| (index, counts[index]))
+            .max_by_key(|&(index, votes)| (votes, std::cmp::Reverse(index)))
+            .unwrap_or((0, 0));
+        
+        if leader_votes * 2 > active_ballots {
+            return WinnerResult::Winner(leader, leader_votes);
+        }
+        
+        // Drop every option sharing last place; if that is all of them, they are tied
+        let fewest = standing.iter().map(|&index| counts[index]).min().unwrap_or(0);
+        let last_place: Vec<usize> = standing
+            .iter()
+            .copied()
+            .filter(|&index| counts[index] == fewest)
+            .collect();
+        
+        if last_place.len() == standing.len() {
+            return WinnerResult::Tie(standing, fewest);
+        }
+        
+        for index in last_place {
+            eliminated[index] = true;
+        }
+    }
 }
 
 impl Poll {
@@ -59,27 +310,293 @@ impl Poll {
         self.is_active && self.end_time > Clock::get().unwrap().unix_timestamp
     }
     
+    // Helper method to check if votes must go through commit-reveal
+    pub fn is_commit_reveal(&self) -> bool {
+        self.commit_end_time > 0
+    }
+    
+    // Helper method to check if commitments are still accepted
+    pub fn is_commit_open(&self) -> bool {
+        self.is_active && Clock::get().unwrap().unix_timestamp < self.commit_end_time
+    }
+    
+    // Helper method to check if committed votes can be revealed
+    pub fn is_reveal_open(&self) -> bool {
+        let current_time = Clock::get().unwrap().unix_timestamp;
+        self.is_active && current_time >= self.commit_end_time && current_time < self.reveal_end_time
+    }
+    
+    // Helper method to check a voter may vote on this poll
+    // The pass PDA is program-owned and non-empty only once add_voter has created it
+    pub fn is_eligible(&self, voter_pass: &AccountInfo) -> bool {
+        !self.restricted || (voter_pass.owner == &crate::ID && !voter_pass.data_is_empty())
+    }
+    
+    // Helper method to check if the running tally must not be surfaced yet
+    pub fn counts_hidden(&self) -> bool {
+        self.hide_counts_until_close && self.is_active
+    }
+    
+    // Helper method to check if options live in separate OptionAccount PDAs
+    pub fn has_option_accounts(&self) -> bool {
+        self.option_account_count > 0
+    }
+    
+    // Helper method to check every option account has been added
+    pub fn options_complete(&self) -> bool {
+        self.option_accounts_added == self.option_account_count
+    }
+    
     // Helper method to validate option index
+    // During a runoff only the tied options can receive votes
     pub fn is_valid_option(&self, option_index: u8) -> bool {
+        if self.has_option_accounts() {
+            return option_index < self.option_account_count;
+        }
+        if self.is_runoff {
+            return self.runoff_options.contains(&option_index);
+        }
         (option_index as usize) < self.options.len()
     }
     
-    // Helper method to get the winning option (returns index and vote count)
-    pub fn get_winner(&self) -> Option<(usize, u64)> {
-        if self.vote_counts.is_empty() {
-            return None;
+    // Helper method to check if a tie should send the poll into a runoff
+    pub fn runoff_enabled(&self) -> bool {
+        self.runoff_duration > 0 && !self.is_runoff
+    }
+    
+    // Helper method to get every option tied for first place
+    // Returns an empty vec when there is a single leader or no votes at all
+    pub fn get_tied_options(&self) -> Vec<u8> {
+        match self.get_winner() {
+            WinnerResult::Tie(tied, _) => tied.into_iter().map(|index| index as u8).collect(),
+            _ => Vec::new(),
+        }
+    }
+    
+    // Helper method to check if enough votes were cast for the result to count
+    pub fn has_quorum(&self) -> bool {
+        self.total_votes >= self.quorum
+    }
+    
+    // Helper method to get the leading option(s) from the vote counts
+    // Below quorum there is no winner or tie, so a tie can't start a runoff either
+    pub fn get_winner(&self) -> WinnerResult {
+        let max_votes = self.vote_counts.iter().copied().max().unwrap_or(0);
+        if !self.has_quorum() {
+            return WinnerResult::NoQuorum(max_votes);
+        }
+        if max_votes == 0 {
+            return WinnerResult::NoVotes;
         }
         
-        let mut max_votes = 0;
-        let mut winner_index = 0;
+        let leaders: Vec<usize> = self.vote_counts
+            .iter()
+            .enumerate()
+            .filter(|(_, &votes)| votes == max_votes)
+            .map(|(index, _)| index)
+            .collect();
         
-        for (index, &votes) in self.vote_counts.iter().enumerate() {
-            if votes > max_votes {
-                max_votes = votes;
-                winner_index = index;
-            }
+        if leaders.len() > 1 {
+            WinnerResult::Tie(leaders, max_votes)
+        } else {
+            WinnerResult::Winner(leaders[0], max_votes)
         }
-        
-        Some((winner_index, max_votes))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn mock_poll(vote_counts: Vec<u64>) -> Poll {
+        Poll {
+            creator: Pubkey::default(),
+            poll_id: 1,
+            question: "Favorite color?".to_string(),
+            options: vote_counts.iter().enumerate().map(|(index, _)| format!("Option {}", index)).collect(),
+            total_votes: vote_counts.iter().sum(),
+            vote_counts,
+            end_time: 0,
+            is_active: false,
+            quorum: 0,
+            quorum_met: false,
+            restricted: false,
+            allow_early_close: true,
+            created_at: 0,
+            runoff_duration: 0,
+            preserve_counts_on_runoff: false,
+            is_runoff: false,
+            runoff_options: Vec::new(),
+            commit_end_time: 0,
+            reveal_end_time: 0,
+            option_account_count: 0,
+            option_accounts_added: 0,
+            hide_counts_until_close: false,
+            vote_deposit_lamports: 0,
+            deposits_held: 0,
+        }
+    }
+
+    #[test]
+    fn test_clear_winner() {
+        let poll = mock_poll(vec![1, 4, 2]);
+        assert_eq!(poll.get_winner(), WinnerResult::Winner(1, 4));
+        assert!(poll.get_tied_options().is_empty());
+    }
+
+    #[test]
+    fn test_two_way_tie() {
+        let poll = mock_poll(vec![3, 1, 3]);
+        assert_eq!(poll.get_winner(), WinnerResult::Tie(vec![0, 2], 3));
+        assert_eq!(poll.get_tied_options(), vec![0, 2]);
+    }
+
+    #[test]
+    fn test_below_quorum_has_no_winner_or_tie() {
+        let mut poll = mock_poll(vec![3, 1, 3]);
+        poll.quorum = 8;
+        assert_eq!(poll.get_winner(), WinnerResult::NoQuorum(3));
+        assert!(poll.get_tied_options().is_empty());
+
+        let mut poll = mock_poll(vec![1, 4, 2]);
+        poll.quorum = 8;
+        assert_eq!(poll.get_winner(), WinnerResult::NoQuorum(4));
+
+        // Reaching the quorum exactly lets the tie through
+        let mut poll = mock_poll(vec![3, 1, 3]);
+        poll.quorum = 7;
+        assert_eq!(poll.get_winner(), WinnerResult::Tie(vec![0, 2], 3));
+    }
+
+    #[test]
+    fn test_empty_poll() {
+        assert_eq!(mock_poll(vec![0, 0, 0]).get_winner(), WinnerResult::NoVotes);
+        assert_eq!(mock_poll(Vec::new()).get_winner(), WinnerResult::NoVotes);
+    }
+
+    #[test]
+    fn test_poll_results_percentages() {
+        let results = get_poll_results(&mock_poll(vec![1, 2, 1]));
+        assert_eq!(results, vec![
+            ("Option 0".to_string(), 1, 2_500),
+            ("Option 1".to_string(), 2, 5_000),
+            ("Option 2".to_string(), 1, 2_500),
+        ]);
+
+        // Thirds round down but stay within one bp per option of 10000
+        let results = get_poll_results(&mock_poll(vec![1, 1, 1]));
+        let total_bps: u64 = results.iter().map(|(_, _, bps)| *bps as u64).sum();
+        assert!(results.iter().all(|(_, _, bps)| *bps == 3_333));
+        assert!(BPS_DENOMINATOR - total_bps < results.len() as u64);
+    }
+
+    #[test]
+    fn test_poll_results_without_votes() {
+        let results = get_poll_results(&mock_poll(vec![0, 0, 0]));
+        assert_eq!(results.len(), 3);
+        assert!(results.iter().all(|(_, count, bps)| *count == 0 && *bps == 0));
+    }
+
+    fn ranked_ballot(ranking: Vec<u8>) -> VoteReceipt {
+        VoteReceipt {
+            poll: Pubkey::default(),
+            voter: Pubkey::default(),
+            option_index: ranking[0],
+            voted_at: 0,
+            commitment: [0; 32],
+            is_revealed: true,
+            weight: 1,
+            ranking,
+            deposit_lamports: 0,
+        }
+    }
+
+    #[test]
+    fn test_ranked_tally_with_elimination() {
+        let poll = mock_poll(vec![0, 0, 0]);
+        // First preferences: A=4, B=3, C=2; nobody has a majority of 9
+        let mut ballots = Vec::new();
+        ballots.extend((0..4).map(|_| ranked_ballot(vec![0, 1, 2])));
+        ballots.extend((0..3).map(|_| ranked_ballot(vec![1, 2, 0])));
+        ballots.extend((0..2).map(|_| ranked_ballot(vec![2, 1, 0])));
+
+        // C is eliminated and its ballots transfer to B, which wins 5-4
+        assert_eq!(tally_ranked(&poll, &ballots), WinnerResult::Winner(1, 5));
+
+        // A first-round majority wins without any elimination
+        ballots.extend((0..2).map(|_| ranked_ballot(vec![0])));
+        assert_eq!(tally_ranked(&poll, &ballots), WinnerResult::Winner(0, 6));
+    }
+
+    #[test]
+    fn test_ranked_tally_exhausted_ballots_and_ties() {
+        let poll = mock_poll(vec![0, 0, 0]);
+        // Every option shares last place, so no elimination can break the tie
+        let ballots = vec![
+            ranked_ballot(vec![0]),
+            ranked_ballot(vec![1]),
+            ranked_ballot(vec![2]),
+        ];
+        assert_eq!(tally_ranked(&poll, &ballots), WinnerResult::Tie(vec![0, 1, 2], 1));
+
+        let ballots = vec![
+            ranked_ballot(vec![0, 2]),
+            ranked_ballot(vec![1]),
+            ranked_ballot(vec![2, 0]),
+            ranked_ballot(vec![1]),
+            ranked_ballot(vec![0]),
+        ];
+        // C is eliminated and its ballot moves to A, leaving A 3-2 ahead
+        assert_eq!(tally_ranked(&poll, &ballots), WinnerResult::Winner(0, 3));
+
+        assert_eq!(tally_ranked(&poll, &[]), WinnerResult::NoVotes);
+    }
+
+    #[test]
+    fn test_hidden_counts_suppressed_while_open() {
+        let mut poll = mock_poll(vec![3, 1]);
+        poll.hide_counts_until_close = true;
+        poll.is_active = true;
+
+        assert!(poll.counts_hidden());
+        let results = get_poll_results(&poll);
+        assert_eq!(results, vec![
+            ("Option 0".to_string(), 0, 0),
+            ("Option 1".to_string(), 0, 0),
+        ]);
+
+        // Counts still accumulate underneath
+        assert_eq!(poll.vote_counts, vec![3, 1]);
+    }
+
+    #[test]
+    fn test_hidden_counts_revealed_after_close() {
+        let mut poll = mock_poll(vec![3, 1]);
+        poll.hide_counts_until_close = true;
+        poll.is_active = false;
+
+        assert!(!poll.counts_hidden());
+        let results = get_poll_results(&poll);
+        assert_eq!(results, vec![
+            ("Option 0".to_string(), 3, 7_500),
+            ("Option 1".to_string(), 1, 2_500),
+        ]);
+    }
+
+    #[test]
+    fn test_creator_stats_caps_active_polls() {
+        let mut stats = CreatorStats { creator: Pubkey::default(), active_polls: 0, bump: 0 };
+
+        for _ in 0..MAX_ACTIVE_POLLS {
+            stats.record_poll_created().unwrap();
+        }
+        assert_eq!(stats.active_polls, MAX_ACTIVE_POLLS);
+        assert!(stats.record_poll_created().is_err());
+        assert_eq!(stats.active_polls, MAX_ACTIVE_POLLS);
+
+        // Closing a poll frees a slot
+        stats.record_poll_closed();
+        stats.record_poll_created().unwrap();
+        assert_eq!(stats.active_polls, MAX_ACTIVE_POLLS);
     }
 }
\ No newline at end of file
@@ -16,6 +16,25 @@ pub const STAKE_VAULT_SEED: &[u8] = b"stake_vault";
 /// Token account that holds reward tokens for distribution
 pub const REWARD_VAULT_SEED: &[u8] = b"reward_vault";
 
+/// Seed for Reward SOL Vault PDAs: ["reward_sol_vault", pool.key()]
+/// System account that holds lamports for pools paying rewards in native SOL
+pub const REWARD_SOL_VAULT_SEED: &[u8] = b"reward_sol_vault";
+
+/// Seed for the GlobalStats PDA: ["global_stats"]
+/// Single program-wide account aggregating totals across all pools
+pub const GLOBAL_STATS_SEED: &[u8] = b"global_stats";
+
+/// Seed for RateHistory PDAs: ["rate_history", pool.key()]
+/// Created the first time a pool's reward rate changes
+pub const RATE_HISTORY_SEED: &[u8] = b"rate_history";
+
+/// Seed for StakeWhitelist PDAs: ["whitelist", pool.key(), user.key()]
+/// Its existence lets the user stake in a whitelist-gated pool
+pub const WHITELIST_SEED: &[u8] = b"whitelist";
+
+/// Number of reward rate changes a RateHistory keeps before evicting the oldest
+pub const RATE_HISTORY_CAPACITY: usize = 16;
+
 // Precision and Mathematical Constants
 
 /// Precision multiplier for reward calculations (1e18)
@@ -37,6 +56,29 @@ pub const MAX_LOCK_DURATION: i64 = 365 * 24 * 60 * 60; // 31,536,000 seconds
 /// Default lock duration (7 days in seconds)
 pub const DEFAULT_LOCK_DURATION: i64 = 7 * 24 * 60 * 60; // 604,800 seconds
 
+/// Maximum cooldown between request_unstake and withdraw_unstaked (30 days)
+pub const MAX_COOLDOWN_PERIOD: i64 = 30 * 24 * 60 * 60;
+
+/// Maximum wait between two reward claims on the same stake (7 days)
+pub const MAX_CLAIM_COOLDOWN: i64 = 7 * 24 * 60 * 60;
+
+// Lock Tier Reward Multipliers (basis points, 10,000 = 1x)
+
+/// Multiplier for locks shorter than the first tier
+pub const BASE_REWARD_MULTIPLIER_BPS: u16 = 10_000;
+
+/// Locks of at least 30 days earn 1.25x rewards
+pub const LOCK_TIER_1_DURATION: i64 = 30 * 24 * 60 * 60;
+pub const LOCK_TIER_1_MULTIPLIER_BPS: u16 = 12_500;
+
+/// Locks of at least 90 days earn 1.5x rewards
+pub const LOCK_TIER_2_DURATION: i64 = 90 * 24 * 60 * 60;
+pub const LOCK_TIER_2_MULTIPLIER_BPS: u16 = 15_000;
+
+/// Locks of at least 180 days earn 2x rewards
+pub const LOCK_TIER_3_DURATION: i64 = 180 * 24 * 60 * 60;
+pub const LOCK_TIER_3_MULTIPLIER_BPS: u16 = 20_000;
+
 // Staking Limits
 
 /// Minimum stake amount (to prevent dust attacks)
@@ -53,6 +95,15 @@ pub const MIN_REWARD_RATE: u64 = 1; // 1 token per second per 1B staked tokens
 /// Maximum reward rate (to prevent excessive inflation)
 pub const MAX_REWARD_RATE: u64 = 1_000_000_000; // 1 token per second per staked token
 
+/// Basis point denominator (10,000 bps = 100%)
+pub const BPS_DENOMINATOR: u64 = 10_000;
+
+/// Maximum early staker boost (3x)
+pub const MAX_BOOST_MULTIPLIER_BPS: u16 = 30_000;
+
+/// Maximum penalty for unstaking before the lock expires (50%)
+pub const MAX_EARLY_UNSTAKE_PENALTY_BPS: u16 = 5_000;
+
 // Account Space Constants
 
 /// Anchor discriminator size (8 bytes)
@@ -86,14 +137,19 @@ pub fn apr_to_reward_rate(apr_percent: u64) -> u64 {
 }
 
 /// Convert reward rate per second to annual percentage rate
+/// Whole percent only; use `reward_rate_to_apr_bps` where fractions matter
 pub fn reward_rate_to_apr(reward_rate: u64) -> u64 {
-    let seconds_per_year = 365u64 * 24 * 60 * 60;
-    
-    reward_rate
-        .checked_mul(seconds_per_year)
-        .and_then(|x| x.checked_mul(100))
-        .and_then(|x| x.checked_div(RATE_PRECISION))
-        .unwrap_or(0)
+    reward_rate_to_apr_bps(reward_rate) / 100
+}
+
+/// Convert reward rate per second to annual percentage rate in basis points
+/// 0.5% APR reads as 50; u128 intermediates keep large rates from overflowing
+pub fn reward_rate_to_apr_bps(reward_rate: u64) -> u64 {
+    let seconds_per_year = 365u128 * 24 * 60 * 60;
+
+    let apr_bps = (reward_rate as u128) * seconds_per_year * (BPS_DENOMINATOR as u128) / (RATE_PRECISION as u128);
+
+    u64::try_from(apr_bps).unwrap_or(u64::MAX)
 }
 
 /// Check if a lock duration is valid
@@ -111,6 +167,48 @@ pub fn is_valid_reward_rate(rate: u64) -> bool {
     rate >= MIN_REWARD_RATE && rate <= MAX_REWARD_RATE
 }
 
+/// Check if an early unstake penalty is valid (zero disables the penalty)
+pub fn is_valid_early_unstake_penalty(penalty_bps: u16) -> bool {
+    penalty_bps <= MAX_EARLY_UNSTAKE_PENALTY_BPS
+}
+
+/// Check if a staking window is valid (must be non-empty)
+pub fn is_valid_staking_window(start_time: i64, end_time: i64) -> bool {
+    end_time > start_time
+}
+
+/// Reward multiplier earned by a lock duration, from the lock tier table
+pub fn lock_tier_multiplier_bps(lock_duration: i64) -> u16 {
+    if lock_duration >= LOCK_TIER_3_DURATION {
+        LOCK_TIER_3_MULTIPLIER_BPS
+    } else if lock_duration >= LOCK_TIER_2_DURATION {
+        LOCK_TIER_2_MULTIPLIER_BPS
+    } else if lock_duration >= LOCK_TIER_1_DURATION {
+        LOCK_TIER_1_MULTIPLIER_BPS
+    } else {
+        BASE_REWARD_MULTIPLIER_BPS
+    }
+}
+
+/// Reward weight of a stake: amount * multiplier / 10,000
+pub fn calculate_weighted_amount(amount: u64, multiplier_bps: u16) -> Option<u64> {
+    (amount as u128)
+        .checked_mul(multiplier_bps as u128)
+        .and_then(|x| x.checked_div(BPS_DENOMINATOR as u128))
+        .and_then(|x| u64::try_from(x).ok())
+}
+
+/// Reward weight of a stake with both a lock tier multiplier and an early staker boost
+pub fn calculate_boosted_weighted_amount(amount: u64, multiplier_bps: u16, boost_multiplier_bps: u16) -> Option<u64> {
+    calculate_weighted_amount(amount, multiplier_bps)
+        .and_then(|weighted| calculate_weighted_amount(weighted, boost_multiplier_bps))
+}
+
+/// Check if an early staker boost is valid (1x disables it)
+pub fn is_valid_boost_multiplier(boost_multiplier_bps: u16) -> bool {
+    (BASE_REWARD_MULTIPLIER_BPS..=MAX_BOOST_MULTIPLIER_BPS).contains(&boost_multiplier_bps)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -125,6 +223,30 @@ mod tests {
         assert!(back_to_apr >= 9 && back_to_apr <= 11);
     }
 
+    #[test]
+    fn test_apr_bps_keeps_fractional_percent() {
+        // The smallest rate is 3.1536% APR; whole-percent reading drops the fraction
+        assert_eq!(reward_rate_to_apr_bps(MIN_REWARD_RATE), 315);
+        assert_eq!(reward_rate_to_apr(MIN_REWARD_RATE), 3);
+
+        // 3 units per second is 9.4608% APR
+        assert_eq!(reward_rate_to_apr_bps(3), 946);
+        assert_eq!(reward_rate_to_apr(3), 9);
+
+        assert_eq!(reward_rate_to_apr_bps(0), 0);
+    }
+
+    #[test]
+    fn test_apr_bps_does_not_overflow_large_rates() {
+        let rate = MAX_REWARD_RATE;
+        assert_eq!(reward_rate_to_apr_bps(rate), 315_360_000_000);
+        assert_eq!(reward_rate_to_apr(rate), 3_153_600_000);
+
+        // Rates whose u64 intermediate overflowed (and read as 0%) now saturate
+        assert!(reward_rate_to_apr_bps(6_000_000_000) > 0);
+        assert_eq!(reward_rate_to_apr_bps(u64::MAX), u64::MAX);
+    }
+
     #[test]
     fn test_validation_functions() {
         // Test lock duration validation
@@ -141,5 +263,40 @@ mod tests {
         assert!(is_valid_reward_rate(MIN_REWARD_RATE));
         assert!(!is_valid_reward_rate(0));
         assert!(!is_valid_reward_rate(MAX_REWARD_RATE + 1));
+
+        // Test staking window validation
+        assert!(is_valid_staking_window(0, 1));
+        assert!(!is_valid_staking_window(1, 1));
+        assert!(!is_valid_staking_window(2, 1));
+    }
+
+    #[test]
+    fn test_lock_tier_multipliers() {
+        // Tier boundaries are inclusive
+        assert_eq!(lock_tier_multiplier_bps(DEFAULT_LOCK_DURATION), BASE_REWARD_MULTIPLIER_BPS);
+        assert_eq!(lock_tier_multiplier_bps(LOCK_TIER_1_DURATION - 1), BASE_REWARD_MULTIPLIER_BPS);
+        assert_eq!(lock_tier_multiplier_bps(LOCK_TIER_1_DURATION), LOCK_TIER_1_MULTIPLIER_BPS);
+        assert_eq!(lock_tier_multiplier_bps(LOCK_TIER_2_DURATION), LOCK_TIER_2_MULTIPLIER_BPS);
+        assert_eq!(lock_tier_multiplier_bps(LOCK_TIER_3_DURATION), LOCK_TIER_3_MULTIPLIER_BPS);
+        assert_eq!(lock_tier_multiplier_bps(MAX_LOCK_DURATION), LOCK_TIER_3_MULTIPLIER_BPS);
+
+        // Weighted amounts scale the principal
+        assert_eq!(calculate_weighted_amount(1_000_000, BASE_REWARD_MULTIPLIER_BPS), Some(1_000_000));
+        assert_eq!(calculate_weighted_amount(1_000_000, LOCK_TIER_1_MULTIPLIER_BPS), Some(1_250_000));
+        assert_eq!(calculate_weighted_amount(u64::MAX, LOCK_TIER_3_MULTIPLIER_BPS), None);
+    }
+
+    #[test]
+    fn test_boosted_weighted_amount() {
+        // The boost stacks on top of the lock tier
+        assert_eq!(calculate_boosted_weighted_amount(1_000_000, BASE_REWARD_MULTIPLIER_BPS, BASE_REWARD_MULTIPLIER_BPS), Some(1_000_000));
+        assert_eq!(calculate_boosted_weighted_amount(1_000_000, BASE_REWARD_MULTIPLIER_BPS, 15_000), Some(1_500_000));
+        assert_eq!(calculate_boosted_weighted_amount(1_000_000, LOCK_TIER_3_MULTIPLIER_BPS, 15_000), Some(3_000_000));
+        assert_eq!(calculate_boosted_weighted_amount(u64::MAX, BASE_REWARD_MULTIPLIER_BPS, MAX_BOOST_MULTIPLIER_BPS), None);
+
+        assert!(is_valid_boost_multiplier(BASE_REWARD_MULTIPLIER_BPS));
+        assert!(is_valid_boost_multiplier(MAX_BOOST_MULTIPLIER_BPS));
+        assert!(!is_valid_boost_multiplier(BASE_REWARD_MULTIPLIER_BPS - 1));
+        assert!(!is_valid_boost_multiplier(MAX_BOOST_MULTIPLIER_BPS + 1));
     }
 }
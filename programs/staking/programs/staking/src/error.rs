@@ -20,6 +20,36 @@ pub enum StakingError {
     #[msg("Invalid lock duration provided")]
     InvalidLockDuration,
     
+    #[msg("Early unstake penalty exceeds the maximum allowed")]
+    InvalidEarlyUnstakePenalty,
+    
+    #[msg("Staking window end time must be after its start time")]
+    InvalidStakingWindow,
+    
+    #[msg("Reward duration must be positive")]
+    InvalidRewardDuration,
+    
+    #[msg("Signer is not the pool's pending authority")]
+    NotPendingAuthority,
+    
+    #[msg("Pool was updated too recently")]
+    UpdateTooSoon,
+    
+    #[msg("Minimum update interval cannot be negative")]
+    InvalidMinUpdateInterval,
+    
+    #[msg("Slash must be between 1 and 10,000 basis points")]
+    InvalidSlashBps,
+    
+    #[msg("Early staker boost must be between 1x and the maximum multiplier")]
+    InvalidBoostMultiplier,
+    
+    #[msg("Cooldown period must be between zero and the maximum allowed")]
+    InvalidCooldownPeriod,
+    
+    #[msg("Claim cooldown must be between zero and the maximum allowed")]
+    InvalidClaimCooldown,
+    
     // Staking Errors
     #[msg("Stake amount is below minimum required")]
     StakeAmountTooSmall,
@@ -33,6 +63,21 @@ pub enum StakingError {
     #[msg("Insufficient token balance to stake")]
     InsufficientBalance,
     
+    #[msg("Pool is outside its staking window")]
+    StakingWindowClosed,
+    
+    #[msg("Stake is already owned by this wallet")]
+    StakeTransferToSelf,
+    
+    #[msg("Lock extension must be positive and end in the future")]
+    InvalidLockExtension,
+    
+    #[msg("Extended lock would exceed the maximum lock duration")]
+    LockExtensionExceedsMax,
+    
+    #[msg("Wallet is not whitelisted for this pool")]
+    NotWhitelisted,
+    
     // Unstaking Errors
     #[msg("No active stake found for this user")]
     NoActiveStake,
@@ -43,6 +88,21 @@ pub enum StakingError {
     #[msg("Cannot unstake zero amount")]
     CannotUnstakeZero,
     
+    #[msg("Stake account still holds principal or rewards")]
+    StakeNotEmpty,
+    
+    #[msg("Pool has a cooldown period; use request_unstake and withdraw_unstaked")]
+    CooldownRequired,
+    
+    #[msg("Stake is unbonding")]
+    StakeUnbonding,
+    
+    #[msg("Stake has not requested an unstake")]
+    NotUnbonding,
+    
+    #[msg("Cooldown period has not elapsed yet")]
+    CooldownNotElapsed,
+    
     // Reward Errors
     #[msg("No rewards available to claim")]
     NoRewardsAvailable,
@@ -53,6 +113,18 @@ pub enum StakingError {
     #[msg("Reward calculation overflow")]
     RewardCalculationOverflow,
     
+    #[msg("Compounding requires the pool's stake and reward mints to match")]
+    CompoundMintMismatch,
+    
+    #[msg("Reward period has not ended yet")]
+    RewardPeriodNotEnded,
+    
+    #[msg("Withdrawal would take rewards still owed to stakers")]
+    WithdrawExceedsUnusedRewards,
+    
+    #[msg("Claim cooldown has not elapsed since the last claim")]
+    ClaimTooSoon,
+    
     // Time and Math Errors
     #[msg("Invalid timestamp provided")]
     InvalidTimestamp,
@@ -86,6 +158,18 @@ pub enum StakingError {
     #[msg("Vault balance mismatch")]
     VaultBalanceMismatch,
     
+    #[msg("Reward vault authority must co-sign reward disbursements")]
+    RewardVaultAuthorityRequired,
+    
+    #[msg("Vault balance would fall below the rent-exempt minimum")]
+    VaultNotRentExempt,
+    
+    #[msg("Vault holds fewer lamports than requested")]
+    InsufficientVaultBalance,
+    
+    #[msg("Vault deposits and withdrawals must be greater than zero")]
+    InvalidVaultAmount,
+    
     // General Validation Errors
     #[msg("Invalid account provided")]
     InvalidAccount,
@@ -126,22 +210,46 @@ impl StakingError {
             StakingError::PoolAlreadyExists => 1003,
             StakingError::InvalidRewardRate => 1004,
             StakingError::InvalidLockDuration => 1005,
+            StakingError::InvalidEarlyUnstakePenalty => 1006,
+            StakingError::InvalidStakingWindow => 1007,
+            StakingError::InvalidRewardDuration => 1008,
+            StakingError::NotPendingAuthority => 1009,
+            StakingError::UpdateTooSoon => 1010,
+            StakingError::InvalidMinUpdateInterval => 1011,
+            StakingError::InvalidSlashBps => 1012,
+            StakingError::InvalidBoostMultiplier => 1013,
+            StakingError::InvalidCooldownPeriod => 1014,
+            StakingError::InvalidClaimCooldown => 1015,
             
             // Staking errors: 1100-1199
             StakingError::StakeAmountTooSmall => 1101,
             StakingError::StakeAmountTooLarge => 1102,
             StakingError::UserAlreadyStaked => 1103,
             StakingError::InsufficientBalance => 1104,
+            StakingError::StakingWindowClosed => 1105,
+            StakingError::StakeTransferToSelf => 1106,
+            StakingError::InvalidLockExtension => 1107,
+            StakingError::LockExtensionExceedsMax => 1108,
+            StakingError::NotWhitelisted => 1109,
             
             // Unstaking errors: 1200-1299
             StakingError::NoActiveStake => 1201,
             StakingError::StakeStillLocked => 1202,
             StakingError::CannotUnstakeZero => 1203,
+            StakingError::StakeNotEmpty => 1204,
+            StakingError::CooldownRequired => 1205,
+            StakingError::StakeUnbonding => 1206,
+            StakingError::NotUnbonding => 1207,
+            StakingError::CooldownNotElapsed => 1208,
             
             // Reward errors: 1300-1399
             StakingError::NoRewardsAvailable => 1301,
             StakingError::InsufficientRewardTokens => 1302,
             StakingError::RewardCalculationOverflow => 1303,
+            StakingError::CompoundMintMismatch => 1304,
+            StakingError::RewardPeriodNotEnded => 1305,
+            StakingError::WithdrawExceedsUnusedRewards => 1306,
+            StakingError::ClaimTooSoon => 1307,
             
             // Math errors: 1400-1499
             StakingError::InvalidTimestamp => 1401,
@@ -158,6 +266,10 @@ impl StakingError {
             StakingError::EmptyStakeVault => 1601,
             StakingError::EmptyRewardVault => 1602,
             StakingError::VaultBalanceMismatch => 1603,
+            StakingError::RewardVaultAuthorityRequired => 1604,
+            StakingError::VaultNotRentExempt => 1605,
+            StakingError::InsufficientVaultBalance => 1606,
+            StakingError::InvalidVaultAmount => 1607,
             
             // General errors: 1700-1799
             StakingError::InvalidAccount => 1701,
@@ -0,0 +1,98 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    error::StakingError,
+    state::StakingPool,
+};
+
+/// Accept a proposed pool authority transfer (step two of the handover)
+/// Must be signed by the pending authority, proving the key is controlled
+#[derive(Accounts)]
+pub struct AcceptAuthority<'info> {
+    /// The proposed authority taking control of the pool
+    pub new_authority: Signer<'info>,
+
+    /// The staking pool being handed over
+    #[account(mut)]
+    pub pool: Account<'info, StakingPool>,
+}
+
+impl<'info> AcceptAuthority<'info> {
+    /// Make the pending authority the pool authority
+    pub fn accept_authority(&mut self) -> Result<()> {
+        let previous_authority = self.pool.authority;
+
+        accept_authority_transfer(&mut self.pool, self.new_authority.key())?;
+
+        msg!(
+            "Pool authority transferred: pool={}, previous={}, new={}",
+            self.pool.key(),
+            previous_authority,
+            self.pool.authority
+        );
+
+        Ok(())
+    }
+}
+
+/// Complete a pending transfer if `signer` is the proposed authority
+pub fn accept_authority_transfer(pool: &mut StakingPool, signer: Pubkey) -> Result<()> {
+    if pool.pending_authority != Some(signer) {
+        return Err(StakingError::NotPendingAuthority.into());
+    }
+
+    pool.authority = signer;
+    pool.pending_authority = None;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{mock_pool};
+    use crate::instructions::transfer_authority::propose_authority_transfer;
+
+    #[test]
+    fn test_propose_then_accept() {
+        let original = Pubkey::new_unique();
+        let new_authority = Pubkey::new_unique();
+        let mut pool = StakingPool { authority: original, creator: original, ..mock_pool(0) };
+
+        // Proposing does not hand over control yet
+        propose_authority_transfer(&mut pool, new_authority);
+        assert_eq!(pool.authority, original);
+        assert_eq!(pool.pending_authority, Some(new_authority));
+
+        accept_authority_transfer(&mut pool, new_authority).unwrap();
+        assert_eq!(pool.authority, new_authority);
+        assert_eq!(pool.pending_authority, None);
+
+        // Signer seeds still come from the original creator
+        assert_eq!(pool.creator, original);
+    }
+
+    #[test]
+    fn test_accept_requires_pending_authority() {
+        let original = Pubkey::new_unique();
+        let new_authority = Pubkey::new_unique();
+        let mut pool = StakingPool { authority: original, creator: original, ..mock_pool(0) };
+
+        // Nothing proposed yet
+        let err = accept_authority_transfer(&mut pool, new_authority).unwrap_err();
+        assert_eq!(err, StakingError::NotPendingAuthority.into());
+
+        // Only the proposed key can accept
+        propose_authority_transfer(&mut pool, new_authority);
+        let err = accept_authority_transfer(&mut pool, Pubkey::new_unique()).unwrap_err();
+        assert_eq!(err, StakingError::NotPendingAuthority.into());
+        assert_eq!(pool.authority, original);
+
+        // A newer proposal replaces the old one
+        let replacement = Pubkey::new_unique();
+        propose_authority_transfer(&mut pool, replacement);
+        assert!(accept_authority_transfer(&mut pool, new_authority).is_err());
+        assert!(accept_authority_transfer(&mut pool, replacement).is_ok());
+        assert_eq!(pool.authority, replacement);
+    }
+}
@@ -0,0 +1,59 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::*,
+    error::StakingError,
+    state::{StakeWhitelist, StakingPool},
+};
+
+/// Allow a wallet to stake in a whitelist-gated pool
+#[derive(Accounts)]
+#[instruction(user: Pubkey)]
+pub struct AddToWhitelist<'info> {
+    /// The pool authority adding the wallet
+    /// Pays for the whitelist entry
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    /// The staking pool the wallet may stake in
+    /// Must be managed by the signing authority
+    #[account(
+        constraint = pool.authority == authority.key() @ StakingError::UnauthorizedPoolAuthority,
+    )]
+    pub pool: Account<'info, StakingPool>,
+
+    /// The wallet's whitelist entry
+    /// PDA: ["whitelist", pool.key(), user]
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + StakeWhitelist::INIT_SPACE,
+        seeds = [WHITELIST_SEED, pool.key().as_ref(), user.as_ref()],
+        bump
+    )]
+    pub whitelist_entry: Account<'info, StakeWhitelist>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> AddToWhitelist<'info> {
+    /// Record the whitelist entry
+    pub fn add_to_whitelist(&mut self, user: Pubkey, bumps: &AddToWhitelistBumps) -> Result<()> {
+        let current_time = Clock::get()?.unix_timestamp;
+
+        self.whitelist_entry.set_inner(StakeWhitelist {
+            pool: self.pool.key(),
+            user,
+            added_at: current_time,
+            bump: bumps.whitelist_entry,
+        });
+
+        msg!(
+            "Wallet whitelisted: pool={}, user={}",
+            self.pool.key(),
+            user
+        );
+
+        Ok(())
+    }
+}
@@ -1,17 +1,29 @@
 use anchor_lang::prelude::*;
 use anchor_spl::{
     associated_token::AssociatedToken,
-    token::{self, Mint, Token, TokenAccount, Transfer},
+    token::{Mint, Token, TokenAccount},
 };
 
 use crate::{
     constants::*,
     error::StakingError,
-    state::{StakingPool, UserStake},
+    instructions::user_position::get_user_position,
+    state::{GlobalStats, StakingPool, UserStake},
+    vault::{RewardVault, Vault},
 };
 
+/// Emitted when a user claims their accumulated rewards
+#[event]
+pub struct RewardsClaimed {
+    pub user: Pubkey,
+    pub pool: Pubkey,
+    pub amount: u64,
+    pub timestamp: i64,
+}
+
 /// Claim accumulated rewards without unstaking
 /// Allows users to harvest rewards while keeping tokens staked
+/// Also settles rewards left behind by an unstake without the reward vault authority
 #[derive(Accounts)]
 pub struct ClaimRewards<'info> {
     /// The user claiming rewards
@@ -50,12 +62,33 @@ pub struct ClaimRewards<'info> {
     )]
     pub reward_vault: Account<'info, TokenAccount>,
 
+    /// Co-signer for reward disbursements
+    /// Required when the pool's reward vault is held by an external authority
+    pub reward_vault_authority: Option<Signer<'info>>,
+
+    /// Pool's SOL reward vault, paid out of instead of reward_vault for native SOL pools
+    /// PDA: ["reward_sol_vault", pool.key()]
+    #[account(
+        mut,
+        seeds = [REWARD_SOL_VAULT_SEED, pool.key().as_ref()],
+        bump,
+    )]
+    pub reward_sol_vault: SystemAccount<'info>,
+
     /// The reward token mint (for validation)
     #[account(
         constraint = reward_mint.key() == pool.reward_mint @ StakingError::InvalidTokenMint,
     )]
     pub reward_mint: Account<'info, Mint>,
 
+    /// Program-wide statistics updated by this instruction
+    #[account(
+        mut,
+        seeds = [GLOBAL_STATS_SEED],
+        bump = global_stats.bump,
+    )]
+    pub global_stats: Account<'info, GlobalStats>,
+
     /// Required system programs
     pub system_program: Program<'info, System>,
     pub token_program: Program<'info, Token>,
@@ -64,7 +97,7 @@ pub struct ClaimRewards<'info> {
 
 impl<'info> ClaimRewards<'info> {
     /// Execute the reward claiming operation
-    pub fn claim_rewards(&mut self) -> Result<()> {
+    pub fn claim_rewards(&mut self, bumps: &ClaimRewardsBumps) -> Result<()> {
         let current_time = Clock::get()?.unix_timestamp;
 
         // Validate that reward claiming is allowed
@@ -73,20 +106,44 @@ impl<'info> ClaimRewards<'info> {
         // Update pool rewards to get accurate calculations
         self.update_pool_rewards(current_time)?;
 
-        // Calculate total claimable rewards
-        let claimable_rewards = self.calculate_claimable_rewards()?;
+        // Settle the claim; amounts below the pool's minimum stay accrued
+        let claimable_rewards = settle_claim(&self.pool, &mut self.user_stake);
+        self.user_stake.last_claim_time = current_time;
 
         // Transfer reward tokens to user (if any)
         if claimable_rewards > 0 {
-            self.transfer_reward_tokens(claimable_rewards)?;
+            if self.pool.reward_is_native {
+                self.transfer_reward_sol(claimable_rewards, bumps.reward_sol_vault)?;
+            } else {
+                self.transfer_reward_tokens(claimable_rewards)?;
+            }
+            self.pool.release_owed_rewards(claimable_rewards);
         }
 
-        // Update user stake reward tracking
-        self.update_user_reward_tracking(claimable_rewards)?;
+        msg!(
+            "User reward tracking updated: claimed={}, baseline={}",
+            claimable_rewards,
+            self.user_stake.reward_per_token_paid
+        );
+
+        // Update program-wide statistics
+        self.global_stats.record_rewards_distributed(claimable_rewards)?;
 
         // Log the claim event
         self.log_claim_event(claimable_rewards, current_time)?;
 
+        // Stake was already unstaked: rewards are settled, close account and return rent
+        if self.user_stake.amount == 0 {
+            self.user_stake.close(self.user.to_account_info())?;
+        }
+
+        emit!(RewardsClaimed {
+            user: self.user.key(),
+            pool: self.pool.key(),
+            amount: claimable_rewards,
+            timestamp: current_time,
+        });
+
         Ok(())
     }
 
@@ -99,14 +156,17 @@ impl<'info> ClaimRewards<'info> {
             return Err(StakingError::InactiveStake.into());
         }
 
-        // Check if user has any tokens staked
-        if user_stake.amount == 0 {
+        // Check if user has any tokens staked or rewards left from unstaking
+        if user_stake.amount == 0 && user_stake.rewards == 0 {
             return Err(StakingError::NoActiveStake.into());
         }
 
         // Validate timestamp
         crate::error::validate_timestamp(current_time)?;
 
+        // Reject claims spammed faster than the pool's claim cooldown
+        check_claim_cooldown(&self.pool, user_stake, current_time)?;
+
         msg!(
             "Claim validation passed: stake_amount={}, is_active={}",
             user_stake.amount,
@@ -120,12 +180,9 @@ impl<'info> ClaimRewards<'info> {
     fn update_pool_rewards(&mut self, current_time: i64) -> Result<()> {
         let pool = &mut self.pool;
 
-        // Calculate new reward per token
-        let new_reward_per_token = pool.calculate_reward_per_token(current_time);
-
-        // Update pool state
-        pool.reward_per_token_stored = new_reward_per_token;
-        pool.last_update_time = current_time;
+        // Checkpoint rewards, recording newly accrued rewards as owed
+        pool.checkpoint_rewards(current_time)?;
+        let new_reward_per_token = pool.reward_per_token_stored;
 
         msg!(
             "Pool rewards updated for claim: reward_per_token={}, time={}",
@@ -136,29 +193,6 @@ impl<'info> ClaimRewards<'info> {
         Ok(())
     }
 
-    /// Calculate the total claimable rewards for the user
-    fn calculate_claimable_rewards(&mut self) -> Result<u64> {
-        let pool = &self.pool;
-        let user_stake = &mut self.user_stake;
-
-        // Calculate pending rewards using current reward_per_token
-        let pending_rewards = user_stake.calculate_pending_rewards(pool.reward_per_token_stored);
-
-        // Add to existing unclaimed rewards
-        let total_claimable = user_stake.rewards
-            .checked_add(pending_rewards)
-            .ok_or(StakingError::RewardCalculationOverflow)?;
-
-        msg!(
-            "Claimable rewards calculated: existing={}, pending={}, total={}",
-            user_stake.rewards,
-            pending_rewards,
-            total_claimable
-        );
-
-        Ok(total_claimable)
-    }
-
     /// Transfer reward tokens to user
     fn transfer_reward_tokens(&self, amount: u64) -> Result<()> {
         // Check if there are rewards to transfer
@@ -167,61 +201,29 @@ impl<'info> ClaimRewards<'info> {
             return Ok(());
         }
 
-        // Check vault has sufficient balance
-        if self.reward_vault.amount < amount {
-            msg!(
-                "Insufficient reward vault balance: has {}, needs {}",
-                self.reward_vault.amount,
-                amount
-            );
-            return Err(StakingError::InsufficientRewardTokens.into());
-        }
-
-        // Create PDA signer seeds for pool authority
-        let pool_key = self.pool.key();
-        let seeds = &[
-            POOL_SEED,
-            self.pool.authority.as_ref(),
-            &pool_key.to_bytes()[..8], // Use first 8 bytes as pool_id
-            &[self.pool.bump],
-        ];
-        let signer_seeds = &[&seeds[..]];
-
-        // Create transfer context with pool as authority
-        let transfer_ctx = CpiContext::new_with_signer(
-            self.token_program.to_account_info(),
-            Transfer {
-                from: self.reward_vault.to_account_info(),
-                to: self.user_reward_token_account.to_account_info(),
-                authority: self.pool.to_account_info(),
-            },
-            signer_seeds,
-        );
-
-        // Execute the transfer
-        token::transfer(transfer_ctx, amount)?;
+        RewardVault::new(&self.pool, &self.reward_vault, &self.reward_vault_authority, &self.token_program)
+            .withdraw(&self.user_reward_token_account.to_account_info(), amount)?;
 
         msg!("Transferred {} reward tokens to user", amount);
 
         Ok(())
     }
 
-    /// Update user stake reward tracking after claiming
-    fn update_user_reward_tracking(&mut self, claimed_amount: u64) -> Result<()> {
-        let pool = &self.pool;
-        let user_stake = &mut self.user_stake;
-
-        // Reset rewards to zero since they've been claimed
-        user_stake.rewards = 0;
+    /// Pay rewards in lamports from the pool's reward SOL vault
+    /// The vault is always in program custody, so it signs with its own seeds
+    fn transfer_reward_sol(&self, amount: u64, reward_sol_vault_bump: u8) -> Result<()> {
+        let pool_key = self.pool.key();
+        let seeds = &[
+            REWARD_SOL_VAULT_SEED,
+            pool_key.as_ref(),
+            &[reward_sol_vault_bump],
+        ];
+        let signer_seeds = &[&seeds[..]];
 
-        // Update the reward baseline to current reward_per_token
-        user_stake.reward_per_token_paid = pool.reward_per_token_stored;
+        Vault::new(&self.reward_sol_vault, &self.system_program)
+            .withdraw_sol(&self.user.to_account_info(), amount, signer_seeds)?;
 
-        msg!(
-            "User reward tracking updated: claimed={}, new_baseline={}",
-            claimed_amount,
-            user_stake.reward_per_token_paid
-        );
+        msg!("Transferred {} reward lamports to user", amount);
 
         Ok(())
     }
@@ -290,26 +292,20 @@ impl<'info> ClaimRewards<'info> {
     }
 
     /// Get claim summary for display
-    pub fn get_claim_summary(&self, current_time: i64) -> ClaimSummary {
-        let user_stake = &self.user_stake;
-        let pool = &self.pool;
-
-        // Calculate pending rewards
-        let current_reward_per_token = pool.calculate_reward_per_token(current_time);
-        let pending_rewards = user_stake.calculate_pending_rewards(current_reward_per_token);
-        let total_claimable = user_stake.rewards + pending_rewards;
-
-        // Calculate staking duration
-        let staking_duration = current_time - user_stake.stake_time;
-
-        ClaimSummary {
-            existing_rewards: user_stake.rewards,
-            pending_rewards,
-            total_claimable,
-            stake_amount: user_stake.amount,
-            staking_duration_days: staking_duration / (24 * 60 * 60),
+    pub fn get_claim_summary(&self, current_time: i64) -> Result<ClaimSummary> {
+        let position = get_user_position(&self.user_stake, &self.pool, current_time)?;
+
+        // Position rewards already include the stored ones
+        let existing_rewards = self.user_stake.rewards;
+
+        Ok(ClaimSummary {
+            existing_rewards,
+            pending_rewards: position.pending_rewards.saturating_sub(existing_rewards),
+            total_claimable: position.pending_rewards,
+            stake_amount: position.staked_amount,
+            staking_duration_days: position.staking_duration_seconds / (24 * 60 * 60),
             reward_vault_balance: 0, // Would need to be passed in or fetched
-        }
+        })
     }
 }
 
@@ -324,15 +320,64 @@ pub struct ClaimSummary {
     pub reward_vault_balance: u64,
 }
 
+/// Work out what a claim pays and reset the stake's reward tracking
+/// The pool must already be checkpointed to the claim time
+///
+/// Claims below the pool's min_claimable pay nothing and leave both the stored
+/// rewards and the reward_per_token baseline untouched. Because the baseline
+/// isn't advanced, the fraction lost to integer division in one claim is still
+/// counted by the next, so tiny stakes eventually cross the threshold instead
+/// of rounding to zero forever. A fully unstaked position always pays out,
+/// since it can no longer accrue towards the threshold.
+pub fn settle_claim(pool: &StakingPool, user_stake: &mut UserStake) -> u64 {
+    // Includes rewards already stored on the stake account
+    let claimable = user_stake.calculate_pending_rewards(pool.reward_per_token_stored);
+
+    if claimable < pool.min_claimable && user_stake.amount > 0 {
+        msg!(
+            "Claim deferred: {} rewards accrued, minimum claim is {}",
+            claimable,
+            pool.min_claimable
+        );
+        return 0;
+    }
+
+    user_stake.rewards = 0;
+    user_stake.reward_per_token_paid = pool.reward_per_token_stored;
+
+    claimable
+}
+
+/// Reject a claim made less than the pool's claim_cooldown after the previous one
+/// The first claim on a stake always goes through, and so does settling a fully
+/// unstaked position, since it has nothing left to spam
+pub fn check_claim_cooldown(pool: &StakingPool, user_stake: &UserStake, current_time: i64) -> Result<()> {
+    if user_stake.last_claim_time == 0 || user_stake.amount == 0 {
+        return Ok(());
+    }
+
+    let elapsed = current_time.saturating_sub(user_stake.last_claim_time);
+    if elapsed < pool.claim_cooldown {
+        msg!(
+            "Claim too soon: {} seconds since last claim, cooldown is {}",
+            elapsed,
+            pool.claim_cooldown
+        );
+        return Err(StakingError::ClaimTooSoon.into());
+    }
+
+    Ok(())
+}
+
 /// Calculate pending rewards for a user stake
 pub fn calculate_pending_rewards(
     user_stake: &UserStake,
     pool: &StakingPool,
     current_time: i64,
-) -> u64 {
-    let current_reward_per_token = pool.calculate_reward_per_token(current_time);
+) -> Result<u64> {
+    let current_reward_per_token = pool.calculate_reward_per_token(current_time)?;
     let pending = user_stake.calculate_pending_rewards(current_reward_per_token);
-    user_stake.rewards + pending
+    Ok(user_stake.rewards + pending)
 }
 
 /// Check if a user has claimable rewards
@@ -340,9 +385,9 @@ pub fn has_claimable_rewards(
     user_stake: &UserStake,
     pool: &StakingPool,
     current_time: i64,
-) -> bool {
-    let total_rewards = calculate_pending_rewards(user_stake, pool, current_time);
-    total_rewards > 0
+) -> Result<bool> {
+    let total_rewards = calculate_pending_rewards(user_stake, pool, current_time)?;
+    Ok(total_rewards > 0)
 }
 
 /// Validate that a user can claim rewards
@@ -363,41 +408,21 @@ pub fn can_user_claim_rewards(user_stake: &UserStake, current_time: i64) -> Resu
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::test_utils::{mock_pool, mock_user_stake, START};
 
     #[test]
     fn test_calculate_pending_rewards() {
         // Create mock user stake
         let user_stake = UserStake {
-            user: Pubkey::default(),
-            pool: Pubkey::default(),
-            amount: 1000 * 10_u64.pow(6), // 1000 tokens
-            reward_per_token_paid: 0,
             rewards: 50 * 10_u64.pow(6), // 50 tokens existing rewards
-            stake_time: 1000000,
-            unlock_time: 1000000 + DEFAULT_LOCK_DURATION,
-            is_active: true,
-            bump: 0,
+            ..mock_user_stake(1000 * 10_u64.pow(6)) // 1000 tokens
         };
 
-        // Create mock pool
-        let pool = StakingPool {
-            authority: Pubkey::default(),
-            stake_mint: Pubkey::default(),
-            reward_mint: Pubkey::default(),
-            stake_vault: Pubkey::default(),
-            reward_vault: Pubkey::default(),
-            reward_rate: apr_to_reward_rate(10), // 10% APR
-            total_staked: 1000 * 10_u64.pow(6),
-            last_update_time: 1000000,
-            reward_per_token_stored: 0,
-            lock_duration: DEFAULT_LOCK_DURATION,
-            is_active: true,
-            created_at: 1000000,
-            bump: 0,
-        };
+        // Create mock pool, 10% APR
+        let pool = mock_pool(1000 * 10_u64.pow(6));
 
-        let current_time = 1000000 + (30 * 24 * 60 * 60); // 30 days later
-        let total_rewards = calculate_pending_rewards(&user_stake, &pool, current_time);
+        let current_time = START + (30 * 24 * 60 * 60); // 30 days later
+        let total_rewards = calculate_pending_rewards(&user_stake, &pool, current_time).unwrap();
 
         // Should have existing rewards plus some pending rewards
         assert!(total_rewards >= user_stake.rewards);
@@ -408,54 +433,28 @@ mod tests {
     fn test_has_claimable_rewards() {
         // Create mock data (simplified)
         let user_stake = UserStake {
-            user: Pubkey::default(),
-            pool: Pubkey::default(),
-            amount: 1000 * 10_u64.pow(6),
-            reward_per_token_paid: 0,
             rewards: 100 * 10_u64.pow(6), // Has existing rewards
-            stake_time: 1000000,
-            unlock_time: 1000000 + DEFAULT_LOCK_DURATION,
-            is_active: true,
-            bump: 0,
+            ..mock_user_stake(1000 * 10_u64.pow(6))
         };
 
-        let pool = StakingPool {
-            authority: Pubkey::default(),
-            stake_mint: Pubkey::default(),
-            reward_mint: Pubkey::default(),
-            stake_vault: Pubkey::default(),
-            reward_vault: Pubkey::default(),
-            reward_rate: apr_to_reward_rate(10),
-            total_staked: 1000 * 10_u64.pow(6),
-            last_update_time: 1000000,
-            reward_per_token_stored: 0,
-            lock_duration: DEFAULT_LOCK_DURATION,
-            is_active: true,
-            created_at: 1000000,
-            bump: 0,
-        };
+        let pool = mock_pool(1000 * 10_u64.pow(6));
 
-        let current_time = 1000000 + (7 * 24 * 60 * 60); // 7 days later
+        let current_time = START + (7 * 24 * 60 * 60); // 7 days later
 
         // Should have claimable rewards
-        assert!(has_claimable_rewards(&user_stake, &pool, current_time));
+        assert!(has_claimable_rewards(&user_stake, &pool, current_time).unwrap());
     }
 
     #[test]
     fn test_can_user_claim_rewards_validation() {
-        let current_time = 1000000;
+        let current_time = START;
         
         // Create mock user stake
         let mut user_stake = UserStake {
-            user: Pubkey::default(),
-            pool: Pubkey::default(),
-            amount: 1000 * 10_u64.pow(6),
-            reward_per_token_paid: 0,
-            rewards: 0,
             stake_time: current_time - 1000,
             unlock_time: current_time + 1000,
             is_active: true,
-            bump: 0,
+            ..mock_user_stake(1000 * 10_u64.pow(6))
         };
 
         // Should be able to claim
@@ -470,4 +469,90 @@ mod tests {
         user_stake.amount = 0;
         assert!(can_user_claim_rewards(&user_stake, current_time).is_err());
     }
+
+    const DAY: i64 = 24 * 60 * 60;
+
+    /// A 1-token stake in a 1M-token pool, earning well under one unit per day
+    fn dust_position(min_claimable: u64) -> (StakingPool, UserStake) {
+        let pool = StakingPool { min_claimable, ..mock_pool(1_000_000 * 10_u64.pow(6)) };
+        (pool, mock_user_stake(10_u64.pow(6)))
+    }
+
+    #[test]
+    fn test_claim_cooldown() {
+        let (mut pool, mut user_stake) = dust_position(0);
+        pool.claim_cooldown = DAY;
+
+        // The first claim is never held back
+        assert!(check_claim_cooldown(&pool, &user_stake, START).is_ok());
+        user_stake.last_claim_time = START;
+
+        // Too soon after the last claim
+        let err = check_claim_cooldown(&pool, &user_stake, START + DAY - 1).unwrap_err();
+        assert_eq!(err, StakingError::ClaimTooSoon.into());
+
+        // Fine once the cooldown has passed
+        assert!(check_claim_cooldown(&pool, &user_stake, START + DAY).is_ok());
+
+        // Leftover rewards of an unstaked position can always be settled
+        user_stake.amount = 0;
+        assert!(check_claim_cooldown(&pool, &user_stake, START + 1).is_ok());
+    }
+
+    /// Claim once a day for `days` days, returning the total paid out
+    fn claim_daily(pool: &mut StakingPool, user_stake: &mut UserStake, days: i64) -> u64 {
+        (1..=days)
+            .map(|day| {
+                pool.checkpoint_rewards(START + day * DAY).unwrap();
+                settle_claim(pool, user_stake)
+            })
+            .sum()
+    }
+
+    #[test]
+    fn test_dust_is_lost_without_min_claimable() {
+        let (mut pool, mut user_stake) = dust_position(0);
+
+        // Each daily claim rounds down to zero and advances the baseline
+        assert_eq!(claim_daily(&mut pool, &mut user_stake, 10), 0);
+        assert_eq!(user_stake.reward_per_token_paid, pool.reward_per_token_stored);
+    }
+
+    #[test]
+    fn test_small_rewards_accumulate_across_claims() {
+        let (mut pool, mut user_stake) = dust_position(1);
+
+        // Three days of dust stay below one unit, so claims are deferred
+        assert_eq!(claim_daily(&mut pool, &mut user_stake, 3), 0);
+        assert_eq!(user_stake.reward_per_token_paid, 0);
+        assert_eq!(user_stake.rewards, 0);
+
+        // On day four the accrued dust crosses the threshold and pays out
+        pool.checkpoint_rewards(START + 4 * DAY).unwrap();
+        assert_eq!(settle_claim(&pool, &mut user_stake), 1);
+        assert_eq!(user_stake.reward_per_token_paid, pool.reward_per_token_stored);
+    }
+
+    #[test]
+    fn test_unstaked_position_pays_out_below_min_claimable() {
+        let (mut pool, mut user_stake) = dust_position(100);
+
+        // Leftover rewards of an unstaked position can't grow any further
+        user_stake.amount = 0;
+        user_stake.weighted_amount = 0;
+        user_stake.rewards = 5;
+        pool.checkpoint_rewards(START + DAY).unwrap();
+
+        assert_eq!(settle_claim(&pool, &mut user_stake), 5);
+        assert_eq!(user_stake.rewards, 0);
+    }
+
+    #[test]
+    fn test_settle_claim_counts_stored_rewards_once() {
+        let (mut pool, mut user_stake) = dust_position(0);
+        user_stake.rewards = 50;
+
+        pool.checkpoint_rewards(START).unwrap();
+        assert_eq!(settle_claim(&pool, &mut user_stake), 50);
+    }
 }
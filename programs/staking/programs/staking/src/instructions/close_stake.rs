@@ -0,0 +1,78 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    error::StakingError,
+    state::{StakingPool, UserStake},
+};
+
+/// Close a stake account that no longer holds anything
+/// Rent is returned to the user; stakes with principal or rewards cannot be closed
+#[derive(Accounts)]
+pub struct CloseStake<'info> {
+    /// The user closing their stake account
+    /// Must be the owner of the stake account and receives the rent
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    /// The staking pool the stake belongs to
+    pub pool: Account<'info, StakingPool>,
+
+    /// User's emptied stake account, closed and rent returned to the user
+    #[account(
+        mut,
+        close = user,
+        constraint = user_stake.user == user.key() @ StakingError::InvalidAccount,
+        constraint = user_stake.pool == pool.key() @ StakingError::InvalidAccount,
+    )]
+    pub user_stake: Account<'info, UserStake>,
+}
+
+impl<'info> CloseStake<'info> {
+    /// Verify the stake is empty; Anchor closes the account on success
+    pub fn close_stake(&mut self) -> Result<()> {
+        can_user_close_stake(&self.user_stake)?;
+
+        msg!(
+            "Stake account closed: user={}, pool={}, stake={}",
+            self.user.key(),
+            self.pool.key(),
+            self.user_stake.key()
+        );
+
+        Ok(())
+    }
+}
+
+/// Check that a stake holds no principal and no unclaimed rewards
+pub fn can_user_close_stake(user_stake: &UserStake) -> Result<()> {
+    if user_stake.amount != 0 || user_stake.rewards != 0 {
+        msg!(
+            "Stake still holds value: amount={}, rewards={}",
+            user_stake.amount,
+            user_stake.rewards
+        );
+        return Err(StakingError::StakeNotEmpty.into());
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::mock_user_stake;
+
+    #[test]
+    fn test_close_zero_balance_stake() {
+        assert!(can_user_close_stake(&UserStake { is_active: false, ..mock_user_stake(0) }).is_ok());
+    }
+
+    #[test]
+    fn test_close_rejects_stake_with_value() {
+        // Remaining principal
+        assert!(can_user_close_stake(&UserStake { is_active: false, ..mock_user_stake(1000 * 10_u64.pow(6)) }).is_err());
+
+        // Unclaimed rewards
+        assert!(can_user_close_stake(&UserStake { rewards: 5 * 10_u64.pow(6), is_active: false, ..mock_user_stake(0) }).is_err());
+    }
+}
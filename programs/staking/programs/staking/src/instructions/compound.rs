@@ -0,0 +1,204 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Token, TokenAccount};
+
+use crate::{
+    constants::*,
+    error::StakingError,
+    state::{GlobalStats, StakingPool, UserStake},
+    vault::RewardVault,
+};
+
+/// Roll accumulated rewards back into the user's principal
+/// Only available for single-token pools where rewards are paid in the stake token
+#[derive(Accounts)]
+pub struct Compound<'info> {
+    /// The user compounding rewards
+    /// Must be the owner of the stake account
+    pub user: Signer<'info>,
+
+    /// The staking pool to compound in
+    /// Stake and reward mints must match
+    #[account(
+        mut,
+        constraint = pool.stake_mint == pool.reward_mint @ StakingError::CompoundMintMismatch,
+        constraint = !pool.reward_is_native @ StakingError::CompoundMintMismatch,
+    )]
+    pub pool: Account<'info, StakingPool>,
+
+    /// User's stake account whose principal grows
+    /// Must belong to the user and be active
+    #[account(
+        mut,
+        constraint = user_stake.user == user.key() @ StakingError::InvalidAccount,
+        constraint = user_stake.pool == pool.key() @ StakingError::InvalidAccount,
+        constraint = user_stake.is_active @ StakingError::InactiveStake,
+        constraint = !user_stake.is_unbonding() @ StakingError::StakeUnbonding,
+    )]
+    pub user_stake: Account<'info, UserStake>,
+
+    /// Pool's stake vault receiving the compounded rewards
+    #[account(
+        mut,
+        constraint = stake_vault.key() == pool.stake_vault @ StakingError::InvalidTokenAccount,
+    )]
+    pub stake_vault: Account<'info, TokenAccount>,
+
+    /// Pool's reward vault the compounded rewards are moved out of
+    #[account(
+        mut,
+        constraint = reward_vault.key() == pool.reward_vault @ StakingError::InvalidTokenAccount,
+    )]
+    pub reward_vault: Account<'info, TokenAccount>,
+
+    /// Co-signer for reward disbursements
+    /// Required when the pool's reward vault is held by an external authority
+    pub reward_vault_authority: Option<Signer<'info>>,
+
+    /// Program-wide statistics updated by this instruction
+    #[account(
+        mut,
+        seeds = [GLOBAL_STATS_SEED],
+        bump = global_stats.bump,
+    )]
+    pub global_stats: Account<'info, GlobalStats>,
+
+    /// Required system programs
+    pub token_program: Program<'info, Token>,
+}
+
+impl<'info> Compound<'info> {
+    /// Execute the compounding operation
+    pub fn compound(&mut self) -> Result<()> {
+        let current_time = Clock::get()?.unix_timestamp;
+
+        // Validate timestamp
+        crate::error::validate_timestamp(current_time)?;
+
+        if self.user_stake.amount == 0 {
+            return Err(StakingError::NoActiveStake.into());
+        }
+
+        // Settle rewards into principal
+        let compounded = apply_compound(&mut self.pool, &mut self.user_stake, current_time)?;
+
+        if compounded == 0 {
+            return Err(StakingError::NoRewardsAvailable.into());
+        }
+
+        // Back the new principal with tokens: reward vault -> stake vault
+        self.move_rewards_to_stake_vault(compounded)?;
+
+        // Compounded rewards count as both distributed and newly staked
+        self.global_stats.record_rewards_distributed(compounded)?;
+        self.global_stats.record_stake(compounded)?;
+
+        msg!(
+            "COMPOUND EVENT: user={}, pool={}, compounded={}, new_stake_amount={}, total_staked={}",
+            self.user.key(),
+            self.pool.key(),
+            compounded,
+            self.user_stake.amount,
+            self.pool.total_staked
+        );
+
+        Ok(())
+    }
+
+    /// Transfer compounded rewards into the stake vault so principal stays fully backed
+    fn move_rewards_to_stake_vault(&self, amount: u64) -> Result<()> {
+        RewardVault::new(&self.pool, &self.reward_vault, &self.reward_vault_authority, &self.token_program)
+            .withdraw(&self.stake_vault.to_account_info(), amount)
+    }
+}
+
+/// Checkpoint the pool and move the user's claimable rewards into their principal
+/// Returns the amount compounded
+pub fn apply_compound(pool: &mut StakingPool, user_stake: &mut UserStake, current_time: i64) -> Result<u64> {
+    // Lock in rewards earned up to now at the current stake sizes
+    pool.checkpoint_rewards(current_time)?;
+
+    // Includes rewards left unclaimed on the stake account
+    let claimable = user_stake.calculate_pending_rewards(pool.reward_per_token_stored);
+
+    // Compounded principal earns at the stake's original lock tier and boost
+    let added_weight = user_stake.weight_of(claimable)
+        .ok_or(StakingError::MathOverflow)?;
+
+    user_stake.amount = user_stake.amount
+        .checked_add(claimable)
+        .ok_or(StakingError::MathOverflow)?;
+    user_stake.weighted_amount = user_stake.weighted_amount
+        .checked_add(added_weight)
+        .ok_or(StakingError::MathOverflow)?;
+    pool.total_staked = pool.total_staked
+        .checked_add(added_weight)
+        .ok_or(StakingError::MathOverflow)?;
+
+    user_stake.rewards = 0;
+    user_stake.reward_per_token_paid = pool.reward_per_token_stored;
+
+    // Compounded rewards leave the reward vault
+    pool.release_owed_rewards(claimable);
+
+    Ok(claimable)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{mock_pool, mock_user_stake, START};
+
+    #[test]
+    fn test_compound_grows_principal() {
+        let stake_amount = 1000 * 10_u64.pow(6);
+        let mut pool = mock_pool(stake_amount);
+        let mut user_stake = mock_user_stake(stake_amount);
+
+        let compounded = apply_compound(&mut pool, &mut user_stake, START + 3600).unwrap();
+
+        assert!(compounded > 0);
+        assert_eq!(user_stake.amount, stake_amount + compounded);
+        assert_eq!(pool.total_staked, stake_amount + compounded);
+        assert_eq!(user_stake.rewards, 0);
+        assert_eq!(user_stake.reward_per_token_paid, pool.reward_per_token_stored);
+
+        // Nothing left to compound at the same instant
+        assert_eq!(apply_compound(&mut pool, &mut user_stake, START + 3600).unwrap(), 0);
+    }
+
+    #[test]
+    fn test_compound_keeps_lock_tier_multiplier() {
+        let stake_amount = 1000 * 10_u64.pow(6);
+        let mut user_stake = mock_user_stake(stake_amount);
+        user_stake.reward_multiplier_bps = LOCK_TIER_1_MULTIPLIER_BPS;
+        user_stake.weighted_amount = calculate_weighted_amount(stake_amount, LOCK_TIER_1_MULTIPLIER_BPS).unwrap();
+        let mut pool = mock_pool(user_stake.weighted_amount);
+
+        let compounded = apply_compound(&mut pool, &mut user_stake, START + 3600).unwrap();
+
+        // Weight grows by the compounded amount at the same multiplier
+        let expected_weight = calculate_weighted_amount(stake_amount + compounded, LOCK_TIER_1_MULTIPLIER_BPS).unwrap();
+        assert!(user_stake.weighted_amount.abs_diff(expected_weight) <= 1);
+        assert_eq!(pool.total_staked, user_stake.weighted_amount);
+    }
+
+    #[test]
+    fn test_compound_scales_future_rewards() {
+        let stake_amount = 1000 * 10_u64.pow(6);
+        let mut pool = mock_pool(stake_amount * 2);
+        let mut compounder = mock_user_stake(stake_amount);
+        let holder = mock_user_stake(stake_amount);
+
+        apply_compound(&mut pool, &mut compounder, START + 3600).unwrap();
+        let holder_before = holder.calculate_pending_rewards(pool.reward_per_token_stored);
+
+        // Over the next hour the compounder earns in proportion to its larger principal
+        let next_rpt = pool.calculate_reward_per_token(START + 7200).unwrap();
+        let compounder_earned = compounder.calculate_pending_rewards(next_rpt);
+        let holder_earned = holder.calculate_pending_rewards(next_rpt) - holder_before;
+
+        assert!(compounder_earned > holder_earned);
+        let expected = (holder_earned as u128) * (compounder.weighted_amount as u128) / (holder.weighted_amount as u128);
+        assert!((compounder_earned as u128).abs_diff(expected) <= 1);
+    }
+}
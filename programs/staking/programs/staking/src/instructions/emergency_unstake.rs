@@ -0,0 +1,243 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Mint, Token, TokenAccount, Transfer};
+
+use crate::{
+    constants::*,
+    error::StakingError,
+    state::{GlobalStats, StakingPool, UserStake},
+};
+
+/// Emitted when a user exits a stake early and pays the penalty
+#[event]
+pub struct EmergencyUnstake {
+    pub user: Pubkey,
+    pub amount_returned: u64,
+    pub penalty: u64,
+}
+
+/// Withdraw principal at any time, ignoring the lock period
+/// All accrued rewards are forfeited and the pool's early unstake penalty
+/// is deducted from principal; the penalty stays in the stake vault
+#[derive(Accounts)]
+pub struct EmergencyUnstakeAccounts<'info> {
+    /// The user who is exiting their stake
+    /// Must be the owner of the stake account
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    /// The staking pool to withdraw from
+    #[account(mut)]
+    pub pool: Account<'info, StakingPool>,
+
+    /// User's stake account, closed and rent returned to the user
+    #[account(
+        mut,
+        close = user,
+        constraint = user_stake.user == user.key() @ StakingError::InvalidAccount,
+        constraint = user_stake.pool == pool.key() @ StakingError::InvalidAccount,
+        constraint = user_stake.is_active @ StakingError::InactiveStake,
+    )]
+    pub user_stake: Account<'info, UserStake>,
+
+    /// User's token account to receive the remaining principal
+    #[account(
+        mut,
+        constraint = user_stake_token_account.mint == pool.stake_mint @ StakingError::InvalidTokenMint,
+        constraint = user_stake_token_account.owner == user.key() @ StakingError::InvalidTokenAccountOwner,
+    )]
+    pub user_stake_token_account: Account<'info, TokenAccount>,
+
+    /// Pool's stake vault containing the staked tokens
+    #[account(
+        mut,
+        constraint = stake_vault.key() == pool.stake_vault @ StakingError::InvalidTokenAccount,
+    )]
+    pub stake_vault: Account<'info, TokenAccount>,
+
+    /// The stake token mint (for validation)
+    #[account(
+        constraint = stake_mint.key() == pool.stake_mint @ StakingError::InvalidTokenMint,
+    )]
+    pub stake_mint: Account<'info, Mint>,
+
+    /// Program-wide statistics updated by this instruction
+    #[account(
+        mut,
+        seeds = [GLOBAL_STATS_SEED],
+        bump = global_stats.bump,
+    )]
+    pub global_stats: Account<'info, GlobalStats>,
+
+    /// Required system programs
+    pub system_program: Program<'info, System>,
+    pub token_program: Program<'info, Token>,
+}
+
+impl<'info> EmergencyUnstakeAccounts<'info> {
+    /// Execute the emergency unstake
+    pub fn emergency_unstake(&mut self) -> Result<()> {
+        let current_time = Clock::get()?.unix_timestamp;
+
+        // Validate the stake can be exited (no lock check)
+        can_user_emergency_unstake(&self.user_stake)?;
+
+        // Checkpoint rewards before total_staked changes so other stakers keep theirs
+        self.pool.checkpoint_rewards(current_time)?;
+
+        // Rewards are forfeited and no longer owed
+        let forfeited_rewards = self
+            .user_stake
+            .calculate_pending_rewards(self.pool.reward_per_token_stored);
+        self.pool.release_owed_rewards(forfeited_rewards);
+
+        let stake_amount = self.user_stake.amount;
+        let (amount_returned, penalty) =
+            calculate_emergency_unstake(stake_amount, self.pool.early_unstake_penalty_bps)?;
+
+        // Return principal minus the penalty
+        self.transfer_staked_tokens(amount_returned)?;
+
+        // The whole stake leaves the pool's accounting; the penalty stays in the vault
+        self.pool.total_staked = self.pool.total_staked
+            .checked_sub(self.user_stake.weighted_amount)
+            .ok_or(StakingError::MathOverflow)?;
+
+        self.global_stats.record_unstake(stake_amount)?;
+
+        emit!(EmergencyUnstake {
+            user: self.user.key(),
+            amount_returned,
+            penalty,
+        });
+
+        msg!(
+            "EMERGENCY UNSTAKE: user={}, pool={}, returned={}, penalty={}, forfeited_rewards={}, locked={}",
+            self.user.key(),
+            self.pool.key(),
+            amount_returned,
+            penalty,
+            forfeited_rewards,
+            !self.user_stake.can_unstake(current_time)
+        );
+
+        Ok(())
+    }
+
+    /// Transfer the remaining principal back to the user
+    fn transfer_staked_tokens(&self, amount: u64) -> Result<()> {
+        if amount == 0 {
+            return Ok(());
+        }
+
+        // Check vault has sufficient balance
+        if self.stake_vault.amount < amount {
+            msg!(
+                "Insufficient stake vault balance: has {}, needs {}",
+                self.stake_vault.amount,
+                amount
+            );
+            return Err(StakingError::InsufficientTokenBalance.into());
+        }
+
+        // Create PDA signer seeds for pool authority
+        let seeds = &[
+            POOL_SEED,
+            self.pool.creator.as_ref(),
+            &self.pool.pool_id.to_le_bytes(),
+            &[self.pool.bump],
+        ];
+        let signer_seeds = &[&seeds[..]];
+
+        // Create transfer context with pool as authority
+        let transfer_ctx = CpiContext::new_with_signer(
+            self.token_program.to_account_info(),
+            Transfer {
+                from: self.stake_vault.to_account_info(),
+                to: self.user_stake_token_account.to_account_info(),
+                authority: self.pool.to_account_info(),
+            },
+            signer_seeds,
+        );
+
+        token::transfer(transfer_ctx, amount)
+    }
+}
+
+/// Split a stake into the amount returned and the penalty kept by the pool
+pub fn calculate_emergency_unstake(stake_amount: u64, penalty_bps: u16) -> Result<(u64, u64)> {
+    let amount_returned = (stake_amount as u128)
+        .checked_mul(BPS_DENOMINATOR as u128 - penalty_bps as u128)
+        .and_then(|x| x.checked_div(BPS_DENOMINATOR as u128))
+        .ok_or(StakingError::MathOverflow)? as u64;
+
+    let penalty = stake_amount
+        .checked_sub(amount_returned)
+        .ok_or(StakingError::MathOverflow)?;
+
+    Ok((amount_returned, penalty))
+}
+
+/// Check if a user can emergency unstake (ignores the lock period)
+pub fn can_user_emergency_unstake(user_stake: &UserStake) -> Result<()> {
+    if !user_stake.is_active {
+        return Err(StakingError::InactiveStake.into());
+    }
+
+    if user_stake.amount == 0 {
+        return Err(StakingError::CannotUnstakeZero.into());
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{mock_user_stake, START};
+
+    #[test]
+    fn test_emergency_unstake_while_locked() {
+        let current_time = START + 100;
+        let user_stake = mock_user_stake(1000 * 10_u64.pow(6));
+
+        // Regular unstake is blocked, emergency unstake is not
+        assert!(!user_stake.can_unstake(current_time));
+        assert!(can_user_emergency_unstake(&user_stake).is_ok());
+    }
+
+    #[test]
+    fn test_emergency_unstake_while_unlocked() {
+        let current_time = START + DEFAULT_LOCK_DURATION + 100;
+        let mut user_stake = mock_user_stake(1000 * 10_u64.pow(6));
+
+        assert!(user_stake.can_unstake(current_time));
+        assert!(can_user_emergency_unstake(&user_stake).is_ok());
+
+        // Inactive or empty stakes still fail
+        user_stake.amount = 0;
+        assert!(can_user_emergency_unstake(&user_stake).is_err());
+        user_stake.amount = 1000 * 10_u64.pow(6);
+        user_stake.is_active = false;
+        assert!(can_user_emergency_unstake(&user_stake).is_err());
+    }
+
+    #[test]
+    fn test_calculate_emergency_unstake() {
+        let stake_amount = 1000 * 10_u64.pow(6);
+
+        // 10% penalty
+        let (returned, penalty) = calculate_emergency_unstake(stake_amount, 1_000).unwrap();
+        assert_eq!(returned, 900 * 10_u64.pow(6));
+        assert_eq!(penalty, 100 * 10_u64.pow(6));
+
+        // No penalty returns everything
+        let (returned, penalty) = calculate_emergency_unstake(stake_amount, 0).unwrap();
+        assert_eq!(returned, stake_amount);
+        assert_eq!(penalty, 0);
+
+        // Rounding always favours the pool and nothing is lost
+        let (returned, penalty) = calculate_emergency_unstake(999, 1_000).unwrap();
+        assert_eq!(returned, 899);
+        assert_eq!(returned + penalty, 999);
+    }
+}
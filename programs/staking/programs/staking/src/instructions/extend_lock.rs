@@ -0,0 +1,198 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::*,
+    error::StakingError,
+    state::{StakingPool, UserStake},
+};
+
+/// Voluntarily relock a stake for longer without unstaking
+#[derive(Accounts)]
+pub struct ExtendLock<'info> {
+    /// The owner of the stake
+    pub user: Signer<'info>,
+
+    /// The staking pool the stake belongs to
+    /// Its total weight changes if the stake reaches a higher lock tier
+    #[account(mut)]
+    pub pool: Account<'info, StakingPool>,
+
+    /// The stake being relocked
+    /// PDA: ["stake", pool.key(), user.key()]
+    #[account(
+        mut,
+        seeds = [STAKE_SEED, pool.key().as_ref(), user.key().as_ref()],
+        bump = user_stake.bump,
+        constraint = user_stake.pool == pool.key() @ StakingError::InvalidAccount,
+        constraint = user_stake.is_active @ StakingError::InactiveStake,
+        constraint = !user_stake.is_unbonding() @ StakingError::StakeUnbonding,
+    )]
+    pub user_stake: Account<'info, UserStake>,
+}
+
+impl<'info> ExtendLock<'info> {
+    /// Execute the lock extension
+    pub fn extend_lock(&mut self, additional_seconds: i64) -> Result<()> {
+        let current_time = Clock::get()?.unix_timestamp;
+        let old_multiplier_bps = self.user_stake.reward_multiplier_bps;
+
+        apply_lock_extension(&mut self.pool, &mut self.user_stake, additional_seconds, current_time)?;
+
+        msg!(
+            "LOCK EXTENDED: user={}, pool={}, additional_seconds={}, unlock_time={}, multiplier_bps={}->{}, weighted_amount={}",
+            self.user.key(),
+            self.pool.key(),
+            additional_seconds,
+            self.user_stake.unlock_time,
+            old_multiplier_bps,
+            self.user_stake.reward_multiplier_bps,
+            self.user_stake.weighted_amount
+        );
+
+        Ok(())
+    }
+}
+
+/// Move a stake's unlock time `additional_seconds` later
+/// The whole lock, measured from `stake_time`, may not exceed MAX_LOCK_DURATION
+/// If the longer lock reaches a higher tier, rewards earned so far are settled
+/// at the old weight and the stake earns at the new weight from here on
+pub fn apply_lock_extension(
+    pool: &mut StakingPool,
+    user_stake: &mut UserStake,
+    additional_seconds: i64,
+    current_time: i64,
+) -> Result<()> {
+    // Shortening a lock (or a no-op) is never allowed
+    if additional_seconds <= 0 {
+        return Err(StakingError::InvalidLockExtension.into());
+    }
+
+    let new_unlock_time = user_stake.unlock_time
+        .checked_add(additional_seconds)
+        .ok_or(StakingError::MathOverflow)?;
+
+    // A lock that would already have expired earns nothing by being "extended"
+    if new_unlock_time <= current_time {
+        return Err(StakingError::InvalidLockExtension.into());
+    }
+
+    let new_lock_duration = new_unlock_time - user_stake.stake_time;
+    if new_lock_duration > MAX_LOCK_DURATION {
+        return Err(StakingError::LockExtensionExceedsMax.into());
+    }
+
+    user_stake.unlock_time = new_unlock_time;
+
+    let new_multiplier_bps = lock_tier_multiplier_bps(new_lock_duration);
+    if new_multiplier_bps <= user_stake.reward_multiplier_bps {
+        return Ok(());
+    }
+
+    // Lock in rewards earned at the current weight
+    pool.checkpoint_rewards(current_time)?;
+    user_stake.rewards = user_stake.calculate_pending_rewards(pool.reward_per_token_stored);
+    user_stake.reward_per_token_paid = pool.reward_per_token_stored;
+
+    user_stake.reward_multiplier_bps = new_multiplier_bps;
+    let new_weight = user_stake.weight_of(user_stake.amount)
+        .ok_or(StakingError::MathOverflow)?;
+    let added_weight = new_weight.saturating_sub(user_stake.weighted_amount);
+
+    user_stake.weighted_amount = new_weight;
+    pool.total_staked = pool.total_staked
+        .checked_add(added_weight)
+        .ok_or(StakingError::MathOverflow)?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::instructions::unstake::{can_user_unstake, settle_final_rewards};
+    use crate::test_utils::{mock_pool, mock_user_stake, START};
+
+    #[test]
+    fn test_extension_within_tier() {
+        let stake_amount = 1000 * 10_u64.pow(6);
+        let mut pool = mock_pool(stake_amount);
+        let mut user_stake = mock_user_stake(stake_amount);
+
+        // 7 + 1 days stays below the first tier
+        apply_lock_extension(&mut pool, &mut user_stake, 24 * 60 * 60, START).unwrap();
+
+        assert_eq!(user_stake.unlock_time, START + DEFAULT_LOCK_DURATION + 24 * 60 * 60);
+        assert_eq!(user_stake.reward_multiplier_bps, BASE_REWARD_MULTIPLIER_BPS);
+        assert_eq!(user_stake.weighted_amount, stake_amount);
+        assert_eq!(pool.total_staked, stake_amount);
+    }
+
+    #[test]
+    fn test_extension_into_higher_tier_reweights_stake() {
+        let stake_amount = 1000 * 10_u64.pow(6);
+        let mut pool = mock_pool(stake_amount);
+        let mut user_stake = mock_user_stake(stake_amount);
+
+        // Rewards earned before the extension are kept at the old weight
+        let earned_before = user_stake.calculate_pending_rewards(pool.calculate_reward_per_token(START + 3600).unwrap());
+        let to_tier_2 = LOCK_TIER_2_DURATION - DEFAULT_LOCK_DURATION;
+        apply_lock_extension(&mut pool, &mut user_stake, to_tier_2, START + 3600).unwrap();
+
+        assert_eq!(user_stake.unlock_time, START + LOCK_TIER_2_DURATION);
+        assert_eq!(user_stake.reward_multiplier_bps, LOCK_TIER_2_MULTIPLIER_BPS);
+        assert_eq!(user_stake.weighted_amount, 1500 * 10_u64.pow(6));
+        assert_eq!(pool.total_staked, 1500 * 10_u64.pow(6));
+        assert_eq!(user_stake.rewards, earned_before);
+    }
+
+    #[test]
+    fn test_extended_stake_unstakes_with_rewards_paid_once() {
+        let stake_amount = 1000 * 10_u64.pow(6);
+        let mut pool = mock_pool(stake_amount);
+        let mut user_stake = mock_user_stake(stake_amount);
+
+        // Extending into a higher tier stores the rewards earned so far
+        let to_tier_1 = LOCK_TIER_1_DURATION - DEFAULT_LOCK_DURATION;
+        apply_lock_extension(&mut pool, &mut user_stake, to_tier_1, START + 3600).unwrap();
+        assert!(user_stake.rewards > 0);
+
+        // The sole staker is paid the pool's whole emission at unstake, no more
+        let unlocked = START + LOCK_TIER_1_DURATION;
+        assert!(can_user_unstake(&user_stake, unlocked).is_ok());
+        pool.checkpoint_rewards(unlocked).unwrap();
+        let final_rewards = settle_final_rewards(&pool, &mut user_stake);
+
+        assert!(final_rewards <= pool.total_rewards_owed);
+        assert!(pool.total_rewards_owed - final_rewards <= 1);
+    }
+
+    #[test]
+    fn test_extension_past_max_duration_rejected() {
+        let stake_amount = 1000 * 10_u64.pow(6);
+        let mut pool = mock_pool(stake_amount);
+        let mut user_stake = mock_user_stake(stake_amount);
+
+        // Exactly reaching the cap is allowed, one second more is not
+        let to_max = MAX_LOCK_DURATION - DEFAULT_LOCK_DURATION;
+        assert!(apply_lock_extension(&mut pool, &mut user_stake.clone(), to_max + 1, START).is_err());
+        apply_lock_extension(&mut pool, &mut user_stake, to_max, START).unwrap();
+        assert_eq!(user_stake.unlock_time, START + MAX_LOCK_DURATION);
+        assert_eq!(user_stake.reward_multiplier_bps, LOCK_TIER_3_MULTIPLIER_BPS);
+    }
+
+    #[test]
+    fn test_shortening_or_expired_extension_rejected() {
+        let stake_amount = 1000 * 10_u64.pow(6);
+        let mut pool = mock_pool(stake_amount);
+        let mut user_stake = mock_user_stake(stake_amount);
+
+        assert!(apply_lock_extension(&mut pool, &mut user_stake, 0, START).is_err());
+        assert!(apply_lock_extension(&mut pool, &mut user_stake, -3600, START).is_err());
+
+        // A long-expired lock can't be extended to a time still in the past
+        let later = START + DEFAULT_LOCK_DURATION + 10 * 24 * 60 * 60;
+        assert!(apply_lock_extension(&mut pool, &mut user_stake, 24 * 60 * 60, later).is_err());
+        assert_eq!(user_stake.unlock_time, START + DEFAULT_LOCK_DURATION);
+    }
+}
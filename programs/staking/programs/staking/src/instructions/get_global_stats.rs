@@ -0,0 +1,105 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::*,
+    state::GlobalStats,
+};
+
+/// Read-only view of the program-wide statistics
+/// Intended for dashboards via simulation; nothing is modified
+#[derive(Accounts)]
+pub struct GetGlobalStats<'info> {
+    /// The global statistics account
+    #[account(
+        seeds = [GLOBAL_STATS_SEED],
+        bump = global_stats.bump,
+    )]
+    pub global_stats: Account<'info, GlobalStats>,
+}
+
+impl<'info> GetGlobalStats<'info> {
+    /// Build the snapshot returned to the caller
+    pub fn get_global_stats(&self) -> Result<GlobalStatsSnapshot> {
+        let snapshot = GlobalStatsSnapshot::from(&*self.global_stats);
+
+        msg!(
+            "GLOBAL STATS: total_staked={}, total_rewards_distributed={}, total_pools_created={}",
+            snapshot.total_staked,
+            snapshot.total_rewards_distributed,
+            snapshot.total_pools_created
+        );
+
+        Ok(snapshot)
+    }
+}
+
+/// Program-wide totals returned by get_global_stats
+/// Anchor serializes this into the transaction return data (set_return_data)
+#[derive(AnchorSerialize, AnchorDeserialize, Debug, Clone, PartialEq, Eq)]
+pub struct GlobalStatsSnapshot {
+    pub total_staked: u64,
+    pub total_rewards_distributed: u64,
+    pub total_pools_created: u64,
+}
+
+impl From<&GlobalStats> for GlobalStatsSnapshot {
+    fn from(stats: &GlobalStats) -> Self {
+        Self {
+            total_staked: stats.total_staked,
+            total_rewards_distributed: stats.total_rewards_distributed,
+            total_pools_created: stats.total_pools_created,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn create_mock_stats() -> GlobalStats {
+        GlobalStats {
+            total_staked: 0,
+            total_rewards_distributed: 0,
+            total_pools_created: 0,
+            bump: 0,
+        }
+    }
+
+    #[test]
+    fn test_global_stats_aggregate_across_pools() {
+        let mut stats = create_mock_stats();
+
+        // Two pools created, each receiving stakes
+        stats.record_pool_created().unwrap();
+        stats.record_pool_created().unwrap();
+        stats.record_stake(1000 * 10_u64.pow(6)).unwrap();
+        stats.record_stake(2000 * 10_u64.pow(6)).unwrap();
+
+        // One user leaves the first pool with rewards, another claims on the second
+        stats.record_unstake(1000 * 10_u64.pow(6)).unwrap();
+        stats.record_rewards_distributed(10 * 10_u64.pow(6)).unwrap();
+        stats.record_rewards_distributed(5 * 10_u64.pow(6)).unwrap();
+
+        let snapshot = GlobalStatsSnapshot::from(&stats);
+        assert_eq!(snapshot.total_pools_created, 2);
+        assert_eq!(snapshot.total_staked, 2000 * 10_u64.pow(6));
+        assert_eq!(snapshot.total_rewards_distributed, 15 * 10_u64.pow(6));
+    }
+
+    #[test]
+    fn test_global_stats_checked_arithmetic() {
+        let mut stats = create_mock_stats();
+
+        // Unstaking more than is recorded must not wrap
+        assert!(stats.record_unstake(1).is_err());
+
+        stats.total_staked = u64::MAX;
+        assert!(stats.record_stake(1).is_err());
+
+        stats.total_rewards_distributed = u64::MAX;
+        assert!(stats.record_rewards_distributed(1).is_err());
+
+        stats.total_pools_created = u64::MAX;
+        assert!(stats.record_pool_created().is_err());
+    }
+}
@@ -0,0 +1,93 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::*,
+    state::{RateChange, RateHistory, StakingPool},
+};
+
+/// Read-only view of a pool's recent reward rate changes
+/// Intended for charting effective yields via simulation; nothing is modified
+#[derive(Accounts)]
+pub struct GetRateHistory<'info> {
+    /// The staking pool whose history is read
+    pub pool: Account<'info, StakingPool>,
+
+    /// The pool's rate history, created on its first rate change
+    #[account(
+        seeds = [RATE_HISTORY_SEED, pool.key().as_ref()],
+        bump = rate_history.bump,
+    )]
+    pub rate_history: Account<'info, RateHistory>,
+}
+
+impl<'info> GetRateHistory<'info> {
+    /// Build the list returned to the caller, oldest change first
+    pub fn get_rate_history(&self) -> Result<Vec<RateChange>> {
+        let entries = self.rate_history.recent();
+
+        msg!(
+            "RATE HISTORY: pool={}, entries={}, current_rate={} ({} bps APR)",
+            self.pool.key(),
+            entries.len(),
+            self.pool.reward_rate,
+            reward_rate_to_apr_bps(self.pool.reward_rate)
+        );
+
+        Ok(entries)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn empty_history() -> RateHistory {
+        RateHistory {
+            pool: Pubkey::default(),
+            entries: Vec::new(),
+            head: 0,
+            bump: 0,
+        }
+    }
+
+    fn change(index: u64) -> RateChange {
+        RateChange {
+            timestamp: 1000000 + index as i64,
+            old_rate: apr_to_reward_rate(index),
+            new_rate: apr_to_reward_rate(index + 1),
+        }
+    }
+
+    #[test]
+    fn test_records_changes_in_order() {
+        let mut history = empty_history();
+
+        history.record(change(1));
+        history.record(change(2));
+        history.record(change(3));
+
+        assert_eq!(history.recent(), vec![change(1), change(2), change(3)]);
+    }
+
+    #[test]
+    fn test_full_history_evicts_oldest() {
+        let mut history = empty_history();
+        let capacity = RATE_HISTORY_CAPACITY as u64;
+
+        for index in 0..capacity {
+            history.record(change(index));
+        }
+        assert_eq!(history.recent().len(), RATE_HISTORY_CAPACITY);
+        assert_eq!(history.recent()[0], change(0));
+
+        // Two more changes push out the two oldest
+        history.record(change(capacity));
+        history.record(change(capacity + 1));
+
+        let recent = history.recent();
+        assert_eq!(recent.len(), RATE_HISTORY_CAPACITY);
+        assert_eq!(recent[0], change(2));
+        assert_eq!(recent[RATE_HISTORY_CAPACITY - 1], change(capacity + 1));
+        assert!(recent.windows(2).all(|pair| pair[0].timestamp < pair[1].timestamp));
+    }
+}
@@ -0,0 +1,45 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::*,
+    state::GlobalStats,
+};
+
+/// Create the program-wide statistics account
+/// Must run once before any pool is created
+#[derive(Accounts)]
+pub struct InitializeGlobalStats<'info> {
+    /// Whoever pays for the singleton account (no special permissions)
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// The global statistics account
+    /// PDA: ["global_stats"]
+    #[account(
+        init,
+        payer = payer,
+        space = DISCRIMINATOR_SIZE + GlobalStats::INIT_SPACE,
+        seeds = [GLOBAL_STATS_SEED],
+        bump
+    )]
+    pub global_stats: Account<'info, GlobalStats>,
+
+    /// Required system program
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> InitializeGlobalStats<'info> {
+    /// Initialize all totals to zero
+    pub fn initialize_global_stats(&mut self, bumps: &InitializeGlobalStatsBumps) -> Result<()> {
+        self.global_stats.set_inner(GlobalStats {
+            total_staked: 0,
+            total_rewards_distributed: 0,
+            total_pools_created: 0,
+            bump: bumps.global_stats,
+        });
+
+        msg!("Global stats initialized by {}", self.payer.key());
+
+        Ok(())
+    }
+}
@@ -7,7 +7,8 @@ use anchor_spl::{
 use crate::{
     constants::*,
     error::StakingError,
-    state::StakingPool,
+    state::{GlobalStats, StakingPool},
+    vault::Vault,
 };
 
 /// Initialize a new staking pool with specified parameters
@@ -26,7 +27,7 @@ pub struct InitializePool<'info> {
     #[account(
         init,
         payer = authority,
-        space = StakingPool::INIT_SPACE,
+        space = 8 + StakingPool::INIT_SPACE,
         seeds = [POOL_SEED, authority.key().as_ref(), pool_id.to_le_bytes().as_ref()],
         bump
     )]
@@ -65,6 +66,24 @@ pub struct InitializePool<'info> {
     )]
     pub reward_vault: Account<'info, TokenAccount>,
 
+    /// System account that will hold lamports for native SOL rewards
+    /// PDA: ["reward_sol_vault", pool.key()]
+    /// Funded to rent exemption here when the pool pays rewards in SOL
+    #[account(
+        mut,
+        seeds = [REWARD_SOL_VAULT_SEED, pool.key().as_ref()],
+        bump,
+    )]
+    pub reward_sol_vault: SystemAccount<'info>,
+
+    /// Program-wide statistics updated by this instruction
+    #[account(
+        mut,
+        seeds = [GLOBAL_STATS_SEED],
+        bump = global_stats.bump,
+    )]
+    pub global_stats: Account<'info, GlobalStats>,
+
     /// Required system programs
     pub system_program: Program<'info, System>,
     pub token_program: Program<'info, Token>,
@@ -74,43 +93,114 @@ pub struct InitializePool<'info> {
 
 impl<'info> InitializePool<'info> {
     /// Initialize the staking pool with the provided parameters
+    #[allow(clippy::too_many_arguments)]
     pub fn initialize_pool(
         &mut self,
         pool_id: u64,
         reward_rate: u64,
         lock_duration: i64,
+        early_unstake_penalty_bps: u16,
+        start_time: i64,
+        end_time: i64,
+        reward_duration: i64,
+        min_update_interval: i64,
+        reward_is_native: bool,
         bumps: &InitializePoolBumps,
     ) -> Result<()> {
         // Get current timestamp for pool creation
         let current_time = Clock::get()?.unix_timestamp;
 
         // Validate input parameters before proceeding
-        self.validate_parameters(reward_rate, lock_duration)?;
+        self.validate_parameters(reward_rate, lock_duration, early_unstake_penalty_bps)?;
+
+        // Validate the staking window is non-empty
+        if !is_valid_staking_window(start_time, end_time) {
+            msg!(
+                "Invalid staking window: start={}, end={}. End must be after start",
+                start_time,
+                end_time
+            );
+            return Err(StakingError::InvalidStakingWindow.into());
+        }
+
+        // Validate the reward period has a positive length
+        if reward_duration <= 0 {
+            msg!("Invalid reward duration: {} seconds. Must be positive", reward_duration);
+            return Err(StakingError::InvalidRewardDuration.into());
+        }
+
+        // Validate the update interval (zero allows every-slot updates)
+        if min_update_interval < 0 {
+            msg!("Invalid minimum update interval: {} seconds. Cannot be negative", min_update_interval);
+            return Err(StakingError::InvalidMinUpdateInterval.into());
+        }
+
+        // The SOL vault must be rent exempt before it can hold rewards
+        if reward_is_native {
+            self.fund_reward_sol_vault_rent()?;
+        }
 
         // Initialize the pool account with all necessary data
         let pool = &mut self.pool;
         
         // Set pool authority and basic configuration
         pool.authority = self.authority.key();
+        pool.creator = self.authority.key();
+        pool.pending_authority = None;
+        pool.pool_id = pool_id;
         pool.stake_mint = self.stake_mint.key();
         pool.reward_mint = self.reward_mint.key();
         pool.stake_vault = self.stake_vault.key();
         pool.reward_vault = self.reward_vault.key();
 
+        // Rewards start in pool PDA custody, like the stake vault
+        pool.reward_vault_authority = pool.key();
+
         // Set reward parameters
         pool.reward_rate = reward_rate;
         pool.lock_duration = lock_duration;
+        pool.early_unstake_penalty_bps = early_unstake_penalty_bps;
+
+        // Set the window during which stakes are accepted
+        pool.start_time = start_time;
+        pool.end_time = end_time;
+
+        // No early staker boost until set_early_boost is called
+        pool.boost_end_time = 0;
+        pool.boost_multiplier_bps = BASE_REWARD_MULTIPLIER_BPS;
+
+        // Stakes can be withdrawn directly until set_cooldown_period is called
+        pool.cooldown_period = 0;
+
+        // Open to every wallet until set_whitelist_required is called
+        pool.whitelist_required = false;
+        pool.reward_accrual_halted = false;
+
+        // Native SOL pools pay rewards from the reward SOL vault
+        pool.reward_is_native = reward_is_native;
 
         // Initialize state variables
         pool.total_staked = 0;
         pool.last_update_time = current_time;
         pool.reward_per_token_stored = 0;
 
+        // Rewards accrue for a fixed period from creation
+        pool.reward_end_time = current_time
+            .checked_add(reward_duration)
+            .ok_or(StakingError::MathOverflow)?;
+        pool.total_rewards_owed = 0;
+        pool.min_claimable = 0;
+        pool.claim_cooldown = 0;
+        pool.min_update_interval = min_update_interval;
+
         // Set pool status and metadata
         pool.is_active = true;
         pool.created_at = current_time;
         pool.bump = bumps.pool;
 
+        // Count the new pool in the program-wide statistics
+        self.global_stats.record_pool_created()?;
+
         // Log pool creation for monitoring and debugging
         msg!(
             "Staking pool initialized: ID={}, Authority={}, StakeMint={}, RewardMint={}",
@@ -121,17 +211,39 @@ impl<'info> InitializePool<'info> {
         );
 
         msg!(
-            "Pool parameters: RewardRate={}, LockDuration={} seconds, APR={}%",
+            "Pool parameters: RewardRate={}, LockDuration={} seconds, APR={} bps",
             pool.reward_rate,
             pool.lock_duration,
-            reward_rate_to_apr(pool.reward_rate)
+            reward_rate_to_apr_bps(pool.reward_rate)
+        );
+
+        msg!(
+            "Staking window: StartTime={}, EndTime={}, RewardEndTime={}",
+            pool.start_time,
+            pool.end_time,
+            pool.reward_end_time
         );
 
         Ok(())
     }
 
+    /// Create the reward SOL vault by topping it up to its rent-exempt minimum
+    /// Reward lamports are then sent to the vault address with plain transfers
+    fn fund_reward_sol_vault_rent(&self) -> Result<()> {
+        let vault = Vault::new(&self.reward_sol_vault, &self.system_program);
+        let shortfall = vault.rent_exempt_minimum()?.saturating_sub(vault.balance());
+
+        if shortfall > 0 {
+            vault.deposit_sol(&self.authority.to_account_info(), shortfall)?;
+        }
+
+        msg!("Reward SOL vault ready: {}", self.reward_sol_vault.key());
+
+        Ok(())
+    }
+
     /// Validate all input parameters to ensure they meet our requirements
-    fn validate_parameters(&self, reward_rate: u64, lock_duration: i64) -> Result<()> {
+    fn validate_parameters(&self, reward_rate: u64, lock_duration: i64, early_unstake_penalty_bps: u16) -> Result<()> {
         // Validate reward rate is within acceptable bounds
         if !is_valid_reward_rate(reward_rate) {
             msg!(
@@ -154,6 +266,16 @@ impl<'info> InitializePool<'info> {
             return Err(StakingError::InvalidLockDuration.into());
         }
 
+        // Validate early unstake penalty is within acceptable bounds
+        if !is_valid_early_unstake_penalty(early_unstake_penalty_bps) {
+            msg!(
+                "Invalid early unstake penalty: {} bps. Must be at most {} bps",
+                early_unstake_penalty_bps,
+                MAX_EARLY_UNSTAKE_PENALTY_BPS
+            );
+            return Err(StakingError::InvalidEarlyUnstakePenalty.into());
+        }
+
         // Validate token mints are different if this is a dual-token pool
         // (This is actually allowed - same token can be used for stake and rewards)
         if self.stake_mint.key() == self.reward_mint.key() {
@@ -5,6 +5,32 @@ pub mod stake;
 pub mod unstake;
 pub mod claim_rewards;
 pub mod update_pool;
+pub mod update_pools;
+pub mod initialize_global_stats;
+pub mod get_global_stats;
+pub mod set_reward_vault_authority;
+pub mod update_reward_rate;
+pub mod pause_pool;
+pub mod resume_pool;
+pub mod emergency_unstake;
+pub mod compound;
+pub mod transfer_authority;
+pub mod accept_authority;
+pub mod withdraw_unused_rewards;
+pub mod user_position;
+pub mod close_stake;
+pub mod slash;
+pub mod set_early_boost;
+pub mod set_min_claimable;
+pub mod transfer_stake;
+pub mod extend_lock;
+pub mod get_rate_history;
+pub mod set_whitelist_required;
+pub mod add_to_whitelist;
+pub mod set_cooldown_period;
+pub mod request_unstake;
+pub mod simulate_rewards;
+pub mod set_claim_cooldown;
 
 // Re-export the instruction structs for easy access
 pub use initialize_pool::*;
@@ -12,3 +38,29 @@ pub use stake::*;
 pub use unstake::*;
 pub use claim_rewards::*;
 pub use update_pool::*;
+pub use update_pools::*;
+pub use initialize_global_stats::*;
+pub use get_global_stats::*;
+pub use set_reward_vault_authority::*;
+pub use update_reward_rate::*;
+pub use pause_pool::*;
+pub use resume_pool::*;
+pub use emergency_unstake::*;
+pub use compound::*;
+pub use transfer_authority::*;
+pub use accept_authority::*;
+pub use withdraw_unused_rewards::*;
+pub use user_position::*;
+pub use close_stake::*;
+pub use slash::*;
+pub use set_early_boost::*;
+pub use set_min_claimable::*;
+pub use transfer_stake::*;
+pub use extend_lock::*;
+pub use get_rate_history::*;
+pub use set_whitelist_required::*;
+pub use add_to_whitelist::*;
+pub use set_cooldown_period::*;
+pub use request_unstake::*;
+pub use simulate_rewards::*;
+pub use set_claim_cooldown::*;
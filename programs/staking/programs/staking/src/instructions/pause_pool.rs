@@ -0,0 +1,33 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    error::StakingError,
+    state::StakingPool,
+};
+
+/// Stop a pool from accepting new stakes
+/// Existing stakers can still claim rewards and unstake while paused
+#[derive(Accounts)]
+pub struct PausePool<'info> {
+    /// The pool authority pausing the pool
+    pub authority: Signer<'info>,
+
+    /// The staking pool being paused
+    /// Must be managed by the signing authority
+    #[account(
+        mut,
+        constraint = pool.authority == authority.key() @ StakingError::UnauthorizedPoolAuthority,
+    )]
+    pub pool: Account<'info, StakingPool>,
+}
+
+impl<'info> PausePool<'info> {
+    /// Mark the pool inactive so `stake` rejects with PoolNotActive
+    pub fn pause_pool(&mut self) -> Result<()> {
+        self.pool.is_active = false;
+
+        msg!("Staking pool paused: pool={}, total_staked={}", self.pool.key(), self.pool.total_staked);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,133 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::*,
+    error::StakingError,
+    state::{StakingPool, UserStake},
+};
+
+/// Start the cooldown on an unlocked stake
+/// Tokens stay in the vault until withdraw_unstaked after the pool's cooldown period
+#[derive(Accounts)]
+pub struct RequestUnstake<'info> {
+    /// The owner of the stake
+    pub user: Signer<'info>,
+
+    /// The staking pool the stake belongs to
+    /// The stake's reward weight is removed from it
+    #[account(mut)]
+    pub pool: Account<'info, StakingPool>,
+
+    /// The stake entering its cooldown
+    /// PDA: ["stake", pool.key(), user.key()]
+    #[account(
+        mut,
+        seeds = [STAKE_SEED, pool.key().as_ref(), user.key().as_ref()],
+        bump = user_stake.bump,
+        constraint = user_stake.pool == pool.key() @ StakingError::InvalidAccount,
+        constraint = user_stake.is_active @ StakingError::InactiveStake,
+    )]
+    pub user_stake: Account<'info, UserStake>,
+}
+
+impl<'info> RequestUnstake<'info> {
+    /// Execute the unstake request
+    pub fn request_unstake(&mut self) -> Result<()> {
+        let current_time = Clock::get()?.unix_timestamp;
+
+        crate::error::validate_timestamp(current_time)?;
+        begin_unbonding(&mut self.pool, &mut self.user_stake, current_time)?;
+
+        msg!(
+            "UNSTAKE REQUESTED: user={}, pool={}, amount={}, rewards={}, withdrawable_at={}",
+            self.user.key(),
+            self.pool.key(),
+            self.user_stake.amount,
+            self.user_stake.rewards,
+            current_time.saturating_add(self.pool.cooldown_period)
+        );
+
+        Ok(())
+    }
+}
+
+/// Move an unlocked stake into its cooldown
+/// Rewards earned so far are settled onto the stake and its weight leaves the
+/// pool, so it earns nothing more while it waits to be withdrawn
+pub fn begin_unbonding(pool: &mut StakingPool, user_stake: &mut UserStake, current_time: i64) -> Result<()> {
+    if user_stake.is_unbonding() {
+        return Err(StakingError::StakeUnbonding.into());
+    }
+
+    if !user_stake.can_unstake(current_time) {
+        return Err(StakingError::StakeStillLocked.into());
+    }
+
+    if user_stake.amount == 0 {
+        return Err(StakingError::CannotUnstakeZero.into());
+    }
+
+    // Lock in rewards earned up to the request
+    pool.checkpoint_rewards(current_time)?;
+    user_stake.rewards = user_stake.calculate_pending_rewards(pool.reward_per_token_stored);
+    user_stake.reward_per_token_paid = pool.reward_per_token_stored;
+
+    pool.total_staked = pool.total_staked
+        .checked_sub(user_stake.weighted_amount)
+        .ok_or(StakingError::MathOverflow)?;
+    user_stake.weighted_amount = 0;
+    user_stake.unbonding_start = current_time;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{mock_pool, mock_user_stake, START};
+
+    #[test]
+    fn test_unbonding_stops_reward_accrual() {
+        let stake_amount = 1000 * 10_u64.pow(6);
+        let mut pool = StakingPool { cooldown_period: 3 * 24 * 60 * 60, ..mock_pool(stake_amount) };
+        let mut user_stake = mock_user_stake(stake_amount);
+        let unlocked = START + DEFAULT_LOCK_DURATION;
+
+        let earned = user_stake.calculate_pending_rewards(pool.calculate_reward_per_token(unlocked).unwrap());
+        begin_unbonding(&mut pool, &mut user_stake, unlocked).unwrap();
+
+        assert_eq!(user_stake.unbonding_start, unlocked);
+        assert_eq!(user_stake.rewards, earned);
+        assert_eq!(user_stake.weighted_amount, 0);
+        assert_eq!(pool.total_staked, 0);
+
+        // Nothing more accrues during the cooldown
+        let later = unlocked + pool.cooldown_period;
+        assert_eq!(user_stake.calculate_pending_rewards(pool.calculate_reward_per_token(later).unwrap()), earned);
+    }
+
+    #[test]
+    fn test_unbonding_requires_unlocked_stake() {
+        let stake_amount = 1000 * 10_u64.pow(6);
+        let mut pool = StakingPool { cooldown_period: 3 * 24 * 60 * 60, ..mock_pool(stake_amount) };
+        let mut user_stake = mock_user_stake(stake_amount);
+
+        let err = begin_unbonding(&mut pool, &mut user_stake, START + DEFAULT_LOCK_DURATION - 1).unwrap_err();
+        assert_eq!(err, StakingError::StakeStillLocked.into());
+        assert!(!user_stake.is_unbonding());
+        assert_eq!(pool.total_staked, stake_amount);
+    }
+
+    #[test]
+    fn test_unbonding_only_once() {
+        let stake_amount = 1000 * 10_u64.pow(6);
+        let mut pool = StakingPool { cooldown_period: 3 * 24 * 60 * 60, ..mock_pool(stake_amount) };
+        let mut user_stake = mock_user_stake(stake_amount);
+        let unlocked = START + DEFAULT_LOCK_DURATION;
+
+        begin_unbonding(&mut pool, &mut user_stake, unlocked).unwrap();
+        let err = begin_unbonding(&mut pool, &mut user_stake, unlocked + 60).unwrap_err();
+        assert_eq!(err, StakingError::StakeUnbonding.into());
+        assert_eq!(user_stake.unbonding_start, unlocked);
+    }
+}
@@ -0,0 +1,32 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    error::StakingError,
+    state::StakingPool,
+};
+
+/// Let a paused pool accept new stakes again
+#[derive(Accounts)]
+pub struct ResumePool<'info> {
+    /// The pool authority resuming the pool
+    pub authority: Signer<'info>,
+
+    /// The staking pool being resumed
+    /// Must be managed by the signing authority
+    #[account(
+        mut,
+        constraint = pool.authority == authority.key() @ StakingError::UnauthorizedPoolAuthority,
+    )]
+    pub pool: Account<'info, StakingPool>,
+}
+
+impl<'info> ResumePool<'info> {
+    /// Mark the pool active so `stake` accepts deposits again
+    pub fn resume_pool(&mut self) -> Result<()> {
+        self.pool.is_active = true;
+
+        msg!("Staking pool resumed: pool={}, total_staked={}", self.pool.key(), self.pool.total_staked);
+
+        Ok(())
+    }
+}
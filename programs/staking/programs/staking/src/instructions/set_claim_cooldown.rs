@@ -0,0 +1,49 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::*,
+    error::StakingError,
+    state::StakingPool,
+};
+
+/// Set the minimum time between two reward claims on the same stake
+/// Stops users from claiming dust every slot
+#[derive(Accounts)]
+pub struct SetClaimCooldown<'info> {
+    /// The pool authority changing the cooldown
+    pub authority: Signer<'info>,
+
+    /// The staking pool being configured
+    /// Must be managed by the signing authority
+    #[account(
+        mut,
+        constraint = pool.authority == authority.key() @ StakingError::UnauthorizedPoolAuthority,
+    )]
+    pub pool: Account<'info, StakingPool>,
+}
+
+impl<'info> SetClaimCooldown<'info> {
+    /// Store the new claim cooldown
+    pub fn set_claim_cooldown(&mut self, claim_cooldown: i64) -> Result<()> {
+        if !(0..=MAX_CLAIM_COOLDOWN).contains(&claim_cooldown) {
+            msg!(
+                "Invalid claim cooldown: {} seconds. Must be between 0 and {} seconds",
+                claim_cooldown,
+                MAX_CLAIM_COOLDOWN
+            );
+            return Err(StakingError::InvalidClaimCooldown.into());
+        }
+
+        let previous = self.pool.claim_cooldown;
+        self.pool.claim_cooldown = claim_cooldown;
+
+        msg!(
+            "Claim cooldown updated: pool={}, previous={}, new={}",
+            self.pool.key(),
+            previous,
+            claim_cooldown
+        );
+
+        Ok(())
+    }
+}
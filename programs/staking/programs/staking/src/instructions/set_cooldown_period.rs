@@ -0,0 +1,49 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::*,
+    error::StakingError,
+    state::StakingPool,
+};
+
+/// Set how long unstaked tokens wait before they can be withdrawn
+/// Stakes already unbonding are measured against the new period
+#[derive(Accounts)]
+pub struct SetCooldownPeriod<'info> {
+    /// The pool authority changing the cooldown
+    pub authority: Signer<'info>,
+
+    /// The staking pool being configured
+    /// Must be managed by the signing authority
+    #[account(
+        mut,
+        constraint = pool.authority == authority.key() @ StakingError::UnauthorizedPoolAuthority,
+    )]
+    pub pool: Account<'info, StakingPool>,
+}
+
+impl<'info> SetCooldownPeriod<'info> {
+    /// Store the new cooldown period
+    pub fn set_cooldown_period(&mut self, cooldown_period: i64) -> Result<()> {
+        if !(0..=MAX_COOLDOWN_PERIOD).contains(&cooldown_period) {
+            msg!(
+                "Invalid cooldown period: {} seconds. Must be between 0 and {} seconds",
+                cooldown_period,
+                MAX_COOLDOWN_PERIOD
+            );
+            return Err(StakingError::InvalidCooldownPeriod.into());
+        }
+
+        let previous = self.pool.cooldown_period;
+        self.pool.cooldown_period = cooldown_period;
+
+        msg!(
+            "Cooldown period updated: pool={}, previous={}, new={}",
+            self.pool.key(),
+            previous,
+            cooldown_period
+        );
+
+        Ok(())
+    }
+}
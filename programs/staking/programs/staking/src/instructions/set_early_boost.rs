@@ -0,0 +1,129 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::*,
+    error::StakingError,
+    state::StakingPool,
+};
+
+/// Configure the early staker boost for a pool
+/// Stakes created before boost_end_time keep the boost for their whole life
+#[derive(Accounts)]
+pub struct SetEarlyBoost<'info> {
+    /// The pool authority configuring the boost
+    pub authority: Signer<'info>,
+
+    /// The staking pool being configured
+    /// Must be managed by the signing authority
+    #[account(
+        mut,
+        constraint = pool.authority == authority.key() @ StakingError::UnauthorizedPoolAuthority,
+    )]
+    pub pool: Account<'info, StakingPool>,
+}
+
+impl<'info> SetEarlyBoost<'info> {
+    /// Set the boost window end and multiplier
+    pub fn set_early_boost(&mut self, boost_end_time: i64, boost_multiplier_bps: u16) -> Result<()> {
+        apply_early_boost(&mut self.pool, boost_end_time, boost_multiplier_bps)?;
+
+        msg!(
+            "Early boost set: pool={}, boost_end_time={}, boost_multiplier_bps={}",
+            self.pool.key(),
+            boost_end_time,
+            boost_multiplier_bps
+        );
+
+        Ok(())
+    }
+}
+
+/// Validate and store a pool's early staker boost
+/// Only affects stakes created afterwards; existing stakes keep the boost they recorded
+pub fn apply_early_boost(pool: &mut StakingPool, boost_end_time: i64, boost_multiplier_bps: u16) -> Result<()> {
+    if !is_valid_boost_multiplier(boost_multiplier_bps) {
+        return Err(StakingError::InvalidBoostMultiplier.into());
+    }
+
+    pool.boost_end_time = boost_end_time;
+    pool.boost_multiplier_bps = boost_multiplier_bps;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{mock_pool, mock_user_stake, START};
+    use crate::state::UserStake;
+
+    const BOOST_END: i64 = START + 24 * 60 * 60;
+
+    /// A base-tier stake created at `stake_time`, boosted as the stake instruction would
+    fn boosted_stake(pool: &StakingPool, amount: u64, stake_time: i64) -> UserStake {
+        let mut user_stake = UserStake {
+            boost_multiplier_bps: pool.boost_multiplier_at(stake_time),
+            reward_per_token_paid: pool.reward_per_token_stored,
+            stake_time,
+            unlock_time: stake_time + DEFAULT_LOCK_DURATION,
+            ..mock_user_stake(amount)
+        };
+        user_stake.weighted_amount = user_stake.weight_of(amount).unwrap();
+        user_stake
+    }
+
+    #[test]
+    fn test_apply_early_boost_validation() {
+        let mut pool = mock_pool(0);
+
+        apply_early_boost(&mut pool, BOOST_END, 15_000).unwrap();
+        assert_eq!(pool.boost_end_time, BOOST_END);
+        assert_eq!(pool.boost_multiplier_bps, 15_000);
+
+        // Boosts below 1x or above the cap are rejected
+        let err = apply_early_boost(&mut pool, BOOST_END, BASE_REWARD_MULTIPLIER_BPS - 1).unwrap_err();
+        assert_eq!(err, StakingError::InvalidBoostMultiplier.into());
+        let err = apply_early_boost(&mut pool, BOOST_END, MAX_BOOST_MULTIPLIER_BPS + 1).unwrap_err();
+        assert_eq!(err, StakingError::InvalidBoostMultiplier.into());
+    }
+
+    #[test]
+    fn test_boost_window_is_exclusive_of_end() {
+        let mut pool = mock_pool(0);
+
+        // Without a boost every stake gets 1x
+        assert_eq!(pool.boost_multiplier_at(START), BASE_REWARD_MULTIPLIER_BPS);
+
+        apply_early_boost(&mut pool, BOOST_END, 15_000).unwrap();
+        assert_eq!(pool.boost_multiplier_at(START), 15_000);
+        assert_eq!(pool.boost_multiplier_at(BOOST_END - 1), 15_000);
+        assert_eq!(pool.boost_multiplier_at(BOOST_END), BASE_REWARD_MULTIPLIER_BPS);
+    }
+
+    #[test]
+    fn test_early_staker_out_earns_late_staker() {
+        let stake_amount = 1000 * 10_u64.pow(6);
+        let mut pool = mock_pool(0);
+        apply_early_boost(&mut pool, BOOST_END, 15_000).unwrap();
+
+        // Same principal, one stake inside the boost window and one after it
+        let early = boosted_stake(&pool, stake_amount, START);
+        let late = boosted_stake(&pool, stake_amount, BOOST_END);
+        assert_eq!(early.boost_multiplier_bps, 15_000);
+        assert_eq!(late.boost_multiplier_bps, BASE_REWARD_MULTIPLIER_BPS);
+        pool.total_staked = early.weighted_amount + late.weighted_amount;
+
+        // Well after the window closes the early stake still earns 1.5x
+        let reward_per_token = pool.calculate_reward_per_token(BOOST_END + 30 * 24 * 60 * 60).unwrap();
+        let early_rewards = early.calculate_pending_rewards(reward_per_token);
+        let late_rewards = late.calculate_pending_rewards(reward_per_token);
+
+        assert!(early_rewards > late_rewards);
+        let expected = (late_rewards as u128) * 15_000 / (BASE_REWARD_MULTIPLIER_BPS as u128);
+        assert!((early_rewards as u128).abs_diff(expected) <= 1);
+
+        // Growing the principal later keeps the recorded boost
+        assert_eq!(early.weight_of(stake_amount), Some(early.weighted_amount));
+        assert_eq!(early.weight_of(100), Some(150));
+    }
+}
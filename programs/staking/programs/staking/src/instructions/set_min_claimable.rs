@@ -0,0 +1,39 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    error::StakingError,
+    state::StakingPool,
+};
+
+/// Set the smallest reward amount a claim pays out
+/// Smaller claims leave rewards accrued until they cross the threshold
+#[derive(Accounts)]
+pub struct SetMinClaimable<'info> {
+    /// The pool authority changing the threshold
+    pub authority: Signer<'info>,
+
+    /// The staking pool being configured
+    /// Must be managed by the signing authority
+    #[account(
+        mut,
+        constraint = pool.authority == authority.key() @ StakingError::UnauthorizedPoolAuthority,
+    )]
+    pub pool: Account<'info, StakingPool>,
+}
+
+impl<'info> SetMinClaimable<'info> {
+    /// Store the new minimum claim
+    pub fn set_min_claimable(&mut self, min_claimable: u64) -> Result<()> {
+        let previous = self.pool.min_claimable;
+        self.pool.min_claimable = min_claimable;
+
+        msg!(
+            "Minimum claim updated: pool={}, previous={}, new={}",
+            self.pool.key(),
+            previous,
+            min_claimable
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,130 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, spl_token::instruction::AuthorityType, SetAuthority, Token, TokenAccount};
+
+use crate::{
+    constants::*,
+    error::StakingError,
+    state::StakingPool,
+};
+
+/// Hand custody of the reward vault to a separate authority (e.g. a multisig)
+/// Principal in the stake vault always stays under the pool PDA
+#[derive(Accounts)]
+pub struct SetRewardVaultAuthority<'info> {
+    /// The pool authority approving the custody change
+    pub authority: Signer<'info>,
+
+    /// The staking pool whose reward vault custody is changing
+    /// Must be managed by the signing authority
+    #[account(
+        mut,
+        constraint = pool.authority == authority.key() @ StakingError::UnauthorizedPoolAuthority,
+    )]
+    pub pool: Account<'info, StakingPool>,
+
+    /// Pool's reward vault whose token authority is being reassigned
+    #[account(
+        mut,
+        constraint = reward_vault.key() == pool.reward_vault @ StakingError::InvalidTokenAccount,
+    )]
+    pub reward_vault: Account<'info, TokenAccount>,
+
+    /// The current reward vault authority
+    /// Required only when custody has already been moved off the pool PDA
+    pub current_reward_vault_authority: Option<Signer<'info>>,
+
+    /// Required system programs
+    pub token_program: Program<'info, Token>,
+}
+
+impl<'info> SetRewardVaultAuthority<'info> {
+    /// Reassign the reward vault's token authority and record it on the pool
+    pub fn set_reward_vault_authority(&mut self, new_reward_vault_authority: Pubkey) -> Result<()> {
+        let pool_key = self.pool.key();
+        let previous_authority = self.pool.reward_vault_authority;
+
+        if self.pool.has_external_reward_custody(&pool_key) {
+            // The external custodian must approve giving up custody
+            let current = self
+                .current_reward_vault_authority
+                .as_ref()
+                .filter(|signer| signer.key() == previous_authority)
+                .ok_or(StakingError::RewardVaultAuthorityRequired)?;
+
+            let set_authority_ctx = CpiContext::new(
+                self.token_program.to_account_info(),
+                SetAuthority {
+                    current_authority: current.to_account_info(),
+                    account_or_mint: self.reward_vault.to_account_info(),
+                },
+            );
+
+            token::set_authority(
+                set_authority_ctx,
+                AuthorityType::AccountOwner,
+                Some(new_reward_vault_authority),
+            )?;
+        } else {
+            // Create PDA signer seeds for pool authority
+            let seeds = &[
+                POOL_SEED,
+                self.pool.creator.as_ref(),
+                &self.pool.pool_id.to_le_bytes(),
+                &[self.pool.bump],
+            ];
+            let signer_seeds = &[&seeds[..]];
+
+            let set_authority_ctx = CpiContext::new_with_signer(
+                self.token_program.to_account_info(),
+                SetAuthority {
+                    current_authority: self.pool.to_account_info(),
+                    account_or_mint: self.reward_vault.to_account_info(),
+                },
+                signer_seeds,
+            );
+
+            token::set_authority(
+                set_authority_ctx,
+                AuthorityType::AccountOwner,
+                Some(new_reward_vault_authority),
+            )?;
+        }
+
+        self.pool.reward_vault_authority = new_reward_vault_authority;
+
+        msg!(
+            "Reward vault authority updated: pool={}, previous={}, new={}, external_custody={}",
+            pool_key,
+            previous_authority,
+            new_reward_vault_authority,
+            self.pool.has_external_reward_custody(&pool_key)
+        );
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::mock_pool;
+
+    #[test]
+    fn test_reward_custody_defaults_to_pool() {
+        let pool_key = Pubkey::new_unique();
+        let pool = StakingPool { reward_vault_authority: pool_key, ..mock_pool(0) };
+
+        // Pool PDA signs reward payouts itself
+        assert!(!pool.has_external_reward_custody(&pool_key));
+    }
+
+    #[test]
+    fn test_external_reward_custody() {
+        let pool_key = Pubkey::new_unique();
+        let multisig = Pubkey::new_unique();
+        let pool = StakingPool { reward_vault_authority: multisig, ..mock_pool(0) };
+
+        // Reward payouts need the multisig to co-sign
+        assert!(pool.has_external_reward_custody(&pool_key));
+    }
+}
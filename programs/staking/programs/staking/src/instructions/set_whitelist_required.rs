@@ -0,0 +1,38 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    error::StakingError,
+    state::StakingPool,
+};
+
+/// Turn the pool's staking whitelist on or off
+/// While on, only wallets added with add_to_whitelist can stake
+#[derive(Accounts)]
+pub struct SetWhitelistRequired<'info> {
+    /// The pool authority changing the setting
+    pub authority: Signer<'info>,
+
+    /// The staking pool being configured
+    /// Must be managed by the signing authority
+    #[account(
+        mut,
+        constraint = pool.authority == authority.key() @ StakingError::UnauthorizedPoolAuthority,
+    )]
+    pub pool: Account<'info, StakingPool>,
+}
+
+impl<'info> SetWhitelistRequired<'info> {
+    /// Store the new setting
+    /// Existing stakes are unaffected either way
+    pub fn set_whitelist_required(&mut self, whitelist_required: bool) -> Result<()> {
+        self.pool.whitelist_required = whitelist_required;
+
+        msg!(
+            "Whitelist requirement updated: pool={}, required={}",
+            self.pool.key(),
+            whitelist_required
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,94 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    error::StakingError,
+    instructions::stake::calculate_estimated_rewards,
+    state::{StakingPool, UserStake},
+};
+
+/// Read-only projection of the rewards a stake would earn over a future period
+/// Intended to be simulated by clients; nothing is modified
+#[derive(Accounts)]
+pub struct SimulateRewards<'info> {
+    /// The staking pool whose current reward rate is projected
+    pub pool: Account<'info, StakingPool>,
+
+    /// The stake being projected
+    /// Must belong to the pool
+    #[account(
+        constraint = user_stake.pool == pool.key() @ StakingError::InvalidAccount,
+    )]
+    pub user_stake: Account<'info, UserStake>,
+}
+
+impl<'info> SimulateRewards<'info> {
+    /// Log and return the projected rewards for the next `future_seconds`
+    pub fn simulate_rewards(&self, future_seconds: i64) -> Result<u64> {
+        let projected = project_rewards(&self.pool, &self.user_stake, future_seconds)?;
+
+        msg!(
+            "REWARD SIMULATION: user={}, pool={}, future_seconds={}, weighted_amount={}, reward_rate={}, projected_rewards={}",
+            self.user_stake.user,
+            self.pool.key(),
+            future_seconds,
+            self.user_stake.weighted_amount,
+            self.pool.reward_rate,
+            projected
+        );
+
+        Ok(projected)
+    }
+}
+
+/// Rewards a stake would earn over `future_seconds` at the pool's current rate
+/// Uses the stake's earning weight, so lock tiers and boosts are included
+pub fn project_rewards(pool: &StakingPool, user_stake: &UserStake, future_seconds: i64) -> Result<u64> {
+    if future_seconds < 0 {
+        return Err(StakingError::InvalidTimestamp.into());
+    }
+
+    Ok(calculate_estimated_rewards(
+        user_stake.weighted_amount,
+        pool.reward_rate,
+        future_seconds,
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{mock_pool, mock_user_stake};
+    use crate::constants::*;
+
+    #[test]
+    fn test_projection_matches_estimated_rewards() {
+        let pool = StakingPool { reward_rate: apr_to_reward_rate(12), ..mock_pool(0) };
+        let user_stake = UserStake {
+            weighted_amount: calculate_weighted_amount(1000 * 10_u64.pow(6), LOCK_TIER_1_MULTIPLIER_BPS).unwrap(),
+            reward_multiplier_bps: LOCK_TIER_1_MULTIPLIER_BPS,
+            ..mock_user_stake(1000 * 10_u64.pow(6))
+        };
+        let thirty_days = 30 * 24 * 60 * 60;
+
+        let projected = project_rewards(&pool, &user_stake, thirty_days).unwrap();
+
+        assert!(projected > 0);
+        assert_eq!(
+            projected,
+            calculate_estimated_rewards(user_stake.weighted_amount, pool.reward_rate, thirty_days)
+        );
+    }
+
+    #[test]
+    fn test_projection_rejects_past_periods() {
+        let pool = StakingPool { reward_rate: apr_to_reward_rate(12), ..mock_pool(0) };
+        let user_stake = UserStake {
+            weighted_amount: calculate_weighted_amount(1000 * 10_u64.pow(6), LOCK_TIER_1_MULTIPLIER_BPS).unwrap(),
+            reward_multiplier_bps: LOCK_TIER_1_MULTIPLIER_BPS,
+            ..mock_user_stake(1000 * 10_u64.pow(6))
+        };
+
+        assert_eq!(project_rewards(&pool, &user_stake, 0).unwrap(), 0);
+        assert!(project_rewards(&pool, &user_stake, -1).is_err());
+    }
+}
@@ -0,0 +1,253 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+
+use crate::{
+    constants::*,
+    error::StakingError,
+    state::{GlobalStats, StakingPool, UserStake},
+};
+
+/// Confiscate part of a misbehaving user's stake
+/// For security-deposit style pools; slashed principal goes to a treasury account
+#[derive(Accounts)]
+pub struct Slash<'info> {
+    /// The pool authority imposing the slash
+    pub authority: Signer<'info>,
+
+    /// The staking pool the stake belongs to
+    /// Must be managed by the signing authority
+    #[account(
+        mut,
+        constraint = pool.authority == authority.key() @ StakingError::UnauthorizedPoolAuthority,
+    )]
+    pub pool: Account<'info, StakingPool>,
+
+    /// The stake being slashed
+    #[account(
+        mut,
+        constraint = user_stake.pool == pool.key() @ StakingError::InvalidAccount,
+        constraint = user_stake.is_active @ StakingError::InactiveStake,
+    )]
+    pub user_stake: Account<'info, UserStake>,
+
+    /// Pool's stake vault the slashed tokens are taken from
+    #[account(
+        mut,
+        constraint = stake_vault.key() == pool.stake_vault @ StakingError::InvalidTokenAccount,
+    )]
+    pub stake_vault: Account<'info, TokenAccount>,
+
+    /// Token account receiving the slashed tokens
+    #[account(
+        mut,
+        constraint = treasury_token_account.mint == pool.stake_mint @ StakingError::InvalidTokenMint,
+    )]
+    pub treasury_token_account: Account<'info, TokenAccount>,
+
+    /// Program-wide statistics updated by this instruction
+    #[account(
+        mut,
+        seeds = [GLOBAL_STATS_SEED],
+        bump = global_stats.bump,
+    )]
+    pub global_stats: Account<'info, GlobalStats>,
+
+    /// Required system programs
+    pub token_program: Program<'info, Token>,
+}
+
+impl<'info> Slash<'info> {
+    /// Execute the slash
+    pub fn slash(&mut self, bps: u16) -> Result<()> {
+        let current_time = Clock::get()?.unix_timestamp;
+
+        let slashed = apply_slash(&mut self.pool, &mut self.user_stake, bps, current_time)?;
+
+        self.transfer_to_treasury(slashed)?;
+
+        // Slashed principal is no longer staked anywhere in the program
+        self.global_stats.record_unstake(slashed)?;
+
+        msg!(
+            "SLASH: user={}, pool={}, bps={}, slashed={}, remaining={}, total_slashed={}",
+            self.user_stake.user,
+            self.pool.key(),
+            bps,
+            slashed,
+            self.user_stake.amount,
+            self.user_stake.slashed_amount
+        );
+
+        Ok(())
+    }
+
+    /// Transfer slashed tokens from the stake vault to the treasury
+    fn transfer_to_treasury(&self, amount: u64) -> Result<()> {
+        if amount == 0 {
+            return Ok(());
+        }
+
+        // Check vault has sufficient balance
+        if self.stake_vault.amount < amount {
+            msg!(
+                "Insufficient stake vault balance: has {}, needs {}",
+                self.stake_vault.amount,
+                amount
+            );
+            return Err(StakingError::InsufficientTokenBalance.into());
+        }
+
+        // Create PDA signer seeds for pool authority
+        let seeds = &[
+            POOL_SEED,
+            self.pool.creator.as_ref(),
+            &self.pool.pool_id.to_le_bytes(),
+            &[self.pool.bump],
+        ];
+        let signer_seeds = &[&seeds[..]];
+
+        let transfer_ctx = CpiContext::new_with_signer(
+            self.token_program.to_account_info(),
+            Transfer {
+                from: self.stake_vault.to_account_info(),
+                to: self.treasury_token_account.to_account_info(),
+                authority: self.pool.to_account_info(),
+            },
+            signer_seeds,
+        );
+
+        token::transfer(transfer_ctx, amount)
+    }
+}
+
+/// Remove `bps` of a stake's principal from it and from the pool
+/// Rewards earned so far are settled onto the stake first and are not slashed
+/// Returns the amount slashed
+pub fn apply_slash(pool: &mut StakingPool, user_stake: &mut UserStake, bps: u16, current_time: i64) -> Result<u64> {
+    if bps == 0 || bps as u64 > BPS_DENOMINATOR {
+        return Err(StakingError::InvalidSlashBps.into());
+    }
+
+    // Lock in rewards earned at the current stake size
+    pool.checkpoint_rewards(current_time)?;
+    user_stake.rewards = user_stake.calculate_pending_rewards(pool.reward_per_token_stored);
+    user_stake.reward_per_token_paid = pool.reward_per_token_stored;
+
+    let slashed = (user_stake.amount as u128)
+        .checked_mul(bps as u128)
+        .and_then(|x| x.checked_div(BPS_DENOMINATOR as u128))
+        .ok_or(StakingError::MathOverflow)? as u64;
+
+    user_stake.amount = user_stake.amount
+        .checked_sub(slashed)
+        .ok_or(StakingError::MathOverflow)?;
+
+    // Remaining principal keeps earning at the stake's lock tier and boost,
+    // unless it is unbonding and no longer earns at all
+    let new_weight = if user_stake.is_unbonding() {
+        0
+    } else {
+        user_stake.weight_of(user_stake.amount)
+            .ok_or(StakingError::MathOverflow)?
+    };
+    let removed_weight = user_stake.weighted_amount.saturating_sub(new_weight);
+
+    user_stake.weighted_amount = new_weight;
+    pool.total_staked = pool.total_staked
+        .checked_sub(removed_weight)
+        .ok_or(StakingError::MathOverflow)?;
+
+    user_stake.slashed_amount = user_stake.slashed_amount
+        .checked_add(slashed)
+        .ok_or(StakingError::MathOverflow)?;
+
+    Ok(slashed)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::instructions::unstake::settle_final_rewards;
+    use crate::test_utils::{mock_pool, mock_user_stake, START};
+
+    #[test]
+    fn test_slashed_stake_is_not_paid_more_than_unslashed() {
+        let stake_amount = 1000 * 10_u64.pow(6);
+        let mut pool = mock_pool(2 * stake_amount);
+        let mut slashed = mock_user_stake(stake_amount);
+        let mut unslashed = mock_user_stake(stake_amount);
+
+        // Slashing stores the rewards earned so far on the stake
+        apply_slash(&mut pool, &mut slashed, 5_000, START + 3600).unwrap();
+        assert!(slashed.rewards > 0);
+
+        // At unstake those stored rewards are counted once
+        let unlocked = START + DEFAULT_LOCK_DURATION;
+        pool.checkpoint_rewards(unlocked).unwrap();
+        let slashed_rewards = settle_final_rewards(&pool, &mut slashed);
+        let unslashed_rewards = settle_final_rewards(&pool, &mut unslashed);
+
+        assert!(slashed_rewards < unslashed_rewards);
+        assert!(slashed_rewards + unslashed_rewards <= pool.total_rewards_owed);
+    }
+
+    #[test]
+    fn test_partial_slash() {
+        let stake_amount = 1000 * 10_u64.pow(6);
+        let mut pool = mock_pool(stake_amount * 2);
+        let mut user_stake = mock_user_stake(stake_amount);
+
+        // 25% slash
+        let slashed = apply_slash(&mut pool, &mut user_stake, 2_500, START).unwrap();
+
+        assert_eq!(slashed, 250 * 10_u64.pow(6));
+        assert_eq!(user_stake.amount, 750 * 10_u64.pow(6));
+        assert_eq!(user_stake.weighted_amount, 750 * 10_u64.pow(6));
+        assert_eq!(pool.total_staked, stake_amount * 2 - slashed);
+
+        // Slashing is cumulative
+        apply_slash(&mut pool, &mut user_stake, 2_500, START).unwrap();
+        assert_eq!(user_stake.slashed_amount, slashed + 187_500_000);
+    }
+
+    #[test]
+    fn test_full_slash() {
+        let stake_amount = 1000 * 10_u64.pow(6);
+        let mut pool = mock_pool(stake_amount);
+        let mut user_stake = mock_user_stake(stake_amount);
+
+        let slashed = apply_slash(&mut pool, &mut user_stake, BPS_DENOMINATOR as u16, START).unwrap();
+
+        assert_eq!(slashed, stake_amount);
+        assert_eq!(user_stake.amount, 0);
+        assert_eq!(user_stake.weighted_amount, 0);
+        assert_eq!(user_stake.slashed_amount, stake_amount);
+        assert_eq!(pool.total_staked, 0);
+
+        // Out-of-range slashes are rejected
+        assert!(apply_slash(&mut pool, &mut user_stake, 0, START).is_err());
+        assert!(apply_slash(&mut pool, &mut user_stake, BPS_DENOMINATOR as u16 + 1, START).is_err());
+    }
+
+    #[test]
+    fn test_slash_keeps_earned_rewards_and_cuts_future_ones() {
+        let stake_amount = 1000 * 10_u64.pow(6);
+        let mut pool = mock_pool(stake_amount * 2);
+        let mut slashed_stake = mock_user_stake(stake_amount);
+        let honest_stake = mock_user_stake(stake_amount);
+
+        // Rewards earned in the first hour survive the slash
+        let earned_before = slashed_stake.calculate_pending_rewards(pool.calculate_reward_per_token(START + 3600).unwrap());
+        apply_slash(&mut pool, &mut slashed_stake, 5_000, START + 3600).unwrap();
+        assert_eq!(slashed_stake.rewards, earned_before);
+
+        // Over the next hour the slashed stake earns half of what the honest one does
+        let next_rpt = pool.calculate_reward_per_token(START + 7200).unwrap();
+        let slashed_earned = slashed_stake.calculate_pending_rewards(next_rpt) - earned_before;
+        let honest_earned = honest_stake.calculate_pending_rewards(next_rpt)
+            - honest_stake.calculate_pending_rewards(pool.reward_per_token_stored);
+
+        assert!(slashed_earned > 0);
+        assert!(slashed_earned.abs_diff(honest_earned / 2) <= 1);
+    }
+}
@@ -7,9 +7,20 @@ use anchor_spl::{
 use crate::{
     constants::*,
     error::StakingError,
-    state::{StakingPool, UserStake},
+    state::{GlobalStats, StakingPool, UserStake},
 };
 
+/// Emitted when a user stakes tokens into a pool
+#[event]
+pub struct Staked {
+    pub user: Pubkey,
+    pub pool: Pubkey,
+    pub amount: u64,
+    pub weighted_amount: u64,
+    pub unlock_time: i64,
+    pub timestamp: i64,
+}
+
 /// Stake tokens into a pool
 /// Creates a user stake account and transfers tokens to the pool vault
 #[derive(Accounts)]
@@ -33,12 +44,21 @@ pub struct Stake<'info> {
     #[account(
         init,
         payer = user,
-        space = UserStake::INIT_SPACE,
+        space = 8 + UserStake::INIT_SPACE,
         seeds = [STAKE_SEED, pool.key().as_ref(), user.key().as_ref()],
         bump
     )]
     pub user_stake: Account<'info, UserStake>,
 
+    /// User's whitelist entry, required only when the pool is whitelist-gated
+    /// PDA: ["whitelist", pool.key(), user.key()]
+    /// CHECK: only checked for existence, never read
+    #[account(
+        seeds = [WHITELIST_SEED, pool.key().as_ref(), user.key().as_ref()],
+        bump
+    )]
+    pub whitelist_entry: UncheckedAccount<'info>,
+
     /// User's token account containing the tokens to stake
     /// Must have sufficient balance and be owned by the user
     #[account(
@@ -62,6 +82,14 @@ pub struct Stake<'info> {
     )]
     pub stake_mint: Account<'info, Mint>,
 
+    /// Program-wide statistics updated by this instruction
+    #[account(
+        mut,
+        seeds = [GLOBAL_STATS_SEED],
+        bump = global_stats.bump,
+    )]
+    pub global_stats: Account<'info, GlobalStats>,
+
     /// Required system programs
     pub system_program: Program<'info, System>,
     pub token_program: Program<'info, Token>,
@@ -71,38 +99,70 @@ pub struct Stake<'info> {
 
 impl<'info> Stake<'info> {
     /// Execute the staking operation
-    pub fn stake(&mut self, amount: u64, bumps: &StakeBumps) -> Result<()> {
+    pub fn stake(&mut self, amount: u64, lock_duration_override: i64, bumps: &StakeBumps) -> Result<()> {
         let current_time = Clock::get()?.unix_timestamp;
 
         // Validate the stake amount and user eligibility
         self.validate_stake(amount, current_time)?;
 
+        // Resolve the lock period, which determines the reward multiplier
+        let lock_duration = resolve_lock_duration(&self.pool, lock_duration_override)?;
+
         // Update pool rewards before adding new stake
         // This ensures fair reward distribution
         self.update_pool_rewards(current_time)?;
 
         // Initialize the user stake account
-        self.initialize_user_stake(amount, current_time, bumps)?;
+        self.initialize_user_stake(amount, lock_duration, current_time, bumps)?;
 
         // Transfer tokens from user to pool vault
         self.transfer_tokens_to_vault(amount)?;
 
-        // Update pool state with new stake
-        self.update_pool_state(amount, current_time)?;
+        // Update pool state with the stake's reward weight
+        self.update_pool_state(self.user_stake.weighted_amount, current_time)?;
+
+        // Update program-wide statistics
+        self.global_stats.record_stake(amount)?;
 
         // Log the staking event
         self.log_stake_event(amount, current_time)?;
 
+        emit!(Staked {
+            user: self.user.key(),
+            pool: self.pool.key(),
+            amount,
+            weighted_amount: self.user_stake.weighted_amount,
+            unlock_time: self.user_stake.unlock_time,
+            timestamp: current_time,
+        });
+
         Ok(())
     }
 
     /// Validate that the stake operation is allowed
     fn validate_stake(&self, amount: u64, current_time: i64) -> Result<()> {
         // Check if pool allows staking
-        if !self.pool.can_stake(current_time) {
+        if !self.pool.is_active {
             return Err(StakingError::PoolNotActive.into());
         }
 
+        // Check the pool's staking window is open
+        if !self.pool.can_stake(current_time) {
+            msg!(
+                "Staking window closed: now={}, start={}, end={}",
+                current_time,
+                self.pool.start_time,
+                self.pool.end_time
+            );
+            return Err(StakingError::StakingWindowClosed.into());
+        }
+
+        // Gated pools only accept wallets the authority has whitelisted
+        if !self.pool.is_whitelisted(&self.whitelist_entry) {
+            msg!("Wallet {} is not whitelisted", self.user.key());
+            return Err(StakingError::NotWhitelisted.into());
+        }
+
         // Validate stake amount is within bounds
         if !is_valid_stake_amount(amount) {
             if amount < MIN_STAKE_AMOUNT {
@@ -143,12 +203,9 @@ impl<'info> Stake<'info> {
     fn update_pool_rewards(&mut self, current_time: i64) -> Result<()> {
         let pool = &mut self.pool;
 
-        // Calculate new reward per token
-        let new_reward_per_token = pool.calculate_reward_per_token(current_time);
-
-        // Update pool state
-        pool.reward_per_token_stored = new_reward_per_token;
-        pool.last_update_time = current_time;
+        // Checkpoint rewards, recording newly accrued rewards as owed
+        pool.checkpoint_rewards(current_time)?;
+        let new_reward_per_token = pool.reward_per_token_stored;
 
         msg!(
             "Pool rewards updated: reward_per_token={}, time={}",
@@ -163,6 +220,7 @@ impl<'info> Stake<'info> {
     fn initialize_user_stake(
         &mut self,
         amount: u64,
+        lock_duration: i64,
         current_time: i64,
         bumps: &StakeBumps,
     ) -> Result<()> {
@@ -174,22 +232,36 @@ impl<'info> Stake<'info> {
         user_stake.pool = pool.key();
         user_stake.amount = amount;
 
+        // Longer locks earn a larger share of pool rewards
+        user_stake.reward_multiplier_bps = lock_tier_multiplier_bps(lock_duration);
+
+        // Early stakers keep the pool's boost for the life of the stake
+        user_stake.boost_multiplier_bps = pool.boost_multiplier_at(current_time);
+        user_stake.weighted_amount = user_stake.weight_of(amount)
+            .ok_or(StakingError::MathOverflow)?;
+
         // Set reward tracking
         // User starts with current reward_per_token as their baseline
         user_stake.reward_per_token_paid = pool.reward_per_token_stored;
         user_stake.rewards = 0; // No rewards yet
+        user_stake.slashed_amount = 0;
+        user_stake.last_claim_time = 0; // First claim is never held back
 
         // Set time information
         user_stake.stake_time = current_time;
-        user_stake.unlock_time = current_time + pool.lock_duration;
+        user_stake.unlock_time = current_time + lock_duration;
+        user_stake.unbonding_start = 0;
 
         // Set status
         user_stake.is_active = true;
         user_stake.bump = bumps.user_stake;
 
         msg!(
-            "User stake initialized: amount={}, unlock_time={}",
+            "User stake initialized: amount={}, weighted_amount={}, multiplier_bps={}, boost_bps={}, unlock_time={}",
             amount,
+            user_stake.weighted_amount,
+            user_stake.reward_multiplier_bps,
+            user_stake.boost_multiplier_bps,
             user_stake.unlock_time
         );
 
@@ -217,12 +289,12 @@ impl<'info> Stake<'info> {
     }
 
     /// Update pool state after successful stake
-    fn update_pool_state(&mut self, amount: u64, current_time: i64) -> Result<()> {
+    fn update_pool_state(&mut self, weighted_amount: u64, current_time: i64) -> Result<()> {
         let pool = &mut self.pool;
 
-        // Add to total staked amount
+        // Add the stake's reward weight to the pool total
         pool.total_staked = pool.total_staked
-            .checked_add(amount)
+            .checked_add(weighted_amount)
             .ok_or(StakingError::MathOverflow)?;
 
         // Update last update time
@@ -252,9 +324,9 @@ impl<'info> Stake<'info> {
         );
 
         // Calculate and log expected rewards
-        let lock_duration = pool.lock_duration;
+        let lock_duration = user_stake.unlock_time - user_stake.stake_time;
         let estimated_rewards = calculate_estimated_rewards(
-            amount,
+            user_stake.weighted_amount,
             pool.reward_rate,
             lock_duration,
         );
@@ -317,6 +389,26 @@ pub fn calculate_estimated_rewards(
     rewards
 }
 
+/// Resolve the lock period for a new stake
+/// Zero uses the pool's lock duration; overrides must lie between it and MAX_LOCK_DURATION
+pub fn resolve_lock_duration(pool: &StakingPool, lock_duration_override: i64) -> Result<i64> {
+    if lock_duration_override == 0 {
+        return Ok(pool.lock_duration);
+    }
+
+    if lock_duration_override < pool.lock_duration || lock_duration_override > MAX_LOCK_DURATION {
+        msg!(
+            "Invalid lock duration override: {} seconds. Must be between {} and {} seconds",
+            lock_duration_override,
+            pool.lock_duration,
+            MAX_LOCK_DURATION
+        );
+        return Err(StakingError::InvalidLockDuration.into());
+    }
+
+    Ok(lock_duration_override)
+}
+
 /// Validate that a user can stake in a pool
 pub fn can_user_stake(
     pool: &StakingPool,
@@ -325,10 +417,15 @@ pub fn can_user_stake(
     current_time: i64,
 ) -> Result<()> {
     // Check pool is active
-    if !pool.can_stake(current_time) {
+    if !pool.is_active {
         return Err(StakingError::PoolNotActive.into());
     }
 
+    // Check the staking window is open
+    if !pool.can_stake(current_time) {
+        return Err(StakingError::StakingWindowClosed.into());
+    }
+
     // Check stake amount is valid
     if !is_valid_stake_amount(stake_amount) {
         return Err(StakingError::StakeAmountTooSmall.into());
@@ -345,6 +442,7 @@ pub fn can_user_stake(
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::test_utils::{mock_pool, mock_user_stake, START};
 
     #[test]
     fn test_calculate_estimated_rewards() {
@@ -364,24 +462,9 @@ mod tests {
 
     #[test]
     fn test_can_user_stake_validation() {
-        // Create a mock pool (this would normally be more complex)
-        let mut pool = StakingPool {
-            authority: Pubkey::default(),
-            stake_mint: Pubkey::default(),
-            reward_mint: Pubkey::default(),
-            stake_vault: Pubkey::default(),
-            reward_vault: Pubkey::default(),
-            reward_rate: apr_to_reward_rate(10),
-            total_staked: 0,
-            last_update_time: 0,
-            reward_per_token_stored: 0,
-            lock_duration: DEFAULT_LOCK_DURATION,
-            is_active: true,
-            created_at: 0,
-            bump: 0,
-        };
+        let mut pool = mock_pool(0);
 
-        let current_time = 1000000;
+        let current_time = START;
         let user_balance = 5000 * 10_u64.pow(6);
         let stake_amount = 1000 * 10_u64.pow(6);
 
@@ -400,6 +483,36 @@ mod tests {
         assert!(can_user_stake(&pool, user_balance, MIN_STAKE_AMOUNT - 1, current_time).is_err());
     }
 
+    #[test]
+    fn test_staking_window_boundaries() {
+        let mut pool = StakingPool {
+            start_time: 1000000,
+            end_time: 2000000,
+            ..mock_pool(0)
+        };
+
+        // Start is inclusive, end is exclusive
+        assert!(!pool.can_stake(999999));
+        assert!(pool.can_stake(1000000));
+        assert!(pool.can_stake(1999999));
+        assert!(!pool.can_stake(2000000));
+
+        let user_balance = 5000 * 10_u64.pow(6);
+        let stake_amount = 1000 * 10_u64.pow(6);
+
+        // Outside the window reports a closed window, not an inactive pool
+        let err = can_user_stake(&pool, user_balance, stake_amount, 2000000).unwrap_err();
+        assert_eq!(err, StakingError::StakingWindowClosed.into());
+        let err = can_user_stake(&pool, user_balance, stake_amount, 999999).unwrap_err();
+        assert_eq!(err, StakingError::StakingWindowClosed.into());
+
+        // A paused pool is rejected even inside the window
+        pool.is_active = false;
+        assert!(!pool.can_stake(1500000));
+        let err = can_user_stake(&pool, user_balance, stake_amount, 1500000).unwrap_err();
+        assert_eq!(err, StakingError::PoolNotActive.into());
+    }
+
     #[test]
     fn test_stake_summary() {
         let stake_amount = 2000 * 10_u64.pow(6);
@@ -412,4 +525,106 @@ mod tests {
         assert!(estimated_rewards > 0);
         assert!(estimated_rewards < stake_amount); // Rewards shouldn't exceed principal for short periods
     }
+
+    /// A stake at the lock tier `lock_duration` selects
+    fn tiered_stake(amount: u64, lock_duration: i64) -> UserStake {
+        let reward_multiplier_bps = lock_tier_multiplier_bps(lock_duration);
+        UserStake {
+            weighted_amount: calculate_weighted_amount(amount, reward_multiplier_bps).unwrap(),
+            reward_multiplier_bps,
+            unlock_time: START + lock_duration,
+            ..mock_user_stake(amount)
+        }
+    }
+
+    #[test]
+    fn test_resolve_lock_duration() {
+        let pool = mock_pool(0);
+
+        // Zero falls back to the pool's lock duration
+        assert_eq!(resolve_lock_duration(&pool, 0).unwrap(), DEFAULT_LOCK_DURATION);
+
+        // Overrides within [pool lock, max lock] are accepted
+        assert_eq!(resolve_lock_duration(&pool, DEFAULT_LOCK_DURATION).unwrap(), DEFAULT_LOCK_DURATION);
+        assert_eq!(resolve_lock_duration(&pool, LOCK_TIER_1_DURATION).unwrap(), LOCK_TIER_1_DURATION);
+        assert_eq!(resolve_lock_duration(&pool, MAX_LOCK_DURATION).unwrap(), MAX_LOCK_DURATION);
+
+        // Overrides outside the bounds are rejected
+        let err = resolve_lock_duration(&pool, DEFAULT_LOCK_DURATION - 1).unwrap_err();
+        assert_eq!(err, StakingError::InvalidLockDuration.into());
+        let err = resolve_lock_duration(&pool, MAX_LOCK_DURATION + 1).unwrap_err();
+        assert_eq!(err, StakingError::InvalidLockDuration.into());
+    }
+
+    #[test]
+    fn test_longer_lock_earns_more_rewards() {
+        let stake_amount = 1000 * 10_u64.pow(6);
+        let seven_day = tiered_stake(stake_amount, DEFAULT_LOCK_DURATION);
+        let thirty_day = tiered_stake(stake_amount, LOCK_TIER_1_DURATION);
+
+        // Pool total tracks the weighted sum, not the raw principal
+        let mut pool = mock_pool(0);
+        pool.total_staked = seven_day.weighted_amount + thirty_day.weighted_amount;
+        assert!(pool.total_staked > 2 * stake_amount);
+
+        let reward_per_token = pool.calculate_reward_per_token(START + 24 * 60 * 60).unwrap();
+        let seven_day_rewards = seven_day.calculate_pending_rewards(reward_per_token);
+        let thirty_day_rewards = thirty_day.calculate_pending_rewards(reward_per_token);
+
+        // Identical principal, but the 30-day lock earns 1.25x
+        assert!(thirty_day_rewards > seven_day_rewards);
+        let expected = (seven_day_rewards as u128) * (LOCK_TIER_1_MULTIPLIER_BPS as u128)
+            / (BASE_REWARD_MULTIPLIER_BPS as u128);
+        assert!((thirty_day_rewards as u128).abs_diff(expected) <= 1);
+    }
+
+    #[test]
+    fn test_lock_weights_split_the_same_emission() {
+        let stake_amount = 1000 * 10_u64.pow(6);
+        let one_day = START + 24 * 60 * 60;
+
+        // Two base-tier stakes split the day's emission evenly
+        let mut equal_pool = mock_pool(0);
+        equal_pool.total_staked = 2 * tiered_stake(stake_amount, DEFAULT_LOCK_DURATION).weighted_amount;
+        equal_pool.checkpoint_rewards(one_day).unwrap();
+
+        // Same principal, but one stake commits to a longer lock
+        let seven_day = tiered_stake(stake_amount, DEFAULT_LOCK_DURATION);
+        let thirty_day = tiered_stake(stake_amount, LOCK_TIER_1_DURATION);
+        let mut pool = mock_pool(0);
+        pool.total_staked = seven_day.weighted_amount + thirty_day.weighted_amount;
+        pool.checkpoint_rewards(one_day).unwrap();
+
+        // The emission doesn't grow with the weights, only its split changes
+        assert!(pool.total_rewards_owed.abs_diff(equal_pool.total_rewards_owed) <= 1);
+
+        let seven_day_rewards = seven_day.calculate_pending_rewards(pool.reward_per_token_stored);
+        let thirty_day_rewards = thirty_day.calculate_pending_rewards(pool.reward_per_token_stored);
+        assert!((seven_day_rewards + thirty_day_rewards).abs_diff(pool.total_rewards_owed) <= 2);
+
+        // Each stake's slice is its weight over the pool's total weight
+        let expected_thirty_day = (pool.total_rewards_owed as u128) * (thirty_day.weighted_amount as u128)
+            / (pool.total_staked as u128);
+        assert!((thirty_day_rewards as u128).abs_diff(expected_thirty_day) <= 1);
+        assert!(thirty_day_rewards > pool.total_rewards_owed / 2);
+    }
+
+    #[test]
+    fn test_whitelist_gate() {
+        let key = Pubkey::new_unique();
+        let system_owner = Pubkey::default();
+        let (mut entry_lamports, mut missing_lamports) = (1_000_000, 0);
+        let (mut entry_data, mut missing_data) = (vec![1u8; 8], vec![]);
+        let entry = AccountInfo::new(&key, false, false, &mut entry_lamports, &mut entry_data, &crate::ID, false, 0);
+        let missing = AccountInfo::new(&key, false, false, &mut missing_lamports, &mut missing_data, &system_owner, false, 0);
+
+        // Open pools accept any wallet
+        let mut pool = mock_pool(0);
+        assert!(pool.is_whitelisted(&missing));
+
+        // Gated pools need an existing entry
+        pool.whitelist_required = true;
+        assert!(pool.is_whitelisted(&entry));
+        assert!(!pool.is_whitelisted(&missing));
+    }
 }
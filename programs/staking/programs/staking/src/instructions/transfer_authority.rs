@@ -0,0 +1,43 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    error::StakingError,
+    state::StakingPool,
+};
+
+/// Propose a new pool authority (step one of a two-step handover)
+/// The proposed key only takes control once it signs accept_authority
+#[derive(Accounts)]
+pub struct TransferAuthority<'info> {
+    /// The current pool authority proposing the handover
+    pub authority: Signer<'info>,
+
+    /// The staking pool whose authority is being transferred
+    /// Must be managed by the signing authority
+    #[account(
+        mut,
+        constraint = pool.authority == authority.key() @ StakingError::UnauthorizedPoolAuthority,
+    )]
+    pub pool: Account<'info, StakingPool>,
+}
+
+impl<'info> TransferAuthority<'info> {
+    /// Record the proposed authority; the current authority stays in control until it is accepted
+    pub fn transfer_authority(&mut self, new_authority: Pubkey) -> Result<()> {
+        propose_authority_transfer(&mut self.pool, new_authority);
+
+        msg!(
+            "Pool authority transfer proposed: pool={}, current={}, pending={}",
+            self.pool.key(),
+            self.pool.authority,
+            new_authority
+        );
+
+        Ok(())
+    }
+}
+
+/// Set the pending authority, replacing any earlier proposal
+pub fn propose_authority_transfer(pool: &mut StakingPool, new_authority: Pubkey) {
+    pool.pending_authority = Some(new_authority);
+}
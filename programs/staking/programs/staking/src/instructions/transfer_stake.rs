@@ -0,0 +1,176 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::*,
+    error::StakingError,
+    state::{StakingPool, UserStake},
+};
+
+/// Emitted when a staked position moves to another wallet
+#[event]
+pub struct StakeTransferred {
+    pub pool: Pubkey,
+    pub from: Pubkey,
+    pub to: Pubkey,
+    pub amount: u64,
+    pub timestamp: i64,
+}
+
+/// Move a staked position to another wallet without unstaking
+/// The stake PDA is keyed by owner, so the old account is closed and
+/// an identical one is created under the new owner in the same instruction
+#[derive(Accounts)]
+#[instruction(new_owner: Pubkey)]
+pub struct TransferStake<'info> {
+    /// The current owner of the stake
+    /// Pays for the new stake account and receives the old one's rent
+    #[account(
+        mut,
+        constraint = owner.key() != new_owner @ StakingError::StakeTransferToSelf,
+    )]
+    pub owner: Signer<'info>,
+
+    /// The staking pool the stake belongs to
+    pub pool: Account<'info, StakingPool>,
+
+    /// The owner's stake account, closed once its state is copied
+    /// PDA: ["stake", pool.key(), owner.key()]
+    #[account(
+        mut,
+        close = owner,
+        seeds = [STAKE_SEED, pool.key().as_ref(), owner.key().as_ref()],
+        bump = user_stake.bump,
+        constraint = user_stake.pool == pool.key() @ StakingError::InvalidAccount,
+        constraint = user_stake.is_active @ StakingError::InactiveStake,
+    )]
+    pub user_stake: Account<'info, UserStake>,
+
+    /// The new owner's stake account
+    /// PDA: ["stake", pool.key(), new_owner]
+    /// Fails if the new owner already has a stake in this pool
+    #[account(
+        init,
+        payer = owner,
+        space = 8 + UserStake::INIT_SPACE,
+        seeds = [STAKE_SEED, pool.key().as_ref(), new_owner.as_ref()],
+        bump
+    )]
+    pub new_user_stake: Account<'info, UserStake>,
+
+    /// New owner's whitelist entry, required only when the pool is whitelist-gated
+    /// PDA: ["whitelist", pool.key(), new_owner]
+    /// CHECK: only checked for existence, never read
+    #[account(
+        seeds = [WHITELIST_SEED, pool.key().as_ref(), new_owner.as_ref()],
+        bump
+    )]
+    pub new_owner_whitelist_entry: UncheckedAccount<'info>,
+
+    /// Required system programs
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> TransferStake<'info> {
+    /// Copy the position to the new owner's stake account
+    pub fn transfer_stake(&mut self, new_owner: Pubkey, bumps: &TransferStakeBumps) -> Result<()> {
+        let current_time = Clock::get()?.unix_timestamp;
+
+        // Gated pools only let a stake move to a wallet that could have staked itself
+        if !self.pool.is_whitelisted(&self.new_owner_whitelist_entry) {
+            msg!("Wallet {} is not whitelisted", new_owner);
+            return Err(StakingError::NotWhitelisted.into());
+        }
+
+        self.new_user_stake
+            .set_inner(transferred_stake(&self.user_stake, new_owner, bumps.new_user_stake));
+
+        msg!(
+            "STAKE TRANSFER: pool={}, from={}, to={}, amount={}, rewards={}, unlock_time={}",
+            self.pool.key(),
+            self.owner.key(),
+            new_owner,
+            self.new_user_stake.amount,
+            self.new_user_stake.rewards,
+            self.new_user_stake.unlock_time
+        );
+
+        emit!(StakeTransferred {
+            pool: self.pool.key(),
+            from: self.owner.key(),
+            to: new_owner,
+            amount: self.new_user_stake.amount,
+            timestamp: current_time,
+        });
+
+        Ok(())
+    }
+}
+
+/// The stake as it will exist under `new_owner`
+/// Everything but the owner and PDA bump carries over, so lock, multipliers
+/// and reward tracking continue exactly where they were
+pub fn transferred_stake(user_stake: &UserStake, new_owner: Pubkey, bump: u8) -> UserStake {
+    UserStake {
+        user: new_owner,
+        bump,
+        ..user_stake.clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{mock_pool, mock_user_stake, START};
+
+    /// A 30-day stake with some rewards already stored
+    fn tier_1_stake(user: Pubkey) -> UserStake {
+        UserStake {
+            user,
+            pool: Pubkey::new_unique(),
+            weighted_amount: 1250 * 10_u64.pow(6),
+            reward_multiplier_bps: LOCK_TIER_1_MULTIPLIER_BPS,
+            rewards: 42,
+            unlock_time: START + LOCK_TIER_1_DURATION,
+            bump: 254,
+            ..mock_user_stake(1000 * 10_u64.pow(6))
+        }
+    }
+
+    #[test]
+    fn test_transfer_keeps_lock_and_reward_tracking() {
+        let old_owner = Pubkey::new_unique();
+        let new_owner = Pubkey::new_unique();
+        let user_stake = tier_1_stake(old_owner);
+
+        let moved = transferred_stake(&user_stake, new_owner, 250);
+
+        assert_eq!(moved.user, new_owner);
+        assert_eq!(moved.bump, 250);
+        assert_eq!(moved.pool, user_stake.pool);
+        assert_eq!(moved.amount, user_stake.amount);
+        assert_eq!(moved.weighted_amount, user_stake.weighted_amount);
+        assert_eq!(moved.reward_multiplier_bps, user_stake.reward_multiplier_bps);
+        assert_eq!(moved.stake_time, user_stake.stake_time);
+        assert_eq!(moved.unlock_time, user_stake.unlock_time);
+        assert_eq!(moved.rewards, user_stake.rewards);
+        assert_eq!(moved.reward_per_token_paid, user_stake.reward_per_token_paid);
+    }
+
+    #[test]
+    fn test_new_owner_earns_what_old_owner_would_have() {
+        let pool = mock_pool(1000 * 10_u64.pow(6));
+        let user_stake = tier_1_stake(Pubkey::new_unique());
+        let moved = transferred_stake(&user_stake, Pubkey::new_unique(), 0);
+
+        // Rewards accrued before and after the transfer all go to the new owner
+        let reward_per_token = pool.calculate_reward_per_token(START + 24 * 60 * 60).unwrap();
+        assert_eq!(
+            moved.calculate_pending_rewards(reward_per_token),
+            user_stake.calculate_pending_rewards(reward_per_token)
+        );
+
+        // The lock still ends at the original unlock time
+        assert!(!moved.can_unstake(START + LOCK_TIER_1_DURATION - 1));
+        assert!(moved.can_unstake(START + LOCK_TIER_1_DURATION));
+    }
+}
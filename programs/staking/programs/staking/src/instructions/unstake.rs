@@ -7,11 +7,25 @@ use anchor_spl::{
 use crate::{
     constants::*,
     error::StakingError,
-    state::{StakingPool, UserStake},
+    instructions::user_position::get_user_position,
+    state::{GlobalStats, StakingPool, UserStake},
+    vault::{RewardVault, Vault},
 };
 
+/// Emitted when a user withdraws their stake after the lock period
+#[event]
+pub struct Unstaked {
+    pub user: Pubkey,
+    pub pool: Pubkey,
+    pub amount: u64,
+    pub rewards_paid: u64,
+    pub timestamp: i64,
+}
+
 /// Unstake tokens from a pool (after lock period expires)
 /// Calculates final rewards and transfers tokens back to user
+/// Principal is always returned by the pool PDA; rewards held by an external
+/// reward vault authority stay claimable until that authority co-signs
 #[derive(Accounts)]
 pub struct Unstake<'info> {
     /// The user who is unstaking tokens
@@ -24,11 +38,10 @@ pub struct Unstake<'info> {
     #[account(mut)]
     pub pool: Account<'info, StakingPool>,
 
-    /// User's stake account that will be closed
+    /// User's stake account that will be closed once fully settled
     /// Must belong to the user and be ready for unstaking
     #[account(
         mut,
-        close = user,  // Close account and return rent to user
         constraint = user_stake.user == user.key() @ StakingError::InvalidAccount,
         constraint = user_stake.pool == pool.key() @ StakingError::InvalidAccount,
         constraint = user_stake.is_active @ StakingError::InactiveStake,
@@ -67,6 +80,19 @@ pub struct Unstake<'info> {
     )]
     pub reward_vault: Account<'info, TokenAccount>,
 
+    /// Co-signer for reward disbursements
+    /// Required when the pool's reward vault is held by an external authority
+    pub reward_vault_authority: Option<Signer<'info>>,
+
+    /// Pool's SOL reward vault, paid out of instead of reward_vault for native SOL pools
+    /// PDA: ["reward_sol_vault", pool.key()]
+    #[account(
+        mut,
+        seeds = [REWARD_SOL_VAULT_SEED, pool.key().as_ref()],
+        bump,
+    )]
+    pub reward_sol_vault: SystemAccount<'info>,
+
     /// The stake token mint (for validation)
     #[account(
         constraint = stake_mint.key() == pool.stake_mint @ StakingError::InvalidTokenMint,
@@ -79,6 +105,14 @@ pub struct Unstake<'info> {
     )]
     pub reward_mint: Account<'info, Mint>,
 
+    /// Program-wide statistics updated by this instruction
+    #[account(
+        mut,
+        seeds = [GLOBAL_STATS_SEED],
+        bump = global_stats.bump,
+    )]
+    pub global_stats: Account<'info, GlobalStats>,
+
     /// Required system programs
     pub system_program: Program<'info, System>,
     pub token_program: Program<'info, Token>,
@@ -87,12 +121,28 @@ pub struct Unstake<'info> {
 
 impl<'info> Unstake<'info> {
     /// Execute the unstaking operation
-    pub fn unstake(&mut self) -> Result<()> {
+    pub fn unstake(&mut self, bumps: &UnstakeBumps) -> Result<()> {
         let current_time = Clock::get()?.unix_timestamp;
 
         // Validate that unstaking is allowed
         self.validate_unstake(current_time)?;
 
+        self.withdraw(current_time, bumps)
+    }
+
+    /// Finish an unstake started by request_unstake once the cooldown has passed
+    /// Pays out the rewards settled at the request along with the principal
+    pub fn withdraw_unstaked(&mut self, bumps: &UnstakeBumps) -> Result<()> {
+        let current_time = Clock::get()?.unix_timestamp;
+
+        crate::error::validate_timestamp(current_time)?;
+        can_user_withdraw_unstaked(&self.user_stake, self.pool.cooldown_period, current_time)?;
+
+        self.withdraw(current_time, bumps)
+    }
+
+    /// Return the principal and final rewards, closing the stake once fully settled
+    fn withdraw(&mut self, current_time: i64, bumps: &UnstakeBumps) -> Result<()> {
         // Update pool rewards to get accurate final calculations
         self.update_pool_rewards(current_time)?;
 
@@ -105,20 +155,68 @@ impl<'info> Unstake<'info> {
         // Transfer staked tokens back to user
         self.transfer_staked_tokens(stake_amount)?;
 
-        // Transfer reward tokens to user (if any)
-        if final_rewards > 0 {
-            self.transfer_reward_tokens(final_rewards)?;
+        // Transfer reward tokens to user (if any and the reward custodian is present)
+        let rewards_payable = self.can_pay_rewards();
+        if final_rewards > 0 && rewards_payable {
+            if self.pool.reward_is_native {
+                self.transfer_reward_sol(final_rewards, bumps.reward_sol_vault)?;
+            } else {
+                self.transfer_reward_tokens(final_rewards)?;
+            }
         }
 
-        // Update pool state after unstaking
-        self.update_pool_state(stake_amount, current_time)?;
+        // Remove the stake's reward weight from the pool
+        let weighted_amount = self.user_stake.weighted_amount;
+        self.update_pool_state(weighted_amount, current_time)?;
+
+        // Update program-wide statistics
+        self.global_stats.record_unstake(stake_amount)?;
+
+        let rewards_paid = if rewards_payable { final_rewards } else { 0 };
+
+        if rewards_payable {
+            self.pool.release_owed_rewards(final_rewards);
+            self.global_stats.record_rewards_distributed(final_rewards)?;
+
+            // Log the unstaking event
+            self.log_unstake_event(stake_amount, final_rewards, current_time)?;
 
-        // Log the unstaking event
-        self.log_unstake_event(stake_amount, final_rewards, current_time)?;
+            // Fully settled: close account and return rent to user
+            self.user_stake.close(self.user.to_account_info())?;
+        } else {
+            // Principal is returned, but rewards wait for the reward vault authority
+            self.user_stake.amount = 0;
+            self.user_stake.weighted_amount = 0;
+
+            // Log the unstaking event
+            self.log_unstake_event(stake_amount, 0, current_time)?;
+
+            msg!(
+                "Rewards of {} left on stake account until the reward vault authority co-signs a claim",
+                final_rewards
+            );
+        }
+
+        emit!(Unstaked {
+            user: self.user.key(),
+            pool: self.pool.key(),
+            amount: stake_amount,
+            rewards_paid,
+            timestamp: current_time,
+        });
 
         Ok(())
     }
 
+    /// Whether reward tokens can be paid out in this transaction
+    /// External custody requires the reward vault authority to be present;
+    /// the reward SOL vault is always in program custody
+    fn can_pay_rewards(&self) -> bool {
+        self.pool.reward_is_native
+            || !self.pool.has_external_reward_custody(&self.pool.key())
+            || self.reward_vault_authority.is_some()
+    }
+
     /// Validate that the unstake operation is allowed
     fn validate_unstake(&self, current_time: i64) -> Result<()> {
         let user_stake = &self.user_stake;
@@ -128,6 +226,20 @@ impl<'info> Unstake<'info> {
             return Err(StakingError::InactiveStake.into());
         }
 
+        // Stakes that started a cooldown finish with withdraw_unstaked
+        if user_stake.is_unbonding() {
+            return Err(StakingError::StakeUnbonding.into());
+        }
+
+        // Cooldown pools only release stakes through request_unstake
+        if self.pool.cooldown_period > 0 {
+            msg!(
+                "Pool requires a {} second cooldown: call request_unstake first",
+                self.pool.cooldown_period
+            );
+            return Err(StakingError::CooldownRequired.into());
+        }
+
         // Check if lock period has expired
         if !user_stake.can_unstake(current_time) {
             let time_remaining = user_stake.time_until_unlock(current_time);
@@ -160,12 +272,9 @@ impl<'info> Unstake<'info> {
     fn update_pool_rewards(&mut self, current_time: i64) -> Result<()> {
         let pool = &mut self.pool;
 
-        // Calculate new reward per token
-        let new_reward_per_token = pool.calculate_reward_per_token(current_time);
-
-        // Update pool state
-        pool.reward_per_token_stored = new_reward_per_token;
-        pool.last_update_time = current_time;
+        // Checkpoint rewards, recording newly accrued rewards as owed
+        pool.checkpoint_rewards(current_time)?;
+        let new_reward_per_token = pool.reward_per_token_stored;
 
         msg!(
             "Pool rewards updated for unstake: reward_per_token={}, time={}",
@@ -178,31 +287,38 @@ impl<'info> Unstake<'info> {
 
     /// Calculate the final rewards earned by the user
     fn calculate_final_rewards(&mut self) -> Result<u64> {
-        let pool = &self.pool;
-        let user_stake = &mut self.user_stake;
-
-        // Calculate pending rewards using current reward_per_token
-        let pending_rewards = user_stake.calculate_pending_rewards(pool.reward_per_token_stored);
-
-        // Add to existing unclaimed rewards
-        let total_rewards = user_stake.rewards
-            .checked_add(pending_rewards)
-            .ok_or(StakingError::RewardCalculationOverflow)?;
-
-        // Update user stake with final reward calculation
-        user_stake.rewards = total_rewards;
-        user_stake.reward_per_token_paid = pool.reward_per_token_stored;
+        let stored_rewards = self.user_stake.rewards;
+        let total_rewards = settle_final_rewards(&self.pool, &mut self.user_stake);
 
         msg!(
-            "Final rewards calculated: pending={}, total={}, reward_per_token={}",
-            pending_rewards,
+            "Final rewards calculated: stored={}, total={}, reward_per_token={}",
+            stored_rewards,
             total_rewards,
-            pool.reward_per_token_stored
+            self.pool.reward_per_token_stored
         );
 
         Ok(total_rewards)
     }
 
+    /// Pay rewards in lamports from the pool's reward SOL vault
+    /// The vault is always in program custody, so it signs with its own seeds
+    fn transfer_reward_sol(&self, amount: u64, reward_sol_vault_bump: u8) -> Result<()> {
+        let pool_key = self.pool.key();
+        let seeds = &[
+            REWARD_SOL_VAULT_SEED,
+            pool_key.as_ref(),
+            &[reward_sol_vault_bump],
+        ];
+        let signer_seeds = &[&seeds[..]];
+
+        Vault::new(&self.reward_sol_vault, &self.system_program)
+            .withdraw_sol(&self.user.to_account_info(), amount, signer_seeds)?;
+
+        msg!("Transferred {} reward lamports to user", amount);
+
+        Ok(())
+    }
+
     /// Transfer staked tokens back to user
     fn transfer_staked_tokens(&self, amount: u64) -> Result<()> {
         // Check vault has sufficient balance
@@ -216,11 +332,10 @@ impl<'info> Unstake<'info> {
         }
 
         // Create PDA signer seeds for pool authority
-        let pool_key = self.pool.key();
         let seeds = &[
             POOL_SEED,
-            self.pool.authority.as_ref(),
-            &pool_key.to_bytes()[..8], // Use first 8 bytes as pool_id
+            self.pool.creator.as_ref(),
+            &self.pool.pool_id.to_le_bytes(),
             &[self.pool.bump],
         ];
         let signer_seeds = &[&seeds[..]];
@@ -251,39 +366,8 @@ impl<'info> Unstake<'info> {
             return Ok(());
         }
 
-        // Check vault has sufficient balance
-        if self.reward_vault.amount < amount {
-            msg!(
-                "Insufficient reward vault balance: has {}, needs {}",
-                self.reward_vault.amount,
-                amount
-            );
-            return Err(StakingError::InsufficientRewardTokens.into());
-        }
-
-        // Create PDA signer seeds for pool authority
-        let pool_key = self.pool.key();
-        let seeds = &[
-            POOL_SEED,
-            self.pool.authority.as_ref(),
-            &pool_key.to_bytes()[..8], // Use first 8 bytes as pool_id
-            &[self.pool.bump],
-        ];
-        let signer_seeds = &[&seeds[..]];
-
-        // Create transfer context with pool as authority
-        let transfer_ctx = CpiContext::new_with_signer(
-            self.token_program.to_account_info(),
-            Transfer {
-                from: self.reward_vault.to_account_info(),
-                to: self.user_reward_token_account.to_account_info(),
-                authority: self.pool.to_account_info(),
-            },
-            signer_seeds,
-        );
-
-        // Execute the transfer
-        token::transfer(transfer_ctx, amount)?;
+        RewardVault::new(&self.pool, &self.reward_vault, &self.reward_vault_authority, &self.token_program)
+            .withdraw(&self.user_reward_token_account.to_account_info(), amount)?;
 
         msg!("Transferred {} reward tokens to user", amount);
 
@@ -291,12 +375,12 @@ impl<'info> Unstake<'info> {
     }
 
     /// Update pool state after unstaking
-    fn update_pool_state(&mut self, stake_amount: u64, current_time: i64) -> Result<()> {
+    fn update_pool_state(&mut self, weighted_amount: u64, current_time: i64) -> Result<()> {
         let pool = &mut self.pool;
 
-        // Subtract from total staked amount
+        // Subtract the stake's reward weight from the pool total
         pool.total_staked = pool.total_staked
-            .checked_sub(stake_amount)
+            .checked_sub(weighted_amount)
             .ok_or(StakingError::MathOverflow)?;
 
         // Update last update time
@@ -336,7 +420,7 @@ impl<'info> Unstake<'info> {
 
         // Calculate actual APR achieved
         if staking_duration > 0 {
-            let actual_apr = self.calculate_actual_apr(stake_amount, rewards, staking_duration);
+            let actual_apr = calculate_actual_apr(stake_amount, rewards, staking_duration);
             msg!(
                 "Actual APR achieved: {}% (expected: {}%)",
                 actual_apr,
@@ -353,49 +437,17 @@ impl<'info> Unstake<'info> {
         Ok(())
     }
 
-    /// Calculate the actual APR achieved by the user
-    fn calculate_actual_apr(&self, stake_amount: u64, rewards: u64, duration_seconds: i64) -> u64 {
-        if stake_amount == 0 || duration_seconds == 0 {
-            return 0;
-        }
-
-        // Convert to annual rate
-        let seconds_per_year = 365 * 24 * 60 * 60;
-        let annual_rewards = (rewards as u128)
-            .checked_mul(seconds_per_year as u128)
-            .and_then(|x| x.checked_div(duration_seconds as u128))
-            .unwrap_or(0);
-
-        // Calculate APR as percentage
-        let apr = annual_rewards
-            .checked_mul(100)
-            .and_then(|x| x.checked_div(stake_amount as u128))
-            .unwrap_or(0) as u64;
-
-        apr
-    }
-
     /// Get unstake summary for display
-    pub fn get_unstake_summary(&self, current_time: i64) -> UnstakeSummary {
-        let user_stake = &self.user_stake;
-        let pool = &self.pool;
-
-        let staking_duration = current_time - user_stake.stake_time;
-        let can_unstake = user_stake.can_unstake(current_time);
-        let time_until_unlock = if can_unstake { 0 } else { user_stake.time_until_unlock(current_time) };
-
-        // Calculate pending rewards
-        let current_reward_per_token = pool.calculate_reward_per_token(current_time);
-        let pending_rewards = user_stake.calculate_pending_rewards(current_reward_per_token);
-        let total_rewards = user_stake.rewards + pending_rewards;
-
-        UnstakeSummary {
-            stake_amount: user_stake.amount,
-            total_rewards,
-            staking_duration_days: staking_duration / (24 * 60 * 60),
-            can_unstake,
-            time_until_unlock_seconds: time_until_unlock,
-        }
+    pub fn get_unstake_summary(&self, current_time: i64) -> Result<UnstakeSummary> {
+        let position = get_user_position(&self.user_stake, &self.pool, current_time)?;
+
+        Ok(UnstakeSummary {
+            stake_amount: position.staked_amount,
+            total_rewards: position.pending_rewards,
+            staking_duration_days: position.staking_duration_seconds / (24 * 60 * 60),
+            can_unstake: position.can_unstake,
+            time_until_unlock_seconds: position.time_until_unlock_seconds,
+        })
     }
 }
 
@@ -409,6 +461,18 @@ pub struct UnstakeSummary {
     pub time_until_unlock_seconds: i64,
 }
 
+/// Settle everything the stake has earned onto it and return the total
+/// The total already includes rewards stored by an earlier checkpoint
+/// (request_unstake, extend_lock, slash), so they are only counted once
+pub fn settle_final_rewards(pool: &StakingPool, user_stake: &mut UserStake) -> u64 {
+    let total_rewards = user_stake.calculate_pending_rewards(pool.reward_per_token_stored);
+
+    user_stake.rewards = total_rewards;
+    user_stake.reward_per_token_paid = pool.reward_per_token_stored;
+
+    total_rewards
+}
+
 /// Check if a user can unstake their tokens
 pub fn can_user_unstake(user_stake: &UserStake, current_time: i64) -> Result<()> {
     if !user_stake.is_active {
@@ -426,53 +490,78 @@ pub fn can_user_unstake(user_stake: &UserStake, current_time: i64) -> Result<()>
     Ok(())
 }
 
+/// Check if an unbonding stake can be withdrawn
+/// The cooldown is measured with the pool's current cooldown period
+pub fn can_user_withdraw_unstaked(user_stake: &UserStake, cooldown_period: i64, current_time: i64) -> Result<()> {
+    if !user_stake.is_unbonding() {
+        return Err(StakingError::NotUnbonding.into());
+    }
+
+    if !user_stake.can_withdraw_unbonded(cooldown_period, current_time) {
+        msg!(
+            "Cooldown still running: unbonding_start={}, cooldown_period={}, now={}",
+            user_stake.unbonding_start,
+            cooldown_period,
+            current_time
+        );
+        return Err(StakingError::CooldownNotElapsed.into());
+    }
+
+    Ok(())
+}
+
+/// Calculate the actual APR achieved by a stake, as a whole percentage
+pub fn calculate_actual_apr(stake_amount: u64, rewards: u64, duration_seconds: i64) -> u64 {
+    if stake_amount == 0 || duration_seconds == 0 {
+        return 0;
+    }
+
+    // Convert to annual rate
+    let seconds_per_year = 365 * 24 * 60 * 60;
+    let annual_rewards = (rewards as u128)
+        .checked_mul(seconds_per_year as u128)
+        .and_then(|x| x.checked_div(duration_seconds as u128))
+        .unwrap_or(0);
+
+    // Calculate APR as percentage
+    annual_rewards
+        .checked_mul(100)
+        .and_then(|x| x.checked_div(stake_amount as u128))
+        .unwrap_or(0) as u64
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::instructions::claim_rewards::settle_claim;
+    use crate::instructions::request_unstake::begin_unbonding;
+    use crate::test_utils::{mock_pool, mock_user_stake, START};
 
     #[test]
     fn test_calculate_actual_apr() {
-        // Mock unstake context (simplified)
         let stake_amount = 1000 * 10_u64.pow(6); // 1000 tokens
         let rewards = 100 * 10_u64.pow(6); // 100 tokens reward
         let duration = 365 * 24 * 60 * 60; // 1 year
 
-        // Create a mock unstake context
-        let mock_unstake = Unstake {
-            user: todo!(), // These would be properly initialized in real tests
-            pool: todo!(),
-            user_stake: todo!(),
-            user_stake_token_account: todo!(),
-            user_reward_token_account: todo!(),
-            stake_vault: todo!(),
-            reward_vault: todo!(),
-            stake_mint: todo!(),
-            reward_mint: todo!(),
-            system_program: todo!(),
-            token_program: todo!(),
-            associated_token_program: todo!(),
-        };
+        assert_eq!(calculate_actual_apr(stake_amount, rewards, duration), 10); // Should be 10% APR
 
-        // This test would need proper mock setup to work
-        // let actual_apr = mock_unstake.calculate_actual_apr(stake_amount, rewards, duration);
-        // assert_eq!(actual_apr, 10); // Should be 10% APR
+        // Half the rewards over half a year is the same APR
+        assert_eq!(calculate_actual_apr(stake_amount, rewards / 2, duration / 2), 10);
+
+        // Degenerate inputs yield zero instead of dividing by zero
+        assert_eq!(calculate_actual_apr(0, rewards, duration), 0);
+        assert_eq!(calculate_actual_apr(stake_amount, rewards, 0), 0);
     }
 
     #[test]
     fn test_can_user_unstake_validation() {
-        let current_time = 1000000;
+        let current_time = START;
         
         // Create mock user stake
         let mut user_stake = UserStake {
-            user: Pubkey::default(),
-            pool: Pubkey::default(),
-            amount: 1000 * 10_u64.pow(6),
-            reward_per_token_paid: 0,
-            rewards: 0,
             stake_time: current_time - 1000,
             unlock_time: current_time - 100, // Already unlocked
-            is_active: true,
-            bump: 0,
+            ..mock_user_stake(1000 * 10_u64.pow(6))
         };
 
         // Should be able to unstake
@@ -493,6 +582,99 @@ mod tests {
         assert!(can_user_unstake(&user_stake, current_time).is_err());
     }
 
+    #[test]
+    fn test_can_user_withdraw_unstaked_validation() {
+        let current_time = START;
+        let cooldown_period = 3 * 24 * 60 * 60;
+
+        let mut user_stake = UserStake {
+            stake_time: current_time - 1000,
+            unlock_time: current_time - 100,
+            ..mock_user_stake(1000 * 10_u64.pow(6))
+        };
+
+        // No request yet
+        let err = can_user_withdraw_unstaked(&user_stake, cooldown_period, current_time).unwrap_err();
+        assert_eq!(err, StakingError::NotUnbonding.into());
+
+        // Premature withdraw, one second before the cooldown ends
+        user_stake.unbonding_start = current_time;
+        let err = can_user_withdraw_unstaked(&user_stake, cooldown_period, current_time + cooldown_period - 1).unwrap_err();
+        assert_eq!(err, StakingError::CooldownNotElapsed.into());
+
+        // Withdrawable once the cooldown has passed
+        assert!(can_user_withdraw_unstaked(&user_stake, cooldown_period, current_time + cooldown_period).is_ok());
+    }
+
+    #[test]
+    fn test_stored_rewards_are_paid_once_after_unbonding() {
+        let stake_amount = 1000 * 10_u64.pow(6);
+        let mut pool = StakingPool { cooldown_period: 3 * 24 * 60 * 60, ..mock_pool(stake_amount) };
+        let mut user_stake = mock_user_stake(stake_amount);
+        let unlocked = START + DEFAULT_LOCK_DURATION;
+
+        // request_unstake stores the rewards earned so far on the stake
+        begin_unbonding(&mut pool, &mut user_stake, unlocked).unwrap();
+        let earned = user_stake.rewards;
+        assert!(earned > 0);
+        let owed = pool.total_rewards_owed;
+
+        // Withdrawing after the cooldown pays them exactly once
+        pool.checkpoint_rewards(unlocked + pool.cooldown_period).unwrap();
+        assert_eq!(settle_final_rewards(&pool, &mut user_stake), earned);
+        assert_eq!(user_stake.rewards, earned);
+        assert!(earned <= owed);
+
+        // Settling again adds nothing on top
+        assert_eq!(settle_final_rewards(&pool, &mut user_stake), earned);
+    }
+
+    #[test]
+    fn test_deferred_rewards_are_claimed_once() {
+        let stake_amount = 1000 * 10_u64.pow(6);
+        let mut pool = mock_pool(stake_amount);
+        let mut user_stake = UserStake { rewards: 5 * 10_u64.pow(6), ..mock_user_stake(stake_amount) };
+        let unlocked = START + DEFAULT_LOCK_DURATION;
+
+        // Without the reward vault authority, withdraw returns the principal only
+        pool.checkpoint_rewards(unlocked).unwrap();
+        let final_rewards = settle_final_rewards(&pool, &mut user_stake);
+        user_stake.amount = 0;
+        user_stake.weighted_amount = 0;
+
+        // The later co-signed claim pays what was left on the stake, once
+        assert_eq!(user_stake.rewards, final_rewards);
+        assert_eq!(settle_claim(&pool, &mut user_stake), final_rewards);
+        assert_eq!(user_stake.rewards, 0);
+        assert_eq!(settle_claim(&pool, &mut user_stake), 0);
+    }
+
+    #[test]
+    fn test_pool_signer_seeds_match_pool_pda() {
+        let authority = Pubkey::new_unique();
+        let pool_id: u64 = 0x0123_4567_89ab_cdef;
+
+        // Same derivation as InitializePool
+        let (pool_key, bump) = Pubkey::find_program_address(
+            &[POOL_SEED, authority.as_ref(), pool_id.to_le_bytes().as_ref()],
+            &crate::ID,
+        );
+
+        // Signer seeds rebuilt from the stored pool_id sign for the pool PDA
+        let signer = Pubkey::create_program_address(
+            &[POOL_SEED, authority.as_ref(), &pool_id.to_le_bytes(), &[bump]],
+            &crate::ID,
+        );
+        assert_eq!(signer.ok(), Some(pool_key));
+
+        // Seeds built from the pool key bytes do not
+        let key_bytes_signer = Pubkey::create_program_address(
+            &[POOL_SEED, authority.as_ref(), &pool_key.to_bytes()[..8], &[bump]],
+            &crate::ID,
+        );
+        assert_ne!(key_bytes_signer.ok(), Some(pool_key));
+    }
+
     #[test]
     fn test_unstake_summary_calculation() {
         let current_time = 1000000;
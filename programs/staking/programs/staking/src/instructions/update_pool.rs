@@ -5,6 +5,16 @@ use crate::{
     state::StakingPool,
 };
 
+/// Emitted when a pool's reward accounting is brought up to date
+#[event]
+pub struct PoolUpdated {
+    pub pool: Pubkey,
+    pub caller: Pubkey,
+    pub reward_per_token: u128,
+    pub total_staked: u64,
+    pub timestamp: i64,
+}
+
 /// Update pool reward calculations
 /// Should be called periodically to keep reward calculations accurate
 /// This is a lightweight operation that anyone can call
@@ -34,15 +44,20 @@ impl<'info> UpdatePool<'info> {
 
         // Calculate and store new reward per token
         let previous_reward_per_token = self.pool.reward_per_token_stored;
-        let new_reward_per_token = self.pool.calculate_reward_per_token(current_time);
-
-        // Update pool state
-        self.pool.reward_per_token_stored = new_reward_per_token;
-        self.pool.last_update_time = current_time;
+        self.pool.checkpoint_rewards(current_time)?;
+        let new_reward_per_token = self.pool.reward_per_token_stored;
 
         // Log the update event
         self.log_update_event(previous_reward_per_token, new_reward_per_token, current_time)?;
 
+        emit!(PoolUpdated {
+            pool: self.pool.key(),
+            caller: self.caller.key(),
+            reward_per_token: new_reward_per_token,
+            total_staked: self.pool.total_staked,
+            timestamp: current_time,
+        });
+
         Ok(())
     }
 
@@ -66,6 +81,9 @@ impl<'info> UpdatePool<'info> {
             return Err(StakingError::InvalidTimestamp.into());
         }
 
+        // Reject updates inside the pool's minimum interval
+        check_update_interval(pool, current_time)?;
+
         // Log validation info
         msg!(
             "Update validation passed: time_elapsed={} seconds, total_staked={}",
@@ -122,13 +140,13 @@ impl<'info> UpdatePool<'info> {
     }
 
     /// Get pool update summary for display
-    pub fn get_update_summary(&self, current_time: i64) -> UpdateSummary {
+    pub fn get_update_summary(&self, current_time: i64) -> Result<UpdateSummary> {
         let pool = &self.pool;
         let time_since_last_update = current_time - pool.last_update_time;
-        let new_reward_per_token = pool.calculate_reward_per_token(current_time);
+        let new_reward_per_token = pool.calculate_reward_per_token(current_time)?;
         let reward_increase = new_reward_per_token.saturating_sub(pool.reward_per_token_stored);
 
-        UpdateSummary {
+        Ok(UpdateSummary {
             pool_address: pool.key(),
             time_since_last_update_seconds: time_since_last_update,
             current_reward_per_token: pool.reward_per_token_stored,
@@ -136,7 +154,7 @@ impl<'info> UpdatePool<'info> {
             reward_increase,
             total_staked: pool.total_staked,
             is_meaningful_update: time_since_last_update > 0 && (pool.total_staked > 0 || reward_increase > 0),
-        }
+        })
     }
 
     /// Check if a pool update would be meaningful
@@ -196,22 +214,40 @@ pub fn should_update_pool(
     time_elapsed >= min_time_threshold
 }
 
+/// Enforce the pool's minimum interval between update_pool calls
+/// A pool that has not been checkpointed since creation can always be updated
+pub fn check_update_interval(pool: &StakingPool, current_time: i64) -> Result<()> {
+    let never_updated = pool.last_update_time == pool.created_at;
+    let time_since_last_update = current_time - pool.last_update_time;
+
+    if !never_updated && time_since_last_update < pool.min_update_interval {
+        msg!(
+            "Update too soon: {} seconds since last update, minimum is {}",
+            time_since_last_update,
+            pool.min_update_interval
+        );
+        return Err(StakingError::UpdateTooSoon.into());
+    }
+
+    Ok(())
+}
+
 /// Calculate the reward increase that would result from updating a pool
 pub fn calculate_potential_reward_increase(
     pool: &StakingPool,
     current_time: i64,
-) -> u128 {
-    let new_reward_per_token = pool.calculate_reward_per_token(current_time);
-    new_reward_per_token.saturating_sub(pool.reward_per_token_stored)
+) -> Result<u128> {
+    let new_reward_per_token = pool.calculate_reward_per_token(current_time)?;
+    Ok(new_reward_per_token.saturating_sub(pool.reward_per_token_stored))
 }
 
 /// Get pool statistics for monitoring
-pub fn get_pool_stats(pool: &StakingPool, current_time: i64) -> PoolStats {
+pub fn get_pool_stats(pool: &StakingPool, current_time: i64) -> Result<PoolStats> {
     let time_since_last_update = current_time - pool.last_update_time;
-    let current_reward_per_token = pool.calculate_reward_per_token(current_time);
+    let current_reward_per_token = pool.calculate_reward_per_token(current_time)?;
     let pending_reward_increase = current_reward_per_token.saturating_sub(pool.reward_per_token_stored);
 
-    PoolStats {
+    Ok(PoolStats {
         total_staked: pool.total_staked,
         reward_rate: pool.reward_rate,
         current_apr: crate::constants::reward_rate_to_apr(pool.reward_rate),
@@ -222,7 +258,7 @@ pub fn get_pool_stats(pool: &StakingPool, current_time: i64) -> PoolStats {
         pending_reward_increase,
         is_active: pool.is_active,
         created_at: pool.created_at,
-    }
+    })
 }
 
 /// Comprehensive pool statistics
@@ -270,19 +306,13 @@ mod tests {
         is_active: bool,
     ) -> StakingPool {
         StakingPool {
-            authority: Pubkey::default(),
-            stake_mint: Pubkey::default(),
-            reward_mint: Pubkey::default(),
-            stake_vault: Pubkey::default(),
-            reward_vault: Pubkey::default(),
             reward_rate: apr_to_reward_rate(10), // 10% APR
             total_staked,
             last_update_time,
-            reward_per_token_stored: 0,
             lock_duration: DEFAULT_LOCK_DURATION,
             is_active,
             created_at: last_update_time,
-            bump: 0,
+            ..Default::default()
         }
     }
 
@@ -313,7 +343,7 @@ mod tests {
         let current_time = 1000000;
         let pool = create_mock_pool(1000 * 10_u64.pow(6), current_time - 3600, true); // 1 hour ago
 
-        let reward_increase = calculate_potential_reward_increase(&pool, current_time);
+        let reward_increase = calculate_potential_reward_increase(&pool, current_time).unwrap();
         
         // Should have some reward increase for 1 hour of staking
         assert!(reward_increase > 0);
@@ -324,7 +354,7 @@ mod tests {
         let current_time = 1000000;
         let pool = create_mock_pool(2000 * 10_u64.pow(6), current_time - 1800, true); // 30 minutes ago
 
-        let stats = get_pool_stats(&pool, current_time);
+        let stats = get_pool_stats(&pool, current_time).unwrap();
 
         assert_eq!(stats.total_staked, 2000 * 10_u64.pow(6));
         assert_eq!(stats.time_since_last_update, 1800);
@@ -361,7 +391,7 @@ mod tests {
         
         let pool = create_mock_pool(1000 * 10_u64.pow(6), current_time - 3600, true);
         let time_elapsed = current_time - pool.last_update_time;
-        let new_reward_per_token = pool.calculate_reward_per_token(current_time);
+        let new_reward_per_token = pool.calculate_reward_per_token(current_time).unwrap();
         let reward_increase = new_reward_per_token.saturating_sub(pool.reward_per_token_stored);
         
         // Should be a meaningful update
@@ -369,4 +399,86 @@ mod tests {
         assert!(pool.total_staked > 0);
         assert!(reward_increase > 0);
     }
+
+    #[test]
+    fn test_update_interval_enforced() {
+        let created_at = 1000000;
+        let mut pool = create_mock_pool(1000 * 10_u64.pow(6), created_at, true);
+        pool.min_update_interval = 60;
+
+        // A pool that was never updated can be updated right away
+        assert!(check_update_interval(&pool, created_at + 1).is_ok());
+
+        // After an update, calls inside the interval are rejected
+        pool.last_update_time = created_at + 1;
+        let err = check_update_interval(&pool, created_at + 60).unwrap_err();
+        assert_eq!(err, StakingError::UpdateTooSoon.into());
+
+        // Exactly at the interval, and later, updates succeed
+        assert!(check_update_interval(&pool, created_at + 61).is_ok());
+        assert!(check_update_interval(&pool, created_at + 3600).is_ok());
+
+        // Zero interval allows every call
+        pool.min_update_interval = 0;
+        assert!(check_update_interval(&pool, created_at + 1).is_ok());
+    }
+
+    #[test]
+    fn test_rewards_plateau_after_reward_end_time() {
+        let start = 1000000;
+        let mut pool = create_mock_pool(1000 * 10_u64.pow(6), start, true);
+        pool.reward_end_time = start + 3600;
+
+        // Accrual runs until the end time, then stops
+        let before_end = pool.calculate_reward_per_token(start + 1800).unwrap();
+        let at_end = pool.calculate_reward_per_token(start + 3600).unwrap();
+        assert!(at_end > before_end);
+        assert_eq!(pool.calculate_reward_per_token(start + 7200).unwrap(), at_end);
+        assert_eq!(pool.calculate_reward_per_token(start + 365 * 24 * 60 * 60).unwrap(), at_end);
+
+        // Checkpointing past the end does not restart accrual
+        pool.reward_per_token_stored = pool.calculate_reward_per_token(start + 7200).unwrap();
+        pool.last_update_time = start + 7200;
+        assert_eq!(pool.calculate_reward_per_token(start + 10800).unwrap(), at_end);
+    }
+
+    #[test]
+    fn test_reward_per_token_overflow_halts_accrual() {
+        let start = 1000000;
+        let mut pool = create_mock_pool(1, start, true);
+
+        // A normal checkpoint leaves the flag alone
+        pool.checkpoint_rewards(start + 3600).unwrap();
+        assert!(!pool.reward_accrual_halted);
+        let stored = pool.reward_per_token_stored;
+        let owed = pool.total_rewards_owed;
+
+        // Huge rate over ten years overflows rate * elapsed * 1e18
+        let normal_rate = pool.reward_rate;
+        pool.reward_rate = u64::MAX;
+        let later = start + 3600 + 10 * 365 * 24 * 60 * 60;
+        assert!(pool.try_calculate_reward_per_token(later).is_none());
+        assert!(pool.calculate_reward_per_token(later).is_err());
+
+        pool.checkpoint_rewards(later).unwrap();
+        assert!(pool.reward_accrual_halted);
+        assert_eq!(pool.reward_per_token_stored, stored);
+        assert_eq!(pool.total_rewards_owed, owed);
+
+        // The window is kept open rather than skipped
+        assert_eq!(pool.last_update_time, start + 3600);
+
+        // Once the math fits again the whole window accrues and the flag clears
+        pool.reward_rate = normal_rate;
+        let mut uninterrupted = create_mock_pool(1, start, true);
+        uninterrupted.checkpoint_rewards(start + 3600).unwrap();
+        uninterrupted.checkpoint_rewards(later).unwrap();
+
+        pool.checkpoint_rewards(later).unwrap();
+        assert!(!pool.reward_accrual_halted);
+        assert_eq!(pool.last_update_time, later);
+        assert!(pool.reward_per_token_stored > stored);
+        assert_eq!(pool.reward_per_token_stored, uninterrupted.reward_per_token_stored);
+        assert_eq!(pool.total_rewards_owed, uninterrupted.total_rewards_owed);
+    }
 }
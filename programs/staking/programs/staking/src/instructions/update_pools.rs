@@ -0,0 +1,133 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    error::StakingError,
+    instructions::update_pool::{should_update_pool, PoolUpdated},
+    state::StakingPool,
+};
+
+/// Update reward calculations for several pools in one transaction
+/// Pools are passed as writable remaining accounts; keepers use this to save fees
+/// Like update_pool, anyone can call it
+#[derive(Accounts)]
+pub struct UpdatePools<'info> {
+    /// The caller of this instruction (can be anyone)
+    /// CHECK: This account is not validated as anyone can call this instruction
+    pub caller: UncheckedAccount<'info>,
+}
+
+impl<'info> UpdatePools<'info> {
+    /// Checkpoint every passed pool that needs it and skip the rest
+    pub fn update_pools(&self, pool_infos: &'info [AccountInfo<'info>]) -> Result<()> {
+        let current_time = Clock::get()?.unix_timestamp;
+
+        let mut pools = pool_infos
+            .iter()
+            .map(|info| {
+                if !info.is_writable {
+                    return Err(StakingError::InvalidAccount.into());
+                }
+                Account::<StakingPool>::try_from(info)
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        let updated = {
+            let mut pool_refs: Vec<&mut StakingPool> = pools.iter_mut().map(|pool| &mut **pool).collect();
+            checkpoint_pools(&mut pool_refs, current_time)?
+        };
+
+        for &index in &updated {
+            let pool = &pools[index];
+            emit!(PoolUpdated {
+                pool: pool.key(),
+                caller: self.caller.key(),
+                reward_per_token: pool.reward_per_token_stored,
+                total_staked: pool.total_staked,
+                timestamp: current_time,
+            });
+        }
+
+        // Remaining accounts aren't persisted automatically
+        for pool in &pools {
+            pool.exit(&crate::ID)?;
+        }
+
+        msg!(
+            "BATCH POOL UPDATE: caller={}, pools={}, updated={}, skipped={}",
+            self.caller.key(),
+            pools.len(),
+            updated.len(),
+            pools.len() - updated.len()
+        );
+
+        Ok(())
+    }
+}
+
+/// Checkpoint the pools that should_update_pool selects, each against its own min_update_interval
+/// Pools that don't need an update are left untouched rather than failing the batch
+/// Returns the indices of the updated pools
+pub fn checkpoint_pools(pools: &mut [&mut StakingPool], current_time: i64) -> Result<Vec<usize>> {
+    let mut updated = Vec::new();
+
+    for (index, pool) in pools.iter_mut().enumerate() {
+        if !should_update_pool(pool, current_time, pool.min_update_interval) {
+            continue;
+        }
+
+        pool.checkpoint_rewards(current_time)?;
+        updated.push(index);
+    }
+
+    Ok(updated)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::constants::*;
+
+    fn create_mock_pool(total_staked: u64, last_update_time: i64, min_update_interval: i64) -> StakingPool {
+        StakingPool {
+            reward_rate: apr_to_reward_rate(10), // 10% APR
+            total_staked,
+            last_update_time,
+            min_update_interval,
+            lock_duration: DEFAULT_LOCK_DURATION,
+            created_at: last_update_time,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_checkpoint_pools_skips_pools_not_needing_update() {
+        let current_time = 1000000;
+
+        let mut first = create_mock_pool(1000 * 10_u64.pow(6), current_time - 7200, 3600);
+        let mut too_recent = create_mock_pool(1000 * 10_u64.pow(6), current_time - 1800, 3600);
+        let mut third = create_mock_pool(2000 * 10_u64.pow(6), current_time - 3600, 0);
+
+        let expected_first = first.calculate_reward_per_token(current_time).unwrap();
+        let expected_third = third.calculate_reward_per_token(current_time).unwrap();
+
+        let updated = checkpoint_pools(&mut [&mut first, &mut too_recent, &mut third], current_time).unwrap();
+        assert_eq!(updated, vec![0, 2]);
+
+        // Updated pools have their new reward_per_token committed
+        assert_eq!(first.reward_per_token_stored, expected_first);
+        assert_eq!(first.last_update_time, current_time);
+        assert_eq!(third.reward_per_token_stored, expected_third);
+        assert_eq!(third.last_update_time, current_time);
+        assert!(first.total_rewards_owed > 0);
+
+        // The skipped pool is untouched
+        assert_eq!(too_recent.reward_per_token_stored, 0);
+        assert_eq!(too_recent.last_update_time, current_time - 1800);
+        assert_eq!(too_recent.total_rewards_owed, 0);
+    }
+
+    #[test]
+    fn test_checkpoint_pools_empty_batch() {
+        assert!(checkpoint_pools(&mut [], 1000000).unwrap().is_empty());
+    }
+}
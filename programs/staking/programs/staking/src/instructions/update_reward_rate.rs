@@ -0,0 +1,129 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::*,
+    error::StakingError,
+    state::{RateChange, RateHistory, StakingPool},
+};
+
+/// Change a pool's reward rate (APR)
+/// Rewards already earned at the old rate are checkpointed first
+#[derive(Accounts)]
+pub struct UpdateRewardRate<'info> {
+    /// The pool authority changing the rate
+    /// Pays for the rate history account on the pool's first rate change
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    /// The staking pool whose reward rate is changing
+    /// Must be managed by the signing authority
+    #[account(
+        mut,
+        constraint = pool.authority == authority.key() @ StakingError::UnauthorizedPoolAuthority,
+    )]
+    pub pool: Account<'info, StakingPool>,
+
+    /// Recent rate changes of this pool
+    /// PDA: ["rate_history", pool.key()]
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = DISCRIMINATOR_SIZE + RateHistory::INIT_SPACE,
+        seeds = [RATE_HISTORY_SEED, pool.key().as_ref()],
+        bump
+    )]
+    pub rate_history: Account<'info, RateHistory>,
+
+    /// Required system programs
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> UpdateRewardRate<'info> {
+    /// Checkpoint accrued rewards, then switch to the new rate
+    pub fn update_reward_rate(&mut self, new_rate: u64, bumps: &UpdateRewardRateBumps) -> Result<()> {
+        let current_time = Clock::get()?.unix_timestamp;
+        let previous_rate = self.pool.reward_rate;
+
+        apply_reward_rate_change(&mut self.pool, new_rate, current_time)?;
+
+        // First change for this pool: the history account was just created
+        if self.rate_history.pool == Pubkey::default() {
+            self.rate_history.pool = self.pool.key();
+            self.rate_history.bump = bumps.rate_history;
+        }
+
+        self.rate_history.record(RateChange {
+            timestamp: current_time,
+            old_rate: previous_rate,
+            new_rate,
+        });
+
+        msg!(
+            "Reward rate updated: pool={}, previous={} ({} bps APR), new={} ({} bps APR)",
+            self.pool.key(),
+            previous_rate,
+            reward_rate_to_apr_bps(previous_rate),
+            new_rate,
+            reward_rate_to_apr_bps(new_rate)
+        );
+
+        Ok(())
+    }
+}
+
+/// Commit rewards accrued at the current rate, then set the new rate
+/// The checkpoint must happen first so the new rate only applies from now on
+pub fn apply_reward_rate_change(pool: &mut StakingPool, new_rate: u64, current_time: i64) -> Result<()> {
+    if !is_valid_reward_rate(new_rate) {
+        return Err(StakingError::InvalidRewardRate.into());
+    }
+
+    // Lock in everything earned at the old rate up to now
+    pool.checkpoint_rewards(current_time)?;
+
+    pool.reward_rate = new_rate;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{mock_pool, mock_user_stake, START};
+
+    #[test]
+    fn test_rate_change_keeps_earned_rewards() {
+        let old_rate = apr_to_reward_rate(10);
+        let new_rate = apr_to_reward_rate(20);
+        let mut pool = StakingPool { reward_rate: old_rate, ..mock_pool(1000 * 10_u64.pow(6)) };
+        let user_stake = mock_user_stake(1000 * 10_u64.pow(6));
+        let change_time = START + 3600;
+
+        // Rewards earned during the first hour at the old rate
+        let earned_before = user_stake.calculate_pending_rewards(pool.calculate_reward_per_token(change_time).unwrap());
+
+        apply_reward_rate_change(&mut pool, new_rate, change_time).unwrap();
+
+        // Right after the change the user has exactly what they earned before
+        let earned_at_change = user_stake.calculate_pending_rewards(pool.calculate_reward_per_token(change_time).unwrap());
+        assert_eq!(earned_at_change, earned_before);
+        assert_eq!(pool.last_update_time, change_time);
+        assert_eq!(pool.reward_rate, new_rate);
+
+        // The second hour accrues at the new (doubled) rate only
+        let earned_later = user_stake.calculate_pending_rewards(pool.calculate_reward_per_token(change_time + 3600).unwrap());
+        let second_hour = earned_later - earned_at_change;
+        assert!(second_hour > earned_before);
+    }
+
+    #[test]
+    fn test_rate_change_rejects_invalid_rate() {
+        let mut pool = mock_pool(1000 * 10_u64.pow(6));
+
+        assert!(apply_reward_rate_change(&mut pool, 0, START + 3600).is_err());
+
+        // Nothing is checkpointed when the new rate is rejected
+        assert_eq!(pool.last_update_time, START);
+        assert_eq!(pool.reward_per_token_stored, 0);
+    }
+}
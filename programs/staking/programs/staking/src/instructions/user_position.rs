@@ -0,0 +1,73 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::*,
+    state::{StakingPool, UserStake},
+};
+
+/// Snapshot of a user's position in a pool at a point in time
+/// Shared by the claim and unstake summaries and usable by frontends
+#[derive(Debug, Clone)]
+pub struct UserPosition {
+    pub staked_amount: u64,
+    pub weighted_amount: u64,
+    pub pending_rewards: u64,
+    pub staking_duration_seconds: i64,
+    pub time_until_unlock_seconds: i64,
+    pub can_unstake: bool,
+    pub current_apr: u64,
+}
+
+/// Build a user's position from the pool's current reward accounting
+/// Pending rewards include anything already stored on the stake account
+pub fn get_user_position(user_stake: &UserStake, pool: &StakingPool, current_time: i64) -> Result<UserPosition> {
+    let current_reward_per_token = pool.calculate_reward_per_token(current_time)?;
+
+    Ok(UserPosition {
+        staked_amount: user_stake.amount,
+        weighted_amount: user_stake.weighted_amount,
+        pending_rewards: user_stake.calculate_pending_rewards(current_reward_per_token),
+        staking_duration_seconds: (current_time - user_stake.stake_time).max(0),
+        time_until_unlock_seconds: user_stake.time_until_unlock(current_time),
+        can_unstake: user_stake.can_unstake(current_time),
+        current_apr: reward_rate_to_apr(pool.reward_rate),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{mock_pool, mock_user_stake, START};
+
+    const SECONDS_PER_DAY: i64 = 24 * 60 * 60;
+
+    #[test]
+    fn test_position_of_week_old_stake() {
+        let stake_amount = 1000 * 10_u64.pow(6);
+        let pool = StakingPool { lock_duration: LOCK_TIER_1_DURATION, ..mock_pool(stake_amount) };
+        let user_stake = UserStake { unlock_time: START + LOCK_TIER_1_DURATION, ..mock_user_stake(stake_amount) };
+        let current_time = START + 7 * SECONDS_PER_DAY;
+
+        let position = get_user_position(&user_stake, &pool, current_time).unwrap();
+
+        assert_eq!(position.staked_amount, stake_amount);
+        assert_eq!(position.weighted_amount, stake_amount);
+        assert_eq!(position.staking_duration_seconds, 7 * SECONDS_PER_DAY);
+        assert_eq!(position.current_apr, reward_rate_to_apr(pool.reward_rate));
+
+        // 30-day lock with 23 days to go
+        assert!(!position.can_unstake);
+        assert_eq!(position.time_until_unlock_seconds, 23 * SECONDS_PER_DAY);
+
+        // Sole staker earns the pool's full emission for the week
+        let expected = user_stake.calculate_pending_rewards(pool.calculate_reward_per_token(current_time).unwrap());
+        assert!(position.pending_rewards > 0);
+        assert_eq!(position.pending_rewards, expected);
+
+        // Stored rewards are counted once, not added on top again
+        let mut with_stored = user_stake.clone();
+        with_stored.rewards = 5 * 10_u64.pow(6);
+        let position = get_user_position(&with_stored, &pool, current_time).unwrap();
+        assert_eq!(position.pending_rewards, expected + 5 * 10_u64.pow(6));
+    }
+}
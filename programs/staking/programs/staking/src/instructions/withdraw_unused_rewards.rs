@@ -0,0 +1,152 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Token, TokenAccount};
+
+use crate::{
+    error::StakingError,
+    state::StakingPool,
+    vault::RewardVault,
+};
+
+/// Recover reward tokens left in the reward vault after the reward period
+/// Rewards still owed to stakers can never be withdrawn
+#[derive(Accounts)]
+pub struct WithdrawUnusedRewards<'info> {
+    /// The pool authority recovering the leftover rewards
+    pub authority: Signer<'info>,
+
+    /// The staking pool whose reward period has ended
+    /// Must be managed by the signing authority
+    #[account(
+        mut,
+        constraint = pool.authority == authority.key() @ StakingError::UnauthorizedPoolAuthority,
+    )]
+    pub pool: Account<'info, StakingPool>,
+
+    /// Pool's reward vault holding the leftover rewards
+    #[account(
+        mut,
+        constraint = reward_vault.key() == pool.reward_vault @ StakingError::InvalidTokenAccount,
+    )]
+    pub reward_vault: Account<'info, TokenAccount>,
+
+    /// Authority's token account receiving the withdrawn rewards
+    #[account(
+        mut,
+        constraint = authority_reward_token_account.mint == pool.reward_mint @ StakingError::InvalidTokenMint,
+        constraint = authority_reward_token_account.owner == authority.key() @ StakingError::InvalidTokenAccountOwner,
+    )]
+    pub authority_reward_token_account: Account<'info, TokenAccount>,
+
+    /// Co-signer for reward disbursements
+    /// Required when the pool's reward vault is held by an external authority
+    pub reward_vault_authority: Option<Signer<'info>>,
+
+    /// Required system programs
+    pub token_program: Program<'info, Token>,
+}
+
+impl<'info> WithdrawUnusedRewards<'info> {
+    /// Transfer `amount` of unused rewards back to the pool authority
+    pub fn withdraw_unused_rewards(&mut self, amount: u64) -> Result<()> {
+        let current_time = Clock::get()?.unix_timestamp;
+
+        if current_time <= self.pool.reward_end_time {
+            msg!(
+                "Reward period still running: now={}, reward_end_time={}",
+                current_time,
+                self.pool.reward_end_time
+            );
+            return Err(StakingError::RewardPeriodNotEnded.into());
+        }
+
+        // Count everything accrued up to the end of the reward period as owed
+        self.pool.checkpoint_rewards(current_time)?;
+
+        let withdrawable = calculate_withdrawable_rewards(self.reward_vault.amount, self.pool.total_rewards_owed);
+        if amount > withdrawable {
+            msg!(
+                "Withdrawal of {} exceeds unused rewards: vault={}, owed={}, withdrawable={}",
+                amount,
+                self.reward_vault.amount,
+                self.pool.total_rewards_owed,
+                withdrawable
+            );
+            return Err(StakingError::WithdrawExceedsUnusedRewards.into());
+        }
+
+        self.transfer_to_authority(amount)?;
+
+        msg!(
+            "Unused rewards withdrawn: pool={}, amount={}, still_owed={}",
+            self.pool.key(),
+            amount,
+            self.pool.total_rewards_owed
+        );
+
+        Ok(())
+    }
+
+    /// Transfer reward tokens from the vault to the authority's token account
+    fn transfer_to_authority(&self, amount: u64) -> Result<()> {
+        RewardVault::new(&self.pool, &self.reward_vault, &self.reward_vault_authority, &self.token_program)
+            .withdraw(&self.authority_reward_token_account.to_account_info(), amount)
+    }
+}
+
+/// Reward tokens in the vault beyond what stakers are still owed
+pub fn calculate_withdrawable_rewards(vault_balance: u64, total_rewards_owed: u64) -> u64 {
+    vault_balance.saturating_sub(total_rewards_owed)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{mock_pool, mock_user_stake, START};
+
+    #[test]
+    fn test_owed_rewards_cover_every_staker() {
+        let stake_amount = 1000 * 10_u64.pow(6);
+        let mut pool = StakingPool { reward_end_time: START + 3600, ..mock_pool(stake_amount * 2) };
+        let user1 = mock_user_stake(stake_amount);
+        let user2 = mock_user_stake(stake_amount);
+
+        // Checkpoint after the reward period has ended
+        pool.checkpoint_rewards(START + 7200).unwrap();
+        let user1_owed = user1.calculate_pending_rewards(pool.reward_per_token_stored);
+        let user2_owed = user2.calculate_pending_rewards(pool.reward_per_token_stored);
+
+        assert!(pool.total_rewards_owed > 0);
+        assert!(pool.total_rewards_owed >= user1_owed + user2_owed);
+
+        // Further checkpoints past the end add nothing
+        let owed = pool.total_rewards_owed;
+        pool.checkpoint_rewards(START + 10800).unwrap();
+        assert_eq!(pool.total_rewards_owed, owed);
+
+        // Paying a staker releases their share
+        pool.release_owed_rewards(user1_owed);
+        assert_eq!(pool.total_rewards_owed, owed - user1_owed);
+    }
+
+    #[test]
+    fn test_post_end_withdrawal_keeps_owed_rewards() {
+        let stake_amount = 1000 * 10_u64.pow(6);
+        let mut pool = StakingPool { reward_end_time: START + 3600, ..mock_pool(stake_amount) };
+        let vault_balance = 500 * 10_u64.pow(6);
+
+        pool.checkpoint_rewards(START + 7200).unwrap();
+        let owed = pool.total_rewards_owed;
+        let withdrawable = calculate_withdrawable_rewards(vault_balance, owed);
+
+        // Everything except what the staker is owed can be recovered
+        assert_eq!(withdrawable, vault_balance - owed);
+        assert_eq!(withdrawable + owed, vault_balance);
+
+        // Underfunded vaults have nothing to withdraw
+        assert_eq!(calculate_withdrawable_rewards(owed - 1, owed), 0);
+
+        // Once the staker has claimed, the whole remainder is withdrawable
+        pool.release_owed_rewards(owed);
+        assert_eq!(calculate_withdrawable_rewards(vault_balance - owed, pool.total_rewards_owed), vault_balance - owed);
+    }
+}
@@ -4,10 +4,15 @@ use anchor_lang::prelude::*;
 pub mod constants;
 pub mod error;
 pub mod state;
+pub mod vault;
 pub mod instructions;
 
+#[cfg(test)]
+mod test_utils;
+
 // Import instruction handlers
 use instructions::*;
+use state::RateChange;
 
 declare_id!("AtrNJXgaUTAdrgyN8iUjAdydLZJ5s27ZEk92DiXHQ7Rh");
 
@@ -17,34 +22,67 @@ pub mod staking {
 
     /// Initialize a new staking pool with specified parameters
     /// This creates the master pool account and associated token vaults
+    /// With reward_is_native, rewards are paid in SOL from the reward SOL vault
+    #[allow(clippy::too_many_arguments)]
     pub fn initialize_pool(
         ctx: Context<InitializePool>,
         pool_id: u64,
         reward_rate: u64,
         lock_duration: i64,
+        early_unstake_penalty_bps: u16,
+        start_time: i64,
+        end_time: i64,
+        reward_duration: i64,
+        min_update_interval: i64,
+        reward_is_native: bool,
     ) -> Result<()> {
-        ctx.accounts.initialize_pool(pool_id, reward_rate, lock_duration, &ctx.bumps)
+        ctx.accounts.initialize_pool(
+            pool_id,
+            reward_rate,
+            lock_duration,
+            early_unstake_penalty_bps,
+            start_time,
+            end_time,
+            reward_duration,
+            min_update_interval,
+            reward_is_native,
+            &ctx.bumps,
+        )
     }
 
     /// Stake tokens into a pool
     /// Creates a user stake account and transfers tokens to the pool vault
+    /// Longer locks (lock_duration_override, 0 = pool default) earn a reward multiplier
     pub fn stake(
         ctx: Context<Stake>,
         amount: u64,
+        lock_duration_override: i64,
     ) -> Result<()> {
-        ctx.accounts.stake(amount, &ctx.bumps)
+        ctx.accounts.stake(amount, lock_duration_override, &ctx.bumps)
     }
 
     /// Unstake tokens from a pool (after lock period)
     /// Calculates final rewards and transfers tokens back to user
     pub fn unstake(ctx: Context<Unstake>) -> Result<()> {
-        ctx.accounts.unstake()
+        ctx.accounts.unstake(&ctx.bumps)
     }
 
     /// Claim accumulated rewards without unstaking
     /// Allows users to harvest rewards while keeping tokens staked
     pub fn claim_rewards(ctx: Context<ClaimRewards>) -> Result<()> {
-        ctx.accounts.claim_rewards()
+        ctx.accounts.claim_rewards(&ctx.bumps)
+    }
+
+    /// Roll accumulated rewards back into principal
+    /// Only for pools that pay rewards in the stake token
+    pub fn compound(ctx: Context<Compound>) -> Result<()> {
+        ctx.accounts.compound()
+    }
+
+    /// Unstake before the lock period ends
+    /// Forfeits all rewards and pays the pool's early unstake penalty
+    pub fn emergency_unstake(ctx: Context<EmergencyUnstakeAccounts>) -> Result<()> {
+        ctx.accounts.emergency_unstake()
     }
 
     /// Update pool reward calculations
@@ -52,4 +90,154 @@ pub mod staking {
     pub fn update_pool(ctx: Context<UpdatePool>) -> Result<()> {
         ctx.accounts.update_pool()
     }
+
+    /// Update reward calculations for every pool passed in remaining accounts
+    /// Pools that don't need an update are skipped instead of failing the batch
+    pub fn update_pools<'info>(ctx: Context<'_, '_, 'info, 'info, UpdatePools<'info>>) -> Result<()> {
+        ctx.accounts.update_pools(ctx.remaining_accounts)
+    }
+
+    /// Create the program-wide statistics account
+    /// Must be called once before the first pool is initialized
+    pub fn initialize_global_stats(ctx: Context<InitializeGlobalStats>) -> Result<()> {
+        ctx.accounts.initialize_global_stats(&ctx.bumps)
+    }
+
+    /// Return cumulative totals across all pools
+    /// Read-only view intended to be simulated by dashboards
+    pub fn get_global_stats(ctx: Context<GetGlobalStats>) -> Result<GlobalStatsSnapshot> {
+        ctx.accounts.get_global_stats()
+    }
+
+    /// Hand the reward vault to a separate authority (e.g. a multisig)
+    /// That authority must then co-sign every reward disbursement
+    pub fn set_reward_vault_authority(
+        ctx: Context<SetRewardVaultAuthority>,
+        new_reward_vault_authority: Pubkey,
+    ) -> Result<()> {
+        ctx.accounts.set_reward_vault_authority(new_reward_vault_authority)
+    }
+
+    /// Change the pool's reward rate (APR)
+    /// Rewards earned at the old rate are checkpointed before the switch
+    pub fn update_reward_rate(ctx: Context<UpdateRewardRate>, new_rate: u64) -> Result<()> {
+        ctx.accounts.update_reward_rate(new_rate, &ctx.bumps)
+    }
+
+    /// Return a pool's recent reward rate changes, oldest first
+    /// Read-only view intended to be simulated for charting yields
+    pub fn get_rate_history(ctx: Context<GetRateHistory>) -> Result<Vec<RateChange>> {
+        ctx.accounts.get_rate_history()
+    }
+
+    /// Stop the pool from accepting new stakes
+    /// Unstaking and claiming keep working so users can always exit
+    pub fn pause_pool(ctx: Context<PausePool>) -> Result<()> {
+        ctx.accounts.pause_pool()
+    }
+
+    /// Let a paused pool accept new stakes again
+    pub fn resume_pool(ctx: Context<ResumePool>) -> Result<()> {
+        ctx.accounts.resume_pool()
+    }
+
+    /// Propose a new pool authority
+    /// Control moves only once the new authority calls accept_authority
+    pub fn transfer_authority(ctx: Context<TransferAuthority>, new_authority: Pubkey) -> Result<()> {
+        ctx.accounts.transfer_authority(new_authority)
+    }
+
+    /// Accept a pending pool authority transfer
+    /// Must be signed by the proposed authority
+    pub fn accept_authority(ctx: Context<AcceptAuthority>) -> Result<()> {
+        ctx.accounts.accept_authority()
+    }
+
+    /// Return reward tokens nobody can earn anymore to the pool authority
+    /// Only after reward_end_time, and never below what stakers are still owed
+    pub fn withdraw_unused_rewards(ctx: Context<WithdrawUnusedRewards>, amount: u64) -> Result<()> {
+        ctx.accounts.withdraw_unused_rewards(amount)
+    }
+
+    /// Close an emptied stake account and return its rent to the user
+    /// Fails while the stake still holds principal or rewards
+    pub fn close_stake(ctx: Context<CloseStake>) -> Result<()> {
+        ctx.accounts.close_stake()
+    }
+
+    /// Confiscate `bps` of a user's principal into a treasury account
+    /// Only the pool authority can slash; earned rewards are kept
+    pub fn slash(ctx: Context<Slash>, bps: u16) -> Result<()> {
+        ctx.accounts.slash(bps)
+    }
+
+    /// Boost rewards for stakes created before `boost_end_time`
+    /// Boosted stakes keep the multiplier after the window closes
+    pub fn set_early_boost(
+        ctx: Context<SetEarlyBoost>,
+        boost_end_time: i64,
+        boost_multiplier_bps: u16,
+    ) -> Result<()> {
+        ctx.accounts.set_early_boost(boost_end_time, boost_multiplier_bps)
+    }
+
+    /// Set the smallest reward amount claim_rewards pays out
+    /// Smaller claims stay accrued so rounding dust isn't lost
+    pub fn set_min_claimable(ctx: Context<SetMinClaimable>, min_claimable: u64) -> Result<()> {
+        ctx.accounts.set_min_claimable(min_claimable)
+    }
+
+    /// Move a staked position to another wallet without unstaking
+    /// Lock, multipliers and accrued rewards carry over unchanged
+    pub fn transfer_stake(ctx: Context<TransferStake>, new_owner: Pubkey) -> Result<()> {
+        ctx.accounts.transfer_stake(new_owner, &ctx.bumps)
+    }
+
+    /// Push back a stake's unlock time without unstaking
+    /// Reaching a longer lock tier raises the stake's reward weight
+    pub fn extend_lock(ctx: Context<ExtendLock>, additional_seconds: i64) -> Result<()> {
+        ctx.accounts.extend_lock(additional_seconds)
+    }
+
+    /// Require a whitelist entry to stake in this pool
+    /// Turning it off opens the pool to every wallet again
+    pub fn set_whitelist_required(ctx: Context<SetWhitelistRequired>, whitelist_required: bool) -> Result<()> {
+        ctx.accounts.set_whitelist_required(whitelist_required)
+    }
+
+    /// Let a wallet stake in a whitelist-gated pool
+    /// Creates the wallet's whitelist entry for the pool
+    pub fn add_to_whitelist(ctx: Context<AddToWhitelist>, user: Pubkey) -> Result<()> {
+        ctx.accounts.add_to_whitelist(user, &ctx.bumps)
+    }
+
+    /// Require stakes to wait `cooldown_period` seconds between unstake request and withdrawal
+    /// Zero lets stakes unstake directly again
+    pub fn set_cooldown_period(ctx: Context<SetCooldownPeriod>, cooldown_period: i64) -> Result<()> {
+        ctx.accounts.set_cooldown_period(cooldown_period)
+    }
+
+    /// Start the cooldown on an unlocked stake
+    /// The stake stops earning rewards; rewards so far are kept for the withdrawal
+    pub fn request_unstake(ctx: Context<RequestUnstake>) -> Result<()> {
+        ctx.accounts.request_unstake()
+    }
+
+    /// Withdraw a stake whose cooldown has passed
+    /// Returns principal and rewards and closes the stake account
+    pub fn withdraw_unstaked(ctx: Context<Unstake>) -> Result<()> {
+        ctx.accounts.withdraw_unstaked(&ctx.bumps)
+    }
+
+    /// Project the rewards a stake would earn over the next `future_seconds`
+    /// Read-only dry run at the pool's current rate; nothing is modified
+    pub fn simulate_rewards(ctx: Context<SimulateRewards>, future_seconds: i64) -> Result<u64> {
+        ctx.accounts.simulate_rewards(future_seconds)
+    }
+
+    /// Require `claim_cooldown` seconds between reward claims on the same stake
+    /// Zero lets stakes claim as often as they like again
+    pub fn set_claim_cooldown(ctx: Context<SetClaimCooldown>, claim_cooldown: i64) -> Result<()> {
+        ctx.accounts.set_claim_cooldown(claim_cooldown)
+    }
 }
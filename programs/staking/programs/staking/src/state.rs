@@ -1,5 +1,8 @@
 use anchor_lang::prelude::*;
 
+use crate::constants::{calculate_boosted_weighted_amount, BASE_REWARD_MULTIPLIER_BPS, RATE_HISTORY_CAPACITY};
+use crate::error::StakingError;
+
 /// The main staking pool that manages all stakes and rewards
 /// This is the "master" account that contains global state
 #[account]
@@ -8,6 +11,17 @@ pub struct StakingPool {
     /// Authority that can manage the pool (usually the program creator)
     pub authority: Pubkey,
     
+    /// Authority the pool PDA was derived from; never changes
+    /// Used for signer seeds so vault transfers keep working after an authority transfer
+    pub creator: Pubkey,
+    
+    /// Proposed new authority awaiting accept_authority
+    pub pending_authority: Option<Pubkey>,
+    
+    /// Caller-chosen pool identifier, part of the pool PDA seeds
+    /// Stored so the pool can rebuild its signer seeds for vault transfers
+    pub pool_id: u64,
+    
     /// The token that users stake (e.g., a project token)
     pub stake_mint: Pubkey,
     
@@ -20,11 +34,16 @@ pub struct StakingPool {
     /// Token account that holds reward tokens for distribution
     pub reward_vault: Pubkey,
     
+    /// Authority that must sign reward disbursements from the reward vault
+    /// Defaults to the pool PDA; can be handed to e.g. a multisig for custody
+    pub reward_vault_authority: Pubkey,
+    
     /// Reward rate: tokens per second per staked token (scaled by 1e9 for precision)
     /// Example: 1e9 = 1 reward token per second per staked token
     pub reward_rate: u64,
     
-    /// Total amount of tokens currently staked in the pool
+    /// Total reward weight staked in the pool (sum of each stake's weighted_amount)
+    /// Equals the raw token amount when every stake uses the base lock tier
     pub total_staked: u64,
     
     /// Last time the reward calculations were updated
@@ -34,9 +53,59 @@ pub struct StakingPool {
     /// This is the key to efficient reward calculation
     pub reward_per_token_stored: u128,
     
+    /// Time after which rewards stop accruing (created_at + reward_duration)
+    /// Bounds total emissions so the pool cannot promise more than it was funded for
+    pub reward_end_time: i64,
+    
+    /// Rewards accrued to stakers but not yet paid out or forfeited
+    /// The reward vault must keep at least this much; the rest can be withdrawn
+    pub total_rewards_owed: u64,
+    
+    /// Smallest reward amount claim_rewards pays out (0 = pay any amount)
+    /// Smaller claims leave the rewards accrued so rounding dust keeps adding up
+    pub min_claimable: u64,
+    
+    /// Minimum seconds between two claim_rewards calls on the same stake (0 = no cooldown)
+    /// Stops claim spam that pays out dust every slot
+    pub claim_cooldown: i64,
+    
+    /// Minimum seconds between update_pool calls, to stop spam rewrites
+    pub min_update_interval: i64,
+    
     /// Minimum lock duration in seconds (e.g., 7 days = 604800)
     pub lock_duration: i64,
     
+    /// Seconds between request_unstake and withdraw_unstaked (0 = no cooldown)
+    /// While set, stakes leave through the cooldown instead of unstake
+    pub cooldown_period: i64,
+    
+    /// Share of principal forfeited by emergency_unstake, in basis points
+    /// The forfeited tokens stay in the stake vault
+    pub early_unstake_penalty_bps: u16,
+    
+    /// First timestamp at which the pool accepts stakes
+    pub start_time: i64,
+    
+    /// Timestamp from which the pool stops accepting stakes (exclusive)
+    pub end_time: i64,
+    
+    /// Stakes created before this time get the early staker boost (0 = no boost)
+    pub boost_end_time: i64,
+    
+    /// Reward multiplier for early stakes on top of the lock tier (10,000 = 1x)
+    pub boost_multiplier_bps: u16,
+    
+    /// Whether only wallets with a StakeWhitelist entry may stake
+    pub whitelist_required: bool,
+    
+    /// Set once a checkpoint's reward-per-token math overflowed
+    /// Rewards for that interval were dropped; operators should review the pool's rate
+    pub reward_accrual_halted: bool,
+    
+    /// Whether rewards are paid in lamports from the reward SOL vault
+    /// instead of reward_mint tokens from the reward vault
+    pub reward_is_native: bool,
+    
     /// Whether the pool is currently active and accepting stakes
     pub is_active: bool,
     
@@ -61,6 +130,17 @@ pub struct UserStake {
     /// Amount of tokens this user has staked
     pub amount: u64,
     
+    /// Reward weight of this stake: amount * reward_multiplier_bps / 10,000
+    /// Used in reward-per-token math instead of the raw amount
+    pub weighted_amount: u64,
+    
+    /// Lock tier reward multiplier chosen at stake time (10,000 = 1x)
+    pub reward_multiplier_bps: u16,
+    
+    /// Early staker boost applied at stake time (10,000 = 1x)
+    /// Kept for the life of the stake, even after the pool's boost window closes
+    pub boost_multiplier_bps: u16,
+    
     /// The reward_per_token value when user last claimed/updated
     /// Used to calculate how much reward they've earned since then
     pub reward_per_token_paid: u128,
@@ -68,12 +148,22 @@ pub struct UserStake {
     /// Unclaimed rewards accumulated for this user
     pub rewards: u64,
     
+    /// Cumulative principal confiscated from this stake by the pool authority
+    pub slashed_amount: u64,
+    
+    /// When claim_rewards last succeeded on this stake (0 = never claimed)
+    pub last_claim_time: i64,
+    
     /// When the user first staked (for lock period calculation)
     pub stake_time: i64,
     
     /// When the user can unstake (stake_time + lock_duration)
     pub unlock_time: i64,
     
+    /// When request_unstake started the cooldown (0 = not unbonding)
+    /// Unbonding stakes carry no reward weight
+    pub unbonding_start: i64,
+    
     /// Whether this stake is currently active
     pub is_active: bool,
     
@@ -81,35 +171,174 @@ pub struct UserStake {
     pub bump: u8,
 }
 
+/// Program-wide statistics aggregated across every staking pool
+/// Singleton account updated by each instruction that moves tokens
+#[account]
+#[derive(InitSpace)]
+pub struct GlobalStats {
+    /// Total amount of tokens currently staked across all pools
+    pub total_staked: u64,
+    
+    /// Cumulative reward tokens paid out across all pools
+    pub total_rewards_distributed: u64,
+    
+    /// Number of staking pools created by the program
+    pub total_pools_created: u64,
+    
+    /// Bump seed for PDA derivation
+    pub bump: u8,
+}
+
+/// Marks a wallet as allowed to stake in a whitelist-gated pool
+/// Only its existence matters; created by the pool authority
+#[account]
+#[derive(InitSpace)]
+pub struct StakeWhitelist {
+    /// The pool the wallet may stake in
+    pub pool: Pubkey,
+    
+    /// The whitelisted wallet
+    pub user: Pubkey,
+    
+    /// When the wallet was added
+    pub added_at: i64,
+    
+    /// Bump seed for PDA derivation
+    pub bump: u8,
+}
+
+/// One change of a pool's reward rate
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq, InitSpace)]
+pub struct RateChange {
+    /// When the new rate took effect
+    pub timestamp: i64,
+    
+    /// Reward rate before the change (tokens per second * 1e9)
+    pub old_rate: u64,
+    
+    /// Reward rate after the change (tokens per second * 1e9)
+    pub new_rate: u64,
+}
+
+/// Recent reward rate changes of one pool, for charting its APR over time
+/// A ring buffer: once full, each new change overwrites the oldest one
+#[account]
+#[derive(InitSpace)]
+pub struct RateHistory {
+    /// The pool whose rate changes are recorded
+    pub pool: Pubkey,
+    
+    /// Recorded changes, in ring-buffer order once full
+    #[max_len(RATE_HISTORY_CAPACITY)]
+    pub entries: Vec<RateChange>,
+    
+    /// Index of the oldest entry once the buffer is full
+    pub head: u8,
+    
+    /// Bump seed for PDA derivation
+    pub bump: u8,
+}
+
 impl StakingPool {
     /// Calculate the current reward per token
-    /// This is the core of our reward system
-    pub fn calculate_reward_per_token(&self, current_time: i64) -> u128 {
+    /// This is the core of our reward system; overflow is an error, never a stale value
+    pub fn calculate_reward_per_token(&self, current_time: i64) -> Result<u128> {
+        self.try_calculate_reward_per_token(current_time)
+            .ok_or_else(|| StakingError::MathOverflow.into())
+    }
+    
+    /// Reward per token at `current_time`, or None if the math would overflow
+    pub fn try_calculate_reward_per_token(&self, current_time: i64) -> Option<u128> {
         // If no tokens are staked, no rewards accumulate
         if self.total_staked == 0 {
-            return self.reward_per_token_stored;
+            return Some(self.reward_per_token_stored);
         }
         
-        // Calculate time elapsed since last update
-        let time_elapsed = (current_time - self.last_update_time) as u128;
+        // Accrual stops at the end of the reward period
+        let effective_now = current_time.min(self.reward_end_time);
+        
+        // Calculate time elapsed since last update (nothing once past the end)
+        let time_elapsed = (effective_now - self.last_update_time).max(0) as u128;
         
         // Calculate additional reward per token since last update
         // Formula: (reward_rate * time_elapsed * PRECISION) / total_staked
+        // total_staked is the lock-weighted sum, so longer locks take a larger slice
         let additional_reward_per_token = (self.reward_rate as u128)
             .checked_mul(time_elapsed)
             .and_then(|x| x.checked_mul(1_000_000_000_000_000_000)) // 1e18 precision
-            .and_then(|x| x.checked_div(self.total_staked as u128))
-            .unwrap_or(0);
+            .and_then(|x| x.checked_div(self.total_staked as u128))?;
         
         // Add to stored value
         self.reward_per_token_stored
             .checked_add(additional_reward_per_token)
-            .unwrap_or(self.reward_per_token_stored)
+    }
+    
+    /// Bring reward_per_token_stored up to `current_time`
+    /// Rewards accrued since the last checkpoint are added to total_rewards_owed
+    pub fn checkpoint_rewards(&mut self, current_time: i64) -> Result<()> {
+        let Some(new_reward_per_token) = self.try_calculate_reward_per_token(current_time) else {
+            // Flag the pool and leave last_update_time alone, so the elapsed
+            // window is still accrued once its rewards can be computed again
+            msg!(
+                "WARNING: reward per token overflow, accrual halted: reward_rate={}, total_staked={}, last_update_time={}",
+                self.reward_rate,
+                self.total_staked,
+                self.last_update_time
+            );
+            self.reward_accrual_halted = true;
+            return Ok(());
+        };
+        
+        // Emission since last checkpoint: total_staked * reward_per_token_diff / precision
+        let accrued = new_reward_per_token
+            .saturating_sub(self.reward_per_token_stored)
+            .checked_mul(self.total_staked as u128)
+            .map(|x| x / 1_000_000_000_000_000_000) // 1e18 precision
+            .and_then(|x| u64::try_from(x).ok())
+            .ok_or(StakingError::MathOverflow)?;
+        
+        self.total_rewards_owed = self.total_rewards_owed
+            .checked_add(accrued)
+            .ok_or(StakingError::MathOverflow)?;
+        self.reward_per_token_stored = new_reward_per_token;
+        self.last_update_time = current_time;
+        self.reward_accrual_halted = false;
+        
+        Ok(())
+    }
+    
+    /// Stop tracking rewards that were paid out or forfeited
+    /// Saturates because per-user rounding can leave the sum slightly below the pool total
+    pub fn release_owed_rewards(&mut self, amount: u64) {
+        self.total_rewards_owed = self.total_rewards_owed.saturating_sub(amount);
+    }
+    
+    /// Whether reward payouts need an external co-signer instead of the pool PDA
+    pub fn has_external_reward_custody(&self, pool_key: &Pubkey) -> bool {
+        self.reward_vault_authority != *pool_key
+    }
+    
+    /// Early staker boost for a stake created at `current_time`
+    /// The boost window is [created_at, boost_end_time)
+    pub fn boost_multiplier_at(&self, current_time: i64) -> u16 {
+        if current_time < self.boost_end_time {
+            self.boost_multiplier_bps
+        } else {
+            BASE_REWARD_MULTIPLIER_BPS
+        }
     }
     
     /// Check if the pool is currently accepting stakes
+    /// The staking window is [start_time, end_time)
     pub fn can_stake(&self, current_time: i64) -> bool {
-        self.is_active
+        self.is_active && current_time >= self.start_time && current_time < self.end_time
+    }
+    
+    /// Whether a wallet may stake given its whitelist PDA
+    /// Only gated pools need the entry, which must exist and belong to this program
+    pub fn is_whitelisted(&self, whitelist_entry: &AccountInfo) -> bool {
+        !self.whitelist_required
+            || (whitelist_entry.owner == &crate::ID && !whitelist_entry.data_is_empty())
     }
     
     /// Get pool statistics for display
@@ -126,8 +355,8 @@ impl UserStake {
             .checked_sub(self.reward_per_token_paid)
             .unwrap_or(0);
         
-        // Calculate user's share: amount * reward_per_token_diff / precision
-        let new_rewards = (self.amount as u128)
+        // Calculate user's share: weighted_amount * reward_per_token_diff / precision
+        let new_rewards = (self.weighted_amount as u128)
             .checked_mul(reward_per_token_diff)
             .and_then(|x| x.checked_div(1_000_000_000_000_000_000)) // 1e18 precision
             .unwrap_or(0) as u64;
@@ -136,11 +365,26 @@ impl UserStake {
         self.rewards.checked_add(new_rewards).unwrap_or(self.rewards)
     }
     
+    /// Reward weight of `amount` at this stake's lock tier and early boost
+    pub fn weight_of(&self, amount: u64) -> Option<u64> {
+        calculate_boosted_weighted_amount(amount, self.reward_multiplier_bps, self.boost_multiplier_bps)
+    }
+    
     /// Check if user can unstake (lock period has passed)
     pub fn can_unstake(&self, current_time: i64) -> bool {
         self.is_active && current_time >= self.unlock_time
     }
     
+    /// Whether request_unstake has started this stake's cooldown
+    pub fn is_unbonding(&self) -> bool {
+        self.unbonding_start > 0
+    }
+    
+    /// Check if an unbonding stake can be withdrawn (cooldown has passed)
+    pub fn can_withdraw_unbonded(&self, cooldown_period: i64, current_time: i64) -> bool {
+        self.is_unbonding() && current_time >= self.unbonding_start.saturating_add(cooldown_period)
+    }
+    
     /// Get time remaining until unlock
     pub fn time_until_unlock(&self, current_time: i64) -> i64 {
         if current_time >= self.unlock_time {
@@ -160,3 +404,57 @@ impl UserStake {
         )
     }
 }
+
+impl GlobalStats {
+    /// Record a newly created pool
+    pub fn record_pool_created(&mut self) -> Result<()> {
+        self.total_pools_created = self.total_pools_created
+            .checked_add(1)
+            .ok_or(StakingError::MathOverflow)?;
+        Ok(())
+    }
+    
+    /// Record tokens entering any pool's stake vault
+    pub fn record_stake(&mut self, amount: u64) -> Result<()> {
+        self.total_staked = self.total_staked
+            .checked_add(amount)
+            .ok_or(StakingError::MathOverflow)?;
+        Ok(())
+    }
+    
+    /// Record tokens leaving any pool's stake vault
+    pub fn record_unstake(&mut self, amount: u64) -> Result<()> {
+        self.total_staked = self.total_staked
+            .checked_sub(amount)
+            .ok_or(StakingError::MathOverflow)?;
+        Ok(())
+    }
+    
+    /// Record reward tokens paid out from any pool's reward vault
+    pub fn record_rewards_distributed(&mut self, amount: u64) -> Result<()> {
+        self.total_rewards_distributed = self.total_rewards_distributed
+            .checked_add(amount)
+            .ok_or(StakingError::MathOverflow)?;
+        Ok(())
+    }
+}
+
+impl RateHistory {
+    /// Record a rate change, evicting the oldest entry when full
+    pub fn record(&mut self, change: RateChange) {
+        if self.entries.len() < RATE_HISTORY_CAPACITY {
+            self.entries.push(change);
+            return;
+        }
+        
+        let head = self.head as usize;
+        self.entries[head] = change;
+        self.head = ((head + 1) % RATE_HISTORY_CAPACITY) as u8;
+    }
+    
+    /// Recorded changes from oldest to newest
+    pub fn recent(&self) -> Vec<RateChange> {
+        let (newer, older) = self.entries.split_at(self.head as usize);
+        older.iter().chain(newer).copied().collect()
+    }
+}
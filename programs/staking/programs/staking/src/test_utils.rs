@@ -0,0 +1,96 @@
+//! Shared fixtures for the instruction unit tests
+
+use anchor_lang::prelude::*;
+
+use crate::constants::{apr_to_reward_rate, BASE_REWARD_MULTIPLIER_BPS, DEFAULT_LOCK_DURATION};
+use crate::state::{StakingPool, UserStake};
+
+/// Creation time of the mock pool and stakes
+pub const START: i64 = 1000000;
+
+/// 10% APR pool created at START with the default lock, holding `total_staked` reward weight
+pub fn mock_pool(total_staked: u64) -> StakingPool {
+    StakingPool {
+        reward_rate: apr_to_reward_rate(10),
+        total_staked,
+        last_update_time: START,
+        lock_duration: DEFAULT_LOCK_DURATION,
+        created_at: START,
+        ..Default::default()
+    }
+}
+
+/// Active base-tier stake of `amount` made at START, with nothing accrued yet
+pub fn mock_user_stake(amount: u64) -> UserStake {
+    UserStake {
+        amount,
+        weighted_amount: amount,
+        stake_time: START,
+        unlock_time: START + DEFAULT_LOCK_DURATION,
+        is_active: true,
+        ..Default::default()
+    }
+}
+
+/// Neutral pool for unit tests: active, open-ended, no rewards or stakes yet
+/// Tests override only the fields they exercise with struct update syntax
+impl Default for StakingPool {
+    fn default() -> Self {
+        Self {
+            authority: Pubkey::default(),
+            creator: Pubkey::default(),
+            pending_authority: None,
+            pool_id: 0,
+            stake_mint: Pubkey::default(),
+            reward_mint: Pubkey::default(),
+            stake_vault: Pubkey::default(),
+            reward_vault: Pubkey::default(),
+            reward_vault_authority: Pubkey::default(),
+            reward_rate: 0,
+            total_staked: 0,
+            last_update_time: 0,
+            reward_per_token_stored: 0,
+            reward_end_time: i64::MAX,
+            total_rewards_owed: 0,
+            min_claimable: 0,
+            claim_cooldown: 0,
+            min_update_interval: 0,
+            lock_duration: 0,
+            cooldown_period: 0,
+            early_unstake_penalty_bps: 0,
+            start_time: 0,
+            end_time: i64::MAX,
+            boost_end_time: 0,
+            boost_multiplier_bps: BASE_REWARD_MULTIPLIER_BPS,
+            whitelist_required: false,
+            reward_accrual_halted: false,
+            reward_is_native: false,
+            is_active: true,
+            created_at: 0,
+            bump: 0,
+        }
+    }
+}
+
+/// Neutral stake for unit tests: active, empty, base multipliers, never claimed
+impl Default for UserStake {
+    fn default() -> Self {
+        Self {
+            user: Pubkey::default(),
+            pool: Pubkey::default(),
+            amount: 0,
+            weighted_amount: 0,
+            reward_multiplier_bps: BASE_REWARD_MULTIPLIER_BPS,
+            boost_multiplier_bps: BASE_REWARD_MULTIPLIER_BPS,
+            reward_per_token_paid: 0,
+            rewards: 0,
+            slashed_amount: 0,
+            last_claim_time: 0,
+            stake_time: 0,
+            unlock_time: 0,
+            unbonding_start: 0,
+            is_active: true,
+            bump: 0,
+        }
+    }
+}
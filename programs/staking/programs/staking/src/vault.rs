@@ -0,0 +1,237 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{self, Transfer};
+use anchor_spl::token::{self, Token, TokenAccount};
+
+use crate::constants::POOL_SEED;
+use crate::error::StakingError;
+use crate::state::StakingPool;
+
+/// SOL vault backed by a system-owned PDA
+/// Wraps the raw lamport transfers so every SOL path enforces the same
+/// rent-exemption rules instead of re-deriving them
+pub struct Vault<'a, 'info> {
+    /// The system-owned PDA holding the lamports
+    pub account: &'a SystemAccount<'info>,
+
+    /// System program used for the transfers
+    pub system_program: &'a Program<'info, System>,
+}
+
+impl<'a, 'info> Vault<'a, 'info> {
+    pub fn new(account: &'a SystemAccount<'info>, system_program: &'a Program<'info, System>) -> Self {
+        Self { account, system_program }
+    }
+
+    /// Current lamport balance of the vault
+    pub fn balance(&self) -> u64 {
+        self.account.lamports()
+    }
+
+    /// Minimum balance the vault must keep to stay rent exempt
+    pub fn rent_exempt_minimum(&self) -> Result<u64> {
+        Ok(Rent::get()?.minimum_balance(self.account.to_account_info().data_len()))
+    }
+
+    /// Move `amount` lamports from `from` into the vault
+    /// The vault must end up rent exempt, so a first deposit has to cover rent
+    pub fn deposit_sol(&self, from: &AccountInfo<'info>, amount: u64) -> Result<()> {
+        checked_deposit(self.balance(), amount, self.rent_exempt_minimum()?)?;
+
+        system_program::transfer(
+            CpiContext::new(
+                self.system_program.to_account_info(),
+                Transfer {
+                    from: from.clone(),
+                    to: self.account.to_account_info(),
+                },
+            ),
+            amount,
+        )
+    }
+
+    /// Move `amount` lamports out of the vault to `to`
+    /// `signer_seeds` are the vault PDA's seeds; the vault must stay rent exempt
+    pub fn withdraw_sol(&self, to: &AccountInfo<'info>, amount: u64, signer_seeds: &[&[&[u8]]]) -> Result<()> {
+        checked_withdraw(self.balance(), amount, self.rent_exempt_minimum()?)?;
+
+        system_program::transfer(
+            CpiContext::new_with_signer(
+                self.system_program.to_account_info(),
+                Transfer {
+                    from: self.account.to_account_info(),
+                    to: to.clone(),
+                },
+                signer_seeds,
+            ),
+            amount,
+        )
+    }
+}
+
+/// Reward token vault of a staking pool
+/// Every payout signs the same way: as the pool PDA, or through the
+/// external reward vault authority once custody was handed off
+pub struct RewardVault<'a, 'info> {
+    /// The pool the vault pays rewards for
+    pub pool: &'a Account<'info, StakingPool>,
+
+    /// Token account holding the reward tokens
+    pub account: &'a Account<'info, TokenAccount>,
+
+    /// Co-signer passed by the caller; only used under external custody
+    pub authority: Option<&'a Signer<'info>>,
+
+    /// Token program used for the transfers
+    pub token_program: &'a Program<'info, Token>,
+}
+
+impl<'a, 'info> RewardVault<'a, 'info> {
+    pub fn new(
+        pool: &'a Account<'info, StakingPool>,
+        account: &'a Account<'info, TokenAccount>,
+        authority: &'a Option<Signer<'info>>,
+        token_program: &'a Program<'info, Token>,
+    ) -> Self {
+        Self { pool, account, authority: authority.as_ref(), token_program }
+    }
+
+    /// Move `amount` reward tokens out of the vault to `to`
+    /// Fails if the vault is short or the external custodian did not co-sign
+    pub fn withdraw(&self, to: &AccountInfo<'info>, amount: u64) -> Result<()> {
+        if self.account.amount < amount {
+            msg!(
+                "Insufficient reward vault balance: has {}, needs {}",
+                self.account.amount,
+                amount
+            );
+            return Err(StakingError::InsufficientRewardTokens.into());
+        }
+
+        if self.pool.has_external_reward_custody(&self.pool.key()) {
+            // Reward vault is held by an external authority that must co-sign
+            let authority = self
+                .authority
+                .filter(|signer| signer.key() == self.pool.reward_vault_authority)
+                .ok_or(StakingError::RewardVaultAuthorityRequired)?;
+
+            token::transfer(
+                CpiContext::new(
+                    self.token_program.to_account_info(),
+                    token::Transfer {
+                        from: self.account.to_account_info(),
+                        to: to.clone(),
+                        authority: authority.to_account_info(),
+                    },
+                ),
+                amount,
+            )
+        } else {
+            // The pool PDA owns the vault and signs with its own seeds
+            let pool_id = self.pool.pool_id.to_le_bytes();
+            let seeds = &[
+                POOL_SEED,
+                self.pool.creator.as_ref(),
+                &pool_id,
+                &[self.pool.bump],
+            ];
+
+            token::transfer(
+                CpiContext::new_with_signer(
+                    self.token_program.to_account_info(),
+                    token::Transfer {
+                        from: self.account.to_account_info(),
+                        to: to.clone(),
+                        authority: self.pool.to_account_info(),
+                    },
+                    &[&seeds[..]],
+                ),
+                amount,
+            )
+        }
+    }
+}
+
+/// Vault balance after depositing `amount`
+/// Fails if the deposit is empty or would leave the vault below `rent_exempt_minimum`
+pub fn checked_deposit(balance: u64, amount: u64, rent_exempt_minimum: u64) -> Result<u64> {
+    if amount == 0 {
+        return Err(StakingError::InvalidVaultAmount.into());
+    }
+
+    let new_balance = balance
+        .checked_add(amount)
+        .ok_or(StakingError::MathOverflow)?;
+
+    if new_balance < rent_exempt_minimum {
+        return Err(StakingError::VaultNotRentExempt.into());
+    }
+
+    Ok(new_balance)
+}
+
+/// Vault balance after withdrawing `amount`
+/// Fails if the withdrawal is empty, exceeds the balance, or dips into the rent reserve
+pub fn checked_withdraw(balance: u64, amount: u64, rent_exempt_minimum: u64) -> Result<u64> {
+    if amount == 0 {
+        return Err(StakingError::InvalidVaultAmount.into());
+    }
+
+    let new_balance = balance
+        .checked_sub(amount)
+        .ok_or(StakingError::InsufficientVaultBalance)?;
+
+    if new_balance < rent_exempt_minimum {
+        return Err(StakingError::VaultNotRentExempt.into());
+    }
+
+    Ok(new_balance)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Rent-exempt minimum for a zero-data system account
+    const RENT_MINIMUM: u64 = 890_880;
+    const SOL: u64 = 1_000_000_000;
+
+    #[test]
+    fn test_deposit() {
+        // First deposit into an empty vault must cover rent
+        assert_eq!(checked_deposit(0, SOL, RENT_MINIMUM).unwrap(), SOL);
+        assert_eq!(checked_deposit(SOL, 500, RENT_MINIMUM).unwrap(), SOL + 500);
+
+        assert!(checked_deposit(SOL, 0, RENT_MINIMUM).is_err());
+        assert!(checked_deposit(u64::MAX, 1, RENT_MINIMUM).is_err());
+    }
+
+    #[test]
+    fn test_withdraw() {
+        let balance = SOL + RENT_MINIMUM;
+
+        // Everything above the rent reserve can be withdrawn
+        assert_eq!(checked_withdraw(balance, SOL, RENT_MINIMUM).unwrap(), RENT_MINIMUM);
+        assert_eq!(checked_withdraw(balance, 1, RENT_MINIMUM).unwrap(), balance - 1);
+
+        assert!(checked_withdraw(balance, 0, RENT_MINIMUM).is_err());
+        assert!(checked_withdraw(balance, balance + 1, RENT_MINIMUM).is_err());
+    }
+
+    #[test]
+    fn test_rent_exemption_refusal() {
+        // A deposit too small to make an empty vault rent exempt
+        assert_eq!(
+            checked_deposit(0, RENT_MINIMUM - 1, RENT_MINIMUM).unwrap_err(),
+            StakingError::VaultNotRentExempt.into()
+        );
+
+        // A withdrawal that would eat into the rent reserve
+        assert_eq!(
+            checked_withdraw(SOL + RENT_MINIMUM, SOL + 1, RENT_MINIMUM).unwrap_err(),
+            StakingError::VaultNotRentExempt.into()
+        );
+
+        // Draining the vault entirely is refused too
+        assert!(checked_withdraw(SOL, SOL, RENT_MINIMUM).is_err());
+    }
+}
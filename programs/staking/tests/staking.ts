@@ -6,7 +6,7 @@ import { assert, expect } from "chai";
 
 // Import SPL Token functions - these should be available after 'yarn install'
 let TOKEN_PROGRAM_ID: any, ASSOCIATED_TOKEN_PROGRAM_ID: any;
-let createMint: any, createAssociatedTokenAccount: any, mintTo: any, getAccount: any, getAssociatedTokenAddress: any;
+let createMint: any, createAssociatedTokenAccount: any, mintTo: any, getAccount: any, getAssociatedTokenAddress: any, transfer: any;
 let Staking: any;
 
 try {
@@ -23,6 +23,7 @@ try {
   mintTo = splToken.mintTo;
   getAccount = splToken.getAccount;
   getAssociatedTokenAddress = splToken.getAssociatedTokenAddress;
+  transfer = splToken.transfer;
   
   console.log("✅ Successfully loaded all dependencies");
 } catch (error) {
@@ -82,12 +83,18 @@ describe("🏦 Staking dApp - Simplified Test Suite", () => {
   let user1StakeBump: number;
   let user2StakePda: PublicKey;
   let user2StakeBump: number;
+  let globalStatsPda: PublicKey;
 
   // Test configuration constants
   const POOL_ID = new BN(1);
   const STAKE_AMOUNT = new BN(1000 * 10**6); // 1000 tokens with 6 decimals
   const REWARD_RATE = new BN(317097919); // ~10% APR (calculated from constants)
   const LOCK_DURATION = new BN(7 * 24 * 60 * 60); // 7 days in seconds
+  const EARLY_UNSTAKE_PENALTY_BPS = 1000; // 10% of principal forfeited by emergency unstake
+  const STAKING_START_TIME = new BN(0); // Accept stakes immediately
+  const STAKING_END_TIME = new BN(Math.floor(Date.now() / 1000) + 365 * 24 * 60 * 60); // Close in one year
+  const REWARD_DURATION = new BN(365 * 24 * 60 * 60); // Rewards accrue for one year
+  const MIN_UPDATE_INTERVAL = new BN(0); // Allow update_pool on every call
   const INITIAL_MINT_AMOUNT = new BN(10000 * 10**6); // 10,000 tokens
   const REWARD_VAULT_FUNDING = new BN(5000 * 10**6); // 5,000 reward tokens
 
@@ -241,6 +248,11 @@ describe("🏦 Staking dApp - Simplified Test Suite", () => {
       program.programId
     );
 
+    [globalStatsPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from("global_stats")],
+      program.programId
+    );
+
     [user1StakePda, user1StakeBump] = PublicKey.findProgramAddressSync(
       [Buffer.from("stake"), poolPda.toBuffer(), user1.publicKey.toBuffer()],
       program.programId
@@ -257,6 +269,7 @@ describe("🏦 Staking dApp - Simplified Test Suite", () => {
     console.log(`Reward Vault PDA: ${rewardVaultPda.toBase58()}`);
     console.log(`User1 Stake PDA: ${user1StakePda.toBase58()}`);
     console.log(`User2 Stake PDA: ${user2StakePda.toBase58()}`);
+    console.log(`Global Stats PDA: ${globalStatsPda.toBase58()}`);
     
     console.log("\n✅ Test environment setup complete!\n");
   });
@@ -299,8 +312,21 @@ describe("🏦 Staking dApp - Simplified Test Suite", () => {
     console.log("\n=== INITIALIZING STAKING POOL FOR ALL TESTS ===");
     
     try {
+      // Global stats must exist before the first pool is created
+      const statsTx = await program.methods
+        .initializeGlobalStats()
+        .accounts({
+          payer: poolAuthority.publicKey,
+          globalStats: globalStatsPda,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([poolAuthority])
+        .rpc();
+
+      console.log(`✅ Global stats initialized with transaction: ${statsTx}`);
+
       const tx = await program.methods
-        .initializePool(POOL_ID, REWARD_RATE, LOCK_DURATION)
+        .initializePool(POOL_ID, REWARD_RATE, LOCK_DURATION, EARLY_UNSTAKE_PENALTY_BPS, STAKING_START_TIME, STAKING_END_TIME, REWARD_DURATION, MIN_UPDATE_INTERVAL, false)
         .accounts({
           authority: poolAuthority.publicKey,
           pool: poolPda,
@@ -308,6 +334,7 @@ describe("🏦 Staking dApp - Simplified Test Suite", () => {
           rewardMint: rewardMint,
           stakeVault: stakeVaultPda,
           rewardVault: rewardVaultPda,
+          globalStats: globalStatsPda,
           systemProgram: SystemProgram.programId,
           tokenProgram: TOKEN_PROGRAM_ID,
           associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
@@ -380,6 +407,11 @@ describe("🏦 Staking dApp - Simplified Test Suite", () => {
         true,
         "Pool should be active"
       );
+      assert.equal(
+        poolAccount.rewardEndTime.toNumber(),
+        poolAccount.createdAt.add(REWARD_DURATION).toNumber(),
+        "Rewards should end reward_duration after creation"
+      );
 
       // Verify token vaults were created
       const stakeVaultBalance = await getTokenBalance(stakeVaultPda);
@@ -419,7 +451,13 @@ describe("🏦 Staking dApp - Simplified Test Suite", () => {
           .initializePool(
             invalidPoolId,
             new BN(0), // Invalid reward rate (too low)
-            LOCK_DURATION
+            LOCK_DURATION,
+            EARLY_UNSTAKE_PENALTY_BPS,
+            STAKING_START_TIME,
+            STAKING_END_TIME,
+            REWARD_DURATION,
+            MIN_UPDATE_INTERVAL,
+            false
           )
           .accounts({
             authority: poolAuthority.publicKey,
@@ -428,6 +466,7 @@ describe("🏦 Staking dApp - Simplified Test Suite", () => {
             rewardMint: rewardMint,
             stakeVault: invalidStakeVaultPda,
             rewardVault: invalidRewardVaultPda,
+            globalStats: globalStatsPda,
             systemProgram: SystemProgram.programId,
             tokenProgram: TOKEN_PROGRAM_ID,
             associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
@@ -471,7 +510,13 @@ describe("🏦 Staking dApp - Simplified Test Suite", () => {
           .initializePool(
             invalidPoolId,
             REWARD_RATE,
-            new BN(0) // Invalid lock duration (too short)
+            new BN(0), // Invalid lock duration (too short)
+            EARLY_UNSTAKE_PENALTY_BPS,
+            STAKING_START_TIME,
+            STAKING_END_TIME,
+            REWARD_DURATION,
+            MIN_UPDATE_INTERVAL,
+            false
           )
           .accounts({
             authority: poolAuthority.publicKey,
@@ -480,6 +525,7 @@ describe("🏦 Staking dApp - Simplified Test Suite", () => {
             rewardMint: rewardMint,
             stakeVault: invalidStakeVaultPda,
             rewardVault: invalidRewardVaultPda,
+            globalStats: globalStatsPda,
             systemProgram: SystemProgram.programId,
             tokenProgram: TOKEN_PROGRAM_ID,
             associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
@@ -494,6 +540,124 @@ describe("🏦 Staking dApp - Simplified Test Suite", () => {
         expect(error.message).to.include("InvalidLockDuration");
       }
     });
+
+    it("❌ Should fail with an early unstake penalty above the maximum", async () => {
+      console.log("\n=== Testing Invalid Early Unstake Penalty ===");
+      
+      const invalidPoolId = new BN(997);
+      const [invalidPoolPda] = PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("pool"),
+          poolAuthority.publicKey.toBuffer(),
+          invalidPoolId.toArrayLike(Buffer, "le", 8),
+        ],
+        program.programId
+      );
+      
+      const [invalidStakeVaultPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("stake_vault"), invalidPoolPda.toBuffer()],
+        program.programId
+      );
+      
+      const [invalidRewardVaultPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("reward_vault"), invalidPoolPda.toBuffer()],
+        program.programId
+      );
+
+      try {
+        await program.methods
+          .initializePool(
+            invalidPoolId,
+            REWARD_RATE,
+            LOCK_DURATION,
+            5001, // Invalid penalty (above the 50% maximum)
+            STAKING_START_TIME,
+            STAKING_END_TIME,
+            REWARD_DURATION,
+            MIN_UPDATE_INTERVAL,
+            false
+          )
+          .accounts({
+            authority: poolAuthority.publicKey,
+            pool: invalidPoolPda,
+            stakeMint: stakeMint,
+            rewardMint: rewardMint,
+            stakeVault: invalidStakeVaultPda,
+            rewardVault: invalidRewardVaultPda,
+            globalStats: globalStatsPda,
+            systemProgram: SystemProgram.programId,
+            tokenProgram: TOKEN_PROGRAM_ID,
+            associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+            rent: SYSVAR_RENT_PUBKEY,
+          })
+          .signers([poolAuthority])
+          .rpc();
+        
+        assert.fail("Should have failed with invalid early unstake penalty");
+      } catch (error) {
+        console.log(`✅ Correctly failed with error: ${error.message}`);
+        expect(error.message).to.include("InvalidEarlyUnstakePenalty");
+      }
+    });
+
+    it("❌ Should fail with a non-positive reward duration", async () => {
+      console.log("\n=== Testing Invalid Reward Duration ===");
+      
+      const invalidPoolId = new BN(995);
+      const [invalidPoolPda] = PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("pool"),
+          poolAuthority.publicKey.toBuffer(),
+          invalidPoolId.toArrayLike(Buffer, "le", 8),
+        ],
+        program.programId
+      );
+      
+      const [invalidStakeVaultPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("stake_vault"), invalidPoolPda.toBuffer()],
+        program.programId
+      );
+      
+      const [invalidRewardVaultPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("reward_vault"), invalidPoolPda.toBuffer()],
+        program.programId
+      );
+
+      try {
+        await program.methods
+          .initializePool(
+            invalidPoolId,
+            REWARD_RATE,
+            LOCK_DURATION,
+            EARLY_UNSTAKE_PENALTY_BPS,
+            STAKING_START_TIME,
+            STAKING_END_TIME,
+            new BN(0), // Invalid reward duration (rewards would never accrue)
+            MIN_UPDATE_INTERVAL,
+            false
+          )
+          .accounts({
+            authority: poolAuthority.publicKey,
+            pool: invalidPoolPda,
+            stakeMint: stakeMint,
+            rewardMint: rewardMint,
+            stakeVault: invalidStakeVaultPda,
+            rewardVault: invalidRewardVaultPda,
+            globalStats: globalStatsPda,
+            systemProgram: SystemProgram.programId,
+            tokenProgram: TOKEN_PROGRAM_ID,
+            associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+            rent: SYSVAR_RENT_PUBKEY,
+          })
+          .signers([poolAuthority])
+          .rpc();
+        
+        assert.fail("Should have failed with invalid reward duration");
+      } catch (error) {
+        console.log(`✅ Correctly failed with error: ${error.message}`);
+        expect(error.message).to.include("InvalidRewardDuration");
+      }
+    });
   });
 
   /**
@@ -524,7 +688,7 @@ describe("🏦 Staking dApp - Simplified Test Suite", () => {
       console.log(`   Stake vault balance: ${initialVaultBalance}`);
 
       const tx = await program.methods
-        .stake(STAKE_AMOUNT)
+        .stake(STAKE_AMOUNT, LOCK_DURATION)
         .accounts({
           user: user1.publicKey,
           pool: poolPda,
@@ -532,6 +696,7 @@ describe("🏦 Staking dApp - Simplified Test Suite", () => {
           userTokenAccount: user1StakeTokenAccount,
           stakeVault: stakeVaultPda,
           stakeMint: stakeMint,
+          globalStats: globalStatsPda,
           systemProgram: SystemProgram.programId,
           tokenProgram: TOKEN_PROGRAM_ID,
           associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
@@ -618,7 +783,7 @@ describe("🏦 Staking dApp - Simplified Test Suite", () => {
       console.log(`   Pool total staked: ${initialPoolAccount.totalStaked.toNumber()}`);
 
       const tx = await program.methods
-        .stake(largerStakeAmount)
+        .stake(largerStakeAmount, LOCK_DURATION)
         .accounts({
           user: user2.publicKey,
           pool: poolPda,
@@ -626,6 +791,7 @@ describe("🏦 Staking dApp - Simplified Test Suite", () => {
           userTokenAccount: user2StakeTokenAccount,
           stakeVault: stakeVaultPda,
           stakeMint: stakeMint,
+          globalStats: globalStatsPda,
           systemProgram: SystemProgram.programId,
           tokenProgram: TOKEN_PROGRAM_ID,
           associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
@@ -666,7 +832,7 @@ describe("🏦 Staking dApp - Simplified Test Suite", () => {
       
       try {
         await program.methods
-          .stake(excessiveAmount)
+          .stake(excessiveAmount, LOCK_DURATION)
           .accounts({
             user: user1.publicKey,
             pool: poolPda,
@@ -674,6 +840,7 @@ describe("🏦 Staking dApp - Simplified Test Suite", () => {
             userTokenAccount: user1StakeTokenAccount,
             stakeVault: stakeVaultPda,
             stakeMint: stakeMint,
+            globalStats: globalStatsPda,
             systemProgram: SystemProgram.programId,
             tokenProgram: TOKEN_PROGRAM_ID,
             associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
@@ -726,7 +893,7 @@ describe("🏦 Staking dApp - Simplified Test Suite", () => {
 
       try {
         await program.methods
-          .stake(tinyAmount)
+          .stake(tinyAmount, LOCK_DURATION)
           .accounts({
             user: newUser.publicKey,
             pool: poolPda,
@@ -734,6 +901,7 @@ describe("🏦 Staking dApp - Simplified Test Suite", () => {
             userTokenAccount: newUserTokenAccount,
             stakeVault: stakeVaultPda,
             stakeMint: stakeMint,
+            globalStats: globalStatsPda,
             systemProgram: SystemProgram.programId,
             tokenProgram: TOKEN_PROGRAM_ID,
             associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
@@ -801,6 +969,7 @@ describe("🏦 Staking dApp - Simplified Test Suite", () => {
             userRewardTokenAccount: user1RewardTokenAccount,
             rewardVault: rewardVaultPda,
             rewardMint: rewardMint,
+            globalStats: globalStatsPda,
             systemProgram: SystemProgram.programId,
             tokenProgram: TOKEN_PROGRAM_ID,
             associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
@@ -875,6 +1044,7 @@ describe("🏦 Staking dApp - Simplified Test Suite", () => {
             userRewardTokenAccount: newUserRewardTokenAccount,
             rewardVault: rewardVaultPda,
             rewardMint: rewardMint,
+            globalStats: globalStatsPda,
             systemProgram: SystemProgram.programId,
             tokenProgram: TOKEN_PROGRAM_ID,
             associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
@@ -971,6 +1141,3310 @@ describe("🏦 Staking dApp - Simplified Test Suite", () => {
     });
   });
 
+  /**
+   * TEST SUITE: GLOBAL STATISTICS
+   * Tests that program-wide totals aggregate activity across pools
+   */
+  describe("📈 Global Statistics Tests", () => {
+    it("✅ Should aggregate totals across multiple pools", async () => {
+      console.log("\n=== Testing Global Statistics Across Pools ===");
+
+      const statsBefore = await program.methods
+        .getGlobalStats()
+        .accounts({ globalStats: globalStatsPda })
+        .view();
+
+      // Create a second pool and stake into it
+      const secondPoolId = new BN(2);
+      const [secondPoolPda] = PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("pool"),
+          poolAuthority.publicKey.toBuffer(),
+          secondPoolId.toArrayLike(Buffer, "le", 8),
+        ],
+        program.programId
+      );
+      const [secondStakeVaultPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("stake_vault"), secondPoolPda.toBuffer()],
+        program.programId
+      );
+      const [secondRewardVaultPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("reward_vault"), secondPoolPda.toBuffer()],
+        program.programId
+      );
+      const [secondUserStakePda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("stake"), secondPoolPda.toBuffer(), user1.publicKey.toBuffer()],
+        program.programId
+      );
+
+      await program.methods
+        .initializePool(secondPoolId, REWARD_RATE, LOCK_DURATION, EARLY_UNSTAKE_PENALTY_BPS, STAKING_START_TIME, STAKING_END_TIME, REWARD_DURATION, MIN_UPDATE_INTERVAL, false)
+        .accounts({
+          authority: poolAuthority.publicKey,
+          pool: secondPoolPda,
+          stakeMint: stakeMint,
+          rewardMint: rewardMint,
+          stakeVault: secondStakeVaultPda,
+          rewardVault: secondRewardVaultPda,
+          globalStats: globalStatsPda,
+          systemProgram: SystemProgram.programId,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+          rent: SYSVAR_RENT_PUBKEY,
+        })
+        .signers([poolAuthority])
+        .rpc();
+
+      await program.methods
+        .stake(STAKE_AMOUNT, LOCK_DURATION)
+        .accounts({
+          user: user1.publicKey,
+          pool: secondPoolPda,
+          userStake: secondUserStakePda,
+          userTokenAccount: user1StakeTokenAccount,
+          stakeVault: secondStakeVaultPda,
+          stakeMint: stakeMint,
+          globalStats: globalStatsPda,
+          systemProgram: SystemProgram.programId,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+          rent: SYSVAR_RENT_PUBKEY,
+        })
+        .signers([user1])
+        .rpc();
+
+      const statsAfter = await program.methods
+        .getGlobalStats()
+        .accounts({ globalStats: globalStatsPda })
+        .view();
+
+      console.log(`📊 Global stats:`);
+      console.log(`   Total staked: ${statsAfter.totalStaked.toString()}`);
+      console.log(`   Total rewards distributed: ${statsAfter.totalRewardsDistributed.toString()}`);
+      console.log(`   Total pools created: ${statsAfter.totalPoolsCreated.toString()}`);
+
+      assert.equal(
+        statsAfter.totalPoolsCreated.toNumber(),
+        statsBefore.totalPoolsCreated.toNumber() + 1,
+        "Second pool should be counted"
+      );
+      assert.equal(
+        statsAfter.totalStaked.toNumber(),
+        statsBefore.totalStaked.toNumber() + STAKE_AMOUNT.toNumber(),
+        "Stake in second pool should be added to the global total"
+      );
+
+      // Global total covers both pools
+      const firstPool = await program.account.stakingPool.fetch(poolPda);
+      const secondPool = await program.account.stakingPool.fetch(secondPoolPda);
+      assert.equal(
+        statsAfter.totalStaked.toNumber(),
+        firstPool.totalStaked.toNumber() + secondPool.totalStaked.toNumber(),
+        "Global total staked should equal the sum of all pools"
+      );
+    });
+  });
+
+  /**
+   * Reward vault custody: rewards can be handed to a separate authority (e.g. a multisig)
+   * that must co-sign payouts, while principal stays with the pool PDA
+   */
+  describe("🔐 Reward Vault Custody Tests", () => {
+    const custodyPoolId = new BN(3);
+    let custodian: Keypair;
+    let custodyPoolPda: PublicKey;
+    let custodyStakeVaultPda: PublicKey;
+    let custodyRewardVaultPda: PublicKey;
+    let custodyUserStakePda: PublicKey;
+
+    const claimFromCustodyPool = (rewardVaultAuthority: Keypair | null) =>
+      program.methods
+        .claimRewards()
+        .accounts({
+          user: user2.publicKey,
+          pool: custodyPoolPda,
+          userStake: custodyUserStakePda,
+          userRewardTokenAccount: user2RewardTokenAccount,
+          rewardVault: custodyRewardVaultPda,
+          rewardVaultAuthority: rewardVaultAuthority ? rewardVaultAuthority.publicKey : null,
+          rewardMint: rewardMint,
+          globalStats: globalStatsPda,
+          systemProgram: SystemProgram.programId,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+        })
+        .signers(rewardVaultAuthority ? [user2, rewardVaultAuthority] : [user2])
+        .rpc();
+
+    before("Setup custody pool", async () => {
+      console.log("\n=== Setting up Reward Vault Custody Tests ===");
+
+      custodian = Keypair.generate();
+
+      [custodyPoolPda] = PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("pool"),
+          poolAuthority.publicKey.toBuffer(),
+          custodyPoolId.toArrayLike(Buffer, "le", 8),
+        ],
+        program.programId
+      );
+      [custodyStakeVaultPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("stake_vault"), custodyPoolPda.toBuffer()],
+        program.programId
+      );
+      [custodyRewardVaultPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("reward_vault"), custodyPoolPda.toBuffer()],
+        program.programId
+      );
+      [custodyUserStakePda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("stake"), custodyPoolPda.toBuffer(), user2.publicKey.toBuffer()],
+        program.programId
+      );
+
+      await program.methods
+        .initializePool(custodyPoolId, REWARD_RATE, LOCK_DURATION, EARLY_UNSTAKE_PENALTY_BPS, STAKING_START_TIME, STAKING_END_TIME, REWARD_DURATION, MIN_UPDATE_INTERVAL, false)
+        .accounts({
+          authority: poolAuthority.publicKey,
+          pool: custodyPoolPda,
+          stakeMint: stakeMint,
+          rewardMint: rewardMint,
+          stakeVault: custodyStakeVaultPda,
+          rewardVault: custodyRewardVaultPda,
+          globalStats: globalStatsPda,
+          systemProgram: SystemProgram.programId,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+          rent: SYSVAR_RENT_PUBKEY,
+        })
+        .signers([poolAuthority])
+        .rpc();
+
+      // Fund the reward vault so payouts can succeed
+      await transfer(
+        connection,
+        wallet.payer,
+        authorityRewardTokenAccount,
+        custodyRewardVaultPda,
+        poolAuthority,
+        1000 * 10**6
+      );
+
+      await program.methods
+        .stake(STAKE_AMOUNT, LOCK_DURATION)
+        .accounts({
+          user: user2.publicKey,
+          pool: custodyPoolPda,
+          userStake: custodyUserStakePda,
+          userTokenAccount: user2StakeTokenAccount,
+          stakeVault: custodyStakeVaultPda,
+          stakeMint: stakeMint,
+          globalStats: globalStatsPda,
+          systemProgram: SystemProgram.programId,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+          rent: SYSVAR_RENT_PUBKEY,
+        })
+        .signers([user2])
+        .rpc();
+
+      // Hand reward custody to the external authority
+      await program.methods
+        .setRewardVaultAuthority(custodian.publicKey)
+        .accounts({
+          authority: poolAuthority.publicKey,
+          pool: custodyPoolPda,
+          rewardVault: custodyRewardVaultPda,
+          currentRewardVaultAuthority: null,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .signers([poolAuthority])
+        .rpc();
+
+      // Let some rewards accrue
+      await sleep(2);
+    });
+
+    it("✅ Should move only the reward vault to the new authority", async () => {
+      const pool = await program.account.stakingPool.fetch(custodyPoolPda);
+      assert.equal(pool.rewardVaultAuthority.toBase58(), custodian.publicKey.toBase58());
+
+      const rewardVault = await getAccount(connection, custodyRewardVaultPda);
+      const stakeVault = await getAccount(connection, custodyStakeVaultPda);
+      assert.equal(rewardVault.owner.toBase58(), custodian.publicKey.toBase58(), "Reward vault should be held by the custodian");
+      assert.equal(stakeVault.owner.toBase58(), custodyPoolPda.toBase58(), "Principal should stay with the pool PDA");
+    });
+
+    it("❌ Should reject reward payouts without the reward vault authority", async () => {
+      try {
+        await claimFromCustodyPool(null);
+        assert.fail("Should have failed without the reward vault authority");
+      } catch (error) {
+        console.log(`✅ Correctly failed with error: ${error.message}`);
+        assert(error.message.includes("RewardVaultAuthorityRequired"));
+      }
+
+      // A different signer is not accepted either
+      try {
+        await claimFromCustodyPool(user1);
+        assert.fail("Should have failed with the wrong reward vault authority");
+      } catch (error) {
+        console.log(`✅ Correctly failed with error: ${error.message}`);
+        assert(error.message.includes("RewardVaultAuthorityRequired"));
+      }
+    });
+
+    it("✅ Should pay rewards when the reward vault authority co-signs", async () => {
+      const balanceBefore = await getTokenBalance(user2RewardTokenAccount);
+
+      await claimFromCustodyPool(custodian);
+
+      const balanceAfter = await getTokenBalance(user2RewardTokenAccount);
+      console.log(`🎁 Rewards paid with co-signer: ${balanceAfter - balanceBefore}`);
+      assert.isAbove(balanceAfter, balanceBefore, "Rewards should be paid out");
+    });
+
+    it("✅ Should not require the reward vault authority to unstake principal", async () => {
+      // Principal is still locked here, so the unstake is rejected by the lock check
+      // rather than by missing reward custody
+      try {
+        await program.methods
+          .unstake()
+          .accounts({
+            user: user2.publicKey,
+            pool: custodyPoolPda,
+            userStake: custodyUserStakePda,
+            userStakeTokenAccount: user2StakeTokenAccount,
+            userRewardTokenAccount: user2RewardTokenAccount,
+            stakeVault: custodyStakeVaultPda,
+            rewardVault: custodyRewardVaultPda,
+            rewardVaultAuthority: null,
+            stakeMint: stakeMint,
+            rewardMint: rewardMint,
+            globalStats: globalStatsPda,
+            systemProgram: SystemProgram.programId,
+            tokenProgram: TOKEN_PROGRAM_ID,
+            associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+          })
+          .signers([user2])
+          .rpc();
+        assert.fail("Should have failed while the stake is locked");
+      } catch (error) {
+        console.log(`✅ Correctly failed with error: ${error.message}`);
+        assert(error.message.includes("StakeStillLocked"));
+        assert(!error.message.includes("RewardVaultAuthorityRequired"));
+      }
+    });
+  });
+
+  describe("🔑 Pool Signer Seed Tests", () => {
+    // Little-endian bytes of this id differ from the first 8 bytes of the pool key
+    const seedPoolId = new BN("0123456789abcdef", 16);
+    let seedPoolPda: PublicKey;
+    let seedStakeVaultPda: PublicKey;
+    let seedRewardVaultPda: PublicKey;
+    let seedUserStakePda: PublicKey;
+
+    before("Setup pool with a non-trivial pool_id", async () => {
+      console.log("\n=== Setting up Pool Signer Seed Tests ===");
+
+      [seedPoolPda] = PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("pool"),
+          poolAuthority.publicKey.toBuffer(),
+          seedPoolId.toArrayLike(Buffer, "le", 8),
+        ],
+        program.programId
+      );
+      [seedStakeVaultPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("stake_vault"), seedPoolPda.toBuffer()],
+        program.programId
+      );
+      [seedRewardVaultPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("reward_vault"), seedPoolPda.toBuffer()],
+        program.programId
+      );
+      [seedUserStakePda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("stake"), seedPoolPda.toBuffer(), user1.publicKey.toBuffer()],
+        program.programId
+      );
+
+      await program.methods
+        .initializePool(seedPoolId, REWARD_RATE, LOCK_DURATION, EARLY_UNSTAKE_PENALTY_BPS, STAKING_START_TIME, STAKING_END_TIME, REWARD_DURATION, MIN_UPDATE_INTERVAL, false)
+        .accounts({
+          authority: poolAuthority.publicKey,
+          pool: seedPoolPda,
+          stakeMint: stakeMint,
+          rewardMint: rewardMint,
+          stakeVault: seedStakeVaultPda,
+          rewardVault: seedRewardVaultPda,
+          globalStats: globalStatsPda,
+          systemProgram: SystemProgram.programId,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+          rent: SYSVAR_RENT_PUBKEY,
+        })
+        .signers([poolAuthority])
+        .rpc();
+
+      // Fund the reward vault so payouts can succeed
+      await transfer(
+        connection,
+        wallet.payer,
+        authorityRewardTokenAccount,
+        seedRewardVaultPda,
+        poolAuthority,
+        1000 * 10**6
+      );
+
+      await program.methods
+        .stake(STAKE_AMOUNT, LOCK_DURATION)
+        .accounts({
+          user: user1.publicKey,
+          pool: seedPoolPda,
+          userStake: seedUserStakePda,
+          userTokenAccount: user1StakeTokenAccount,
+          stakeVault: seedStakeVaultPda,
+          stakeMint: stakeMint,
+          globalStats: globalStatsPda,
+          systemProgram: SystemProgram.programId,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+          rent: SYSVAR_RENT_PUBKEY,
+        })
+        .signers([user1])
+        .rpc();
+
+      // Let some rewards accrue
+      await sleep(2);
+    });
+
+    it("✅ Should store the pool_id used to derive the pool PDA", async () => {
+      const pool = await program.account.stakingPool.fetch(seedPoolPda);
+      assert.equal(pool.poolId.toString(), seedPoolId.toString());
+    });
+
+    it("✅ Should sign vault payouts with the pool_id seeds", async () => {
+      const balanceBefore = await getTokenBalance(user1RewardTokenAccount);
+
+      // The reward vault transfer is signed by the pool PDA
+      await program.methods
+        .claimRewards()
+        .accounts({
+          user: user1.publicKey,
+          pool: seedPoolPda,
+          userStake: seedUserStakePda,
+          userRewardTokenAccount: user1RewardTokenAccount,
+          rewardVault: seedRewardVaultPda,
+          rewardVaultAuthority: null,
+          rewardMint: rewardMint,
+          globalStats: globalStatsPda,
+          systemProgram: SystemProgram.programId,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+        })
+        .signers([user1])
+        .rpc();
+
+      const balanceAfter = await getTokenBalance(user1RewardTokenAccount);
+      console.log(`🎁 Rewards paid by pool PDA signer: ${balanceAfter - balanceBefore}`);
+      assert.isAbove(balanceAfter, balanceBefore, "Rewards should be paid out");
+    });
+
+    it("✅ Should reach the unstake transfer only once the lock expires", async () => {
+      // The minimum lock is one day, so on a local validator the unstake stops at the
+      // lock check; the stake vault signer seeds are covered by the Rust unit tests
+      try {
+        await program.methods
+          .unstake()
+          .accounts({
+            user: user1.publicKey,
+            pool: seedPoolPda,
+            userStake: seedUserStakePda,
+            userStakeTokenAccount: user1StakeTokenAccount,
+            userRewardTokenAccount: user1RewardTokenAccount,
+            stakeVault: seedStakeVaultPda,
+            rewardVault: seedRewardVaultPda,
+            rewardVaultAuthority: null,
+            stakeMint: stakeMint,
+            rewardMint: rewardMint,
+            globalStats: globalStatsPda,
+            systemProgram: SystemProgram.programId,
+            tokenProgram: TOKEN_PROGRAM_ID,
+            associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+          })
+          .signers([user1])
+          .rpc();
+        assert.fail("Should have failed while the stake is locked");
+      } catch (error) {
+        console.log(`✅ Correctly failed with error: ${error.message}`);
+        assert(error.message.includes("StakeStillLocked"));
+      }
+    });
+  });
+
+  describe("📈 Reward Rate Update Tests", () => {
+    const ratePoolId = new BN(5);
+    let ratePoolPda: PublicKey;
+    let rateStakeVaultPda: PublicKey;
+    let rateRewardVaultPda: PublicKey;
+    let rateUserStakePda: PublicKey;
+
+    const updateRewardRate = (signer: Keypair, newRate: BN) =>
+      program.methods
+        .updateRewardRate(newRate)
+        .accounts({
+          authority: signer.publicKey,
+          pool: ratePoolPda,
+        })
+        .signers([signer])
+        .rpc();
+
+    before("Setup pool for rate changes", async () => {
+      console.log("\n=== Setting up Reward Rate Update Tests ===");
+
+      [ratePoolPda] = PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("pool"),
+          poolAuthority.publicKey.toBuffer(),
+          ratePoolId.toArrayLike(Buffer, "le", 8),
+        ],
+        program.programId
+      );
+      [rateStakeVaultPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("stake_vault"), ratePoolPda.toBuffer()],
+        program.programId
+      );
+      [rateRewardVaultPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("reward_vault"), ratePoolPda.toBuffer()],
+        program.programId
+      );
+      [rateUserStakePda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("stake"), ratePoolPda.toBuffer(), user2.publicKey.toBuffer()],
+        program.programId
+      );
+
+      await program.methods
+        .initializePool(ratePoolId, REWARD_RATE, LOCK_DURATION, EARLY_UNSTAKE_PENALTY_BPS, STAKING_START_TIME, STAKING_END_TIME, REWARD_DURATION, MIN_UPDATE_INTERVAL, false)
+        .accounts({
+          authority: poolAuthority.publicKey,
+          pool: ratePoolPda,
+          stakeMint: stakeMint,
+          rewardMint: rewardMint,
+          stakeVault: rateStakeVaultPda,
+          rewardVault: rateRewardVaultPda,
+          globalStats: globalStatsPda,
+          systemProgram: SystemProgram.programId,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+          rent: SYSVAR_RENT_PUBKEY,
+        })
+        .signers([poolAuthority])
+        .rpc();
+
+      await program.methods
+        .stake(STAKE_AMOUNT, LOCK_DURATION)
+        .accounts({
+          user: user2.publicKey,
+          pool: ratePoolPda,
+          userStake: rateUserStakePda,
+          userTokenAccount: user2StakeTokenAccount,
+          stakeVault: rateStakeVaultPda,
+          stakeMint: stakeMint,
+          globalStats: globalStatsPda,
+          systemProgram: SystemProgram.programId,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+          rent: SYSVAR_RENT_PUBKEY,
+        })
+        .signers([user2])
+        .rpc();
+
+      // Let some rewards accrue at the original rate
+      await sleep(2);
+    });
+
+    it("❌ Should reject rate changes from non-authority", async () => {
+      try {
+        await updateRewardRate(user1, REWARD_RATE.muln(2));
+        assert.fail("Should have failed with non-authority signer");
+      } catch (error) {
+        console.log(`✅ Correctly failed with error: ${error.message}`);
+        assert(error.message.includes("UnauthorizedPoolAuthority"));
+      }
+    });
+
+    it("❌ Should reject an invalid reward rate", async () => {
+      try {
+        await updateRewardRate(poolAuthority, new BN(0));
+        assert.fail("Should have failed with invalid reward rate");
+      } catch (error) {
+        console.log(`✅ Correctly failed with error: ${error.message}`);
+        assert(error.message.includes("InvalidRewardRate"));
+      }
+    });
+
+    it("✅ Should checkpoint earned rewards at the old rate before switching", async () => {
+      const poolBefore = await program.account.stakingPool.fetch(ratePoolPda);
+      const newRate = REWARD_RATE.muln(2);
+
+      await updateRewardRate(poolAuthority, newRate);
+
+      const poolAfter = await program.account.stakingPool.fetch(ratePoolPda);
+      assert.equal(poolAfter.rewardRate.toString(), newRate.toString());
+
+      // Rewards up to the change are committed using the old rate
+      const elapsed = poolAfter.lastUpdateTime.sub(poolBefore.lastUpdateTime);
+      const expectedRewardPerToken = poolBefore.rewardPerTokenStored.add(
+        poolBefore.rewardRate
+          .mul(elapsed)
+          .mul(new BN("1000000000000000000"))
+          .div(poolBefore.totalStaked)
+      );
+      console.log(`📊 Checkpointed reward per token: ${poolAfter.rewardPerTokenStored.toString()}`);
+      assert.isTrue(elapsed.gtn(0), "Checkpoint should advance the update time");
+      assert.equal(
+        poolAfter.rewardPerTokenStored.toString(),
+        expectedRewardPerToken.toString(),
+        "Pre-change rewards should accrue at the old rate"
+      );
+    });
+
+    it("✅ Should record each rate change in the pool's rate history", async () => {
+      const [rateHistoryPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("rate_history"), ratePoolPda.toBuffer()],
+        program.programId
+      );
+
+      // The checkpoint test above already moved the rate to 2x
+      await updateRewardRate(poolAuthority, REWARD_RATE.muln(3));
+      await updateRewardRate(poolAuthority, REWARD_RATE);
+
+      const history = await program.methods
+        .getRateHistory()
+        .accounts({
+          pool: ratePoolPda,
+          rateHistory: rateHistoryPda,
+        })
+        .view();
+
+      assert.equal(history.length, 3);
+      assert.deepEqual(
+        history.map((entry) => [entry.oldRate.toString(), entry.newRate.toString()]),
+        [
+          [REWARD_RATE.toString(), REWARD_RATE.muln(2).toString()],
+          [REWARD_RATE.muln(2).toString(), REWARD_RATE.muln(3).toString()],
+          [REWARD_RATE.muln(3).toString(), REWARD_RATE.toString()],
+        ]
+      );
+      assert.isTrue(history[0].timestamp.lte(history[2].timestamp));
+    });
+  });
+
+  describe("⏸️ Pool Pause Tests", () => {
+    const pausePoolId = new BN(6);
+    let pausePoolPda: PublicKey;
+    let pauseStakeVaultPda: PublicKey;
+    let pauseRewardVaultPda: PublicKey;
+    let pauseUser1StakePda: PublicKey;
+    let pauseUser2StakePda: PublicKey;
+
+    const stakeInPausePool = (user: Keypair, userStake: PublicKey, userTokenAccount: PublicKey) =>
+      program.methods
+        .stake(STAKE_AMOUNT, LOCK_DURATION)
+        .accounts({
+          user: user.publicKey,
+          pool: pausePoolPda,
+          userStake: userStake,
+          userTokenAccount: userTokenAccount,
+          stakeVault: pauseStakeVaultPda,
+          stakeMint: stakeMint,
+          globalStats: globalStatsPda,
+          systemProgram: SystemProgram.programId,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+          rent: SYSVAR_RENT_PUBKEY,
+        })
+        .signers([user])
+        .rpc();
+
+    before("Setup pool and pause it", async () => {
+      console.log("\n=== Setting up Pool Pause Tests ===");
+
+      [pausePoolPda] = PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("pool"),
+          poolAuthority.publicKey.toBuffer(),
+          pausePoolId.toArrayLike(Buffer, "le", 8),
+        ],
+        program.programId
+      );
+      [pauseStakeVaultPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("stake_vault"), pausePoolPda.toBuffer()],
+        program.programId
+      );
+      [pauseRewardVaultPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("reward_vault"), pausePoolPda.toBuffer()],
+        program.programId
+      );
+      [pauseUser1StakePda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("stake"), pausePoolPda.toBuffer(), user1.publicKey.toBuffer()],
+        program.programId
+      );
+      [pauseUser2StakePda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("stake"), pausePoolPda.toBuffer(), user2.publicKey.toBuffer()],
+        program.programId
+      );
+
+      await program.methods
+        .initializePool(pausePoolId, REWARD_RATE, LOCK_DURATION, EARLY_UNSTAKE_PENALTY_BPS, STAKING_START_TIME, STAKING_END_TIME, REWARD_DURATION, MIN_UPDATE_INTERVAL, false)
+        .accounts({
+          authority: poolAuthority.publicKey,
+          pool: pausePoolPda,
+          stakeMint: stakeMint,
+          rewardMint: rewardMint,
+          stakeVault: pauseStakeVaultPda,
+          rewardVault: pauseRewardVaultPda,
+          globalStats: globalStatsPda,
+          systemProgram: SystemProgram.programId,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+          rent: SYSVAR_RENT_PUBKEY,
+        })
+        .signers([poolAuthority])
+        .rpc();
+
+      // Fund the reward vault so payouts can succeed
+      await transfer(
+        connection,
+        wallet.payer,
+        authorityRewardTokenAccount,
+        pauseRewardVaultPda,
+        poolAuthority,
+        1000 * 10**6
+      );
+
+      // User 1 stakes before the pause
+      await stakeInPausePool(user1, pauseUser1StakePda, user1StakeTokenAccount);
+      await sleep(2);
+
+      await program.methods
+        .pausePool()
+        .accounts({
+          authority: poolAuthority.publicKey,
+          pool: pausePoolPda,
+        })
+        .signers([poolAuthority])
+        .rpc();
+    });
+
+    it("❌ Should reject pausing by non-authority", async () => {
+      try {
+        await program.methods
+          .pausePool()
+          .accounts({
+            authority: user1.publicKey,
+            pool: pausePoolPda,
+          })
+          .signers([user1])
+          .rpc();
+        assert.fail("Should have failed with non-authority signer");
+      } catch (error) {
+        console.log(`✅ Correctly failed with error: ${error.message}`);
+        assert(error.message.includes("UnauthorizedPoolAuthority"));
+      }
+    });
+
+    it("❌ Should block new stakes while paused", async () => {
+      const pool = await program.account.stakingPool.fetch(pausePoolPda);
+      assert.isFalse(pool.isActive, "Pool should be paused");
+
+      try {
+        await stakeInPausePool(user2, pauseUser2StakePda, user2StakeTokenAccount);
+        assert.fail("Should have failed while the pool is paused");
+      } catch (error) {
+        console.log(`✅ Correctly failed with error: ${error.message}`);
+        assert(error.message.includes("PoolNotActive"));
+      }
+    });
+
+    it("✅ Should still pay rewards while paused", async () => {
+      const balanceBefore = await getTokenBalance(user1RewardTokenAccount);
+
+      await program.methods
+        .claimRewards()
+        .accounts({
+          user: user1.publicKey,
+          pool: pausePoolPda,
+          userStake: pauseUser1StakePda,
+          userRewardTokenAccount: user1RewardTokenAccount,
+          rewardVault: pauseRewardVaultPda,
+          rewardVaultAuthority: null,
+          rewardMint: rewardMint,
+          globalStats: globalStatsPda,
+          systemProgram: SystemProgram.programId,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+        })
+        .signers([user1])
+        .rpc();
+
+      const balanceAfter = await getTokenBalance(user1RewardTokenAccount);
+      assert.isAbove(balanceAfter, balanceBefore, "Rewards should be paid out while paused");
+    });
+
+    it("✅ Should not block unstaking while paused", async () => {
+      // The stake is still inside its lock period, so unstake stops at the lock
+      // check rather than at the pool status
+      try {
+        await program.methods
+          .unstake()
+          .accounts({
+            user: user1.publicKey,
+            pool: pausePoolPda,
+            userStake: pauseUser1StakePda,
+            userStakeTokenAccount: user1StakeTokenAccount,
+            userRewardTokenAccount: user1RewardTokenAccount,
+            stakeVault: pauseStakeVaultPda,
+            rewardVault: pauseRewardVaultPda,
+            rewardVaultAuthority: null,
+            stakeMint: stakeMint,
+            rewardMint: rewardMint,
+            globalStats: globalStatsPda,
+            systemProgram: SystemProgram.programId,
+            tokenProgram: TOKEN_PROGRAM_ID,
+            associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+          })
+          .signers([user1])
+          .rpc();
+        assert.fail("Should have failed while the stake is locked");
+      } catch (error) {
+        console.log(`✅ Correctly failed with error: ${error.message}`);
+        assert(error.message.includes("StakeStillLocked"));
+        assert(!error.message.includes("PoolNotActive"));
+      }
+    });
+
+    it("✅ Should accept stakes again after resuming", async () => {
+      await program.methods
+        .resumePool()
+        .accounts({
+          authority: poolAuthority.publicKey,
+          pool: pausePoolPda,
+        })
+        .signers([poolAuthority])
+        .rpc();
+
+      await stakeInPausePool(user2, pauseUser2StakePda, user2StakeTokenAccount);
+
+      const pool = await program.account.stakingPool.fetch(pausePoolPda);
+      assert.isTrue(pool.isActive, "Pool should be active again");
+      assert.equal(pool.totalStaked.toString(), STAKE_AMOUNT.muln(2).toString());
+    });
+  });
+
+  describe("🚨 Emergency Unstake Tests", () => {
+    const emergencyPoolId = new BN(7);
+    let emergencyPoolPda: PublicKey;
+    let emergencyStakeVaultPda: PublicKey;
+    let emergencyRewardVaultPda: PublicKey;
+    let emergencyUserStakePda: PublicKey;
+
+    before("Setup pool and stake", async () => {
+      console.log("\n=== Setting up Emergency Unstake Tests ===");
+
+      [emergencyPoolPda] = PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("pool"),
+          poolAuthority.publicKey.toBuffer(),
+          emergencyPoolId.toArrayLike(Buffer, "le", 8),
+        ],
+        program.programId
+      );
+      [emergencyStakeVaultPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("stake_vault"), emergencyPoolPda.toBuffer()],
+        program.programId
+      );
+      [emergencyRewardVaultPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("reward_vault"), emergencyPoolPda.toBuffer()],
+        program.programId
+      );
+      [emergencyUserStakePda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("stake"), emergencyPoolPda.toBuffer(), user1.publicKey.toBuffer()],
+        program.programId
+      );
+
+      await program.methods
+        .initializePool(emergencyPoolId, REWARD_RATE, LOCK_DURATION, EARLY_UNSTAKE_PENALTY_BPS, STAKING_START_TIME, STAKING_END_TIME, REWARD_DURATION, MIN_UPDATE_INTERVAL, false)
+        .accounts({
+          authority: poolAuthority.publicKey,
+          pool: emergencyPoolPda,
+          stakeMint: stakeMint,
+          rewardMint: rewardMint,
+          stakeVault: emergencyStakeVaultPda,
+          rewardVault: emergencyRewardVaultPda,
+          globalStats: globalStatsPda,
+          systemProgram: SystemProgram.programId,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+          rent: SYSVAR_RENT_PUBKEY,
+        })
+        .signers([poolAuthority])
+        .rpc();
+
+      await program.methods
+        .stake(STAKE_AMOUNT, LOCK_DURATION)
+        .accounts({
+          user: user1.publicKey,
+          pool: emergencyPoolPda,
+          userStake: emergencyUserStakePda,
+          userTokenAccount: user1StakeTokenAccount,
+          stakeVault: emergencyStakeVaultPda,
+          stakeMint: stakeMint,
+          globalStats: globalStatsPda,
+          systemProgram: SystemProgram.programId,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+          rent: SYSVAR_RENT_PUBKEY,
+        })
+        .signers([user1])
+        .rpc();
+
+      // Let some rewards accrue (these are forfeited)
+      await sleep(2);
+    });
+
+    it("✅ Should exit a locked stake and keep the penalty in the vault", async () => {
+      // A fresh stake is still inside its lock period; unlocked stakes are covered
+      // by the Rust unit tests since the minimum lock is one day
+      const userStake = await program.account.userStake.fetch(emergencyUserStakePda);
+      assert.isAbove(userStake.unlockTime.toNumber(), Math.floor(Date.now() / 1000), "Stake should still be locked");
+
+      const userBalanceBefore = await getTokenBalance(user1StakeTokenAccount);
+      const rewardBalanceBefore = await getTokenBalance(user1RewardTokenAccount);
+
+      const tx = await program.methods
+        .emergencyUnstake()
+        .accounts({
+          user: user1.publicKey,
+          pool: emergencyPoolPda,
+          userStake: emergencyUserStakePda,
+          userStakeTokenAccount: user1StakeTokenAccount,
+          stakeVault: emergencyStakeVaultPda,
+          stakeMint: stakeMint,
+          globalStats: globalStatsPda,
+          systemProgram: SystemProgram.programId,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .signers([user1])
+        .rpc({ commitment: "confirmed" });
+
+      const expectedPenalty = STAKE_AMOUNT.muln(EARLY_UNSTAKE_PENALTY_BPS).divn(10000);
+      const expectedReturned = STAKE_AMOUNT.sub(expectedPenalty);
+
+      // User gets principal minus the penalty and no rewards
+      const userBalanceAfter = await getTokenBalance(user1StakeTokenAccount);
+      const rewardBalanceAfter = await getTokenBalance(user1RewardTokenAccount);
+      assert.equal(userBalanceAfter - userBalanceBefore, expectedReturned.toNumber());
+      assert.equal(rewardBalanceAfter, rewardBalanceBefore, "Rewards should be forfeited");
+
+      // Penalty stays in the stake vault, pool accounting drops the whole stake
+      const vaultBalance = await getTokenBalance(emergencyStakeVaultPda);
+      const pool = await program.account.stakingPool.fetch(emergencyPoolPda);
+      assert.equal(vaultBalance, expectedPenalty.toNumber());
+      assert.equal(pool.totalStaked.toNumber(), 0);
+
+      // Stake account is closed
+      const closedStake = await connection.getAccountInfo(emergencyUserStakePda);
+      assert.isNull(closedStake, "User stake account should be closed");
+
+      // Event reports what was returned and kept
+      const txDetails = await connection.getTransaction(tx, {
+        commitment: "confirmed",
+        maxSupportedTransactionVersion: 0,
+      });
+      const parser = new anchor.EventParser(program.programId, new anchor.BorshCoder(program.idl));
+      const event = [...parser.parseLogs(txDetails.meta.logMessages)]
+        .find(e => e.name.toLowerCase() === "emergencyunstake");
+      assert.isDefined(event, "EmergencyUnstake event should be emitted");
+      assert.equal(event.data.user.toBase58(), user1.publicKey.toBase58());
+      assert.equal(event.data.amountReturned.toString(), expectedReturned.toString());
+      assert.equal(event.data.penalty.toString(), expectedPenalty.toString());
+    });
+  });
+
+  describe("🔁 Reward Compounding Tests", () => {
+    // Single-token pool: rewards are paid in the stake token
+    const compoundPoolId = new BN(8);
+    let compoundPoolPda: PublicKey;
+    let compoundStakeVaultPda: PublicKey;
+    let compoundRewardVaultPda: PublicKey;
+    let compoundUserStakePda: PublicKey;
+
+    before("Setup single-token pool", async () => {
+      console.log("\n=== Setting up Reward Compounding Tests ===");
+
+      [compoundPoolPda] = PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("pool"),
+          poolAuthority.publicKey.toBuffer(),
+          compoundPoolId.toArrayLike(Buffer, "le", 8),
+        ],
+        program.programId
+      );
+      [compoundStakeVaultPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("stake_vault"), compoundPoolPda.toBuffer()],
+        program.programId
+      );
+      [compoundRewardVaultPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("reward_vault"), compoundPoolPda.toBuffer()],
+        program.programId
+      );
+      [compoundUserStakePda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("stake"), compoundPoolPda.toBuffer(), user2.publicKey.toBuffer()],
+        program.programId
+      );
+
+      await program.methods
+        .initializePool(compoundPoolId, REWARD_RATE, LOCK_DURATION, EARLY_UNSTAKE_PENALTY_BPS, STAKING_START_TIME, STAKING_END_TIME, REWARD_DURATION, MIN_UPDATE_INTERVAL, false)
+        .accounts({
+          authority: poolAuthority.publicKey,
+          pool: compoundPoolPda,
+          stakeMint: stakeMint,
+          rewardMint: stakeMint,
+          stakeVault: compoundStakeVaultPda,
+          rewardVault: compoundRewardVaultPda,
+          globalStats: globalStatsPda,
+          systemProgram: SystemProgram.programId,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+          rent: SYSVAR_RENT_PUBKEY,
+        })
+        .signers([poolAuthority])
+        .rpc();
+
+      // Fund the reward vault with stake tokens
+      await transfer(
+        connection,
+        wallet.payer,
+        authorityStakeTokenAccount,
+        compoundRewardVaultPda,
+        poolAuthority,
+        1000 * 10**6
+      );
+
+      await program.methods
+        .stake(STAKE_AMOUNT, LOCK_DURATION)
+        .accounts({
+          user: user2.publicKey,
+          pool: compoundPoolPda,
+          userStake: compoundUserStakePda,
+          userTokenAccount: user2StakeTokenAccount,
+          stakeVault: compoundStakeVaultPda,
+          stakeMint: stakeMint,
+          globalStats: globalStatsPda,
+          systemProgram: SystemProgram.programId,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+          rent: SYSVAR_RENT_PUBKEY,
+        })
+        .signers([user2])
+        .rpc();
+
+      // Let some rewards accrue
+      await sleep(2);
+    });
+
+    it("✅ Should roll rewards into principal", async () => {
+      const userBalanceBefore = await getTokenBalance(user2StakeTokenAccount);
+
+      await program.methods
+        .compound()
+        .accounts({
+          user: user2.publicKey,
+          pool: compoundPoolPda,
+          userStake: compoundUserStakePda,
+          stakeVault: compoundStakeVaultPda,
+          rewardVault: compoundRewardVaultPda,
+          rewardVaultAuthority: null,
+          globalStats: globalStatsPda,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .signers([user2])
+        .rpc();
+
+      const userStake = await program.account.userStake.fetch(compoundUserStakePda);
+      const pool = await program.account.stakingPool.fetch(compoundPoolPda);
+      console.log(`📈 Principal after compounding: ${userStake.amount.toString()}`);
+
+      // Principal grew and nothing was paid out to the user
+      assert.isTrue(userStake.amount.gt(STAKE_AMOUNT), "Principal should grow");
+      assert.equal(userStake.rewards.toNumber(), 0);
+      assert.equal(pool.totalStaked.toString(), userStake.amount.toString());
+      assert.equal(await getTokenBalance(user2StakeTokenAccount), userBalanceBefore);
+
+      // Compounded tokens moved from the reward vault into the stake vault
+      const stakeVaultBalance = await getTokenBalance(compoundStakeVaultPda);
+      assert.equal(stakeVaultBalance, userStake.amount.toNumber());
+    });
+
+    it("❌ Should reject compounding when stake and reward mints differ", async () => {
+      try {
+        await program.methods
+          .compound()
+          .accounts({
+            user: user1.publicKey,
+            pool: poolPda,
+            userStake: user1StakePda,
+            stakeVault: stakeVaultPda,
+            rewardVault: rewardVaultPda,
+            rewardVaultAuthority: null,
+            globalStats: globalStatsPda,
+            tokenProgram: TOKEN_PROGRAM_ID,
+          })
+          .signers([user1])
+          .rpc();
+        assert.fail("Should have failed with mismatched mints");
+      } catch (error) {
+        console.log(`✅ Correctly failed with error: ${error.message}`);
+        assert(error.message.includes("CompoundMintMismatch"));
+      }
+    });
+  });
+
+  describe("🗓️ Staking Window Tests", () => {
+    const findPoolPdas = (poolId: BN) => {
+      const [pool] = PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("pool"),
+          poolAuthority.publicKey.toBuffer(),
+          poolId.toArrayLike(Buffer, "le", 8),
+        ],
+        program.programId
+      );
+      const [stakeVault] = PublicKey.findProgramAddressSync(
+        [Buffer.from("stake_vault"), pool.toBuffer()],
+        program.programId
+      );
+      const [rewardVault] = PublicKey.findProgramAddressSync(
+        [Buffer.from("reward_vault"), pool.toBuffer()],
+        program.programId
+      );
+      const [user1Stake] = PublicKey.findProgramAddressSync(
+        [Buffer.from("stake"), pool.toBuffer(), user1.publicKey.toBuffer()],
+        program.programId
+      );
+      return { pool, stakeVault, rewardVault, user1Stake };
+    };
+
+    const initializeWindowPool = (poolId: BN, startTime: BN, endTime: BN) => {
+      const pdas = findPoolPdas(poolId);
+      return program.methods
+        .initializePool(poolId, REWARD_RATE, LOCK_DURATION, EARLY_UNSTAKE_PENALTY_BPS, startTime, endTime, REWARD_DURATION, MIN_UPDATE_INTERVAL, false)
+        .accounts({
+          authority: poolAuthority.publicKey,
+          pool: pdas.pool,
+          stakeMint: stakeMint,
+          rewardMint: rewardMint,
+          stakeVault: pdas.stakeVault,
+          rewardVault: pdas.rewardVault,
+          globalStats: globalStatsPda,
+          systemProgram: SystemProgram.programId,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+          rent: SYSVAR_RENT_PUBKEY,
+        })
+        .signers([poolAuthority])
+        .rpc();
+    };
+
+    const stakeInWindowPool = (poolId: BN) => {
+      const pdas = findPoolPdas(poolId);
+      return program.methods
+        .stake(STAKE_AMOUNT, LOCK_DURATION)
+        .accounts({
+          user: user1.publicKey,
+          pool: pdas.pool,
+          userStake: pdas.user1Stake,
+          userTokenAccount: user1StakeTokenAccount,
+          stakeVault: pdas.stakeVault,
+          stakeMint: stakeMint,
+          globalStats: globalStatsPda,
+          systemProgram: SystemProgram.programId,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+          rent: SYSVAR_RENT_PUBKEY,
+        })
+        .signers([user1])
+        .rpc();
+    };
+
+    it("❌ Should reject a pool whose window ends at its start", async () => {
+      const start = new BN(Math.floor(Date.now() / 1000));
+
+      try {
+        await initializeWindowPool(new BN(996), start, start);
+        assert.fail("Should have failed with an empty staking window");
+      } catch (error) {
+        console.log(`✅ Correctly failed with error: ${error.message}`);
+        expect(error.message).to.include("InvalidStakingWindow");
+      }
+    });
+
+    it("✅ Should store the staking window on the pool", async () => {
+      const poolId = new BN(9);
+      const start = new BN(Math.floor(Date.now() / 1000) + 60 * 60);
+      const end = start.add(new BN(24 * 60 * 60));
+
+      await initializeWindowPool(poolId, start, end);
+
+      const pool = await program.account.stakingPool.fetch(findPoolPdas(poolId).pool);
+      assert(pool.startTime.eq(start), "Start time should be stored");
+      assert(pool.endTime.eq(end), "End time should be stored");
+    });
+
+    it("❌ Should reject stakes before the window opens", async () => {
+      try {
+        await stakeInWindowPool(new BN(9));
+        assert.fail("Should have failed before the staking window opens");
+      } catch (error) {
+        console.log(`✅ Correctly failed with error: ${error.message}`);
+        expect(error.message).to.include("StakingWindowClosed");
+      }
+    });
+
+    it("❌ Should reject stakes after the window closes", async () => {
+      const poolId = new BN(10);
+      const end = new BN(Math.floor(Date.now() / 1000) - 60);
+
+      await initializeWindowPool(poolId, new BN(0), end);
+
+      try {
+        await stakeInWindowPool(poolId);
+        assert.fail("Should have failed after the staking window closed");
+      } catch (error) {
+        console.log(`✅ Correctly failed with error: ${error.message}`);
+        expect(error.message).to.include("StakingWindowClosed");
+      }
+    });
+  });
+
+  describe("🏅 Lock Tier Multiplier Tests", () => {
+    const tierPoolId = new BN(11);
+    const THIRTY_DAYS = new BN(30 * 24 * 60 * 60);
+    let tierPoolPda: PublicKey;
+    let tierStakeVaultPda: PublicKey;
+    let tierRewardVaultPda: PublicKey;
+    let tierUser1StakePda: PublicKey;
+    let tierUser2StakePda: PublicKey;
+
+    const stakeInTierPool = (
+      user: Keypair,
+      userStake: PublicKey,
+      userTokenAccount: PublicKey,
+      lockDurationOverride: BN
+    ) =>
+      program.methods
+        .stake(STAKE_AMOUNT, lockDurationOverride)
+        .accounts({
+          user: user.publicKey,
+          pool: tierPoolPda,
+          userStake: userStake,
+          userTokenAccount: userTokenAccount,
+          stakeVault: tierStakeVaultPda,
+          stakeMint: stakeMint,
+          globalStats: globalStatsPda,
+          systemProgram: SystemProgram.programId,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+          rent: SYSVAR_RENT_PUBKEY,
+        })
+        .signers([user])
+        .rpc();
+
+    before("Setup lock tier pool", async () => {
+      console.log("\n=== Setting up Lock Tier Multiplier Tests ===");
+
+      [tierPoolPda] = PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("pool"),
+          poolAuthority.publicKey.toBuffer(),
+          tierPoolId.toArrayLike(Buffer, "le", 8),
+        ],
+        program.programId
+      );
+      [tierStakeVaultPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("stake_vault"), tierPoolPda.toBuffer()],
+        program.programId
+      );
+      [tierRewardVaultPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("reward_vault"), tierPoolPda.toBuffer()],
+        program.programId
+      );
+      [tierUser1StakePda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("stake"), tierPoolPda.toBuffer(), user1.publicKey.toBuffer()],
+        program.programId
+      );
+      [tierUser2StakePda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("stake"), tierPoolPda.toBuffer(), user2.publicKey.toBuffer()],
+        program.programId
+      );
+
+      await program.methods
+        .initializePool(tierPoolId, REWARD_RATE, LOCK_DURATION, EARLY_UNSTAKE_PENALTY_BPS, STAKING_START_TIME, STAKING_END_TIME, REWARD_DURATION, MIN_UPDATE_INTERVAL, false)
+        .accounts({
+          authority: poolAuthority.publicKey,
+          pool: tierPoolPda,
+          stakeMint: stakeMint,
+          rewardMint: rewardMint,
+          stakeVault: tierStakeVaultPda,
+          rewardVault: tierRewardVaultPda,
+          globalStats: globalStatsPda,
+          systemProgram: SystemProgram.programId,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+          rent: SYSVAR_RENT_PUBKEY,
+        })
+        .signers([poolAuthority])
+        .rpc();
+    });
+
+    it("❌ Should reject a lock shorter than the pool minimum", async () => {
+      try {
+        await stakeInTierPool(user1, tierUser1StakePda, user1StakeTokenAccount, LOCK_DURATION.subn(1));
+        assert.fail("Should have failed with a lock below the pool minimum");
+      } catch (error) {
+        console.log(`✅ Correctly failed with error: ${error.message}`);
+        expect(error.message).to.include("InvalidLockDuration");
+      }
+    });
+
+    it("✅ Should weight a 30-day stake above a 7-day stake", async () => {
+      await stakeInTierPool(user1, tierUser1StakePda, user1StakeTokenAccount, LOCK_DURATION);
+      await stakeInTierPool(user2, tierUser2StakePda, user2StakeTokenAccount, THIRTY_DAYS);
+
+      const sevenDay = await program.account.userStake.fetch(tierUser1StakePda);
+      const thirtyDay = await program.account.userStake.fetch(tierUser2StakePda);
+      const pool = await program.account.stakingPool.fetch(tierPoolPda);
+
+      console.log(`📊 7-day weight: ${sevenDay.weightedAmount.toString()} (${sevenDay.rewardMultiplierBps} bps)`);
+      console.log(`📊 30-day weight: ${thirtyDay.weightedAmount.toString()} (${thirtyDay.rewardMultiplierBps} bps)`);
+
+      // Same principal, different reward weight
+      assert(sevenDay.amount.eq(thirtyDay.amount), "Principal should be identical");
+      assert.equal(sevenDay.rewardMultiplierBps, 10000);
+      assert.equal(thirtyDay.rewardMultiplierBps, 12500);
+      assert(sevenDay.weightedAmount.eq(STAKE_AMOUNT));
+      assert(thirtyDay.weightedAmount.eq(STAKE_AMOUNT.muln(12500).divn(10000)));
+
+      // The chosen lock sets the unlock time
+      assert(thirtyDay.unlockTime.sub(thirtyDay.stakeTime).eq(THIRTY_DAYS));
+
+      // Pool total tracks the weighted sum
+      assert(pool.totalStaked.eq(sevenDay.weightedAmount.add(thirtyDay.weightedAmount)));
+    });
+  });
+
+  describe("📣 Event Tests", () => {
+    const eventPoolId = new BN(12);
+    let eventPoolPda: PublicKey;
+    let eventStakeVaultPda: PublicKey;
+    let eventRewardVaultPda: PublicKey;
+    let eventUser1StakePda: PublicKey;
+
+    const findEvent = async (tx: string, name: string) => {
+      const txDetails = await connection.getTransaction(tx, {
+        commitment: "confirmed",
+        maxSupportedTransactionVersion: 0,
+      });
+      const parser = new anchor.EventParser(program.programId, new anchor.BorshCoder(program.idl));
+      return [...parser.parseLogs(txDetails.meta.logMessages)]
+        .find(e => e.name.toLowerCase() === name.toLowerCase());
+    };
+
+    before("Setup event pool", async () => {
+      console.log("\n=== Setting up Event Tests ===");
+
+      [eventPoolPda] = PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("pool"),
+          poolAuthority.publicKey.toBuffer(),
+          eventPoolId.toArrayLike(Buffer, "le", 8),
+        ],
+        program.programId
+      );
+      [eventStakeVaultPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("stake_vault"), eventPoolPda.toBuffer()],
+        program.programId
+      );
+      [eventRewardVaultPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("reward_vault"), eventPoolPda.toBuffer()],
+        program.programId
+      );
+      [eventUser1StakePda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("stake"), eventPoolPda.toBuffer(), user1.publicKey.toBuffer()],
+        program.programId
+      );
+
+      await program.methods
+        .initializePool(eventPoolId, REWARD_RATE, LOCK_DURATION, EARLY_UNSTAKE_PENALTY_BPS, STAKING_START_TIME, STAKING_END_TIME, REWARD_DURATION, MIN_UPDATE_INTERVAL, false)
+        .accounts({
+          authority: poolAuthority.publicKey,
+          pool: eventPoolPda,
+          stakeMint: stakeMint,
+          rewardMint: rewardMint,
+          stakeVault: eventStakeVaultPda,
+          rewardVault: eventRewardVaultPda,
+          globalStats: globalStatsPda,
+          systemProgram: SystemProgram.programId,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+          rent: SYSVAR_RENT_PUBKEY,
+        })
+        .signers([poolAuthority])
+        .rpc();
+
+      // Fund the reward vault so claims can succeed
+      await transfer(
+        connection,
+        wallet.payer,
+        authorityRewardTokenAccount,
+        eventRewardVaultPda,
+        poolAuthority,
+        1000 * 10**6
+      );
+    });
+
+    it("✅ Should emit Staked when staking", async () => {
+      const tx = await program.methods
+        .stake(STAKE_AMOUNT, LOCK_DURATION)
+        .accounts({
+          user: user1.publicKey,
+          pool: eventPoolPda,
+          userStake: eventUser1StakePda,
+          userTokenAccount: user1StakeTokenAccount,
+          stakeVault: eventStakeVaultPda,
+          stakeMint: stakeMint,
+          globalStats: globalStatsPda,
+          systemProgram: SystemProgram.programId,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+          rent: SYSVAR_RENT_PUBKEY,
+        })
+        .signers([user1])
+        .rpc({ commitment: "confirmed" });
+
+      const event = await findEvent(tx, "Staked");
+      const userStake = await program.account.userStake.fetch(eventUser1StakePda);
+      assert.isDefined(event, "Staked event should be emitted");
+      assert.equal(event.data.user.toBase58(), user1.publicKey.toBase58());
+      assert.equal(event.data.pool.toBase58(), eventPoolPda.toBase58());
+      assert.equal(event.data.amount.toString(), STAKE_AMOUNT.toString());
+      assert.equal(event.data.unlockTime.toString(), userStake.unlockTime.toString());
+      assert.equal(event.data.timestamp.toString(), userStake.stakeTime.toString());
+    });
+
+    it("✅ Should emit PoolUpdated when updating the pool", async () => {
+      await sleep(2);
+
+      const tx = await program.methods
+        .updatePool()
+        .accounts({
+          pool: eventPoolPda,
+          caller: poolAuthority.publicKey,
+        })
+        .signers([poolAuthority])
+        .rpc({ commitment: "confirmed" });
+
+      const event = await findEvent(tx, "PoolUpdated");
+      const pool = await program.account.stakingPool.fetch(eventPoolPda);
+      assert.isDefined(event, "PoolUpdated event should be emitted");
+      assert.equal(event.data.pool.toBase58(), eventPoolPda.toBase58());
+      assert.equal(event.data.caller.toBase58(), poolAuthority.publicKey.toBase58());
+      assert.equal(event.data.rewardPerToken.toString(), pool.rewardPerTokenStored.toString());
+      assert.equal(event.data.totalStaked.toString(), pool.totalStaked.toString());
+      assert.equal(event.data.timestamp.toString(), pool.lastUpdateTime.toString());
+    });
+
+    it("✅ Should emit RewardsClaimed when claiming", async () => {
+      await sleep(2);
+      const balanceBefore = await getTokenBalance(user1RewardTokenAccount);
+
+      const tx = await program.methods
+        .claimRewards()
+        .accounts({
+          user: user1.publicKey,
+          pool: eventPoolPda,
+          userStake: eventUser1StakePda,
+          userRewardTokenAccount: user1RewardTokenAccount,
+          rewardVault: eventRewardVaultPda,
+          rewardVaultAuthority: null,
+          rewardMint: rewardMint,
+          globalStats: globalStatsPda,
+          systemProgram: SystemProgram.programId,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+        })
+        .signers([user1])
+        .rpc({ commitment: "confirmed" });
+
+      const balanceAfter = await getTokenBalance(user1RewardTokenAccount);
+      const event = await findEvent(tx, "RewardsClaimed");
+      assert.isDefined(event, "RewardsClaimed event should be emitted");
+      assert.equal(event.data.user.toBase58(), user1.publicKey.toBase58());
+      assert.equal(event.data.pool.toBase58(), eventPoolPda.toBase58());
+      assert.equal(event.data.amount.toNumber(), balanceAfter - balanceBefore);
+    });
+  });
+
+  describe("👑 Pool Authority Transfer Tests", () => {
+    const handoverPoolId = new BN(13);
+    const newAuthority = Keypair.generate();
+    let handoverPoolPda: PublicKey;
+    let handoverStakeVaultPda: PublicKey;
+    let handoverRewardVaultPda: PublicKey;
+    let handoverUser1StakePda: PublicKey;
+
+    before("Setup pool with a staker", async () => {
+      console.log("\n=== Setting up Pool Authority Transfer Tests ===");
+
+      [handoverPoolPda] = PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("pool"),
+          poolAuthority.publicKey.toBuffer(),
+          handoverPoolId.toArrayLike(Buffer, "le", 8),
+        ],
+        program.programId
+      );
+      [handoverStakeVaultPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("stake_vault"), handoverPoolPda.toBuffer()],
+        program.programId
+      );
+      [handoverRewardVaultPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("reward_vault"), handoverPoolPda.toBuffer()],
+        program.programId
+      );
+      [handoverUser1StakePda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("stake"), handoverPoolPda.toBuffer(), user1.publicKey.toBuffer()],
+        program.programId
+      );
+
+      await program.methods
+        .initializePool(handoverPoolId, REWARD_RATE, LOCK_DURATION, EARLY_UNSTAKE_PENALTY_BPS, STAKING_START_TIME, STAKING_END_TIME, REWARD_DURATION, MIN_UPDATE_INTERVAL, false)
+        .accounts({
+          authority: poolAuthority.publicKey,
+          pool: handoverPoolPda,
+          stakeMint: stakeMint,
+          rewardMint: rewardMint,
+          stakeVault: handoverStakeVaultPda,
+          rewardVault: handoverRewardVaultPda,
+          globalStats: globalStatsPda,
+          systemProgram: SystemProgram.programId,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+          rent: SYSVAR_RENT_PUBKEY,
+        })
+        .signers([poolAuthority])
+        .rpc();
+
+      // Fund the reward vault so payouts can succeed
+      await transfer(
+        connection,
+        wallet.payer,
+        authorityRewardTokenAccount,
+        handoverRewardVaultPda,
+        poolAuthority,
+        1000 * 10**6
+      );
+
+      await program.methods
+        .stake(STAKE_AMOUNT, LOCK_DURATION)
+        .accounts({
+          user: user1.publicKey,
+          pool: handoverPoolPda,
+          userStake: handoverUser1StakePda,
+          userTokenAccount: user1StakeTokenAccount,
+          stakeVault: handoverStakeVaultPda,
+          stakeMint: stakeMint,
+          globalStats: globalStatsPda,
+          systemProgram: SystemProgram.programId,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+          rent: SYSVAR_RENT_PUBKEY,
+        })
+        .signers([user1])
+        .rpc();
+    });
+
+    it("❌ Should reject a proposal from a non-authority", async () => {
+      try {
+        await program.methods
+          .transferAuthority(user1.publicKey)
+          .accounts({
+            authority: user1.publicKey,
+            pool: handoverPoolPda,
+          })
+          .signers([user1])
+          .rpc();
+        assert.fail("Should have failed with non-authority signer");
+      } catch (error) {
+        console.log(`✅ Correctly failed with error: ${error.message}`);
+        expect(error.message).to.include("UnauthorizedPoolAuthority");
+      }
+    });
+
+    it("✅ Should record the proposed authority without handing over control", async () => {
+      await program.methods
+        .transferAuthority(newAuthority.publicKey)
+        .accounts({
+          authority: poolAuthority.publicKey,
+          pool: handoverPoolPda,
+        })
+        .signers([poolAuthority])
+        .rpc();
+
+      const pool = await program.account.stakingPool.fetch(handoverPoolPda);
+      assert.equal(pool.authority.toBase58(), poolAuthority.publicKey.toBase58());
+      assert.equal(pool.pendingAuthority.toBase58(), newAuthority.publicKey.toBase58());
+    });
+
+    it("❌ Should reject acceptance by a key that was not proposed", async () => {
+      try {
+        await program.methods
+          .acceptAuthority()
+          .accounts({
+            newAuthority: user2.publicKey,
+            pool: handoverPoolPda,
+          })
+          .signers([user2])
+          .rpc();
+        assert.fail("Should have failed with a key that was not proposed");
+      } catch (error) {
+        console.log(`✅ Correctly failed with error: ${error.message}`);
+        expect(error.message).to.include("NotPendingAuthority");
+      }
+    });
+
+    it("✅ Should hand over control once the proposed authority accepts", async () => {
+      await program.methods
+        .acceptAuthority()
+        .accounts({
+          newAuthority: newAuthority.publicKey,
+          pool: handoverPoolPda,
+        })
+        .signers([newAuthority])
+        .rpc();
+
+      const pool = await program.account.stakingPool.fetch(handoverPoolPda);
+      assert.equal(pool.authority.toBase58(), newAuthority.publicKey.toBase58());
+      assert.isNull(pool.pendingAuthority, "Pending authority should be cleared");
+    });
+
+    it("✅ Should give admin rights to the new authority only", async () => {
+      try {
+        await program.methods
+          .pausePool()
+          .accounts({
+            authority: poolAuthority.publicKey,
+            pool: handoverPoolPda,
+          })
+          .signers([poolAuthority])
+          .rpc();
+        assert.fail("Previous authority should no longer manage the pool");
+      } catch (error) {
+        console.log(`✅ Correctly failed with error: ${error.message}`);
+        expect(error.message).to.include("UnauthorizedPoolAuthority");
+      }
+
+      await program.methods
+        .pausePool()
+        .accounts({
+          authority: newAuthority.publicKey,
+          pool: handoverPoolPda,
+        })
+        .signers([newAuthority])
+        .rpc();
+      await program.methods
+        .resumePool()
+        .accounts({
+          authority: newAuthority.publicKey,
+          pool: handoverPoolPda,
+        })
+        .signers([newAuthority])
+        .rpc();
+
+      const pool = await program.account.stakingPool.fetch(handoverPoolPda);
+      assert.isTrue(pool.isActive);
+    });
+
+    it("✅ Should keep paying rewards after the handover", async () => {
+      await sleep(2);
+      const balanceBefore = await getTokenBalance(user1RewardTokenAccount);
+
+      // Vault transfers still sign with the seeds of the original creator
+      await program.methods
+        .claimRewards()
+        .accounts({
+          user: user1.publicKey,
+          pool: handoverPoolPda,
+          userStake: handoverUser1StakePda,
+          userRewardTokenAccount: user1RewardTokenAccount,
+          rewardVault: handoverRewardVaultPda,
+          rewardVaultAuthority: null,
+          rewardMint: rewardMint,
+          globalStats: globalStatsPda,
+          systemProgram: SystemProgram.programId,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+        })
+        .signers([user1])
+        .rpc();
+
+      const balanceAfter = await getTokenBalance(user1RewardTokenAccount);
+      assert.isAbove(balanceAfter, balanceBefore, "Rewards should still be paid");
+    });
+  });
+
+  describe("🏧 Unused Reward Withdrawal Tests", () => {
+    const withdrawPoolId = new BN(14);
+    const WITHDRAW_REWARD_RATE = new BN(1000); // Small rate so the funding easily outlasts accrual
+    const WITHDRAW_REWARD_DURATION = new BN(5); // Reward period ends 5 seconds after creation
+    let withdrawPoolPda: PublicKey;
+    let withdrawStakeVaultPda: PublicKey;
+    let withdrawRewardVaultPda: PublicKey;
+    let withdrawUser1StakePda: PublicKey;
+
+    const withdrawUnusedRewards = (amount: BN) =>
+      program.methods
+        .withdrawUnusedRewards(amount)
+        .accounts({
+          authority: poolAuthority.publicKey,
+          pool: withdrawPoolPda,
+          rewardVault: withdrawRewardVaultPda,
+          authorityRewardTokenAccount: authorityRewardTokenAccount,
+          rewardVaultAuthority: null,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .signers([poolAuthority])
+        .rpc();
+
+    before("Setup short reward period pool with a staker", async () => {
+      console.log("\n=== Setting up Unused Reward Withdrawal Tests ===");
+
+      [withdrawPoolPda] = PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("pool"),
+          poolAuthority.publicKey.toBuffer(),
+          withdrawPoolId.toArrayLike(Buffer, "le", 8),
+        ],
+        program.programId
+      );
+      [withdrawStakeVaultPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("stake_vault"), withdrawPoolPda.toBuffer()],
+        program.programId
+      );
+      [withdrawRewardVaultPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("reward_vault"), withdrawPoolPda.toBuffer()],
+        program.programId
+      );
+      [withdrawUser1StakePda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("stake"), withdrawPoolPda.toBuffer(), user1.publicKey.toBuffer()],
+        program.programId
+      );
+
+      await program.methods
+        .initializePool(
+          withdrawPoolId,
+          WITHDRAW_REWARD_RATE,
+          LOCK_DURATION,
+          EARLY_UNSTAKE_PENALTY_BPS,
+          STAKING_START_TIME,
+          STAKING_END_TIME,
+          WITHDRAW_REWARD_DURATION,
+          MIN_UPDATE_INTERVAL,
+          false
+        )
+        .accounts({
+          authority: poolAuthority.publicKey,
+          pool: withdrawPoolPda,
+          stakeMint: stakeMint,
+          rewardMint: rewardMint,
+          stakeVault: withdrawStakeVaultPda,
+          rewardVault: withdrawRewardVaultPda,
+          globalStats: globalStatsPda,
+          systemProgram: SystemProgram.programId,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+          rent: SYSVAR_RENT_PUBKEY,
+        })
+        .signers([poolAuthority])
+        .rpc();
+
+      await transfer(
+        connection,
+        wallet.payer,
+        authorityRewardTokenAccount,
+        withdrawRewardVaultPda,
+        poolAuthority,
+        100 * 10**6
+      );
+
+      await program.methods
+        .stake(STAKE_AMOUNT, LOCK_DURATION)
+        .accounts({
+          user: user1.publicKey,
+          pool: withdrawPoolPda,
+          userStake: withdrawUser1StakePda,
+          userTokenAccount: user1StakeTokenAccount,
+          stakeVault: withdrawStakeVaultPda,
+          stakeMint: stakeMint,
+          globalStats: globalStatsPda,
+          systemProgram: SystemProgram.programId,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+          rent: SYSVAR_RENT_PUBKEY,
+        })
+        .signers([user1])
+        .rpc();
+    });
+
+    it("❌ Should reject withdrawals before the reward period ends", async () => {
+      try {
+        await withdrawUnusedRewards(new BN(1));
+        assert.fail("Should have failed while the reward period is running");
+      } catch (error) {
+        console.log(`✅ Correctly failed with error: ${error.message}`);
+        expect(error.message).to.include("RewardPeriodNotEnded");
+      }
+    });
+
+    it("❌ Should reject withdrawals by a non-authority", async () => {
+      try {
+        await program.methods
+          .withdrawUnusedRewards(new BN(1))
+          .accounts({
+            authority: user1.publicKey,
+            pool: withdrawPoolPda,
+            rewardVault: withdrawRewardVaultPda,
+            authorityRewardTokenAccount: user1RewardTokenAccount,
+            rewardVaultAuthority: null,
+            tokenProgram: TOKEN_PROGRAM_ID,
+          })
+          .signers([user1])
+          .rpc();
+        assert.fail("Should have failed with non-authority signer");
+      } catch (error) {
+        console.log(`✅ Correctly failed with error: ${error.message}`);
+        expect(error.message).to.include("UnauthorizedPoolAuthority");
+      }
+    });
+
+    it("✅ Should withdraw everything except rewards still owed after the end time", async () => {
+      const poolBefore = await program.account.stakingPool.fetch(withdrawPoolPda);
+      const waitSeconds = poolBefore.rewardEndTime.toNumber() - Math.floor(Date.now() / 1000) + 2;
+      await sleep(Math.max(waitSeconds, 1));
+
+      // Checkpoint so the owed total covers the whole reward period
+      await program.methods
+        .updatePool()
+        .accounts({
+          pool: withdrawPoolPda,
+          caller: poolAuthority.publicKey,
+        })
+        .signers([poolAuthority])
+        .rpc();
+
+      const pool = await program.account.stakingPool.fetch(withdrawPoolPda);
+      const owed = pool.totalRewardsOwed.toNumber();
+      const vaultBalance = await getTokenBalance(withdrawRewardVaultPda);
+      const withdrawable = vaultBalance - owed;
+      console.log(`📊 Vault: ${vaultBalance}, owed to stakers: ${owed}, withdrawable: ${withdrawable}`);
+      assert.isAbove(owed, 0, "The staker should be owed rewards");
+
+      // One token more than the surplus would dip into owed rewards
+      try {
+        await withdrawUnusedRewards(new BN(withdrawable + 1));
+        assert.fail("Should not be able to withdraw owed rewards");
+      } catch (error) {
+        console.log(`✅ Correctly failed with error: ${error.message}`);
+        expect(error.message).to.include("WithdrawExceedsUnusedRewards");
+      }
+
+      const authorityBefore = await getTokenBalance(authorityRewardTokenAccount);
+      await withdrawUnusedRewards(new BN(withdrawable));
+      const authorityAfter = await getTokenBalance(authorityRewardTokenAccount);
+
+      assert.equal(authorityAfter - authorityBefore, withdrawable);
+      assert.equal(await getTokenBalance(withdrawRewardVaultPda), owed, "Owed rewards stay in the vault");
+    });
+  });
+
+  describe("⏱️ Update Interval Tests", () => {
+    const intervalPoolId = new BN(15);
+    const INTERVAL = 3; // Seconds between allowed update_pool calls
+    let intervalPoolPda: PublicKey;
+
+    const updateIntervalPool = () =>
+      program.methods
+        .updatePool()
+        .accounts({
+          pool: intervalPoolPda,
+          caller: poolAuthority.publicKey,
+        })
+        .signers([poolAuthority])
+        .rpc();
+
+    before("Setup pool with a minimum update interval", async () => {
+      console.log("\n=== Setting up Update Interval Tests ===");
+
+      [intervalPoolPda] = PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("pool"),
+          poolAuthority.publicKey.toBuffer(),
+          intervalPoolId.toArrayLike(Buffer, "le", 8),
+        ],
+        program.programId
+      );
+      const [intervalStakeVaultPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("stake_vault"), intervalPoolPda.toBuffer()],
+        program.programId
+      );
+      const [intervalRewardVaultPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("reward_vault"), intervalPoolPda.toBuffer()],
+        program.programId
+      );
+
+      await program.methods
+        .initializePool(
+          intervalPoolId,
+          REWARD_RATE,
+          LOCK_DURATION,
+          EARLY_UNSTAKE_PENALTY_BPS,
+          STAKING_START_TIME,
+          STAKING_END_TIME,
+          REWARD_DURATION,
+          new BN(INTERVAL),
+          false
+        )
+        .accounts({
+          authority: poolAuthority.publicKey,
+          pool: intervalPoolPda,
+          stakeMint: stakeMint,
+          rewardMint: rewardMint,
+          stakeVault: intervalStakeVaultPda,
+          rewardVault: intervalRewardVaultPda,
+          globalStats: globalStatsPda,
+          systemProgram: SystemProgram.programId,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+          rent: SYSVAR_RENT_PUBKEY,
+        })
+        .signers([poolAuthority])
+        .rpc();
+
+      const pool = await program.account.stakingPool.fetch(intervalPoolPda);
+      assert.equal(pool.minUpdateInterval.toNumber(), INTERVAL);
+    });
+
+    it("✅ Should allow the first update of a never-updated pool", async () => {
+      // Move past the creation second so the update counts as a real checkpoint
+      await sleep(2);
+      await updateIntervalPool();
+    });
+
+    it("❌ Should reject an update inside the interval", async () => {
+      try {
+        await updateIntervalPool();
+        assert.fail("Should have failed with an update inside the interval");
+      } catch (error) {
+        console.log(`✅ Correctly failed with error: ${error.message}`);
+        expect(error.message).to.include("UpdateTooSoon");
+      }
+    });
+
+    it("✅ Should allow an update once the interval has passed", async () => {
+      const before = await program.account.stakingPool.fetch(intervalPoolPda);
+      await sleep(INTERVAL + 1);
+
+      await updateIntervalPool();
+
+      const after = await program.account.stakingPool.fetch(intervalPoolPda);
+      assert.isAtLeast(
+        after.lastUpdateTime.sub(before.lastUpdateTime).toNumber(),
+        INTERVAL,
+        "Last update time should advance by at least the interval"
+      );
+    });
+  });
+
+  describe("🧹 Close Stake Tests", () => {
+    it("❌ Should refuse to close a stake that still holds principal", async () => {
+      try {
+        await program.methods
+          .closeStake()
+          .accounts({
+            user: user1.publicKey,
+            pool: poolPda,
+            userStake: user1StakePda,
+          })
+          .signers([user1])
+          .rpc();
+        assert.fail("Should have failed closing a non-empty stake");
+      } catch (error) {
+        console.log(`✅ Correctly failed with error: ${error.message}`);
+        expect(error.message).to.include("StakeNotEmpty");
+      }
+
+      // The stake account is untouched
+      const userStake = await program.account.userStake.fetch(user1StakePda);
+      assert.isTrue(userStake.amount.gt(new BN(0)));
+    });
+  });
+
+  describe("⚔️ Slashing Tests", () => {
+    const slashPoolId = new BN(16);
+    const SLASH_BPS = 2500; // 25% of principal
+    let slashPoolPda: PublicKey;
+    let slashStakeVaultPda: PublicKey;
+    let slashRewardVaultPda: PublicKey;
+    let slashUserStakePda: PublicKey;
+
+    const slashAccounts = (authority: PublicKey) => ({
+      authority,
+      pool: slashPoolPda,
+      userStake: slashUserStakePda,
+      stakeVault: slashStakeVaultPda,
+      treasuryTokenAccount: authorityStakeTokenAccount,
+      globalStats: globalStatsPda,
+      tokenProgram: TOKEN_PROGRAM_ID,
+    });
+
+    before("Setup pool and stake", async () => {
+      console.log("\n=== Setting up Slashing Tests ===");
+
+      [slashPoolPda] = PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("pool"),
+          poolAuthority.publicKey.toBuffer(),
+          slashPoolId.toArrayLike(Buffer, "le", 8),
+        ],
+        program.programId
+      );
+      [slashStakeVaultPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("stake_vault"), slashPoolPda.toBuffer()],
+        program.programId
+      );
+      [slashRewardVaultPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("reward_vault"), slashPoolPda.toBuffer()],
+        program.programId
+      );
+      [slashUserStakePda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("stake"), slashPoolPda.toBuffer(), user2.publicKey.toBuffer()],
+        program.programId
+      );
+
+      await program.methods
+        .initializePool(slashPoolId, REWARD_RATE, LOCK_DURATION, EARLY_UNSTAKE_PENALTY_BPS, STAKING_START_TIME, STAKING_END_TIME, REWARD_DURATION, MIN_UPDATE_INTERVAL, false)
+        .accounts({
+          authority: poolAuthority.publicKey,
+          pool: slashPoolPda,
+          stakeMint: stakeMint,
+          rewardMint: rewardMint,
+          stakeVault: slashStakeVaultPda,
+          rewardVault: slashRewardVaultPda,
+          globalStats: globalStatsPda,
+          systemProgram: SystemProgram.programId,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+          rent: SYSVAR_RENT_PUBKEY,
+        })
+        .signers([poolAuthority])
+        .rpc();
+
+      await program.methods
+        .stake(STAKE_AMOUNT, LOCK_DURATION)
+        .accounts({
+          user: user2.publicKey,
+          pool: slashPoolPda,
+          userStake: slashUserStakePda,
+          userTokenAccount: user2StakeTokenAccount,
+          stakeVault: slashStakeVaultPda,
+          stakeMint: stakeMint,
+          globalStats: globalStatsPda,
+          systemProgram: SystemProgram.programId,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+          rent: SYSVAR_RENT_PUBKEY,
+        })
+        .signers([user2])
+        .rpc();
+    });
+
+    it("❌ Should reject a slash from a non-authority", async () => {
+      try {
+        await program.methods
+          .slash(SLASH_BPS)
+          .accounts(slashAccounts(user1.publicKey))
+          .signers([user1])
+          .rpc();
+        assert.fail("Should have failed with a non-authority signer");
+      } catch (error) {
+        console.log(`✅ Correctly failed with error: ${error.message}`);
+        expect(error.message).to.include("UnauthorizedPoolAuthority");
+      }
+    });
+
+    it("❌ Should reject a slash above 100%", async () => {
+      try {
+        await program.methods
+          .slash(10001)
+          .accounts(slashAccounts(poolAuthority.publicKey))
+          .signers([poolAuthority])
+          .rpc();
+        assert.fail("Should have failed with an invalid slash");
+      } catch (error) {
+        console.log(`✅ Correctly failed with error: ${error.message}`);
+        expect(error.message).to.include("InvalidSlashBps");
+      }
+    });
+
+    it("✅ Should move the slashed principal to the treasury", async () => {
+      const treasuryBefore = await getTokenBalance(authorityStakeTokenAccount);
+
+      await program.methods
+        .slash(SLASH_BPS)
+        .accounts(slashAccounts(poolAuthority.publicKey))
+        .signers([poolAuthority])
+        .rpc();
+
+      const expectedSlash = STAKE_AMOUNT.muln(SLASH_BPS).divn(10000);
+      const remaining = STAKE_AMOUNT.sub(expectedSlash);
+
+      const treasuryAfter = await getTokenBalance(authorityStakeTokenAccount);
+      assert.equal(treasuryAfter - treasuryBefore, expectedSlash.toNumber());
+
+      const userStake = await program.account.userStake.fetch(slashUserStakePda);
+      assert.equal(userStake.amount.toString(), remaining.toString());
+      assert.equal(userStake.slashedAmount.toString(), expectedSlash.toString());
+
+      const pool = await program.account.stakingPool.fetch(slashPoolPda);
+      assert.equal(pool.totalStaked.toString(), userStake.weightedAmount.toString());
+      assert.equal(await getTokenBalance(slashStakeVaultPda), remaining.toNumber());
+    });
+  });
+
+  describe("📦 Batch Pool Update Tests", () => {
+    const poolPdaFor = (poolId: BN) =>
+      PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("pool"),
+          poolAuthority.publicKey.toBuffer(),
+          poolId.toArrayLike(Buffer, "le", 8),
+        ],
+        program.programId
+      )[0];
+
+    it("✅ Should update staked pools and skip ones that don't need it", async () => {
+      // Slash pool has stake; the interval pool is empty so it gets skipped
+      const stakedPool = poolPdaFor(new BN(16));
+      const emptyPool = poolPdaFor(new BN(15));
+
+      const stakedBefore = await program.account.stakingPool.fetch(stakedPool);
+      const emptyBefore = await program.account.stakingPool.fetch(emptyPool);
+      await sleep(2);
+
+      await program.methods
+        .updatePools()
+        .accounts({
+          caller: poolAuthority.publicKey,
+        })
+        .remainingAccounts([
+          { pubkey: stakedPool, isWritable: true, isSigner: false },
+          { pubkey: emptyPool, isWritable: true, isSigner: false },
+        ])
+        .signers([poolAuthority])
+        .rpc();
+
+      const stakedAfter = await program.account.stakingPool.fetch(stakedPool);
+      const emptyAfter = await program.account.stakingPool.fetch(emptyPool);
+
+      assert.isAbove(stakedAfter.lastUpdateTime.toNumber(), stakedBefore.lastUpdateTime.toNumber());
+      assert.isTrue(stakedAfter.rewardPerTokenStored.gt(stakedBefore.rewardPerTokenStored));
+      assert.equal(emptyAfter.lastUpdateTime.toString(), emptyBefore.lastUpdateTime.toString());
+    });
+
+    it("❌ Should reject a pool passed as read-only", async () => {
+      try {
+        await program.methods
+          .updatePools()
+          .accounts({
+            caller: poolAuthority.publicKey,
+          })
+          .remainingAccounts([
+            { pubkey: poolPdaFor(new BN(16)), isWritable: false, isSigner: false },
+          ])
+          .signers([poolAuthority])
+          .rpc();
+        assert.fail("Should have failed with a read-only pool");
+      } catch (error) {
+        console.log(`✅ Correctly failed with error: ${error.message}`);
+        expect(error.message).to.include("InvalidAccount");
+      }
+    });
+  });
+
+  describe("🚀 Early Staker Boost Tests", () => {
+    const boostPoolId = new BN(17);
+    const BOOST_MULTIPLIER_BPS = 15000; // 1.5x for early stakers
+    const BOOST_WINDOW = 4; // Seconds the boost window stays open
+    let boostPoolPda: PublicKey;
+    let boostStakeVaultPda: PublicKey;
+    let boostRewardVaultPda: PublicKey;
+
+    const stakePdaFor = (user: PublicKey) =>
+      PublicKey.findProgramAddressSync(
+        [Buffer.from("stake"), boostPoolPda.toBuffer(), user.toBuffer()],
+        program.programId
+      )[0];
+
+    const stakeIntoBoostPool = (user: Keypair, userTokenAccount: PublicKey) =>
+      program.methods
+        .stake(STAKE_AMOUNT, new BN(0))
+        .accounts({
+          user: user.publicKey,
+          pool: boostPoolPda,
+          userStake: stakePdaFor(user.publicKey),
+          userTokenAccount,
+          stakeVault: boostStakeVaultPda,
+          stakeMint: stakeMint,
+          globalStats: globalStatsPda,
+          systemProgram: SystemProgram.programId,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+          rent: SYSVAR_RENT_PUBKEY,
+        })
+        .signers([user])
+        .rpc();
+
+    before("Setup pool for boost tests", async () => {
+      console.log("\n=== Setting up Early Staker Boost Tests ===");
+
+      [boostPoolPda] = PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("pool"),
+          poolAuthority.publicKey.toBuffer(),
+          boostPoolId.toArrayLike(Buffer, "le", 8),
+        ],
+        program.programId
+      );
+      [boostStakeVaultPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("stake_vault"), boostPoolPda.toBuffer()],
+        program.programId
+      );
+      [boostRewardVaultPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("reward_vault"), boostPoolPda.toBuffer()],
+        program.programId
+      );
+
+      await program.methods
+        .initializePool(boostPoolId, REWARD_RATE, LOCK_DURATION, EARLY_UNSTAKE_PENALTY_BPS, STAKING_START_TIME, STAKING_END_TIME, REWARD_DURATION, MIN_UPDATE_INTERVAL, false)
+        .accounts({
+          authority: poolAuthority.publicKey,
+          pool: boostPoolPda,
+          stakeMint: stakeMint,
+          rewardMint: rewardMint,
+          stakeVault: boostStakeVaultPda,
+          rewardVault: boostRewardVaultPda,
+          globalStats: globalStatsPda,
+          systemProgram: SystemProgram.programId,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+          rent: SYSVAR_RENT_PUBKEY,
+        })
+        .signers([poolAuthority])
+        .rpc();
+    });
+
+    it("❌ Should reject a boost from a non-authority", async () => {
+      try {
+        await program.methods
+          .setEarlyBoost(new BN(getCurrentTimestamp() + BOOST_WINDOW), BOOST_MULTIPLIER_BPS)
+          .accounts({
+            authority: user1.publicKey,
+            pool: boostPoolPda,
+          })
+          .signers([user1])
+          .rpc();
+        assert.fail("Should have failed with a non-authority signer");
+      } catch (error) {
+        console.log(`✅ Correctly failed with error: ${error.message}`);
+        expect(error.message).to.include("UnauthorizedPoolAuthority");
+      }
+    });
+
+    it("❌ Should reject a boost below 1x", async () => {
+      try {
+        await program.methods
+          .setEarlyBoost(new BN(getCurrentTimestamp() + BOOST_WINDOW), 9999)
+          .accounts({
+            authority: poolAuthority.publicKey,
+            pool: boostPoolPda,
+          })
+          .signers([poolAuthority])
+          .rpc();
+        assert.fail("Should have failed with an invalid boost");
+      } catch (error) {
+        console.log(`✅ Correctly failed with error: ${error.message}`);
+        expect(error.message).to.include("InvalidBoostMultiplier");
+      }
+    });
+
+    it("✅ Should weight an early stake above a late one for identical principal", async () => {
+      await program.methods
+        .setEarlyBoost(new BN(getCurrentTimestamp() + BOOST_WINDOW), BOOST_MULTIPLIER_BPS)
+        .accounts({
+          authority: poolAuthority.publicKey,
+          pool: boostPoolPda,
+        })
+        .signers([poolAuthority])
+        .rpc();
+
+      // user1 stakes inside the window, user2 after it closes
+      await stakeIntoBoostPool(user1, user1StakeTokenAccount);
+      await sleep(BOOST_WINDOW + 2);
+      await stakeIntoBoostPool(user2, user2StakeTokenAccount);
+
+      const early = await program.account.userStake.fetch(stakePdaFor(user1.publicKey));
+      const late = await program.account.userStake.fetch(stakePdaFor(user2.publicKey));
+
+      assert.equal(early.boostMultiplierBps, BOOST_MULTIPLIER_BPS);
+      assert.equal(late.boostMultiplierBps, 10000);
+      assert.equal(early.amount.toString(), late.amount.toString());
+
+      // Rewards are paid pro rata to weight, so the early stake earns 1.5x
+      assert.equal(
+        early.weightedAmount.toString(),
+        STAKE_AMOUNT.muln(BOOST_MULTIPLIER_BPS).divn(10000).toString()
+      );
+      assert.equal(late.weightedAmount.toString(), STAKE_AMOUNT.toString());
+
+      const pool = await program.account.stakingPool.fetch(boostPoolPda);
+      assert.equal(
+        pool.totalStaked.toString(),
+        early.weightedAmount.add(late.weightedAmount).toString()
+      );
+    });
+  });
+
+  describe("🧮 Minimum Claim Tests", () => {
+    // Reuses the early boost pool, where user2 has a stake
+    const minClaimPoolId = new BN(17);
+    let minClaimPoolPda: PublicKey;
+    let minClaimRewardVaultPda: PublicKey;
+    let minClaimUserStakePda: PublicKey;
+
+    before("Derive boost pool accounts", async () => {
+      [minClaimPoolPda] = PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("pool"),
+          poolAuthority.publicKey.toBuffer(),
+          minClaimPoolId.toArrayLike(Buffer, "le", 8),
+        ],
+        program.programId
+      );
+      [minClaimRewardVaultPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("reward_vault"), minClaimPoolPda.toBuffer()],
+        program.programId
+      );
+      [minClaimUserStakePda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("stake"), minClaimPoolPda.toBuffer(), user2.publicKey.toBuffer()],
+        program.programId
+      );
+    });
+
+    it("❌ Should reject a minimum claim change from a non-authority", async () => {
+      try {
+        await program.methods
+          .setMinClaimable(new BN(1))
+          .accounts({
+            authority: user1.publicKey,
+            pool: minClaimPoolPda,
+          })
+          .signers([user1])
+          .rpc();
+        assert.fail("Should have failed with a non-authority signer");
+      } catch (error) {
+        console.log(`✅ Correctly failed with error: ${error.message}`);
+        expect(error.message).to.include("UnauthorizedPoolAuthority");
+      }
+    });
+
+    it("✅ Should leave rewards below the minimum accrued", async () => {
+      // Far more than a few seconds of rewards can add up to
+      const minClaimable = new BN(1000 * 10**6);
+
+      await program.methods
+        .setMinClaimable(minClaimable)
+        .accounts({
+          authority: poolAuthority.publicKey,
+          pool: minClaimPoolPda,
+        })
+        .signers([poolAuthority])
+        .rpc();
+
+      const pool = await program.account.stakingPool.fetch(minClaimPoolPda);
+      assert.equal(pool.minClaimable.toString(), minClaimable.toString());
+
+      const stakeBefore = await program.account.userStake.fetch(minClaimUserStakePda);
+      const balanceBefore = await getTokenBalance(user2RewardTokenAccount);
+      await sleep(2);
+
+      await program.methods
+        .claimRewards()
+        .accounts({
+          user: user2.publicKey,
+          pool: minClaimPoolPda,
+          userStake: minClaimUserStakePda,
+          userRewardTokenAccount: user2RewardTokenAccount,
+          rewardVault: minClaimRewardVaultPda,
+          rewardMint: rewardMint,
+          globalStats: globalStatsPda,
+          systemProgram: SystemProgram.programId,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+        })
+        .signers([user2])
+        .rpc();
+
+      // Nothing paid, and the reward baseline didn't move, so the rewards still count
+      const stakeAfter = await program.account.userStake.fetch(minClaimUserStakePda);
+      assert.equal(await getTokenBalance(user2RewardTokenAccount), balanceBefore);
+      assert.equal(
+        stakeAfter.rewardPerTokenPaid.toString(),
+        stakeBefore.rewardPerTokenPaid.toString()
+      );
+      assert.equal(stakeAfter.rewards.toString(), stakeBefore.rewards.toString());
+    });
+  });
+
+  describe("🔀 Stake Transfer Tests", () => {
+    const transferPoolId = new BN(18);
+    let transferPoolPda: PublicKey;
+    let transferStakeVaultPda: PublicKey;
+    let transferRewardVaultPda: PublicKey;
+    const newOwner = Keypair.generate();
+
+    const stakePdaFor = (user: PublicKey) =>
+      PublicKey.findProgramAddressSync(
+        [Buffer.from("stake"), transferPoolPda.toBuffer(), user.toBuffer()],
+        program.programId
+      )[0];
+
+    const transferStake = (owner: Keypair, to: PublicKey) =>
+      program.methods
+        .transferStake(to)
+        .accounts({
+          owner: owner.publicKey,
+          pool: transferPoolPda,
+          userStake: stakePdaFor(owner.publicKey),
+          newUserStake: stakePdaFor(to),
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([owner])
+        .rpc();
+
+    before("Setup pool and stake", async () => {
+      console.log("\n=== Setting up Stake Transfer Tests ===");
+
+      [transferPoolPda] = PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("pool"),
+          poolAuthority.publicKey.toBuffer(),
+          transferPoolId.toArrayLike(Buffer, "le", 8),
+        ],
+        program.programId
+      );
+      [transferStakeVaultPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("stake_vault"), transferPoolPda.toBuffer()],
+        program.programId
+      );
+      [transferRewardVaultPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("reward_vault"), transferPoolPda.toBuffer()],
+        program.programId
+      );
+
+      await program.methods
+        .initializePool(transferPoolId, REWARD_RATE, LOCK_DURATION, EARLY_UNSTAKE_PENALTY_BPS, STAKING_START_TIME, STAKING_END_TIME, REWARD_DURATION, MIN_UPDATE_INTERVAL, false)
+        .accounts({
+          authority: poolAuthority.publicKey,
+          pool: transferPoolPda,
+          stakeMint: stakeMint,
+          rewardMint: rewardMint,
+          stakeVault: transferStakeVaultPda,
+          rewardVault: transferRewardVaultPda,
+          globalStats: globalStatsPda,
+          systemProgram: SystemProgram.programId,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+          rent: SYSVAR_RENT_PUBKEY,
+        })
+        .signers([poolAuthority])
+        .rpc();
+
+      await program.methods
+        .stake(STAKE_AMOUNT, new BN(0))
+        .accounts({
+          user: user1.publicKey,
+          pool: transferPoolPda,
+          userStake: stakePdaFor(user1.publicKey),
+          userTokenAccount: user1StakeTokenAccount,
+          stakeVault: transferStakeVaultPda,
+          stakeMint: stakeMint,
+          globalStats: globalStatsPda,
+          systemProgram: SystemProgram.programId,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+          rent: SYSVAR_RENT_PUBKEY,
+        })
+        .signers([user1])
+        .rpc();
+    });
+
+    it("❌ Should reject a transfer to the current owner", async () => {
+      try {
+        await transferStake(user1, user1.publicKey);
+        assert.fail("Should have failed with a self transfer");
+      } catch (error) {
+        console.log(`✅ Correctly failed with error: ${error.message}`);
+        expect(error.message).to.include("StakeTransferToSelf");
+      }
+    });
+
+    it("✅ Should move the position with its lock and rewards intact", async () => {
+      const before = await program.account.userStake.fetch(stakePdaFor(user1.publicKey));
+
+      await transferStake(user1, newOwner.publicKey);
+
+      const after = await program.account.userStake.fetch(stakePdaFor(newOwner.publicKey));
+      assert.isTrue(after.user.equals(newOwner.publicKey));
+      assert.isTrue(after.pool.equals(transferPoolPda));
+      assert.equal(after.amount.toString(), before.amount.toString());
+      assert.equal(after.weightedAmount.toString(), before.weightedAmount.toString());
+      assert.equal(after.stakeTime.toString(), before.stakeTime.toString());
+      assert.equal(after.unlockTime.toString(), before.unlockTime.toString());
+      assert.equal(after.rewards.toString(), before.rewards.toString());
+      assert.equal(after.rewardPerTokenPaid.toString(), before.rewardPerTokenPaid.toString());
+
+      // The old stake account is gone
+      assert.isNull(await connection.getAccountInfo(stakePdaFor(user1.publicKey)));
+    });
+
+    it("❌ Should reject a transfer from a wallet without a stake", async () => {
+      try {
+        await transferStake(user1, user2.publicKey);
+        assert.fail("Should have failed without a stake to transfer");
+      } catch (error) {
+        console.log(`✅ Correctly failed with error: ${error.message}`);
+        expect(error.message).to.include("AccountNotInitialized");
+      }
+    });
+  });
+
+  describe("🔒 Lock Extension Tests", () => {
+    const extendPoolId = new BN(19);
+    let extendPoolPda: PublicKey;
+    let extendStakeVaultPda: PublicKey;
+    let extendRewardVaultPda: PublicKey;
+    let extendStakePda: PublicKey;
+    const DAY = 24 * 60 * 60;
+
+    const extendLock = (seconds: number) =>
+      program.methods
+        .extendLock(new BN(seconds))
+        .accounts({
+          user: user1.publicKey,
+          pool: extendPoolPda,
+          userStake: extendStakePda,
+        })
+        .signers([user1])
+        .rpc();
+
+    before("Setup pool and stake", async () => {
+      console.log("\n=== Setting up Lock Extension Tests ===");
+
+      [extendPoolPda] = PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("pool"),
+          poolAuthority.publicKey.toBuffer(),
+          extendPoolId.toArrayLike(Buffer, "le", 8),
+        ],
+        program.programId
+      );
+      [extendStakeVaultPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("stake_vault"), extendPoolPda.toBuffer()],
+        program.programId
+      );
+      [extendRewardVaultPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("reward_vault"), extendPoolPda.toBuffer()],
+        program.programId
+      );
+      [extendStakePda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("stake"), extendPoolPda.toBuffer(), user1.publicKey.toBuffer()],
+        program.programId
+      );
+
+      await program.methods
+        .initializePool(extendPoolId, REWARD_RATE, LOCK_DURATION, EARLY_UNSTAKE_PENALTY_BPS, STAKING_START_TIME, STAKING_END_TIME, REWARD_DURATION, MIN_UPDATE_INTERVAL, false)
+        .accounts({
+          authority: poolAuthority.publicKey,
+          pool: extendPoolPda,
+          stakeMint: stakeMint,
+          rewardMint: rewardMint,
+          stakeVault: extendStakeVaultPda,
+          rewardVault: extendRewardVaultPda,
+          globalStats: globalStatsPda,
+          systemProgram: SystemProgram.programId,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+          rent: SYSVAR_RENT_PUBKEY,
+        })
+        .signers([poolAuthority])
+        .rpc();
+
+      await program.methods
+        .stake(STAKE_AMOUNT, new BN(0))
+        .accounts({
+          user: user1.publicKey,
+          pool: extendPoolPda,
+          userStake: extendStakePda,
+          userTokenAccount: user1StakeTokenAccount,
+          stakeVault: extendStakeVaultPda,
+          stakeMint: stakeMint,
+          globalStats: globalStatsPda,
+          systemProgram: SystemProgram.programId,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+          rent: SYSVAR_RENT_PUBKEY,
+        })
+        .signers([user1])
+        .rpc();
+    });
+
+    it("✅ Should extend the lock into a higher tier and reweight the stake", async () => {
+      const before = await program.account.userStake.fetch(extendStakePda);
+      const poolBefore = await program.account.stakingPool.fetch(extendPoolPda);
+
+      // 7 + 23 days reaches the 30-day tier (1.25x)
+      await extendLock(23 * DAY);
+
+      const after = await program.account.userStake.fetch(extendStakePda);
+      const poolAfter = await program.account.stakingPool.fetch(extendPoolPda);
+      assert.equal(after.unlockTime.toNumber(), before.unlockTime.toNumber() + 23 * DAY);
+      assert.equal(after.rewardMultiplierBps, 12_500);
+      assert.equal(after.weightedAmount.toString(), STAKE_AMOUNT.muln(5).divn(4).toString());
+      assert.equal(
+        poolAfter.totalStaked.toString(),
+        poolBefore.totalStaked.add(STAKE_AMOUNT.divn(4)).toString()
+      );
+    });
+
+    it("❌ Should reject an extension past the maximum lock duration", async () => {
+      try {
+        await extendLock(365 * DAY);
+        assert.fail("Should have failed past the maximum lock");
+      } catch (error) {
+        console.log(`✅ Correctly failed with error: ${error.message}`);
+        expect(error.message).to.include("LockExtensionExceedsMax");
+      }
+    });
+
+    it("❌ Should reject shortening the lock", async () => {
+      try {
+        await extendLock(-DAY);
+        assert.fail("Should have failed with a negative extension");
+      } catch (error) {
+        console.log(`✅ Correctly failed with error: ${error.message}`);
+        expect(error.message).to.include("InvalidLockExtension");
+      }
+    });
+  });
+
+  describe("🎟️ Whitelist Tests", () => {
+    const whitelistPoolId = new BN(20);
+    let whitelistPoolPda: PublicKey;
+    let whitelistStakeVaultPda: PublicKey;
+    let whitelistRewardVaultPda: PublicKey;
+
+    const stakeInto = (user: Keypair, userTokenAccount: PublicKey) => {
+      const [userStakePda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("stake"), whitelistPoolPda.toBuffer(), user.publicKey.toBuffer()],
+        program.programId
+      );
+      const [whitelistEntryPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("whitelist"), whitelistPoolPda.toBuffer(), user.publicKey.toBuffer()],
+        program.programId
+      );
+
+      return program.methods
+        .stake(STAKE_AMOUNT, new BN(0))
+        .accounts({
+          user: user.publicKey,
+          pool: whitelistPoolPda,
+          userStake: userStakePda,
+          whitelistEntry: whitelistEntryPda,
+          userTokenAccount,
+          stakeVault: whitelistStakeVaultPda,
+          stakeMint: stakeMint,
+          globalStats: globalStatsPda,
+          systemProgram: SystemProgram.programId,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+          rent: SYSVAR_RENT_PUBKEY,
+        })
+        .signers([user])
+        .rpc();
+    };
+
+    before("Setup gated pool and whitelist user1", async () => {
+      console.log("\n=== Setting up Whitelist Tests ===");
+
+      [whitelistPoolPda] = PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("pool"),
+          poolAuthority.publicKey.toBuffer(),
+          whitelistPoolId.toArrayLike(Buffer, "le", 8),
+        ],
+        program.programId
+      );
+      [whitelistStakeVaultPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("stake_vault"), whitelistPoolPda.toBuffer()],
+        program.programId
+      );
+      [whitelistRewardVaultPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("reward_vault"), whitelistPoolPda.toBuffer()],
+        program.programId
+      );
+
+      await program.methods
+        .initializePool(whitelistPoolId, REWARD_RATE, LOCK_DURATION, EARLY_UNSTAKE_PENALTY_BPS, STAKING_START_TIME, STAKING_END_TIME, REWARD_DURATION, MIN_UPDATE_INTERVAL, false)
+        .accounts({
+          authority: poolAuthority.publicKey,
+          pool: whitelistPoolPda,
+          stakeMint: stakeMint,
+          rewardMint: rewardMint,
+          stakeVault: whitelistStakeVaultPda,
+          rewardVault: whitelistRewardVaultPda,
+          globalStats: globalStatsPda,
+          systemProgram: SystemProgram.programId,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+          rent: SYSVAR_RENT_PUBKEY,
+        })
+        .signers([poolAuthority])
+        .rpc();
+
+      await program.methods
+        .setWhitelistRequired(true)
+        .accounts({
+          authority: poolAuthority.publicKey,
+          pool: whitelistPoolPda,
+        })
+        .signers([poolAuthority])
+        .rpc();
+
+      await program.methods
+        .addToWhitelist(user1.publicKey)
+        .accounts({
+          authority: poolAuthority.publicKey,
+          pool: whitelistPoolPda,
+        })
+        .signers([poolAuthority])
+        .rpc();
+    });
+
+    it("✅ Should let a whitelisted wallet stake", async () => {
+      await stakeInto(user1, user1StakeTokenAccount);
+
+      const pool = await program.account.stakingPool.fetch(whitelistPoolPda);
+      assert.isTrue(pool.whitelistRequired);
+      assert.equal(pool.totalStaked.toString(), STAKE_AMOUNT.toString());
+    });
+
+    it("❌ Should reject a wallet that isn't whitelisted", async () => {
+      try {
+        await stakeInto(user2, user2StakeTokenAccount);
+        assert.fail("Should have failed without a whitelist entry");
+      } catch (error) {
+        console.log(`✅ Correctly failed with error: ${error.message}`);
+        expect(error.message).to.include("NotWhitelisted");
+      }
+    });
+
+    it("❌ Should reject a stake transfer to a wallet that isn't whitelisted", async () => {
+      const stakePdaFor = (user: PublicKey) =>
+        PublicKey.findProgramAddressSync(
+          [Buffer.from("stake"), whitelistPoolPda.toBuffer(), user.toBuffer()],
+          program.programId
+        )[0];
+      const [newOwnerWhitelistEntry] = PublicKey.findProgramAddressSync(
+        [Buffer.from("whitelist"), whitelistPoolPda.toBuffer(), user2.publicKey.toBuffer()],
+        program.programId
+      );
+
+      try {
+        await program.methods
+          .transferStake(user2.publicKey)
+          .accounts({
+            owner: user1.publicKey,
+            pool: whitelistPoolPda,
+            userStake: stakePdaFor(user1.publicKey),
+            newUserStake: stakePdaFor(user2.publicKey),
+            newOwnerWhitelistEntry,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([user1])
+          .rpc();
+        assert.fail("Should have failed without a whitelist entry for the new owner");
+      } catch (error) {
+        console.log(`✅ Correctly failed with error: ${error.message}`);
+        expect(error.message).to.include("NotWhitelisted");
+      }
+
+      // The position stays with its original owner
+      const stake = await program.account.userStake.fetch(stakePdaFor(user1.publicKey));
+      assert.isTrue(stake.user.equals(user1.publicKey));
+    });
+  });
+
+  describe("⏳ Unbonding Cooldown Tests", () => {
+    const cooldownPoolId = new BN(21);
+    const COOLDOWN_PERIOD = 3 * 24 * 60 * 60;
+    let cooldownPoolPda: PublicKey;
+    let cooldownStakeVaultPda: PublicKey;
+    let cooldownRewardVaultPda: PublicKey;
+    let cooldownStakePda: PublicKey;
+
+    const unstakeAccounts = () => ({
+      user: user1.publicKey,
+      pool: cooldownPoolPda,
+      userStake: cooldownStakePda,
+      userStakeTokenAccount: user1StakeTokenAccount,
+      userRewardTokenAccount: user1RewardTokenAccount,
+      stakeVault: cooldownStakeVaultPda,
+      rewardVault: cooldownRewardVaultPda,
+      stakeMint: stakeMint,
+      rewardMint: rewardMint,
+      globalStats: globalStatsPda,
+      systemProgram: SystemProgram.programId,
+      tokenProgram: TOKEN_PROGRAM_ID,
+      associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+    });
+
+    before("Setup cooldown pool and stake", async () => {
+      console.log("\n=== Setting up Unbonding Cooldown Tests ===");
+
+      [cooldownPoolPda] = PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("pool"),
+          poolAuthority.publicKey.toBuffer(),
+          cooldownPoolId.toArrayLike(Buffer, "le", 8),
+        ],
+        program.programId
+      );
+      [cooldownStakeVaultPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("stake_vault"), cooldownPoolPda.toBuffer()],
+        program.programId
+      );
+      [cooldownRewardVaultPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("reward_vault"), cooldownPoolPda.toBuffer()],
+        program.programId
+      );
+      [cooldownStakePda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("stake"), cooldownPoolPda.toBuffer(), user1.publicKey.toBuffer()],
+        program.programId
+      );
+
+      await program.methods
+        .initializePool(cooldownPoolId, REWARD_RATE, LOCK_DURATION, EARLY_UNSTAKE_PENALTY_BPS, STAKING_START_TIME, STAKING_END_TIME, REWARD_DURATION, MIN_UPDATE_INTERVAL, false)
+        .accounts({
+          authority: poolAuthority.publicKey,
+          pool: cooldownPoolPda,
+          stakeMint: stakeMint,
+          rewardMint: rewardMint,
+          stakeVault: cooldownStakeVaultPda,
+          rewardVault: cooldownRewardVaultPda,
+          globalStats: globalStatsPda,
+          systemProgram: SystemProgram.programId,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+          rent: SYSVAR_RENT_PUBKEY,
+        })
+        .signers([poolAuthority])
+        .rpc();
+
+      await program.methods
+        .stake(STAKE_AMOUNT, new BN(0))
+        .accounts({
+          user: user1.publicKey,
+          pool: cooldownPoolPda,
+          userStake: cooldownStakePda,
+          userTokenAccount: user1StakeTokenAccount,
+          stakeVault: cooldownStakeVaultPda,
+          stakeMint: stakeMint,
+          globalStats: globalStatsPda,
+          systemProgram: SystemProgram.programId,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+          rent: SYSVAR_RENT_PUBKEY,
+        })
+        .signers([user1])
+        .rpc();
+    });
+
+    it("✅ Should set the pool's cooldown period", async () => {
+      await program.methods
+        .setCooldownPeriod(new BN(COOLDOWN_PERIOD))
+        .accounts({
+          authority: poolAuthority.publicKey,
+          pool: cooldownPoolPda,
+        })
+        .signers([poolAuthority])
+        .rpc();
+
+      const pool = await program.account.stakingPool.fetch(cooldownPoolPda);
+      assert.equal(pool.cooldownPeriod.toNumber(), COOLDOWN_PERIOD);
+    });
+
+    it("❌ Should reject a direct unstake from a cooldown pool", async () => {
+      try {
+        await program.methods
+          .unstake()
+          .accounts(unstakeAccounts())
+          .signers([user1])
+          .rpc();
+        assert.fail("Should have failed - pool requires a cooldown");
+      } catch (error) {
+        console.log(`✅ Correctly failed with error: ${error.message}`);
+        expect(error.message).to.include("CooldownRequired");
+      }
+    });
+
+    it("❌ Should reject requesting an unstake while the stake is locked", async () => {
+      try {
+        await program.methods
+          .requestUnstake()
+          .accounts({
+            user: user1.publicKey,
+            pool: cooldownPoolPda,
+            userStake: cooldownStakePda,
+          })
+          .signers([user1])
+          .rpc();
+        assert.fail("Should have failed - stake is still locked");
+      } catch (error) {
+        console.log(`✅ Correctly failed with error: ${error.message}`);
+        expect(error.message).to.include("StakeStillLocked");
+      }
+    });
+
+    it("❌ Should reject a premature withdrawal", async () => {
+      try {
+        await program.methods
+          .withdrawUnstaked()
+          .accounts(unstakeAccounts())
+          .signers([user1])
+          .rpc();
+        assert.fail("Should have failed - no cooldown has been started");
+      } catch (error) {
+        console.log(`✅ Correctly failed with error: ${error.message}`);
+        expect(error.message).to.include("NotUnbonding");
+      }
+
+      // The stake is untouched
+      const userStake = await program.account.userStake.fetch(cooldownStakePda);
+      assert.equal(userStake.amount.toString(), STAKE_AMOUNT.toString());
+      assert.equal(userStake.unbondingStart.toNumber(), 0);
+    });
+  });
+
+  describe("◎ Native SOL Reward Tests", () => {
+    const solPoolId = new BN(22);
+    let solPoolPda: PublicKey;
+    let solStakeVaultPda: PublicKey;
+    let solRewardVaultPda: PublicKey;
+    let rewardSolVaultPda: PublicKey;
+    let solStakePda: PublicKey;
+
+    before("Setup native SOL reward pool and stake", async () => {
+      console.log("\n=== Setting up Native SOL Reward Tests ===");
+
+      [solPoolPda] = PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("pool"),
+          poolAuthority.publicKey.toBuffer(),
+          solPoolId.toArrayLike(Buffer, "le", 8),
+        ],
+        program.programId
+      );
+      [solStakeVaultPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("stake_vault"), solPoolPda.toBuffer()],
+        program.programId
+      );
+      [solRewardVaultPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("reward_vault"), solPoolPda.toBuffer()],
+        program.programId
+      );
+      [rewardSolVaultPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("reward_sol_vault"), solPoolPda.toBuffer()],
+        program.programId
+      );
+      [solStakePda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("stake"), solPoolPda.toBuffer(), user1.publicKey.toBuffer()],
+        program.programId
+      );
+
+      await program.methods
+        .initializePool(solPoolId, REWARD_RATE, LOCK_DURATION, EARLY_UNSTAKE_PENALTY_BPS, STAKING_START_TIME, STAKING_END_TIME, REWARD_DURATION, MIN_UPDATE_INTERVAL, true)
+        .accounts({
+          authority: poolAuthority.publicKey,
+          pool: solPoolPda,
+          stakeMint: stakeMint,
+          rewardMint: rewardMint,
+          stakeVault: solStakeVaultPda,
+          rewardVault: solRewardVaultPda,
+          rewardSolVault: rewardSolVaultPda,
+          globalStats: globalStatsPda,
+          systemProgram: SystemProgram.programId,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+          rent: SYSVAR_RENT_PUBKEY,
+        })
+        .signers([poolAuthority])
+        .rpc();
+
+      // Reward lamports are sent straight to the vault address
+      await fundAccount(rewardSolVaultPda, 10 * LAMPORTS_PER_SOL);
+
+      await program.methods
+        .stake(STAKE_AMOUNT, new BN(0))
+        .accounts({
+          user: user1.publicKey,
+          pool: solPoolPda,
+          userStake: solStakePda,
+          userTokenAccount: user1StakeTokenAccount,
+          stakeVault: solStakeVaultPda,
+          stakeMint: stakeMint,
+          globalStats: globalStatsPda,
+          systemProgram: SystemProgram.programId,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+          rent: SYSVAR_RENT_PUBKEY,
+        })
+        .signers([user1])
+        .rpc();
+    });
+
+    it("✅ Should create a rent-exempt reward SOL vault for a native pool", async () => {
+      const pool = await program.account.stakingPool.fetch(solPoolPda);
+      assert.isTrue(pool.rewardIsNative);
+
+      const rentExemptMinimum = await connection.getMinimumBalanceForRentExemption(0);
+      const vaultBalance = await connection.getBalance(rewardSolVaultPda);
+      assert.isAtLeast(vaultBalance, rentExemptMinimum + 10 * LAMPORTS_PER_SOL);
+    });
+
+    it("✅ Should pay claimed rewards in native SOL", async () => {
+      await sleep(2);
+
+      const vaultBefore = await connection.getBalance(rewardSolVaultPda);
+      const userLamportsBefore = await connection.getBalance(user1.publicKey);
+      const userRewardTokensBefore = await getTokenBalance(user1RewardTokenAccount);
+
+      await program.methods
+        .claimRewards()
+        .accounts({
+          user: user1.publicKey,
+          pool: solPoolPda,
+          userStake: solStakePda,
+          userRewardTokenAccount: user1RewardTokenAccount,
+          rewardVault: solRewardVaultPda,
+          rewardSolVault: rewardSolVaultPda,
+          rewardMint: rewardMint,
+          globalStats: globalStatsPda,
+          systemProgram: SystemProgram.programId,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+        })
+        .signers([user1])
+        .rpc();
+
+      const paidOut = vaultBefore - (await connection.getBalance(rewardSolVaultPda));
+      const userLamportsAfter = await connection.getBalance(user1.publicKey);
+      console.log(`◎ Claimed ${paidOut} reward lamports`);
+
+      // Rewards leave the SOL vault as lamports, not reward tokens
+      assert.isAbove(paidOut, 0);
+      assert.isAbove(userLamportsAfter, userLamportsBefore);
+      assert.equal(await getTokenBalance(user1RewardTokenAccount), userRewardTokensBefore);
+    });
+  });
+
+  describe("🔮 Reward Simulation Tests", () => {
+    const simPoolId = new BN(23);
+    let simPoolPda: PublicKey;
+    let simStakePda: PublicKey;
+    const DAY = 24 * 60 * 60;
+    const RATE_PRECISION = new BN(1_000_000_000);
+
+    const simulateRewards = (seconds: number) =>
+      program.methods
+        .simulateRewards(new BN(seconds))
+        .accounts({
+          pool: simPoolPda,
+          userStake: simStakePda,
+        })
+        .view();
+
+    before("Setup pool and stake", async () => {
+      console.log("\n=== Setting up Reward Simulation Tests ===");
+
+      [simPoolPda] = PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("pool"),
+          poolAuthority.publicKey.toBuffer(),
+          simPoolId.toArrayLike(Buffer, "le", 8),
+        ],
+        program.programId
+      );
+      const [simStakeVaultPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("stake_vault"), simPoolPda.toBuffer()],
+        program.programId
+      );
+      const [simRewardVaultPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("reward_vault"), simPoolPda.toBuffer()],
+        program.programId
+      );
+      [simStakePda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("stake"), simPoolPda.toBuffer(), user1.publicKey.toBuffer()],
+        program.programId
+      );
+
+      await program.methods
+        .initializePool(simPoolId, REWARD_RATE, LOCK_DURATION, EARLY_UNSTAKE_PENALTY_BPS, STAKING_START_TIME, STAKING_END_TIME, REWARD_DURATION, MIN_UPDATE_INTERVAL, false)
+        .accounts({
+          authority: poolAuthority.publicKey,
+          pool: simPoolPda,
+          stakeMint: stakeMint,
+          rewardMint: rewardMint,
+          stakeVault: simStakeVaultPda,
+          rewardVault: simRewardVaultPda,
+          globalStats: globalStatsPda,
+          systemProgram: SystemProgram.programId,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+          rent: SYSVAR_RENT_PUBKEY,
+        })
+        .signers([poolAuthority])
+        .rpc();
+
+      await program.methods
+        .stake(STAKE_AMOUNT, new BN(0))
+        .accounts({
+          user: user1.publicKey,
+          pool: simPoolPda,
+          userStake: simStakePda,
+          userTokenAccount: user1StakeTokenAccount,
+          stakeVault: simStakeVaultPda,
+          stakeMint: stakeMint,
+          globalStats: globalStatsPda,
+          systemProgram: SystemProgram.programId,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+          rent: SYSVAR_RENT_PUBKEY,
+        })
+        .signers([user1])
+        .rpc();
+    });
+
+    it("✅ Should project rewards with the same formula as the program", async () => {
+      const stake = await program.account.userStake.fetch(simStakePda);
+      const poolBefore = await program.account.stakingPool.fetch(simPoolPda);
+
+      const projected = await simulateRewards(30 * DAY);
+
+      const expected = stake.weightedAmount
+        .mul(poolBefore.rewardRate)
+        .muln(30 * DAY)
+        .div(RATE_PRECISION);
+      assert.equal(projected.toString(), expected.toString());
+
+      // The dry run leaves the pool untouched
+      const poolAfter = await program.account.stakingPool.fetch(simPoolPda);
+      assert.equal(poolAfter.lastUpdateTime.toString(), poolBefore.lastUpdateTime.toString());
+      assert.equal(poolAfter.rewardPerTokenStored.toString(), poolBefore.rewardPerTokenStored.toString());
+    });
+
+    it("❌ Should reject a negative projection period", async () => {
+      try {
+        await simulateRewards(-DAY);
+        assert.fail("Should have failed with a negative period");
+      } catch (error) {
+        console.log(`✅ Correctly failed with error: ${error.message}`);
+        expect(error.message).to.include("InvalidTimestamp");
+      }
+    });
+  });
+
+  describe("⏱️ Claim Cooldown Tests", () => {
+    const claimPoolId = new BN(24);
+    const CLAIM_COOLDOWN = 3;
+    let claimPoolPda: PublicKey;
+    let claimRewardVaultPda: PublicKey;
+    let claimStakePda: PublicKey;
+
+    const claimRewards = () =>
+      program.methods
+        .claimRewards()
+        .accounts({
+          user: user1.publicKey,
+          pool: claimPoolPda,
+          userStake: claimStakePda,
+          userRewardTokenAccount: user1RewardTokenAccount,
+          rewardVault: claimRewardVaultPda,
+          rewardMint: rewardMint,
+          globalStats: globalStatsPda,
+          systemProgram: SystemProgram.programId,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+        })
+        .signers([user1])
+        .rpc();
+
+    before("Setup pool with a claim cooldown", async () => {
+      console.log("\n=== Setting up Claim Cooldown Tests ===");
+
+      [claimPoolPda] = PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("pool"),
+          poolAuthority.publicKey.toBuffer(),
+          claimPoolId.toArrayLike(Buffer, "le", 8),
+        ],
+        program.programId
+      );
+      const [claimStakeVaultPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("stake_vault"), claimPoolPda.toBuffer()],
+        program.programId
+      );
+      [claimRewardVaultPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("reward_vault"), claimPoolPda.toBuffer()],
+        program.programId
+      );
+      [claimStakePda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("stake"), claimPoolPda.toBuffer(), user1.publicKey.toBuffer()],
+        program.programId
+      );
+
+      await program.methods
+        .initializePool(claimPoolId, REWARD_RATE, LOCK_DURATION, EARLY_UNSTAKE_PENALTY_BPS, STAKING_START_TIME, STAKING_END_TIME, REWARD_DURATION, MIN_UPDATE_INTERVAL, false)
+        .accounts({
+          authority: poolAuthority.publicKey,
+          pool: claimPoolPda,
+          stakeMint: stakeMint,
+          rewardMint: rewardMint,
+          stakeVault: claimStakeVaultPda,
+          rewardVault: claimRewardVaultPda,
+          globalStats: globalStatsPda,
+          systemProgram: SystemProgram.programId,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+          rent: SYSVAR_RENT_PUBKEY,
+        })
+        .signers([poolAuthority])
+        .rpc();
+
+      // Fund the reward vault so claims can pay out
+      await mintTo(
+        connection,
+        wallet.payer,
+        rewardMint,
+        claimRewardVaultPda,
+        poolAuthority,
+        REWARD_VAULT_FUNDING.toNumber()
+      );
+
+      await program.methods
+        .setClaimCooldown(new BN(CLAIM_COOLDOWN))
+        .accounts({
+          authority: poolAuthority.publicKey,
+          pool: claimPoolPda,
+        })
+        .signers([poolAuthority])
+        .rpc();
+
+      await program.methods
+        .stake(STAKE_AMOUNT, new BN(0))
+        .accounts({
+          user: user1.publicKey,
+          pool: claimPoolPda,
+          userStake: claimStakePda,
+          userTokenAccount: user1StakeTokenAccount,
+          stakeVault: claimStakeVaultPda,
+          stakeMint: stakeMint,
+          globalStats: globalStatsPda,
+          systemProgram: SystemProgram.programId,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+          rent: SYSVAR_RENT_PUBKEY,
+        })
+        .signers([user1])
+        .rpc();
+    });
+
+    it("❌ Should reject a claim cooldown above the maximum", async () => {
+      try {
+        await program.methods
+          .setClaimCooldown(new BN(7 * 24 * 60 * 60 + 1))
+          .accounts({
+            authority: poolAuthority.publicKey,
+            pool: claimPoolPda,
+          })
+          .signers([poolAuthority])
+          .rpc();
+        assert.fail("Should have failed with an out-of-range cooldown");
+      } catch (error) {
+        console.log(`✅ Correctly failed with error: ${error.message}`);
+        expect(error.message).to.include("InvalidClaimCooldown");
+      }
+    });
+
+    it("❌ Should reject a second claim inside the cooldown", async () => {
+      // The first claim on a stake is never held back
+      await claimRewards();
+      const stake = await program.account.userStake.fetch(claimStakePda);
+      assert.isTrue(stake.lastClaimTime.toNumber() > 0);
+
+      try {
+        await claimRewards();
+        assert.fail("Should have failed with ClaimTooSoon");
+      } catch (error) {
+        console.log(`✅ Correctly failed with error: ${error.message}`);
+        expect(error.message).to.include("ClaimTooSoon");
+      }
+    });
+
+    it("✅ Should allow a claim once the cooldown has passed", async () => {
+      const stakeBefore = await program.account.userStake.fetch(claimStakePda);
+      const balanceBefore = await getTokenBalance(user1RewardTokenAccount);
+      await sleep(CLAIM_COOLDOWN + 1);
+
+      await claimRewards();
+
+      const stakeAfter = await program.account.userStake.fetch(claimStakePda);
+      assert.isTrue(stakeAfter.lastClaimTime.gt(stakeBefore.lastClaimTime));
+      assert.isTrue((await getTokenBalance(user1RewardTokenAccount)) > balanceBefore);
+    });
+  });
+
   /**
    * TEST SUITE 5: UNSTAKING OPERATIONS (Time-sensitive)
    * Tests the unstake instruction - requires lock period to pass
@@ -992,6 +4466,7 @@ describe("🏦 Staking dApp - Simplified Test Suite", () => {
             rewardVault: rewardVaultPda,
             stakeMint: stakeMint,
             rewardMint: rewardMint,
+            globalStats: globalStatsPda,
             systemProgram: SystemProgram.programId,
             tokenProgram: TOKEN_PROGRAM_ID,
             associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,